@@ -0,0 +1,204 @@
+//! Legacy Amino JSON encoding of the Althea and Canto messages, see cosmos_sdk_proto::amino for how to produce sign
+//! bytes. Call [register_amino_types] before encoding Cosmos SDK messages which may contain these messages in an Any,
+//! like authz MsgExec.
+
+use crate::althea::microtx::v1::{MsgLiquify, MsgMicrotx};
+use crate::canto::erc20::v1::{MsgConvertCoin, MsgConvertErc20};
+use crate::canto::vesting::v1::{MsgClawback, MsgCreateClawbackVestingAccount};
+use cosmos_sdk_proto::amino::{register, AminoError, AminoJson, AminoMsg, AminoObject};
+use cosmos_sdk_proto::cosmos::vesting::v1beta1::Period;
+use serde_json::Value;
+
+macro_rules! amino_msg {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            impl AminoMsg for $ty {
+                fn amino_type(&self) -> &'static str {
+                    $name
+                }
+            }
+        )*
+
+        /// Registers the Althea types which may appear inside an Any with cosmos_sdk_proto::amino
+        pub fn register_amino_types() {
+            $(register::<$ty>();)*
+        }
+    };
+}
+
+amino_msg!(
+    MsgMicrotx => "microtx/MsgMicrotx",
+    MsgLiquify => "microtx/MsgLiquify",
+    MsgConvertCoin => "canto/MsgConvertCoin",
+    MsgConvertErc20 => "canto/MsgConvertERC20",
+    MsgCreateClawbackVestingAccount => "canto/MsgCreateClawbackVestingAccount",
+    MsgClawback => "canto/MsgClawback",
+);
+
+impl AminoJson for MsgMicrotx {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("sender", &self.sender)
+            .string("receiver", &self.receiver)
+            .message("amount", &self.amount)?
+            .finish())
+    }
+}
+
+impl AminoJson for MsgLiquify {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new().string("sender", &self.sender).finish())
+    }
+}
+
+impl AminoJson for MsgConvertCoin {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .message("coin", &self.coin)?
+            .string("receiver", &self.receiver)
+            .string("sender", &self.sender)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgConvertErc20 {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("contract_address", &self.contract_address)
+            .string("amount", &self.amount)
+            .string("receiver", &self.receiver)
+            .string("sender", &self.sender)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgCreateClawbackVestingAccount {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        let object = AminoObject::new()
+            .string("from_address", &self.from_address)
+            .string("to_address", &self.to_address)
+            .timestamp("start_time", &self.start_time);
+        let object = periods(object, "lockup_periods", &self.lockup_periods);
+        let object = periods(object, "vesting_periods", &self.vesting_periods);
+        Ok(object.boolean("merge", self.merge).finish())
+    }
+}
+
+impl AminoJson for MsgClawback {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("funder_address", &self.funder_address)
+            .string("account_address", &self.account_address)
+            .string("dest_address", &self.dest_address)
+            .finish())
+    }
+}
+
+/// Adds a list of SDK vesting periods. Period has no AminoJson impl in cosmos_sdk_proto::amino as none of the SDK
+/// messages registered there contain it
+fn periods(object: AminoObject, key: &str, periods: &[Period]) -> AminoObject {
+    if periods.is_empty() {
+        return object;
+    }
+    let periods = periods
+        .iter()
+        .map(|p| {
+            AminoObject::new()
+                .int64("length", p.length)
+                .coins("amount", &p.amount)
+                .finish()
+        })
+        .collect();
+    object.insert(key, Value::Array(periods))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::amino::StdSignDoc;
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExec;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::tx::v1beta1::Fee;
+    use prost_types::{Any, Timestamp};
+
+    const SENDER: &str = "althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72";
+    const RECEIVER: &str = "althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg";
+
+    fn coin(amount: &str) -> Coin {
+        Coin {
+            amount: amount.to_string(),
+            denom: "aalthea".to_string(),
+        }
+    }
+
+    fn sign_bytes(msg: &dyn AminoMsg) -> String {
+        let fee = Fee {
+            amount: vec![coin("2000")],
+            gas_limit: 100000,
+            payer: String::new(),
+            granter: String::new(),
+        };
+        let doc =
+            StdSignDoc::new("althea_417834-4".into(), 5, 0, fee, "memo".into(), &[msg]).unwrap();
+        String::from_utf8(doc.sign_bytes()).unwrap()
+    }
+
+    #[test]
+    fn msg_microtx() {
+        let msg = MsgMicrotx {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            amount: Some(coin("1000")),
+        };
+        assert_eq!(
+            sign_bytes(&msg),
+            format!(
+                r#"{{"account_number":"5","chain_id":"althea_417834-4","fee":{{"amount":[{{"amount":"2000","denom":"aalthea"}}],"gas":"100000"}},"memo":"memo","msgs":[{{"type":"microtx/MsgMicrotx","value":{{"amount":{{"amount":"1000","denom":"aalthea"}},"receiver":"{RECEIVER}","sender":"{SENDER}"}}}}],"sequence":"0"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn clawback_vesting_account_periods() {
+        let period = |length, amount: &str| Period {
+            length,
+            amount: vec![coin(amount)],
+        };
+        let msg = MsgCreateClawbackVestingAccount {
+            from_address: SENDER.to_string(),
+            to_address: RECEIVER.to_string(),
+            // 2021-01-01T00:00:20.5Z
+            start_time: Some(Timestamp {
+                seconds: 1_609_459_220,
+                nanos: 500_000_000,
+            }),
+            lockup_periods: vec![period(3600, "100")],
+            vesting_periods: vec![period(0, "40"), period(86400, "60")],
+            merge: false,
+        };
+        assert_eq!(
+            sign_bytes(&msg),
+            format!(
+                r#"{{"account_number":"5","chain_id":"althea_417834-4","fee":{{"amount":[{{"amount":"2000","denom":"aalthea"}}],"gas":"100000"}},"memo":"memo","msgs":[{{"type":"canto/MsgCreateClawbackVestingAccount","value":{{"from_address":"{SENDER}","lockup_periods":[{{"amount":[{{"amount":"100","denom":"aalthea"}}],"length":"3600"}}],"start_time":"2021-01-01T00:00:20.5Z","to_address":"{RECEIVER}","vesting_periods":[{{"amount":[{{"amount":"40","denom":"aalthea"}}]}},{{"amount":[{{"amount":"60","denom":"aalthea"}}],"length":"86400"}}]}}}}],"sequence":"0"}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn registered_messages_inside_msg_exec() {
+        register_amino_types();
+        let exec = MsgExec {
+            grantee: RECEIVER.to_string(),
+            msgs: vec![Any::from_msg(&MsgLiquify {
+                sender: SENDER.to_string(),
+            })
+            .unwrap()],
+        };
+        assert_eq!(
+            sign_bytes(&exec),
+            format!(
+                r#"{{"account_number":"5","chain_id":"althea_417834-4","fee":{{"amount":[{{"amount":"2000","denom":"aalthea"}}],"gas":"100000"}},"memo":"memo","msgs":[{{"type":"cosmos-sdk/MsgExec","value":{{"grantee":"{RECEIVER}","msgs":[{{"type":"microtx/MsgLiquify","value":{{"sender":"{SENDER}"}}}}]}}}}],"sequence":"0"}}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,536 @@
+//! EIP-712 typed data for Cosmos transactions, allowing Ethereum wallets like MetaMask to sign Cosmos messages with
+//! ethermint.crypto.v1.ethsecp256k1 keys. The typed data wraps the Amino JSON StdSignDoc in the same way Ethermint
+//! does when verifying the signature, so every message with an Amino JSON encoding can be signed this way: the Cosmos
+//! SDK messages in cosmos_sdk_proto::amino and the Althea and Canto messages in [crate::amino].
+//!
+//! The message types are derived from the JSON payload, each message of the StdSignDoc becomes a `msg{index}` field
+//! of the `Tx` primary type. The resulting signature is submitted in the signatures of the transaction, or for the
+//! legacy flow in an [ExtensionOptionsWeb3Tx] built with [web3_extension_option].
+
+use crate::ethermint::types::v1::ExtensionOptionsWeb3Tx;
+use cosmos_sdk_proto::amino::{AminoError, StdSignDoc};
+use serde_json::{json, Map, Value};
+use sha3::{Digest, Keccak256};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Name of the EIP-712 domain used by Ethermint
+pub const DOMAIN_NAME: &str = "Cosmos Web3";
+/// Version of the EIP-712 domain used by Ethermint
+pub const DOMAIN_VERSION: &str = "1.0.0";
+/// Ethermint does not verify against a contract, this fixed value is used instead
+pub const DOMAIN_VERIFYING_CONTRACT: &str = "cosmos";
+pub const DOMAIN_SALT: &str = "0";
+/// The primary type of the typed data
+pub const PRIMARY_TYPE: &str = "Tx";
+
+/// Prefix of the generated type names, and the name of the root prefix it replaces
+const TYPE_PREFIX: &str = "Type";
+const ROOT_PREFIX: &str = "_";
+/// Limit on the number of distinct type definitions which map to the same name
+const MAX_DUPLICATE_TYPE_DEFS: usize = 1000;
+
+#[derive(Debug)]
+pub enum Eip712Error {
+    /// The chain id is not of the Ethermint form `{identifier}_{EIP155 chain id}-{epoch}`
+    InvalidChainId(String),
+    /// A message could not be encoded as Amino JSON
+    Amino(AminoError),
+    /// The payload does not match the types, or contains a message without an amino type
+    InvalidPayload(String),
+}
+
+impl fmt::Display for Eip712Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Eip712Error::InvalidChainId(id) => write!(f, "Invalid Ethermint chain id {id}"),
+            Eip712Error::Amino(e) => write!(f, "Amino JSON encoding failed: {e}"),
+            Eip712Error::InvalidPayload(e) => write!(f, "Invalid EIP-712 payload: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Eip712Error {}
+
+impl From<AminoError> for Eip712Error {
+    fn from(e: AminoError) -> Self {
+        Eip712Error::Amino(e)
+    }
+}
+
+/// A field of an EIP-712 struct type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedDataField {
+    pub name: String,
+    pub r#type: String,
+}
+
+impl TypedDataField {
+    fn new(name: &str, r#type: &str) -> Self {
+        TypedDataField {
+            name: name.to_string(),
+            r#type: r#type.to_string(),
+        }
+    }
+}
+
+/// EIP-712 typed data, as passed to eth_signTypedData_v4
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedData {
+    pub types: BTreeMap<String, Vec<TypedDataField>>,
+    pub primary_type: String,
+    pub domain: Value,
+    pub message: Value,
+}
+
+impl TypedData {
+    /// Wraps a StdSignDoc in typed data, the chain id of the sign doc must be an Ethermint chain id since it
+    /// provides the EIP-155 chain id of the domain
+    pub fn from_sign_doc(sign_doc: &StdSignDoc) -> Result<Self, Eip712Error> {
+        let chain_id = parse_chain_id(&sign_doc.chain_id)?;
+        Self::from_payload(chain_id, sign_doc.to_json())
+    }
+
+    /// Wraps the JSON representation of a StdSignDoc in typed data
+    pub fn from_payload(eip155_chain_id: u64, payload: Value) -> Result<Self, Eip712Error> {
+        let mut message = match payload {
+            Value::Object(map) => map,
+            _ => return Err(invalid("sign doc is not an object")),
+        };
+        let msgs = match message.remove("msgs") {
+            Some(Value::Array(msgs)) => msgs,
+            _ => return Err(invalid("sign doc has no msgs")),
+        };
+
+        let mut types = root_types();
+        for (i, msg) in msgs.into_iter().enumerate() {
+            let field = format!("msg{i}");
+            let root_type = msg_root_type(&msg)?;
+            let type_def = add_types(&mut types, &root_type, ROOT_PREFIX, &msg)?;
+            types
+                .get_mut(PRIMARY_TYPE)
+                .unwrap()
+                .push(TypedDataField::new(&field, &type_def));
+            message.insert(field, msg);
+        }
+
+        Ok(TypedData {
+            types,
+            primary_type: PRIMARY_TYPE.to_string(),
+            domain: json!({
+                "name": DOMAIN_NAME,
+                "version": DOMAIN_VERSION,
+                "chainId": eip155_chain_id,
+                "verifyingContract": DOMAIN_VERIFYING_CONTRACT,
+                "salt": DOMAIN_SALT,
+            }),
+            message: Value::Object(message),
+        })
+    }
+
+    /// Returns the typed data in the JSON form expected by eth_signTypedData_v4
+    pub fn to_json(&self) -> Value {
+        let types: Map<String, Value> = self
+            .types
+            .iter()
+            .map(|(name, fields)| {
+                let fields = fields
+                    .iter()
+                    .map(|f| json!({"name": f.name, "type": f.r#type}))
+                    .collect();
+                (name.clone(), Value::Array(fields))
+            })
+            .collect();
+        json!({
+            "types": types,
+            "primaryType": self.primary_type,
+            "domain": self.domain,
+            "message": self.message,
+        })
+    }
+
+    /// The EIP-712 domain separator
+    pub fn domain_separator(&self) -> Result<[u8; 32], Eip712Error> {
+        self.hash_struct("EIP712Domain", &self.domain)
+    }
+
+    /// The hash to sign, keccak256(0x19 0x01 || domainSeparator || hashStruct(message))
+    pub fn signing_hash(&self) -> Result<[u8; 32], Eip712Error> {
+        let mut hasher = Keccak256::new();
+        hasher.update([0x19, 0x01]);
+        hasher.update(self.domain_separator()?);
+        hasher.update(self.hash_struct(&self.primary_type, &self.message)?);
+        Ok(hasher.finalize().into())
+    }
+
+    /// Returns hashStruct(value) for the given type
+    pub fn hash_struct(&self, type_name: &str, value: &Value) -> Result<[u8; 32], Eip712Error> {
+        let mut hasher = Keccak256::new();
+        hasher.update(keccak(self.encode_type(type_name)?.as_bytes()));
+        hasher.update(self.encode_data(type_name, value)?);
+        Ok(hasher.finalize().into())
+    }
+
+    /// Returns encodeType for the given type, e.g. "Coin(string denom,string amount)"
+    pub fn encode_type(&self, type_name: &str) -> Result<String, Eip712Error> {
+        if !self.types.contains_key(type_name) {
+            return Err(invalid(&format!("unknown type {type_name}")));
+        }
+        let mut dependencies = Vec::new();
+        self.find_dependencies(type_name, &mut dependencies);
+        // the primary type comes first, followed by the referenced types sorted by name
+        dependencies.retain(|d| d != type_name);
+        dependencies.sort();
+        dependencies.insert(0, type_name.to_string());
+
+        let mut encoded = String::new();
+        for dependency in dependencies {
+            let fields = self.types[&dependency]
+                .iter()
+                .map(|f| format!("{} {}", f.r#type, f.name))
+                .collect::<Vec<_>>();
+            encoded.push_str(&format!("{}({})", dependency, fields.join(",")));
+        }
+        Ok(encoded)
+    }
+
+    fn find_dependencies(&self, type_name: &str, found: &mut Vec<String>) {
+        let type_name = type_name.trim_end_matches("[]");
+        if found.iter().any(|f| f == type_name) {
+            return;
+        }
+        if let Some(fields) = self.types.get(type_name) {
+            found.push(type_name.to_string());
+            for field in fields {
+                self.find_dependencies(&field.r#type, found);
+            }
+        }
+    }
+
+    fn encode_data(&self, type_name: &str, value: &Value) -> Result<Vec<u8>, Eip712Error> {
+        let fields = self
+            .types
+            .get(type_name)
+            .ok_or_else(|| invalid(&format!("unknown type {type_name}")))?;
+        let mut encoded = Vec::with_capacity(32 * fields.len());
+        for field in fields {
+            let field_value = value.get(&field.name).ok_or_else(|| {
+                invalid(&format!("missing field {} of {}", field.name, type_name))
+            })?;
+            encoded.extend_from_slice(&self.encode_value(&field.r#type, field_value)?);
+        }
+        Ok(encoded)
+    }
+
+    fn encode_value(&self, type_name: &str, value: &Value) -> Result<[u8; 32], Eip712Error> {
+        if let Some(element_type) = type_name.strip_suffix("[]") {
+            let elements = value
+                .as_array()
+                .ok_or_else(|| invalid(&format!("expected an array for {type_name}")))?;
+            let mut encoded = Vec::with_capacity(32 * elements.len());
+            for element in elements {
+                encoded.extend_from_slice(&self.encode_value(element_type, element)?);
+            }
+            return Ok(keccak(&encoded));
+        }
+        if self.types.contains_key(type_name) {
+            return self.hash_struct(type_name, value);
+        }
+        match (type_name, value) {
+            ("string", Value::String(s)) => Ok(keccak(s.as_bytes())),
+            ("bool", Value::Bool(b)) => Ok(encode_u64(*b as u64)),
+            (t, Value::Number(n)) if t.starts_with("int") => n
+                .as_i64()
+                .map(encode_i64)
+                .ok_or_else(|| invalid(&format!("{n} is not an {t}"))),
+            (t, Value::Number(n)) if t.starts_with("uint") => n
+                .as_u64()
+                .map(encode_u64)
+                .ok_or_else(|| invalid(&format!("{n} is not a {t}"))),
+            (t, v) => Err(invalid(&format!("{v} is not a {t}"))),
+        }
+    }
+}
+
+/// Parses the EIP-155 chain id from an Ethermint chain id like "althea_258432-1"
+pub fn parse_chain_id(chain_id: &str) -> Result<u64, Eip712Error> {
+    let err = || Eip712Error::InvalidChainId(chain_id.to_string());
+    let (identifier, rest) = chain_id.split_once('_').ok_or_else(err)?;
+    let (eip155, epoch) = rest.split_once('-').ok_or_else(err)?;
+    let is_number =
+        |s: &str| !s.is_empty() && !s.starts_with('0') && s.bytes().all(|b| b.is_ascii_digit());
+    if identifier.is_empty()
+        || !identifier.bytes().all(|b| b.is_ascii_lowercase())
+        || !is_number(eip155)
+        || !is_number(epoch)
+    {
+        return Err(err());
+    }
+    eip155.parse().map_err(|_| err())
+}
+
+/// Creates the ExtensionOptionsWeb3Tx extension option for a transaction whose fee payer signed the typed data
+pub fn web3_extension_option(
+    eip155_chain_id: u64,
+    fee_payer: String,
+    fee_payer_sig: Vec<u8>,
+) -> Result<prost_types::Any, prost::EncodeError> {
+    prost_types::Any::from_msg(&ExtensionOptionsWeb3Tx {
+        typed_data_chain_id: eip155_chain_id,
+        fee_payer,
+        fee_payer_sig,
+    })
+}
+
+/// The types which do not depend on the messages
+fn root_types() -> BTreeMap<String, Vec<TypedDataField>> {
+    let mut types = BTreeMap::new();
+    types.insert(
+        "EIP712Domain".to_string(),
+        vec![
+            TypedDataField::new("name", "string"),
+            TypedDataField::new("version", "string"),
+            TypedDataField::new("chainId", "uint256"),
+            TypedDataField::new("verifyingContract", "string"),
+            TypedDataField::new("salt", "string"),
+        ],
+    );
+    types.insert(
+        PRIMARY_TYPE.to_string(),
+        vec![
+            TypedDataField::new("account_number", "string"),
+            TypedDataField::new("chain_id", "string"),
+            TypedDataField::new("fee", "Fee"),
+            TypedDataField::new("memo", "string"),
+            TypedDataField::new("sequence", "string"),
+        ],
+    );
+    types.insert(
+        "Fee".to_string(),
+        vec![
+            TypedDataField::new("amount", "Coin[]"),
+            TypedDataField::new("gas", "string"),
+        ],
+    );
+    types.insert(
+        "Coin".to_string(),
+        vec![
+            TypedDataField::new("denom", "string"),
+            TypedDataField::new("amount", "string"),
+        ],
+    );
+    types
+}
+
+/// The root type name of a message, e.g. "cosmos-sdk/MsgSend" becomes "TypeMsgSend"
+fn msg_root_type(msg: &Value) -> Result<String, Eip712Error> {
+    match msg.get("type") {
+        Some(Value::String(t)) if !t.is_empty() => {
+            Ok(format!("{TYPE_PREFIX}{}", t.rsplit('/').next().unwrap()))
+        }
+        _ => Err(invalid("message has no amino type")),
+    }
+}
+
+/// Adds the types of the given JSON object and its nested objects, returning the name of its type. Fields are sorted
+/// by name, and identical definitions reuse the same type name while different ones get an increasing index
+fn add_types(
+    types: &mut BTreeMap<String, Vec<TypedDataField>>,
+    root_type: &str,
+    prefix: &str,
+    payload: &Value,
+) -> Result<String, Eip712Error> {
+    let object = payload
+        .as_object()
+        .ok_or_else(|| invalid("message is not an object"))?;
+    let mut names: Vec<&String> = object.keys().collect();
+    names.sort();
+
+    let mut fields = Vec::new();
+    for name in names {
+        let mut field = &object[name];
+        let mut is_collection = false;
+        if let Value::Array(elements) = field {
+            match elements.first() {
+                // the element type of an empty array is unknown
+                None => {
+                    fields.push(TypedDataField::new(name, "string[]"));
+                    continue;
+                }
+                Some(first) => {
+                    field = first;
+                    is_collection = true;
+                }
+            }
+        }
+        let suffix = if is_collection { "[]" } else { "" };
+        let eth_type = match field {
+            Value::Bool(_) => "bool".to_string(),
+            Value::Number(_) => "int64".to_string(),
+            Value::String(_) => "string".to_string(),
+            Value::Object(_) => {
+                let field_prefix = format!("{prefix}.{name}");
+                add_types(types, root_type, &field_prefix, field)?
+            }
+            // null and nested arrays have no EIP-712 representation
+            _ => continue,
+        };
+        fields.push(TypedDataField::new(name, &format!("{eth_type}{suffix}")));
+    }
+
+    let type_def = if prefix == ROOT_PREFIX {
+        root_type.to_string()
+    } else {
+        sanitize_type_def(prefix)
+    };
+    for index in 0..MAX_DUPLICATE_TYPE_DEFS {
+        let indexed = format!("{type_def}{index}");
+        match types.get(&indexed) {
+            Some(existing) if *existing == fields => return Ok(indexed),
+            Some(_) => continue,
+            None => {
+                types.insert(indexed.clone(), fields);
+                return Ok(indexed);
+            }
+        }
+    }
+    Err(invalid(&format!(
+        "too many distinct definitions of {type_def}"
+    )))
+}
+
+/// Converts a field path into a type name, e.g. "_.value.bridge_fee" becomes "TypeValueBridgeFee"
+fn sanitize_type_def(path: &str) -> String {
+    let mut type_def = String::new();
+    for part in path.split('.') {
+        if part == ROOT_PREFIX {
+            type_def.push_str(TYPE_PREFIX);
+            continue;
+        }
+        for word in part.split('_') {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                type_def.extend(first.to_uppercase());
+                type_def.push_str(chars.as_str());
+            }
+        }
+    }
+    type_def
+}
+
+fn invalid(reason: &str) -> Eip712Error {
+    Eip712Error::InvalidPayload(reason.to_string())
+}
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+fn encode_u64(value: u64) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[24..].copy_from_slice(&value.to_be_bytes());
+    encoded
+}
+
+/// Two's complement encoding sign extended to 256 bits
+fn encode_i64(value: i64) -> [u8; 32] {
+    let mut encoded = if value < 0 { [0xff; 32] } else { [0u8; 32] };
+    encoded[24..].copy_from_slice(&value.to_be_bytes());
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::MsgDelegate;
+    use cosmos_sdk_proto::cosmos::tx::v1beta1::Fee;
+
+    const SENDER: &str = "althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72";
+
+    // The typed data Ethermint's WrapTxToTypedData produces for the sign docs below, written out by hand from its type
+    // derivation rules, and the signing hashes of that JSON computed by a separate EIP-712 implementation
+    const MSG_SEND_TYPED_DATA: &str = r#"{"domain":{"chainId":417834,"name":"Cosmos Web3","salt":"0","verifyingContract":"cosmos","version":"1.0.0"},"message":{"account_number":"5","chain_id":"althea_417834-4","fee":{"amount":[{"amount":"2000","denom":"aalthea"}],"gas":"200000"},"memo":"","msg0":{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"1000","denom":"aalthea"}],"from_address":"althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72","to_address":"althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg"}},"sequence":"1"},"primaryType":"Tx","types":{"Coin":[{"name":"denom","type":"string"},{"name":"amount","type":"string"}],"EIP712Domain":[{"name":"name","type":"string"},{"name":"version","type":"string"},{"name":"chainId","type":"uint256"},{"name":"verifyingContract","type":"string"},{"name":"salt","type":"string"}],"Fee":[{"name":"amount","type":"Coin[]"},{"name":"gas","type":"string"}],"Tx":[{"name":"account_number","type":"string"},{"name":"chain_id","type":"string"},{"name":"fee","type":"Fee"},{"name":"memo","type":"string"},{"name":"sequence","type":"string"},{"name":"msg0","type":"TypeMsgSend0"}],"TypeMsgSend0":[{"name":"type","type":"string"},{"name":"value","type":"TypeValue0"}],"TypeValue0":[{"name":"amount","type":"TypeValueAmount0[]"},{"name":"from_address","type":"string"},{"name":"to_address","type":"string"}],"TypeValueAmount0":[{"name":"amount","type":"string"},{"name":"denom","type":"string"}]}}"#;
+    const MSG_SEND_HASH: &str = "d93c160527c26860f2ade9b57b902096bc054eea46da9359eb51c425fa20bc2f";
+    const MULTI_MSG_TYPED_DATA: &str = r#"{"domain":{"chainId":417834,"name":"Cosmos Web3","salt":"0","verifyingContract":"cosmos","version":"1.0.0"},"message":{"account_number":"5","chain_id":"althea_417834-4","fee":{"amount":[{"amount":"2000","denom":"aalthea"}],"gas":"200000"},"memo":"memo","msg0":{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"1000","denom":"aalthea"}],"from_address":"althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72","to_address":"althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg"}},"msg1":{"type":"cosmos-sdk/MsgDelegate","value":{"amount":{"amount":"500","denom":"aalthea"},"delegator_address":"althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72","validator_address":"altheavaloper1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg33lpzq0"}},"sequence":"1"},"primaryType":"Tx","types":{"Coin":[{"name":"denom","type":"string"},{"name":"amount","type":"string"}],"EIP712Domain":[{"name":"name","type":"string"},{"name":"version","type":"string"},{"name":"chainId","type":"uint256"},{"name":"verifyingContract","type":"string"},{"name":"salt","type":"string"}],"Fee":[{"name":"amount","type":"Coin[]"},{"name":"gas","type":"string"}],"Tx":[{"name":"account_number","type":"string"},{"name":"chain_id","type":"string"},{"name":"fee","type":"Fee"},{"name":"memo","type":"string"},{"name":"sequence","type":"string"},{"name":"msg0","type":"TypeMsgSend0"},{"name":"msg1","type":"TypeMsgDelegate0"}],"TypeMsgDelegate0":[{"name":"type","type":"string"},{"name":"value","type":"TypeValue1"}],"TypeMsgSend0":[{"name":"type","type":"string"},{"name":"value","type":"TypeValue0"}],"TypeValue0":[{"name":"amount","type":"TypeValueAmount0[]"},{"name":"from_address","type":"string"},{"name":"to_address","type":"string"}],"TypeValue1":[{"name":"amount","type":"TypeValueAmount0"},{"name":"delegator_address","type":"string"},{"name":"validator_address","type":"string"}],"TypeValueAmount0":[{"name":"amount","type":"string"},{"name":"denom","type":"string"}]}}"#;
+    const MULTI_MSG_HASH: &str = "7f0b5f6e433247038b44383f410f5a5fdae1e676959f72b0e76bfc064848c684";
+
+    fn coin(amount: &str) -> Coin {
+        Coin {
+            denom: "aalthea".to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    fn sign_doc(memo: &str, msgs: &[&dyn cosmos_sdk_proto::amino::AminoMsg]) -> StdSignDoc {
+        let fee = Fee {
+            amount: vec![coin("2000")],
+            gas_limit: 200000,
+            payer: String::new(),
+            granter: String::new(),
+        };
+        StdSignDoc::new("althea_417834-4".into(), 5, 1, fee, memo.into(), msgs).unwrap()
+    }
+
+    fn msg_send() -> MsgSend {
+        MsgSend {
+            from_address: SENDER.to_string(),
+            to_address: "althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg".to_string(),
+            amount: vec![coin("1000")],
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn msg_send_typed_data() {
+        let typed_data = TypedData::from_sign_doc(&sign_doc("", &[&msg_send()])).unwrap();
+        let expected: Value = serde_json::from_str(MSG_SEND_TYPED_DATA).unwrap();
+        assert_eq!(typed_data.to_json(), expected);
+        assert_eq!(hex(&typed_data.signing_hash().unwrap()), MSG_SEND_HASH);
+    }
+
+    #[test]
+    fn multi_message_typed_data() {
+        let delegate = MsgDelegate {
+            delegator_address: SENDER.to_string(),
+            validator_address: "altheavaloper1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg33lpzq0".to_string(),
+            amount: Some(coin("500")),
+        };
+        let typed_data =
+            TypedData::from_sign_doc(&sign_doc("memo", &[&msg_send(), &delegate])).unwrap();
+        let expected: Value = serde_json::from_str(MULTI_MSG_TYPED_DATA).unwrap();
+        assert_eq!(typed_data.to_json(), expected);
+        // MsgDelegate's coin reuses the TypeValueAmount0 of MsgSend's coins, the differing values are indexed
+        assert_eq!(
+            typed_data.encode_type("Tx").unwrap(),
+            "Tx(string account_number,string chain_id,Fee fee,string memo,string sequence,TypeMsgSend0 msg0,\
+             TypeMsgDelegate0 msg1)Coin(string denom,string amount)Fee(Coin[] amount,string gas)\
+             TypeMsgDelegate0(string type,TypeValue1 value)TypeMsgSend0(string type,TypeValue0 value)\
+             TypeValue0(TypeValueAmount0[] amount,string from_address,string to_address)\
+             TypeValue1(TypeValueAmount0 amount,string delegator_address,string validator_address)\
+             TypeValueAmount0(string amount,string denom)"
+        );
+        assert_eq!(hex(&typed_data.signing_hash().unwrap()), MULTI_MSG_HASH);
+    }
+
+    #[test]
+    fn chain_ids() {
+        assert_eq!(parse_chain_id("althea_417834-4").unwrap(), 417834);
+        for invalid in [
+            "althea-417834",
+            "althea_417834",
+            "Althea_1-1",
+            "althea_01-1",
+            "_1-1",
+        ] {
+            assert!(matches!(
+                parse_chain_id(invalid),
+                Err(Eip712Error::InvalidChainId(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Typed decoding of the events emitted by the microtx module, see cosmos_sdk_proto::abci_event
+
+use crate::althea::microtx::v1::*;
+use cosmos_sdk_proto::abci_event::{decode_event, EventAttributes, EventError};
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::typed_event;
+use prost::Name;
+
+macro_rules! microtx_events {
+    ($($variant:ident($ty:ident { $($field:ident),* $(,)? })),* $(,)?) => {
+        typed_event!($($ty { $($field),* }),*);
+
+        /// Any of the typed events emitted by the microtx module. The untyped events the module emits alongside
+        /// them, such as the "message" event naming the module and sender, carry no typed message and are not mapped
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum MicrotxEvent {
+            $($variant($ty),)*
+        }
+
+        impl MicrotxEvent {
+            /// Decodes the event if it is one of the microtx module's typed events, otherwise returns None
+            pub fn decode(event: &EventAttributes) -> Result<Option<Self>, EventError> {
+                $(
+                    if event.r#type == $ty::full_name() {
+                        return decode_event::<$ty>(event).map(|e| Some(MicrotxEvent::$variant(e)));
+                    }
+                )*
+                Ok(None)
+            }
+        }
+    };
+}
+
+microtx_events!(
+    Microtx(EventMicrotx {
+        sender,
+        receiver,
+        amounts
+    }),
+    FeeCollected(EventMicrotxFeeCollected { sender, fee }),
+    BalanceRedirect(EventBalanceRedirect { account, amount }),
+    AccountLiquified(EventAccountLiquified { owned, nft_address }),
+);
+
+impl MicrotxEvent {
+    /// Decodes every microtx event emitted by a transaction, skipping the events of other modules
+    pub fn from_tx_response(tx_response: &TxResponse) -> Result<Vec<Self>, EventError> {
+        let mut events = Vec::new();
+        for event in cosmos_sdk_proto::abci_event::tx_response_events(tx_response) {
+            events.extend(MicrotxEvent::decode(&event)?);
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::abci::v1beta1::{AbciMessageLog, Attribute, StringEvent};
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::tendermint::abci::{Event, EventAttribute};
+
+    const SENDER: &str = "althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72";
+    const RECEIVER: &str = "althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg";
+
+    fn event(r#type: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            r#type: r#type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| EventAttribute {
+                    key: k.as_bytes().to_vec(),
+                    value: v.as_bytes().to_vec(),
+                    index: true,
+                })
+                .collect(),
+        }
+    }
+
+    fn coin(amount: &str, denom: &str) -> Coin {
+        Coin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    fn microtx() -> MicrotxEvent {
+        MicrotxEvent::Microtx(EventMicrotx {
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+            amounts: vec![coin("100", "aalthea"), coin("5", "uatom")],
+        })
+    }
+
+    #[test]
+    fn typed_event_with_json_attributes() {
+        let event = event(
+            "althea.microtx.v1.EventMicrotx",
+            &[
+                ("sender", &format!("\"{SENDER}\"")),
+                ("receiver", &format!("\"{RECEIVER}\"")),
+                (
+                    "amounts",
+                    r#"[{"denom":"aalthea","amount":"100"},{"denom":"uatom","amount":"5"}]"#,
+                ),
+            ],
+        );
+        assert_eq!(
+            MicrotxEvent::decode(&EventAttributes::from(&event)),
+            Ok(Some(microtx()))
+        );
+    }
+
+    #[test]
+    fn legacy_plain_string_event() {
+        let legacy = event(
+            "althea.microtx.v1.EventMicrotx",
+            &[
+                ("sender", SENDER),
+                ("receiver", RECEIVER),
+                ("amounts", "100aalthea,5uatom"),
+            ],
+        );
+        assert_eq!(
+            MicrotxEvent::decode(&EventAttributes::from(&legacy)),
+            Ok(Some(microtx()))
+        );
+
+        // untyped events are not mapped
+        let untyped = event("message", &[("module", "microtx"), ("sender", SENDER)]);
+        assert_eq!(
+            MicrotxEvent::decode(&EventAttributes::from(&untyped)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn from_tx_response_falls_back_to_logs() {
+        let string_event = |r#type: &str, attributes: &[(&str, &str)]| StringEvent {
+            r#type: r#type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| Attribute {
+                    key: k.to_string(),
+                    value: v.to_string(),
+                })
+                .collect(),
+        };
+        let tx_response = TxResponse {
+            logs: vec![AbciMessageLog {
+                msg_index: 0,
+                log: String::new(),
+                events: vec![
+                    string_event("message", &[("module", "microtx")]),
+                    string_event(
+                        "althea.microtx.v1.EventMicrotx",
+                        &[
+                            ("sender", &format!("\"{SENDER}\"")),
+                            ("receiver", &format!("\"{RECEIVER}\"")),
+                            (
+                                "amounts",
+                                r#"[{"denom":"aalthea","amount":"100"},{"denom":"uatom","amount":"5"}]"#,
+                            ),
+                        ],
+                    ),
+                    string_event(
+                        "althea.microtx.v1.EventMicrotxFeeCollected",
+                        &[
+                            ("sender", &format!("\"{SENDER}\"")),
+                            ("fee", r#"[{"denom":"aalthea","amount":"1"}]"#),
+                        ],
+                    ),
+                ],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            MicrotxEvent::from_tx_response(&tx_response),
+            Ok(vec![
+                microtx(),
+                MicrotxEvent::FeeCollected(EventMicrotxFeeCollected {
+                    sender: SENDER.to_string(),
+                    fee: vec![coin("1", "aalthea")],
+                }),
+            ])
+        );
+    }
+}
@@ -0,0 +1,177 @@
+// Offline classification of a transaction against the gasfree module's Params. The AnteHandler skips the minimum gas
+// price and fee deduction checks for a transaction whose messages all have one of the gas_free_message_types, those
+// messages are charged by their own Msg handler instead (e.g. the microtx fee). A transaction which mixes gas free and
+// normal messages is rejected by the AnteHandler, so it must be split before it is broadcast.
+
+/// How the AnteHandler treats a transaction's fee
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GasfreeClass {
+    /// Every message is gas free, the transaction needs no fee
+    GasFree,
+    /// The transaction mixes gas free and normal messages and will be rejected, the indexes of each kind are given
+    Mixed {
+        gas_free: Vec<usize>,
+        normal: Vec<usize>,
+    },
+    /// No message is gas free (or there are no messages), the usual fee is charged
+    Normal,
+}
+
+impl GasfreeClass {
+    /// The fee the wallet should attach for the given gas limit, where gas_price is the integer amount charged per
+    /// unit of gas (e.g. the chain's minimum gas price). A gas free transaction needs no fee and a mixed transaction
+    /// has no fee which makes it acceptable, so None is returned for it. Returns None as well if the fee overflows
+    pub fn fee(
+        &self,
+        gas_limit: u64,
+        gas_price: &cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+    ) -> Option<Vec<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>> {
+        match self {
+            GasfreeClass::GasFree => Some(Vec::new()),
+            GasfreeClass::Mixed { .. } => None,
+            GasfreeClass::Normal => {
+                let amount = gas_price
+                    .amount
+                    .parse::<u128>()
+                    .ok()?
+                    .checked_mul(gas_limit as u128)?;
+                Some(vec![cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                    denom: gas_price.denom.clone(),
+                    amount: amount.to_string(),
+                }])
+            }
+        }
+    }
+}
+
+impl Params {
+    /// If messages with this type url are exempt from the AnteHandler's gas fees
+    pub fn is_gas_free(&self, type_url: &str) -> bool {
+        self.gas_free_message_types.iter().any(|t| t == type_url)
+    }
+
+    /// Classifies the messages of a transaction
+    pub fn classify_messages(&self, msgs: &[prost_types::Any]) -> GasfreeClass {
+        let (gas_free, normal): (Vec<usize>, Vec<usize>) =
+            (0..msgs.len()).partition(|&i| self.is_gas_free(&msgs[i].type_url));
+        match (gas_free.is_empty(), normal.is_empty()) {
+            (false, true) => GasfreeClass::GasFree,
+            (false, false) => GasfreeClass::Mixed { gas_free, normal },
+            (true, _) => GasfreeClass::Normal,
+        }
+    }
+
+    /// Classifies the messages of a transaction body
+    pub fn classify_tx_body(
+        &self,
+        body: &cosmos_sdk_proto::cosmos::tx::v1beta1::TxBody,
+    ) -> GasfreeClass {
+        self.classify_messages(&body.messages)
+    }
+
+    /// Classifies the messages of a transaction, a transaction without a body is Normal
+    pub fn classify_tx(&self, tx: &cosmos_sdk_proto::cosmos::tx::v1beta1::Tx) -> GasfreeClass {
+        tx.body
+            .as_ref()
+            .map_or(GasfreeClass::Normal, |body| self.classify_tx_body(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::tx::v1beta1::{Tx, TxBody};
+
+    const MICROTX: &str = "/althea.microtx.v1.MsgMicrotx";
+    const SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+    fn params() -> Params {
+        Params {
+            gas_free_message_types: vec![
+                MICROTX.to_string(),
+                "/althea.microtx.v1.MsgLiquify".to_string(),
+            ],
+        }
+    }
+
+    fn msgs(type_urls: &[&str]) -> Vec<prost_types::Any> {
+        type_urls
+            .iter()
+            .map(|type_url| prost_types::Any {
+                type_url: type_url.to_string(),
+                value: vec![],
+            })
+            .collect()
+    }
+
+    fn gas_price() -> Coin {
+        Coin {
+            denom: "aalthea".to_string(),
+            amount: "1000000000".to_string(),
+        }
+    }
+
+    #[test]
+    fn gas_free_messages_match_exactly() {
+        let params = params();
+        assert!(params.is_gas_free(MICROTX));
+        assert!(!params.is_gas_free("althea.microtx.v1.MsgMicrotx"));
+        assert!(!params.is_gas_free("/althea.microtx.v1.MsgMicrotxResponse"));
+        assert_eq!(
+            params.classify_messages(&msgs(&[MICROTX, "/althea.microtx.v1.MsgLiquify"])),
+            GasfreeClass::GasFree
+        );
+        assert_eq!(
+            params.classify_messages(&msgs(&[SEND, MICROTX, SEND])),
+            GasfreeClass::Mixed {
+                gas_free: vec![1],
+                normal: vec![0, 2],
+            }
+        );
+        assert_eq!(
+            params.classify_messages(&msgs(&[SEND])),
+            GasfreeClass::Normal
+        );
+        assert_eq!(params.classify_messages(&[]), GasfreeClass::Normal);
+    }
+
+    #[test]
+    fn classify_tx() {
+        let params = params();
+        let tx = Tx {
+            body: Some(TxBody {
+                messages: msgs(&[MICROTX]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(params.classify_tx(&tx), GasfreeClass::GasFree);
+        assert_eq!(params.classify_tx(&Tx::default()), GasfreeClass::Normal);
+    }
+
+    #[test]
+    fn fees() {
+        assert_eq!(
+            GasfreeClass::GasFree.fee(200_000, &gas_price()),
+            Some(vec![])
+        );
+        assert_eq!(
+            GasfreeClass::Normal.fee(200_000, &gas_price()),
+            Some(vec![Coin {
+                denom: "aalthea".to_string(),
+                amount: "200000000000000".to_string(),
+            }])
+        );
+        let mixed = GasfreeClass::Mixed {
+            gas_free: vec![0],
+            normal: vec![1],
+        };
+        assert_eq!(mixed.fee(200_000, &gas_price()), None);
+        let invalid = Coin {
+            amount: "1.5".to_string(),
+            ..gas_price()
+        };
+        assert_eq!(GasfreeClass::Normal.fee(200_000, &invalid), None);
+    }
+}
@@ -9,21 +9,25 @@ pub mod althea {
     pub mod lockup {
         pub mod v1 {
             include!("prost/althea.lockup.v1.rs");
+            include!("lockup.rs");
         }
     }
     pub mod microtx {
         pub mod v1 {
             include!("prost/althea.microtx.v1.rs");
+            include!("microtx.rs");
         }
     }
     pub mod gasfree {
         pub mod v1 {
             include!("prost/althea.gasfree.v1.rs");
+            include!("gasfree.rs");
         }
     }
     pub mod onboarding {
         pub mod v1 {
             include!("prost/althea.onboarding.v1.rs");
+            include!("onboarding.rs");
         }
     }
     pub mod nativedex {
@@ -33,6 +37,16 @@ pub mod althea {
     }
 }
 
+/// Legacy Amino JSON encoding of the Althea and Canto messages
+#[cfg(feature = "amino")]
+pub mod amino;
+/// EIP-712 typed data for signing Cosmos transactions with Ethereum wallets
+#[cfg(feature = "eip712")]
+pub mod eip712;
+/// Typed decoding of the microtx module's ABCI events
+#[cfg(feature = "events")]
+pub mod events;
+
 // THIRD PARTY PROTOS MANAGED IN THIS REPO
 pub mod canto {
     pub mod csr {
@@ -117,3 +131,85 @@ pub mod althea_test {
         }
     }
 }
+
+/// Registers every Althea, Canto and Ethermint message along with those of cosmos_sdk_proto, the types an Any in the messages of this
+/// crate is encoded with the fields of rather than its bytes
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    cosmos_sdk_proto::proto_json::register_sdk_types(registry);
+    althea::lockup::v1::register_json_types(registry);
+    althea::microtx::v1::register_json_types(registry);
+    althea::gasfree::v1::register_json_types(registry);
+    althea::onboarding::v1::register_json_types(registry);
+    althea::nativedex::v1::register_json_types(registry);
+    canto::csr::v1::register_json_types(registry);
+    canto::epochs::v1::register_json_types(registry);
+    canto::erc20::v1::register_json_types(registry);
+    canto::fees::v1::register_json_types(registry);
+    canto::govshuttle::v1::register_json_types(registry);
+    canto::inflation::v1::register_json_types(registry);
+    canto::recovery::v1::register_json_types(registry);
+    canto::vesting::v1::register_json_types(registry);
+    ethermint::crypto::v1::ethsecp256k1::register_json_types(registry);
+    ethermint::evm::v1::register_json_types(registry);
+    ethermint::feemarket::v1::register_json_types(registry);
+    ethermint::types::v1::register_json_types(registry);
+    althea_test::gaia::globalfee::v1beta1::register_json_types(registry);
+    althea_test::gaia::icaauth::v1::register_json_types(registry);
+}
+
+/// The Any encoding of this crate's messages, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub mod proto_json {
+    cosmos_sdk_proto::any_json_modules!(crate::register_json_types);
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::ethermint::evm::v1::{LegacyTx, MsgEthereumTx};
+    use serde_json::json;
+
+    #[test]
+    fn any_of_ethermint_message() {
+        let tx = LegacyTx {
+            nonce: 1,
+            gas_price: "1000000000".to_string(),
+            gas: 21000,
+            to: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".to_string(),
+            value: "1".to_string(),
+            data: vec![],
+            v: vec![1],
+            r: vec![2],
+            s: vec![3],
+        };
+        let msg = MsgEthereumTx {
+            data: Some(prost_types::Any::from_msg(&tx).unwrap()),
+            size: 0.0,
+            hash: "0x01".to_string(),
+            from: String::new(),
+        };
+        // the transaction's fields are embedded without registering anything first
+        let expected = json!({
+            "data": {
+                "@type": "/ethermint.evm.v1.LegacyTx",
+                "nonce": "1",
+                "gasPrice": "1000000000",
+                "gas": "21000",
+                "to": "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F",
+                "value": "1",
+                "data": "",
+                "v": "AQ==",
+                "r": "Ag==",
+                "s": "Aw==",
+            },
+            "size": 0.0,
+            "hash": "0x01",
+            "from": "",
+        });
+        assert_eq!(serde_json::to_value(&msg).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<MsgEthereumTx>(expected).unwrap(),
+            msg
+        );
+    }
+}
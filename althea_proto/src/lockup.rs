@@ -0,0 +1,427 @@
+// Offline evaluation of the lockup module's ante handler, which rejects a transaction while the chain is locked if any
+// of its messages has one of the locked_message_types and is not sent from a lock_exempt address. Bank sends, IBC
+// transfers and conversions of Cosmos coins to ERC20 are only blocked when they move one of the locked_token_denoms,
+// every other locked message type is blocked unless its signer is exempt. The messages wrapped in an authz MsgExec are
+// checked as well, they execute as the granter so each is checked against the signer named in the message itself (the
+// address its GetSigners() returns) rather than the grantee.
+
+/// An error evaluating the lockup of a message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LockupError {
+    /// The value of a message's Any could not be decoded as its type_url
+    Decode { type_url: String, error: String },
+}
+
+impl std::fmt::Display for LockupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockupError::Decode { type_url, error } => {
+                write!(f, "could not decode {type_url}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LockupError {}
+
+/// Why the lockup module would reject a message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LockupReason {
+    /// The message type is locked and the sender is not exempt
+    LockedMessageType { type_url: String, sender: String },
+    /// The message moves a locked denom and the sender is not exempt
+    LockedDenom {
+        type_url: String,
+        sender: String,
+        denom: String,
+    },
+    /// The message type is locked and the message is wrapped in a MsgExec, so its signer is a granter, and the message
+    /// is not one of the types whose signer can be read from it to check its exemption
+    UnknownSigner { type_url: String },
+}
+
+impl std::fmt::Display for LockupReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockupReason::LockedMessageType { type_url, sender } => {
+                write!(f, "{type_url} is locked and {sender} is not exempt")
+            }
+            LockupReason::LockedDenom {
+                type_url,
+                sender,
+                denom,
+            } => write!(
+                f,
+                "{type_url} moves locked token {denom} and {sender} is not exempt"
+            ),
+            LockupReason::UnknownSigner { type_url } => {
+                write!(f, "{type_url} is locked and its signer is unknown")
+            }
+        }
+    }
+}
+
+/// A message of a transaction which the lockup module would reject
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockedMessage {
+    /// The index of the message in the transaction, for a message inside an authz MsgExec this is the index of the
+    /// MsgExec
+    pub index: usize,
+    pub reason: LockupReason,
+}
+
+fn decode_any<M: prost::Name + Default>(any: &prost_types::Any) -> Result<M, LockupError> {
+    any.to_msg().map_err(|e| LockupError::Decode {
+        type_url: any.type_url.clone(),
+        error: e.to_string(),
+    })
+}
+
+/// The signer of a message which does not move coins, read from the field its GetSigners() returns, or None if the
+/// type is not known here
+fn message_signer(msg: &prost_types::Any) -> Result<Option<String>, LockupError> {
+    use crate::althea::microtx::v1 as microtx;
+    use crate::canto::vesting::v1 as vesting;
+    use cosmos_sdk_proto::cosmos::authz::v1beta1 as authz;
+    use cosmos_sdk_proto::cosmos::distribution::v1beta1 as distribution;
+    use cosmos_sdk_proto::cosmos::feegrant::v1beta1 as feegrant;
+    use cosmos_sdk_proto::cosmos::gov::{v1 as gov_v1, v1beta1 as gov};
+    use cosmos_sdk_proto::cosmos::staking::v1beta1 as staking;
+    use prost::Name;
+
+    macro_rules! signer_fields {
+        ($($ty:ty => $field:ident),* $(,)?) => {
+            $(
+                if msg.type_url == <$ty>::type_url() {
+                    let decoded: $ty = decode_any(msg)?;
+                    return Ok(Some(decoded.$field));
+                }
+            )*
+        };
+    }
+    signer_fields!(
+        staking::MsgDelegate => delegator_address,
+        staking::MsgUndelegate => delegator_address,
+        staking::MsgBeginRedelegate => delegator_address,
+        staking::MsgCancelUnbondingDelegation => delegator_address,
+        distribution::MsgSetWithdrawAddress => delegator_address,
+        distribution::MsgWithdrawDelegatorReward => delegator_address,
+        distribution::MsgFundCommunityPool => depositor,
+        gov::MsgSubmitProposal => proposer,
+        gov::MsgDeposit => depositor,
+        gov::MsgVote => voter,
+        gov::MsgVoteWeighted => voter,
+        gov_v1::MsgSubmitProposal => proposer,
+        gov_v1::MsgDeposit => depositor,
+        gov_v1::MsgVote => voter,
+        gov_v1::MsgVoteWeighted => voter,
+        authz::MsgGrant => granter,
+        authz::MsgRevoke => granter,
+        feegrant::MsgGrantAllowance => granter,
+        feegrant::MsgRevokeAllowance => granter,
+        microtx::MsgMicrotx => sender,
+        microtx::MsgLiquify => sender,
+        vesting::MsgCreateClawbackVestingAccount => from_address,
+        vesting::MsgClawback => funder_address,
+    );
+    Ok(None)
+}
+
+impl Params {
+    /// If the address is one of the lock_exempt addresses
+    pub fn is_exempt(&self, address: &str) -> bool {
+        self.lock_exempt.iter().any(|a| a == address)
+    }
+
+    /// If messages with this type url are subject to the lockup
+    pub fn is_locked_message_type(&self, type_url: &str) -> bool {
+        self.locked_message_types.iter().any(|t| t == type_url)
+    }
+
+    /// If the denom is one of the locked_token_denoms
+    pub fn is_locked_denom(&self, denom: &str) -> bool {
+        self.locked_token_denoms.iter().any(|d| d == denom)
+    }
+
+    /// The first locked denom moved by a non exempt sender
+    fn check_coins<'a>(
+        &self,
+        type_url: &str,
+        sender: &str,
+        coins: impl IntoIterator<Item = &'a cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
+    ) -> Option<LockupReason> {
+        if self.is_exempt(sender) {
+            return None;
+        }
+        coins
+            .into_iter()
+            .find(|c| self.is_locked_denom(&c.denom))
+            .map(|c| LockupReason::LockedDenom {
+                type_url: type_url.to_string(),
+                sender: sender.to_string(),
+                denom: c.denom.clone(),
+            })
+    }
+
+    /// Why the lockup module would reject the message, or None if it would be accepted. The signer is the address
+    /// which signed the transaction, it is used for the message types which do not name their sender
+    pub fn check_message(
+        &self,
+        msg: &prost_types::Any,
+        signer: &str,
+    ) -> Result<Option<LockupReason>, LockupError> {
+        self.check_message_signed_by(msg, Some(signer))
+    }
+
+    /// check_message for a message whose signer may be unknown, as for the messages wrapped in a MsgExec, which are
+    /// checked against the signer named in the message
+    fn check_message_signed_by(
+        &self,
+        msg: &prost_types::Any,
+        signer: Option<&str>,
+    ) -> Result<Option<LockupReason>, LockupError> {
+        use cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExec;
+        use cosmos_sdk_proto::cosmos::bank::v1beta1::{MsgMultiSend, MsgSend};
+        use cosmos_sdk_proto::ibc::applications::transfer::v1::MsgTransfer;
+        use prost::Name;
+
+        if !self.locked {
+            return Ok(None);
+        }
+        let type_url = msg.type_url.as_str();
+        // the MsgExec itself is signed by the grantee, the wrapped messages execute as their granters so they are only
+        // checked against their own sender fields
+        if type_url == MsgExec::type_url() {
+            let exec: MsgExec = decode_any(msg)?;
+            if self.is_locked_message_type(type_url) && !self.is_exempt(&exec.grantee) {
+                return Ok(Some(LockupReason::LockedMessageType {
+                    type_url: type_url.to_string(),
+                    sender: exec.grantee,
+                }));
+            }
+            for inner in &exec.msgs {
+                if let Some(reason) = self.check_message_signed_by(inner, None)? {
+                    return Ok(Some(reason));
+                }
+            }
+            return Ok(None);
+        }
+        if !self.is_locked_message_type(type_url) {
+            return Ok(None);
+        }
+        Ok(if type_url == MsgSend::type_url() {
+            let send: MsgSend = decode_any(msg)?;
+            self.check_coins(type_url, &send.from_address, &send.amount)
+        } else if type_url == MsgMultiSend::type_url() {
+            let send: MsgMultiSend = decode_any(msg)?;
+            send.inputs
+                .iter()
+                .find_map(|input| self.check_coins(type_url, &input.address, &input.coins))
+        } else if type_url == MsgTransfer::type_url() {
+            let transfer: MsgTransfer = decode_any(msg)?;
+            self.check_coins(type_url, &transfer.sender, &transfer.token)
+        } else if type_url == crate::canto::erc20::v1::MsgConvertCoin::type_url() {
+            let convert: crate::canto::erc20::v1::MsgConvertCoin = decode_any(msg)?;
+            self.check_coins(type_url, &convert.sender, &convert.coin)
+        } else {
+            let signer = match signer {
+                Some(signer) => Some(signer.to_string()),
+                None => message_signer(msg)?,
+            };
+            match signer {
+                Some(signer) if self.is_exempt(&signer) => None,
+                Some(signer) => Some(LockupReason::LockedMessageType {
+                    type_url: type_url.to_string(),
+                    sender: signer,
+                }),
+                None => Some(LockupReason::UnknownSigner {
+                    type_url: type_url.to_string(),
+                }),
+            }
+        })
+    }
+
+    /// The messages the lockup module would reject, the whole transaction fails if any are returned
+    pub fn check_messages(
+        &self,
+        msgs: &[prost_types::Any],
+        signer: &str,
+    ) -> Result<Vec<BlockedMessage>, LockupError> {
+        let mut blocked = Vec::new();
+        for (index, msg) in msgs.iter().enumerate() {
+            if let Some(reason) = self.check_message(msg, signer)? {
+                blocked.push(BlockedMessage { index, reason });
+            }
+        }
+        Ok(blocked)
+    }
+
+    /// The messages of the transaction the lockup module would reject. The signer's address can not be derived from
+    /// the transaction without its chain's bech32 prefix, so it is passed in
+    pub fn check_tx(
+        &self,
+        tx: &cosmos_sdk_proto::cosmos::tx::v1beta1::Tx,
+        signer: &str,
+    ) -> Result<Vec<BlockedMessage>, LockupError> {
+        match &tx.body {
+            Some(body) => self.check_messages(&body.messages, signer),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExec;
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::MsgDelegate;
+    use prost::Name;
+    use prost_types::Any;
+
+    const LOCKED: &str = "althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72";
+    const EXEMPT: &str = "althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg";
+    const GRANTEE: &str = "althea1qyqqzqqpqqqsqqgqqyqqzqqpqqqsqqgqvt3tc0";
+
+    fn params() -> Params {
+        Params {
+            locked: true,
+            lock_exempt: vec![EXEMPT.to_string()],
+            locked_message_types: vec![MsgSend::type_url(), MsgDelegate::type_url()],
+            locked_token_denoms: vec!["aalthea".to_string()],
+        }
+    }
+
+    fn send(from: &str, denom: &str) -> Any {
+        Any::from_msg(&MsgSend {
+            from_address: from.to_string(),
+            to_address: GRANTEE.to_string(),
+            amount: vec![Coin {
+                denom: denom.to_string(),
+                amount: "1".to_string(),
+            }],
+        })
+        .unwrap()
+    }
+
+    fn delegate(delegator: &str) -> Any {
+        Any::from_msg(&MsgDelegate {
+            delegator_address: delegator.to_string(),
+            validator_address: "altheavaloper1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg33lpzq0".to_string(),
+            amount: None,
+        })
+        .unwrap()
+    }
+
+    fn exec(msgs: Vec<Any>) -> Any {
+        Any::from_msg(&MsgExec {
+            grantee: GRANTEE.to_string(),
+            msgs,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn locked_sender_of_direct_send() {
+        let reason = params()
+            .check_message(&send(LOCKED, "aalthea"), LOCKED)
+            .unwrap();
+        assert_eq!(
+            reason,
+            Some(LockupReason::LockedDenom {
+                type_url: MsgSend::type_url(),
+                sender: LOCKED.to_string(),
+                denom: "aalthea".to_string(),
+            })
+        );
+        let unlocked = Params {
+            locked: false,
+            ..params()
+        };
+        assert_eq!(
+            unlocked.check_message(&send(LOCKED, "aalthea"), LOCKED),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn locked_sender_via_msg_exec() {
+        let blocked = params()
+            .check_messages(
+                &[send(LOCKED, "uatom"), exec(vec![send(LOCKED, "aalthea")])],
+                GRANTEE,
+            )
+            .unwrap();
+        assert_eq!(
+            blocked,
+            vec![BlockedMessage {
+                index: 1,
+                reason: LockupReason::LockedDenom {
+                    type_url: MsgSend::type_url(),
+                    sender: LOCKED.to_string(),
+                    denom: "aalthea".to_string(),
+                },
+            }]
+        );
+
+        // Messages which do not move coins are checked against the granter named in them
+        assert_eq!(
+            params().check_message(&exec(vec![delegate(LOCKED)]), GRANTEE),
+            Ok(Some(LockupReason::LockedMessageType {
+                type_url: MsgDelegate::type_url(),
+                sender: LOCKED.to_string(),
+            }))
+        );
+        assert_eq!(
+            params().check_message(&exec(vec![delegate(EXEMPT)]), GRANTEE),
+            Ok(None)
+        );
+
+        // A locked type whose signer is not known here
+        let mut params = params();
+        params
+            .locked_message_types
+            .push("/gravity.v1.MsgSendToEth".to_string());
+        let unknown = Any {
+            type_url: "/gravity.v1.MsgSendToEth".to_string(),
+            value: vec![],
+        };
+        assert_eq!(
+            params.check_message(&exec(vec![unknown]), GRANTEE),
+            Ok(Some(LockupReason::UnknownSigner {
+                type_url: "/gravity.v1.MsgSendToEth".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn exempt_address() {
+        let params = params();
+        assert_eq!(
+            params.check_message(&send(EXEMPT, "aalthea"), EXEMPT),
+            Ok(None)
+        );
+        assert_eq!(params.check_message(&delegate(EXEMPT), EXEMPT), Ok(None));
+        assert_eq!(
+            params.check_message(&delegate(LOCKED), LOCKED),
+            Ok(Some(LockupReason::LockedMessageType {
+                type_url: MsgDelegate::type_url(),
+                sender: LOCKED.to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn non_locked_denom() {
+        let params = params();
+        assert_eq!(
+            params.check_message(&send(LOCKED, "uatom"), LOCKED),
+            Ok(None)
+        );
+        assert_eq!(
+            params.check_message(&exec(vec![send(LOCKED, "uatom")]), GRANTEE),
+            Ok(None)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+// Offline prediction of what the microtx module does with a MsgMicrotx. The fee is microtx_fee_basis_points of the
+// amount, rounded down and charged in the same denom on top of the amount. When the receiver is a Liquid
+// Infrastructure account the module then redirects any balance above the thresholds configured in the account's
+// LiquidInfrastructureNFT contract to that contract. The thresholds live in the EVM rather than in the module state, so
+// they must be supplied by the caller (e.g. from a previous getThresholds() call).
+
+/// The divisor of Params::microtx_fee_basis_points, 10000 basis points is 100%
+pub const BASIS_POINT_DIVISOR: u128 = 10_000;
+
+/// An error predicting the outcome of a microtx
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MicrotxError {
+    /// The MsgMicrotx has no amount
+    MissingAmount,
+    /// An amount is not a valid non-negative integer that fits in a u128
+    InvalidAmount(String),
+    /// The fee or a balance overflows a u128
+    Overflow,
+}
+
+impl std::fmt::Display for MicrotxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MicrotxError::MissingAmount => write!(f, "microtx has no amount"),
+            MicrotxError::InvalidAmount(a) => write!(f, "invalid amount {a:?}"),
+            MicrotxError::Overflow => write!(f, "amount overflows a u128"),
+        }
+    }
+}
+
+impl std::error::Error for MicrotxError {}
+
+fn parse_amount(amount: &str) -> Result<u128, MicrotxError> {
+    amount
+        .parse()
+        .map_err(|_| MicrotxError::InvalidAmount(amount.to_string()))
+}
+
+fn coin(denom: &str, amount: u128) -> cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+    cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+    }
+}
+
+impl Params {
+    /// The fee charged for sending the given amount, amount * basis points / 10000 rounded down as the module's
+    /// getMicrotxFeeForAmount computes it. Params has no minimum or maximum fee, the basis points are the only input
+    pub fn microtx_fee(&self, amount: u128) -> Result<u128, MicrotxError> {
+        amount
+            .checked_mul(self.microtx_fee_basis_points as u128)
+            .map(|a| a / BASIS_POINT_DIVISOR)
+            .ok_or(MicrotxError::Overflow)
+    }
+
+    /// The fee the node would return for a QueryMicrotxFeeRequest
+    pub fn query_microtx_fee(&self, request: &QueryMicrotxFeeRequest) -> QueryMicrotxFeeResponse {
+        QueryMicrotxFeeResponse {
+            // saturates rather than wrapping should the basis points exceed 100%
+            fee_amount: self
+                .microtx_fee(request.amount as u128)
+                .map_or(u64::MAX, |fee| fee.min(u64::MAX as u128) as u64),
+        }
+    }
+}
+
+/// The predicted cost of a MsgMicrotx to its sender
+#[derive(Clone, Debug, PartialEq)]
+pub struct MicrotxCost {
+    /// The amount received by the receiver
+    pub amount: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+    /// The fee collected by the module, in the same denom as the amount
+    pub fee: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+    /// The amount plus the fee, the sender must hold at least this much of the denom
+    pub total: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+}
+
+impl MsgMicrotx {
+    /// The fee and total cost of this microtx under the given Params
+    pub fn cost(&self, params: &Params) -> Result<MicrotxCost, MicrotxError> {
+        let amount = self.amount.as_ref().ok_or(MicrotxError::MissingAmount)?;
+        let value = parse_amount(&amount.amount)?;
+        let fee = params.microtx_fee(value)?;
+        let total = value.checked_add(fee).ok_or(MicrotxError::Overflow)?;
+        Ok(MicrotxCost {
+            amount: amount.clone(),
+            fee: coin(&amount.denom, fee),
+            total: coin(&amount.denom, total),
+        })
+    }
+
+    /// Predicts the balance redirect the module emits after this microtx when the receiver is the given Liquid
+    /// Infrastructure account, see [LiquidInfrastructureAccount::predict_balance_redirect]. Returns None when the
+    /// account is not the receiver
+    pub fn predict_balance_redirect(
+        &self,
+        receiver: &LiquidInfrastructureAccount,
+        receiver_balance: u128,
+        thresholds: &[cosmos_sdk_proto::cosmos::base::v1beta1::Coin],
+    ) -> Result<Option<EventBalanceRedirect>, MicrotxError> {
+        if receiver.account != self.receiver {
+            return Ok(None);
+        }
+        let amount = self.amount.as_ref().ok_or(MicrotxError::MissingAmount)?;
+        receiver.predict_balance_redirect(amount, receiver_balance, thresholds)
+    }
+}
+
+impl LiquidInfrastructureAccount {
+    /// Predicts whether receiving the given amount makes the module redirect part of the account's balance to its NFT
+    /// contract. receiver_balance is the account's balance of the received denom before the transfer and thresholds
+    /// are the amounts the NFT allows the account to keep. A denom without a threshold is never redirected, otherwise
+    /// everything above the threshold is, and the returned event is the one the module would emit
+    pub fn predict_balance_redirect(
+        &self,
+        received: &cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+        receiver_balance: u128,
+        thresholds: &[cosmos_sdk_proto::cosmos::base::v1beta1::Coin],
+    ) -> Result<Option<EventBalanceRedirect>, MicrotxError> {
+        let threshold = match thresholds.iter().find(|t| t.denom == received.denom) {
+            Some(threshold) => parse_amount(&threshold.amount)?,
+            None => return Ok(None),
+        };
+        let balance = receiver_balance
+            .checked_add(parse_amount(&received.amount)?)
+            .ok_or(MicrotxError::Overflow)?;
+        if balance <= threshold {
+            return Ok(None);
+        }
+        Ok(Some(EventBalanceRedirect {
+            account: self.account.clone(),
+            amount: Some(coin(&received.denom, balance - threshold)),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+
+    fn params(microtx_fee_basis_points: u64) -> Params {
+        Params {
+            microtx_fee_basis_points,
+        }
+    }
+
+    fn microtx(amount: &str) -> MsgMicrotx {
+        MsgMicrotx {
+            sender: "althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72".to_string(),
+            receiver: "althea1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeukwehg".to_string(),
+            amount: Some(coin("aalthea", parse_amount(amount).unwrap())),
+        }
+    }
+
+    #[test]
+    fn fee_rounds_down_at_the_basis_point_boundary() {
+        // 10 basis points is a fee of one per thousand
+        let params = params(10);
+        assert_eq!(params.microtx_fee(999), Ok(0));
+        assert_eq!(params.microtx_fee(1000), Ok(1));
+        assert_eq!(params.microtx_fee(1999), Ok(1));
+        assert_eq!(params.microtx_fee(2000), Ok(2));
+        let one = self::params(1);
+        assert_eq!(one.microtx_fee(9_999), Ok(0));
+        assert_eq!(one.microtx_fee(10_000), Ok(1));
+        assert_eq!(self::params(0).microtx_fee(u128::MAX), Ok(0));
+        assert_eq!(params.microtx_fee(u128::MAX), Err(MicrotxError::Overflow));
+    }
+
+    #[test]
+    fn query_fee_saturates() {
+        let request = QueryMicrotxFeeRequest { amount: u64::MAX };
+        assert_eq!(
+            params(20_000).query_microtx_fee(&request).fee_amount,
+            u64::MAX
+        );
+        let request = QueryMicrotxFeeRequest { amount: 12_345 };
+        assert_eq!(params(10).query_microtx_fee(&request).fee_amount, 12);
+    }
+
+    #[test]
+    fn microtx_cost() {
+        assert_eq!(
+            microtx("1999").cost(&params(10)),
+            Ok(MicrotxCost {
+                amount: coin("aalthea", 1999),
+                fee: coin("aalthea", 1),
+                total: coin("aalthea", 2000),
+            })
+        );
+        let mut missing = microtx("1");
+        missing.amount = None;
+        assert_eq!(missing.cost(&params(10)), Err(MicrotxError::MissingAmount));
+        let mut invalid = microtx("1");
+        invalid.amount.as_mut().unwrap().amount = "-1".to_string();
+        assert_eq!(
+            invalid.cost(&params(10)),
+            Err(MicrotxError::InvalidAmount("-1".to_string()))
+        );
+    }
+
+    #[test]
+    fn balance_redirect() {
+        let msg = microtx("600");
+        let account = LiquidInfrastructureAccount {
+            owner: msg.sender.clone(),
+            account: msg.receiver.clone(),
+            nft_address: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".to_string(),
+        };
+        let thresholds = [coin("aalthea", 1000), coin("uatom", 5)];
+        assert_eq!(
+            msg.predict_balance_redirect(&account, 400, &thresholds),
+            Ok(None)
+        );
+        assert_eq!(
+            msg.predict_balance_redirect(&account, 401, &thresholds),
+            Ok(Some(EventBalanceRedirect {
+                account: account.account.clone(),
+                amount: Some(coin("aalthea", 1)),
+            }))
+        );
+        assert_eq!(
+            msg.predict_balance_redirect(&account, 5000, &[Coin::default()]),
+            Ok(None)
+        );
+        let other = LiquidInfrastructureAccount {
+            account: msg.sender.clone(),
+            ..account
+        };
+        assert_eq!(
+            msg.predict_balance_redirect(&other, 5000, &thresholds),
+            Ok(None)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+// Offline prediction of the onboarding IBC middleware. When onboarding is enabled and an ICS20 transfer arrives over one
+// of the whitelisted_channels, the middleware looks up the erc20 module's TokenPair for the received denom and, if the
+// pair is enabled, converts the receiver's entire balance of the denom into the pair's ERC20 token. The ERC20 tokens
+// are sent to the EVM address with the same bytes as the receiver.
+
+/// An error predicting the outcome of a transfer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OnboardingError {
+    /// The amount of the packet data is not a valid non-negative integer that fits in a u128
+    InvalidAmount(String),
+    /// The receiver's balance after the transfer overflows a u128
+    Overflow,
+}
+
+impl std::fmt::Display for OnboardingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OnboardingError::InvalidAmount(a) => write!(f, "invalid amount {a:?}"),
+            OnboardingError::Overflow => write!(f, "balance overflows a u128"),
+        }
+    }
+}
+
+impl std::error::Error for OnboardingError {}
+
+/// What the onboarding middleware does with a received transfer
+#[derive(Clone, Debug, PartialEq)]
+pub enum Onboarding {
+    /// enable_onboarding is false
+    Disabled,
+    /// The packet arrived over a channel which is not whitelisted
+    ChannelNotWhitelisted(String),
+    /// No token pair is registered for the received denom
+    NoTokenPair,
+    /// The token pair of the received denom is disabled
+    TokenPairDisabled(crate::canto::erc20::v1::TokenPair),
+    /// The receiver's balance of the denom is converted into the pair's ERC20 token
+    Converted {
+        pair: crate::canto::erc20::v1::TokenPair,
+        /// The amount of the ERC20 token credited, the receiver's previous balance plus the transfer
+        amount: String,
+    },
+}
+
+/// The predicted result of receiving an ICS20 transfer on Althea
+#[derive(Clone, Debug, PartialEq)]
+pub struct OnboardingPrediction {
+    /// The coin the transfer module credits to the receiver, with the denom it has on Althea
+    pub received: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+    pub onboarding: Onboarding,
+}
+
+impl Params {
+    /// If transfers received over the channel are onboarded
+    pub fn is_whitelisted(&self, channel: &str) -> bool {
+        self.whitelisted_channels.iter().any(|c| c == channel)
+    }
+
+    /// Predicts what the receiver ends up holding after an ICS20 transfer is received on Althea. The packet's
+    /// destination is the Althea end of the channel, data is the packet's decoded FungibleTokenPacketData,
+    /// token_pairs are the erc20 module's registered pairs and receiver_balance is the receiver's balance of the
+    /// received denom before the transfer
+    pub fn predict_onboarding(
+        &self,
+        packet: &cosmos_sdk_proto::ibc::core::channel::v1::Packet,
+        data: &cosmos_sdk_proto::ibc::applications::transfer::v2::FungibleTokenPacketData,
+        token_pairs: &[crate::canto::erc20::v1::TokenPair],
+        receiver_balance: u128,
+    ) -> Result<OnboardingPrediction, OnboardingError> {
+        let amount: u128 = data
+            .amount
+            .parse()
+            .map_err(|_| OnboardingError::InvalidAmount(data.amount.clone()))?;
+        let received = cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+            denom: data.received_denom_trace(packet).ibc_denom(),
+            amount: amount.to_string(),
+        };
+        let onboarding = if !self.enable_onboarding {
+            Onboarding::Disabled
+        } else if !self.is_whitelisted(&packet.destination_channel) {
+            Onboarding::ChannelNotWhitelisted(packet.destination_channel.clone())
+        } else {
+            match token_pairs.iter().find(|p| p.denom == received.denom) {
+                None => Onboarding::NoTokenPair,
+                Some(pair) if !pair.enabled => Onboarding::TokenPairDisabled(pair.clone()),
+                Some(pair) => Onboarding::Converted {
+                    pair: pair.clone(),
+                    amount: receiver_balance
+                        .checked_add(amount)
+                        .ok_or(OnboardingError::Overflow)?
+                        .to_string(),
+                },
+            }
+        };
+        Ok(OnboardingPrediction {
+            received,
+            onboarding,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canto::erc20::v1::TokenPair;
+    use cosmos_sdk_proto::ibc::applications::transfer::v2::FungibleTokenPacketData;
+    use cosmos_sdk_proto::ibc::core::channel::v1::Packet;
+
+    const ATOM: &str = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    fn params() -> Params {
+        Params {
+            enable_onboarding: true,
+            whitelisted_channels: vec!["channel-0".to_string()],
+        }
+    }
+
+    /// A packet from the Cosmos Hub's channel-141 to Althea's channel-0
+    fn packet() -> Packet {
+        Packet {
+            sequence: 1,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-141".to_string(),
+            destination_port: "transfer".to_string(),
+            destination_channel: "channel-0".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn data(denom: &str, amount: &str) -> FungibleTokenPacketData {
+        FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+            sender: "cosmos1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeg5thmd".to_string(),
+            receiver: "althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn pair(denom: &str, enabled: bool) -> TokenPair {
+        TokenPair {
+            erc20_address: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".to_string(),
+            denom: denom.to_string(),
+            enabled,
+            contract_owner: 1,
+        }
+    }
+
+    fn received_denom(denom: &str) -> String {
+        params()
+            .predict_onboarding(&packet(), &data(denom, "1"), &[], 0)
+            .unwrap()
+            .received
+            .denom
+    }
+
+    #[test]
+    fn received_denoms() {
+        // a token native to the sender gains Althea's end of the channel
+        assert_eq!(received_denom("uatom"), ATOM);
+        assert_eq!(
+            received_denom("transfer/channel-5/uosmo"),
+            "ibc/06856266BC0C745B112B055A65BEFBD8AA8E55343D54EC3AF4FBE02A04731E07"
+        );
+        // a token returning over the channel it left on loses the sender's end
+        assert_eq!(received_denom("transfer/channel-141/aalthea"), "aalthea");
+        assert_eq!(
+            received_denom("transfer/channel-141/transfer/channel-0/uatom"),
+            ATOM
+        );
+    }
+
+    #[test]
+    fn onboarding() {
+        let params = params();
+        let pairs = [pair(ATOM, true)];
+        let prediction = params
+            .predict_onboarding(&packet(), &data("uatom", "250"), &pairs, 50)
+            .unwrap();
+        assert_eq!(prediction.received.amount, "250");
+        assert_eq!(
+            prediction.onboarding,
+            Onboarding::Converted {
+                pair: pair(ATOM, true),
+                amount: "300".to_string(),
+            }
+        );
+        let onboarding = |params: &Params, packet: &Packet, pairs: &[TokenPair]| {
+            params
+                .predict_onboarding(packet, &data("uatom", "250"), pairs, 50)
+                .unwrap()
+                .onboarding
+        };
+        assert_eq!(
+            onboarding(&params, &packet(), &[pair(ATOM, false)]),
+            Onboarding::TokenPairDisabled(pair(ATOM, false))
+        );
+        assert_eq!(
+            onboarding(&params, &packet(), &[pair("uatom", true)]),
+            Onboarding::NoTokenPair
+        );
+        let other_channel = Packet {
+            destination_channel: "channel-1".to_string(),
+            ..packet()
+        };
+        assert_eq!(
+            onboarding(&params, &other_channel, &pairs),
+            Onboarding::ChannelNotWhitelisted("channel-1".to_string())
+        );
+        let disabled = Params {
+            enable_onboarding: false,
+            ..params.clone()
+        };
+        assert_eq!(
+            onboarding(&disabled, &packet(), &pairs),
+            Onboarding::Disabled
+        );
+        assert_eq!(
+            params.predict_onboarding(&packet(), &data("uatom", "1.5"), &pairs, 0),
+            Err(OnboardingError::InvalidAmount("1.5".to_string()))
+        );
+        assert_eq!(
+            params.predict_onboarding(&packet(), &data("uatom", "1"), &pairs, u128::MAX),
+            Err(OnboardingError::Overflow)
+        );
+    }
+}
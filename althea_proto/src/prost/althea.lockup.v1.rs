@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// Params struct
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// The lockup module is engaged if locked is true (chain is "locked up")
@@ -16,8 +18,36 @@ pub struct Params {
     #[prost(string, repeated, tag = "4")]
     pub locked_token_denoms: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "althea.lockup.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.lockup.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.lockup.v1.Params".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "althea.lockup.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.lockup.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.lockup.v1.GenesisState".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Params>();
+    registry.register::<GenesisState>();
+}
@@ -1,15 +1,40 @@
 // This file is @generated by prost-build.
 /// Params struct
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Params {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub microtx_fee_basis_points: u64,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.Params".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.GenesisState".into()
+    }
+}
 /// MsgMicrotx A Msg used to send funds from one Althea network wallet to another,
 /// via an automated device. Facilitates Liquid Infrastructure by automatically
 /// redirecting funds received by Liquid Infrastructure beyond configured amounts to the EVM.
@@ -18,6 +43,8 @@ pub struct GenesisState {
 /// RECEIVER The account receiving funds from sender
 /// AMOUNTS The tokens and their quantities which should be transferred, these
 /// must be Cosmos coins registered as ERC20s, or the Cosmos representation of ERC20s
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgMicrotx {
     #[prost(string, tag = "1")]
@@ -27,10 +54,34 @@ pub struct MsgMicrotx {
     #[prost(message, optional, tag = "3")]
     pub amount: ::core::option::Option<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgMicrotx {
+    const NAME: &'static str = "MsgMicrotx";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.MsgMicrotx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.MsgMicrotx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgMicrotxResponse {}
+impl ::prost::Name for MsgMicrotxResponse {
+    const NAME: &'static str = "MsgMicrotxResponse";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.MsgMicrotxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.MsgMicrotxResponse".into()
+    }
+}
 /// A type for the block's event log, every successful Microtx must create one of
 /// these in the event log
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventMicrotx {
     #[prost(string, tag = "1")]
@@ -42,8 +93,20 @@ pub struct EventMicrotx {
         cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
     >,
 }
+impl ::prost::Name for EventMicrotx {
+    const NAME: &'static str = "EventMicrotx";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.EventMicrotx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.EventMicrotx".into()
+    }
+}
 /// A type for the block's event log, Microtx fees may be collected out-of-band and so
 /// this event may be emitted in an AnteHandler or during the Msg handler
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventMicrotxFeeCollected {
     #[prost(string, tag = "1")]
@@ -51,8 +114,20 @@ pub struct EventMicrotxFeeCollected {
     #[prost(message, repeated, tag = "2")]
     pub fee: ::prost::alloc::vec::Vec<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
 }
+impl ::prost::Name for EventMicrotxFeeCollected {
+    const NAME: &'static str = "EventMicrotxFeeCollected";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.EventMicrotxFeeCollected".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.EventMicrotxFeeCollected".into()
+    }
+}
 /// A type for the block's event log recording when a Liquid Infrastructure account
 /// has a received balance redirected to its registered LiquidInfrastructureNFT
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBalanceRedirect {
     #[prost(string, tag = "1")]
@@ -60,10 +135,22 @@ pub struct EventBalanceRedirect {
     #[prost(message, optional, tag = "2")]
     pub amount: ::core::option::Option<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
 }
+impl ::prost::Name for EventBalanceRedirect {
+    const NAME: &'static str = "EventBalanceRedirect";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.EventBalanceRedirect".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.EventBalanceRedirect".into()
+    }
+}
 /// Records critical information about a Liquid Infrastructure Account
 /// ACCOUNT The bech32 address of the liquid infrastructure account
 /// OWNER The bech32 address of the account now in control of the liquid infrastructure
 /// NFT_ADDRESS The EVM address of the token contract in control of the liquid infrastructure account's accrued profits
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LiquidInfrastructureAccount {
     #[prost(string, tag = "1")]
@@ -73,25 +160,61 @@ pub struct LiquidInfrastructureAccount {
     #[prost(string, tag = "3")]
     pub nft_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for LiquidInfrastructureAccount {
+    const NAME: &'static str = "LiquidInfrastructureAccount";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.LiquidInfrastructureAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.LiquidInfrastructureAccount".into()
+    }
+}
 /// MsgLiquify Converts the sender's account into a piece of Liquid Infrastructure,
 /// by creating a Non-fungible Token (NFT) within the Althea L1 EVM which will control all balances
 /// held by the Liquid Infrastructure Account (beyond a configurable threshold).
 /// The liquid infrastructure account itself will be the initial owner of the NFT,
 /// and must transfer control through the EVM NFT contract
 /// SENDER The bech32 address of the account to liquify, must also be the signer of the message
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgLiquify {
     #[prost(string, tag = "1")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgLiquify {
+    const NAME: &'static str = "MsgLiquify";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.MsgLiquify".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.MsgLiquify".into()
+    }
+}
 /// MsgLiquifyResponse potentially returns useful information from the liquification of an account
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgLiquifyResponse {
     #[prost(message, optional, tag = "1")]
     pub account: ::core::option::Option<LiquidInfrastructureAccount>,
 }
+impl ::prost::Name for MsgLiquifyResponse {
+    const NAME: &'static str = "MsgLiquifyResponse";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.MsgLiquifyResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.MsgLiquifyResponse".into()
+    }
+}
 /// A type for the block's event log, every successful MsgLiquify must create one of
 /// these in the event log
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventAccountLiquified {
     #[prost(string, tag = "1")]
@@ -99,6 +222,18 @@ pub struct EventAccountLiquified {
     #[prost(string, tag = "2")]
     pub nft_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventAccountLiquified {
+    const NAME: &'static str = "EventAccountLiquified";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.EventAccountLiquified".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.EventAccountLiquified".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -244,36 +379,112 @@ pub mod msg_client {
     }
 }
 /// Query the current microtx params
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryParamsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryParamsResponse".into()
+    }
+}
 /// Query the additional fee paid on MsgMicrotx, determined by governance
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryMicrotxFeeRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub amount: u64,
 }
+impl ::prost::Name for QueryMicrotxFeeRequest {
+    const NAME: &'static str = "QueryMicrotxFeeRequest";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryMicrotxFeeRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryMicrotxFeeRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryMicrotxFeeResponse {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub fee_amount: u64,
 }
+impl ::prost::Name for QueryMicrotxFeeResponse {
+    const NAME: &'static str = "QueryMicrotxFeeResponse";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryMicrotxFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryMicrotxFeeResponse".into()
+    }
+}
 /// Query the Liquid Infrastructure accounts known to the module
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLiquidAccountsRequest {}
+impl ::prost::Name for QueryLiquidAccountsRequest {
+    const NAME: &'static str = "QueryLiquidAccountsRequest";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryLiquidAccountsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryLiquidAccountsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLiquidAccountsResponse {
     #[prost(message, repeated, tag = "1")]
     pub accounts: ::prost::alloc::vec::Vec<LiquidInfrastructureAccount>,
 }
+impl ::prost::Name for QueryLiquidAccountsResponse {
+    const NAME: &'static str = "QueryLiquidAccountsResponse";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryLiquidAccountsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryLiquidAccountsResponse".into()
+    }
+}
 /// Query for info about one particular Liquid Infrastructure account
 /// OWNER if a bech32 address is provided, potenitally many accounts will be returned
 /// ACCOUNT if a bech32 address is provided, the owner and nft contract address will be returned
 /// NFT if a EVM address is provided and happens to be a LiquidInfrastructureNFT contract, the owner and account will be returned
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLiquidAccountRequest {
     #[prost(string, tag = "1")]
@@ -283,11 +494,35 @@ pub struct QueryLiquidAccountRequest {
     #[prost(string, tag = "3")]
     pub nft: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryLiquidAccountRequest {
+    const NAME: &'static str = "QueryLiquidAccountRequest";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryLiquidAccountRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryLiquidAccountRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLiquidAccountResponse {
     #[prost(message, repeated, tag = "1")]
     pub accounts: ::prost::alloc::vec::Vec<LiquidInfrastructureAccount>,
 }
+impl ::prost::Name for QueryLiquidAccountResponse {
+    const NAME: &'static str = "QueryLiquidAccountResponse";
+    const PACKAGE: &'static str = "althea.microtx.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.microtx.v1.QueryLiquidAccountResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.microtx.v1.QueryLiquidAccountResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -487,3 +722,605 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// The Microtx service handles payments to Althea accounts
+        async fn microtx(
+            &self,
+            request: tonic::Request<super::MsgMicrotx>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgMicrotxResponse>,
+            tonic::Status,
+        >;
+        /// The Liquify service converts an account into a piece of Liquid Infrastructure
+        async fn liquify(
+            &self,
+            request: tonic::Request<super::MsgLiquify>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgLiquifyResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the state transitions possible within microtx
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/althea.microtx.v1.Msg/Microtx" => {
+                    #[allow(non_camel_case_types)]
+                    struct MicrotxSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgMicrotx>
+                    for MicrotxSvc<T> {
+                        type Response = super::MsgMicrotxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgMicrotx>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::microtx(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MicrotxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/althea.microtx.v1.Msg/Liquify" => {
+                    #[allow(non_camel_case_types)]
+                    struct LiquifySvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgLiquify>
+                    for LiquifySvc<T> {
+                        type Response = super::MsgLiquifyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgLiquify>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::liquify(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LiquifySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "althea.microtx.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Query the current microtx params
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// Get an authoritative fee amount which must be paid on Microtx
+        async fn microtx_fee(
+            &self,
+            request: tonic::Request<super::QueryMicrotxFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryMicrotxFeeResponse>,
+            tonic::Status,
+        >;
+        /// Get all of the Liquid Infrastructure accounts known to the module
+        async fn liquid_accounts(
+            &self,
+            request: tonic::Request<super::QueryLiquidAccountsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLiquidAccountsResponse>,
+            tonic::Status,
+        >;
+        /// Get info about one particular Liquid Infrastructure account by owner, bech32 address, or nft address
+        /// Make HTTP GET requests like:
+        /// * `GET /microtx/v1/liquid_account?owner=althea1...`
+        /// * `GET /microtx/v1/liquid_account?owner=0xABCDE...`
+        /// * `GET /microtx/v1/liquid_account?account=althea1...`
+        /// * `GET /microtx/v1/liquid_account?nft=0xABCDE...`
+        async fn liquid_account(
+            &self,
+            request: tonic::Request<super::QueryLiquidAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLiquidAccountResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the possible queries to make of the microtx module
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/althea.microtx.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/althea.microtx.v1.Query/MicrotxFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct MicrotxFeeSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryMicrotxFeeRequest>
+                    for MicrotxFeeSvc<T> {
+                        type Response = super::QueryMicrotxFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryMicrotxFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::microtx_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MicrotxFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/althea.microtx.v1.Query/LiquidAccounts" => {
+                    #[allow(non_camel_case_types)]
+                    struct LiquidAccountsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryLiquidAccountsRequest>
+                    for LiquidAccountsSvc<T> {
+                        type Response = super::QueryLiquidAccountsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryLiquidAccountsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::liquid_accounts(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LiquidAccountsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/althea.microtx.v1.Query/LiquidAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct LiquidAccountSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryLiquidAccountRequest>
+                    for LiquidAccountSvc<T> {
+                        type Response = super::QueryLiquidAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryLiquidAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::liquid_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LiquidAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "althea.microtx.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Params>();
+    registry.register::<GenesisState>();
+    registry.register::<MsgMicrotx>();
+    registry.register::<MsgMicrotxResponse>();
+    registry.register::<EventMicrotx>();
+    registry.register::<EventMicrotxFeeCollected>();
+    registry.register::<EventBalanceRedirect>();
+    registry.register::<LiquidInfrastructureAccount>();
+    registry.register::<MsgLiquify>();
+    registry.register::<MsgLiquifyResponse>();
+    registry.register::<EventAccountLiquified>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryMicrotxFeeRequest>();
+    registry.register::<QueryMicrotxFeeResponse>();
+    registry.register::<QueryLiquidAccountsRequest>();
+    registry.register::<QueryLiquidAccountsResponse>();
+    registry.register::<QueryLiquidAccountRequest>();
+    registry.register::<QueryLiquidAccountResponse>();
+}
@@ -1,11 +1,25 @@
 // This file is @generated by prost-build.
 /// GenesisState defines the nativedex module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.GenesisState".into()
+    }
+}
 /// Params defines the parameters for the module.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     #[prost(string, tag = "1")]
@@ -13,10 +27,22 @@ pub struct Params {
     #[prost(string, tag = "2")]
     pub verified_croc_policy_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.Params".into()
+    }
+}
 /// UpgradeProxyProposal will replace one of the nativedex callpath contracts (or install a new one)
 /// if passes, calls CrocPolicy.treasuryResolution(CrocSwapDex, 0, <ABI Encoded Bytes(21, <callpath_address>, <callpath_index>)>)
 ///
 /// BE VERY CAREFUL EXECUTING THIS PROPOSAL, AS IT CAN COMPLETELY BREAK THE DEX CONTRACT
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpgradeProxyProposal {
     #[prost(string, tag = "1")]
@@ -26,6 +52,18 @@ pub struct UpgradeProxyProposal {
     #[prost(message, optional, tag = "3")]
     pub metadata: ::core::option::Option<UpgradeProxyMetadata>,
 }
+impl ::prost::Name for UpgradeProxyProposal {
+    const NAME: &'static str = "UpgradeProxyProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.UpgradeProxyProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.UpgradeProxyProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpgradeProxyMetadata {
     /// the address of the contract to install
@@ -33,13 +71,26 @@ pub struct UpgradeProxyMetadata {
     pub callpath_address: ::prost::alloc::string::String,
     /// the callpath index to write to, see solidity-dex/contracts/mixins/StorageLayout.sol for the default values
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub callpath_index: u64,
 }
+impl ::prost::Name for UpgradeProxyMetadata {
+    const NAME: &'static str = "UpgradeProxyMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.UpgradeProxyMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.UpgradeProxyMetadata".into()
+    }
+}
 /// CollectTreasuryProposal will pay out protocol fees to the registered (and timelocked) `treasury_` account
 /// If passes, calls CrocPolicy.treasuryResolution(CrocSwapDex, 3, <ABI Encoded Bytes(40, <token_address>)>)
 ///
 /// Note that by default the protocol fees will be set to zero, see the governance history or use CrocQuery with a pool
 /// to determine the current protocol take
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectTreasuryProposal {
     #[prost(string, tag = "1")]
@@ -52,18 +103,42 @@ pub struct CollectTreasuryProposal {
     #[prost(bool, tag = "4")]
     pub in_safe_mode: bool,
 }
+impl ::prost::Name for CollectTreasuryProposal {
+    const NAME: &'static str = "CollectTreasuryProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.CollectTreasuryProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.CollectTreasuryProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CollectTreasuryMetadata {
     /// the ERC20 address to collect into the `treasury_` account, (0x0 for the native token)
     #[prost(string, tag = "1")]
     pub token_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for CollectTreasuryMetadata {
+    const NAME: &'static str = "CollectTreasuryMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.CollectTreasuryMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.CollectTreasuryMetadata".into()
+    }
+}
 /// SetTreasuryProposal will change the `treasury_` address
 /// the treasury_ address will be restricted from receiving protocol fees for a period of time (stored in treasuryStartTime_)
 /// If passes, calls CrocPolicy.treasuryResolution(CrocSwapDex, 3, <ABI Encoded Bytes(41, <treasury_address>)>)
 ///
 /// Note that by default the protocol fees will be set to zero, see the governance history or use CrocQuery with a pool
 /// to determine the current protocol take
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetTreasuryProposal {
     #[prost(string, tag = "1")]
@@ -76,18 +151,42 @@ pub struct SetTreasuryProposal {
     #[prost(bool, tag = "4")]
     pub in_safe_mode: bool,
 }
+impl ::prost::Name for SetTreasuryProposal {
+    const NAME: &'static str = "SetTreasuryProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.SetTreasuryProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.SetTreasuryProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetTreasuryMetadata {
     /// the address to set `treasury_` to
     #[prost(string, tag = "1")]
     pub treasury_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for SetTreasuryMetadata {
+    const NAME: &'static str = "SetTreasuryMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.SetTreasuryMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.SetTreasuryMetadata".into()
+    }
+}
 /// AuthorityTransferProposal will change the `authority_` address, which can be used to upgrade or remove the CrocPolicy
 /// contract (and therefore this module must be upgraded to work with the replacement, and configured as the new authority)
 /// If passes, calls CrocPolicy.treasuryResolution(CrocSwapDex, 3, <ABI Encoded Bytes(20, <auth_address>)>)
 ///
 /// BE VERY CAREFUL EXECUTING THIS PROPOSAL, AS IT CAN COMPLETELY DISABLE THE NATIVEDEX MODULE AND REMOVE STAKER AUTHORITY
 /// OVER THE DEX CONTRACT
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthorityTransferProposal {
     #[prost(string, tag = "1")]
@@ -100,18 +199,42 @@ pub struct AuthorityTransferProposal {
     #[prost(bool, tag = "4")]
     pub in_safe_mode: bool,
 }
+impl ::prost::Name for AuthorityTransferProposal {
+    const NAME: &'static str = "AuthorityTransferProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.AuthorityTransferProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.AuthorityTransferProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthorityTransferMetadata {
     /// the address to set `authority_` to
     #[prost(string, tag = "1")]
     pub auth_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for AuthorityTransferMetadata {
+    const NAME: &'static str = "AuthorityTransferMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.AuthorityTransferMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.AuthorityTransferMetadata".into()
+    }
+}
 /// HotPathOpenProposal will change the `hotPathOpen_` flag, which controls if users are able to call swap directly on the dex contract
 /// The primary purpose of this seems to be enabling upgradeability of the HotProxy contract, which would require users to switch
 /// to calling CrocSwapDex.userCmd(1, <ABI Encoded Args>) instead of CrocSwapDex.swap(<args>) so that they call the new code.
 /// If passes, calls CrocPolicy.treasuryResolution(CrocSwapDex, 3, <ABI Encoded Bytes(22, <open>)>)
 ///
 /// BE VERY CAREFUL EXECUTING THIS PROPOSAL, AS IT CAN BREAK INFLEXIBLE DEX USER INTERFACES
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HotPathOpenProposal {
     #[prost(string, tag = "1")]
@@ -124,6 +247,18 @@ pub struct HotPathOpenProposal {
     #[prost(bool, tag = "4")]
     pub in_safe_mode: bool,
 }
+impl ::prost::Name for HotPathOpenProposal {
+    const NAME: &'static str = "HotPathOpenProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.HotPathOpenProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.HotPathOpenProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct HotPathOpenMetadata {
     /// If true, users can call swap directly on the dex contract.
@@ -131,12 +266,24 @@ pub struct HotPathOpenMetadata {
     #[prost(bool, tag = "1")]
     pub open: bool,
 }
+impl ::prost::Name for HotPathOpenMetadata {
+    const NAME: &'static str = "HotPathOpenMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.HotPathOpenMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.HotPathOpenMetadata".into()
+    }
+}
 /// SetSafeModeProposal will lock down the DEX for emergency changes. This can also be used by the emergency multisig to halt the DEX more quickly.
 /// When the DEX is in safe mode only a UpgradeProxy, CollectTreasury, SetTreasury, AuthorityTransfer, HotPathOpen, or SetSafeMode Proposal can be executed,
 /// and these proposals can only be executed under the SafeMode or Boot Proxy callpaths.
 /// If passes, calls CrocPolicy.treasuryResolution(CrocSwapDex, 3, <ABI Encoded Bytes(23, <lock_dex>)>)
 ///
 /// BE VERY CAREFUL EXECUTING THIS PROPOSAL, IT SHOULD ONLY BE USED TO DISABLE THE DEX OR RECOVER FROM DISABLES
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetSafeModeProposal {
     #[prost(string, tag = "1")]
@@ -149,16 +296,40 @@ pub struct SetSafeModeProposal {
     #[prost(bool, tag = "4")]
     pub in_safe_mode: bool,
 }
+impl ::prost::Name for SetSafeModeProposal {
+    const NAME: &'static str = "SetSafeModeProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.SetSafeModeProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.SetSafeModeProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SetSafeModeMetadata {
     /// If true, the DEX will be disabled
     #[prost(bool, tag = "1")]
     pub lock_dex: bool,
 }
+impl ::prost::Name for SetSafeModeMetadata {
+    const NAME: &'static str = "SetSafeModeMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.SetSafeModeMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.SetSafeModeMetadata".into()
+    }
+}
 /// TransferGovernanceProposal will update the governance role addresses on CrocPolicy.
 /// If passes, calls CrocPolicy.transferGovernance(<ops>, <nativedex module address>, <emergency>)
 ///
 /// BE VERY CAREFUL EXECUTING THIS PROPOSAL, THE OPS AND EMERGENCY ADDRESSES SHOULD BE CAREFULLY CHOSEN MULTISIGS
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransferGovernanceProposal {
     #[prost(string, tag = "1")]
@@ -168,6 +339,18 @@ pub struct TransferGovernanceProposal {
     #[prost(message, optional, tag = "3")]
     pub metadata: ::core::option::Option<TransferGovernanceMetadata>,
 }
+impl ::prost::Name for TransferGovernanceProposal {
+    const NAME: &'static str = "TransferGovernanceProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.TransferGovernanceProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.TransferGovernanceProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransferGovernanceMetadata {
     /// The address to use for the Ops governance role, the least privileged role
@@ -177,11 +360,23 @@ pub struct TransferGovernanceMetadata {
     #[prost(string, tag = "2")]
     pub emergency: ::prost::alloc::string::String,
 }
+impl ::prost::Name for TransferGovernanceMetadata {
+    const NAME: &'static str = "TransferGovernanceMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.TransferGovernanceMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.TransferGovernanceMetadata".into()
+    }
+}
 /// OpsProposal will execute a non-sudo `protocolCmd()` call on the DEX via CrocPolicy.
 /// If passes, calls CrocPolicy.opsResolution (CrocSwapDex, <callpath>, <ABI Encoded Bytes(<cmd args>)>)
 ///
 /// This proposal enables nativedex governance to perform everyday Ops functions on the DEX,
 /// and so the Ops or Emergency addresses could override any decisions made by this proposal.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OpsProposal {
     #[prost(string, tag = "1")]
@@ -191,25 +386,75 @@ pub struct OpsProposal {
     #[prost(message, optional, tag = "3")]
     pub metadata: ::core::option::Option<OpsMetadata>,
 }
+impl ::prost::Name for OpsProposal {
+    const NAME: &'static str = "OpsProposal";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.OpsProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.OpsProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OpsMetadata {
     /// The callpath index to use, see solidity-dex/contracts/mixins/StorageLayout.sol for the default values
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub callpath: u64,
     /// The ABI encoded bytes to pass to the opsResolution() call
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub cmd_args: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for OpsMetadata {
+    const NAME: &'static str = "OpsMetadata";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.OpsMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.OpsMetadata".into()
+    }
+}
 /// QueryParamsRequest is request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// params holds all the parameters of this module.
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "althea.nativedex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "althea.nativedex.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/althea.nativedex.v1.QueryParamsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -329,6 +574,8 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -423,3 +670,340 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Parameters queries the parameters of the module.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/althea.nativedex.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "althea.nativedex.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {}
+    /// Msg defines the Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        #[allow(clippy::match_single_binding)]
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "althea.nativedex.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<GenesisState>();
+    registry.register::<Params>();
+    registry.register::<UpgradeProxyProposal>();
+    registry.register::<UpgradeProxyMetadata>();
+    registry.register::<CollectTreasuryProposal>();
+    registry.register::<CollectTreasuryMetadata>();
+    registry.register::<SetTreasuryProposal>();
+    registry.register::<SetTreasuryMetadata>();
+    registry.register::<AuthorityTransferProposal>();
+    registry.register::<AuthorityTransferMetadata>();
+    registry.register::<HotPathOpenProposal>();
+    registry.register::<HotPathOpenMetadata>();
+    registry.register::<SetSafeModeProposal>();
+    registry.register::<SetSafeModeMetadata>();
+    registry.register::<TransferGovernanceProposal>();
+    registry.register::<TransferGovernanceMetadata>();
+    registry.register::<OpsProposal>();
+    registry.register::<OpsMetadata>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+}
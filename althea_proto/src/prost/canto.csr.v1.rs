@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// The CSR struct is a wrapper to all of the metadata associated with a given CST NFT
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Csr {
     /// Contracts is the list of all EVM address that are registered to this NFT
@@ -7,15 +9,29 @@ pub struct Csr {
     pub contracts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     /// The NFT id which this CSR corresponds to
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub id: u64,
     /// The total number of transactions for this CSR NFT
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub txs: u64,
     /// The cumulative revenue for this CSR NFT -> represented as a sdk.Int
     #[prost(string, tag = "4")]
     pub revenue: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Csr {
+    const NAME: &'static str = "CSR";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.CSR".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.CSR".into()
+    }
+}
 /// Params holds parameters for the csr module
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// boolean to enable the csr module
@@ -25,24 +41,72 @@ pub struct Params {
     #[prost(string, tag = "2")]
     pub csr_shares: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.Params".into()
+    }
+}
 /// GenesisState defines the csr module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// params defines all of the parameters of the module
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.GenesisState".into()
+    }
+}
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// params holds all the parameters of this module.
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryParamsResponse".into()
+    }
+}
 /// QueryCSRsRequest is the request type for the Query/CSRs RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCsRsRequest {
     /// pagination defines an optional pagination for the request.
@@ -51,7 +115,19 @@ pub struct QueryCsRsRequest {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryCsRsRequest {
+    const NAME: &'static str = "QueryCSRsRequest";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryCSRsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryCSRsRequest".into()
+    }
+}
 /// QueryCSRsResponse is the response type for the Query/CSRs RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCsRsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -62,41 +138,126 @@ pub struct QueryCsRsResponse {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryCsRsResponse {
+    const NAME: &'static str = "QueryCSRsResponse";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryCSRsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryCSRsResponse".into()
+    }
+}
 /// QueryCSRByNFTRequest is the request type for the Query/CSRByNFT RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryCsrByNftRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nft_id: u64,
 }
+impl ::prost::Name for QueryCsrByNftRequest {
+    const NAME: &'static str = "QueryCSRByNFTRequest";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryCSRByNFTRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryCSRByNFTRequest".into()
+    }
+}
 /// QueryCSRByNFTResponse is the response type for the Query/CSRByNFT RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCsrByNftResponse {
     /// csr object queried by nft id
     #[prost(message, optional, tag = "1")]
     pub csr: ::core::option::Option<Csr>,
 }
+impl ::prost::Name for QueryCsrByNftResponse {
+    const NAME: &'static str = "QueryCSRByNFTResponse";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryCSRByNFTResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryCSRByNFTResponse".into()
+    }
+}
 /// QueryCSRByContractRequest is the request type for the Query/CSRByContract RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCsrByContractRequest {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryCsrByContractRequest {
+    const NAME: &'static str = "QueryCSRByContractRequest";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryCSRByContractRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryCSRByContractRequest".into()
+    }
+}
 /// QueryCSRByContractResponse is the response type for the Query/CSRByContract RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCsrByContractResponse {
     /// csr object queried by smart contract address
     #[prost(message, optional, tag = "1")]
     pub csr: ::core::option::Option<Csr>,
 }
+impl ::prost::Name for QueryCsrByContractResponse {
+    const NAME: &'static str = "QueryCSRByContractResponse";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryCSRByContractResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryCSRByContractResponse".into()
+    }
+}
 /// QueryTurnstileRequest is the request type for the Query/Turnstile RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryTurnstileRequest {}
+impl ::prost::Name for QueryTurnstileRequest {
+    const NAME: &'static str = "QueryTurnstileRequest";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryTurnstileRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryTurnstileRequest".into()
+    }
+}
 /// QueryTurnstileResponse is the response type for the Query/Turnstile RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTurnstileResponse {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryTurnstileResponse {
+    const NAME: &'static str = "QueryTurnstileResponse";
+    const PACKAGE: &'static str = "canto.csr.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.csr.v1.QueryTurnstileResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.csr.v1.QueryTurnstileResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -312,3 +473,412 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Parameters queries the parameters of the module.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// query all registered CSRs
+        async fn cs_rs(
+            &self,
+            request: tonic::Request<super::QueryCsRsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCsRsResponse>,
+            tonic::Status,
+        >;
+        /// query a specific CSR by the nftId
+        async fn csr_by_nft(
+            &self,
+            request: tonic::Request<super::QueryCsrByNftRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCsrByNftResponse>,
+            tonic::Status,
+        >;
+        /// query a CSR by smart contract address
+        async fn csr_by_contract(
+            &self,
+            request: tonic::Request<super::QueryCsrByContractRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCsrByContractResponse>,
+            tonic::Status,
+        >;
+        /// query the turnstile address
+        async fn turnstile(
+            &self,
+            request: tonic::Request<super::QueryTurnstileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryTurnstileResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.csr.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.csr.v1.Query/CSRs" => {
+                    #[allow(non_camel_case_types)]
+                    struct CSRsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryCsRsRequest>
+                    for CSRsSvc<T> {
+                        type Response = super::QueryCsRsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCsRsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::cs_rs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CSRsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.csr.v1.Query/CSRByNFT" => {
+                    #[allow(non_camel_case_types)]
+                    struct CSRByNFTSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCsrByNftRequest>
+                    for CSRByNFTSvc<T> {
+                        type Response = super::QueryCsrByNftResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCsrByNftRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::csr_by_nft(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CSRByNFTSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.csr.v1.Query/CSRByContract" => {
+                    #[allow(non_camel_case_types)]
+                    struct CSRByContractSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCsrByContractRequest>
+                    for CSRByContractSvc<T> {
+                        type Response = super::QueryCsrByContractResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCsrByContractRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::csr_by_contract(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CSRByContractSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.csr.v1.Query/Turnstile" => {
+                    #[allow(non_camel_case_types)]
+                    struct TurnstileSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryTurnstileRequest>
+                    for TurnstileSvc<T> {
+                        type Response = super::QueryTurnstileResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTurnstileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::turnstile(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TurnstileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.csr.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Csr>();
+    registry.register::<Params>();
+    registry.register::<GenesisState>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryCsRsRequest>();
+    registry.register::<QueryCsRsResponse>();
+    registry.register::<QueryCsrByNftRequest>();
+    registry.register::<QueryCsrByNftResponse>();
+    registry.register::<QueryCsrByContractRequest>();
+    registry.register::<QueryCsrByContractResponse>();
+    registry.register::<QueryTurnstileRequest>();
+    registry.register::<QueryTurnstileResponse>();
+}
@@ -1,27 +1,58 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EpochInfo {
     #[prost(string, tag = "1")]
     pub identifier: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub start_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub duration: ::core::option::Option<::prost_types::Duration>,
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub current_epoch: i64,
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub current_epoch_start_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(bool, tag = "6")]
     pub epoch_counting_started: bool,
     #[prost(int64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub current_epoch_start_height: i64,
 }
+impl ::prost::Name for EpochInfo {
+    const NAME: &'static str = "EpochInfo";
+    const PACKAGE: &'static str = "canto.epochs.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.epochs.v1.EpochInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.epochs.v1.EpochInfo".into()
+    }
+}
 /// GenesisState defines the epochs module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, repeated, tag = "1")]
     pub epochs: ::prost::alloc::vec::Vec<EpochInfo>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "canto.epochs.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.epochs.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.epochs.v1.GenesisState".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryEpochsInfoRequest {
     #[prost(message, optional, tag = "1")]
@@ -29,6 +60,18 @@ pub struct QueryEpochsInfoRequest {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryEpochsInfoRequest {
+    const NAME: &'static str = "QueryEpochsInfoRequest";
+    const PACKAGE: &'static str = "canto.epochs.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.epochs.v1.QueryEpochsInfoRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.epochs.v1.QueryEpochsInfoRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryEpochsInfoResponse {
     #[prost(message, repeated, tag = "1")]
@@ -38,16 +81,53 @@ pub struct QueryEpochsInfoResponse {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryEpochsInfoResponse {
+    const NAME: &'static str = "QueryEpochsInfoResponse";
+    const PACKAGE: &'static str = "canto.epochs.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.epochs.v1.QueryEpochsInfoResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.epochs.v1.QueryEpochsInfoResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCurrentEpochRequest {
     #[prost(string, tag = "1")]
     pub identifier: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryCurrentEpochRequest {
+    const NAME: &'static str = "QueryCurrentEpochRequest";
+    const PACKAGE: &'static str = "canto.epochs.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.epochs.v1.QueryCurrentEpochRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.epochs.v1.QueryCurrentEpochRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryCurrentEpochResponse {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub current_epoch: i64,
 }
+impl ::prost::Name for QueryCurrentEpochResponse {
+    const NAME: &'static str = "QueryCurrentEpochResponse";
+    const PACKAGE: &'static str = "canto.epochs.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.epochs.v1.QueryCurrentEpochResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.epochs.v1.QueryCurrentEpochResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -192,3 +272,250 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// EpochInfos provide running epochInfos
+        async fn epoch_infos(
+            &self,
+            request: tonic::Request<super::QueryEpochsInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryEpochsInfoResponse>,
+            tonic::Status,
+        >;
+        /// CurrentEpoch provide current epoch of specified identifier
+        async fn current_epoch(
+            &self,
+            request: tonic::Request<super::QueryCurrentEpochRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCurrentEpochResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.epochs.v1.Query/EpochInfos" => {
+                    #[allow(non_camel_case_types)]
+                    struct EpochInfosSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryEpochsInfoRequest>
+                    for EpochInfosSvc<T> {
+                        type Response = super::QueryEpochsInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryEpochsInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::epoch_infos(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EpochInfosSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.epochs.v1.Query/CurrentEpoch" => {
+                    #[allow(non_camel_case_types)]
+                    struct CurrentEpochSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCurrentEpochRequest>
+                    for CurrentEpochSvc<T> {
+                        type Response = super::QueryCurrentEpochResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCurrentEpochRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::current_epoch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CurrentEpochSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.epochs.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<EpochInfo>();
+    registry.register::<GenesisState>();
+    registry.register::<QueryEpochsInfoRequest>();
+    registry.register::<QueryEpochsInfoResponse>();
+    registry.register::<QueryCurrentEpochRequest>();
+    registry.register::<QueryCurrentEpochResponse>();
+}
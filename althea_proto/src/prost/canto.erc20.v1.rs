@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// TokenPair defines an instance that records a pairing consisting of a native
 ///   Cosmos Coin and an ERC20 token address.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TokenPair {
     /// address of ERC20 contract token
@@ -14,10 +16,23 @@ pub struct TokenPair {
     pub enabled: bool,
     /// ERC20 owner address ENUM (0 invalid, 1 ModuleAccount, 2 external address)
     #[prost(enumeration = "Owner", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<Owner, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<Owner, _>"))]
     pub contract_owner: i32,
 }
+impl ::prost::Name for TokenPair {
+    const NAME: &'static str = "TokenPair";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.TokenPair".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.TokenPair".into()
+    }
+}
 /// RegisterCoinProposal is a gov Content type to register a token pair for a
 /// native Cosmos coin.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RegisterCoinProposal {
     /// title of the proposal
@@ -32,8 +47,20 @@ pub struct RegisterCoinProposal {
         cosmos_sdk_proto::cosmos::bank::v1beta1::Metadata,
     >,
 }
+impl ::prost::Name for RegisterCoinProposal {
+    const NAME: &'static str = "RegisterCoinProposal";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.RegisterCoinProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.RegisterCoinProposal".into()
+    }
+}
 /// RegisterERC20Proposal is a gov Content type to register a token pair for an
 /// ERC20 token
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RegisterErc20Proposal {
     /// title of the proposa  string title = 1;
@@ -46,8 +73,20 @@ pub struct RegisterErc20Proposal {
     #[prost(string, tag = "3")]
     pub erc20address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RegisterErc20Proposal {
+    const NAME: &'static str = "RegisterERC20Proposal";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.RegisterERC20Proposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.RegisterERC20Proposal".into()
+    }
+}
 /// ToggleTokenConversionProposal is a gov Content type to toggle the conversion
 /// of a token pair.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ToggleTokenConversionProposal {
     /// title of the proposal
@@ -61,6 +100,16 @@ pub struct ToggleTokenConversionProposal {
     #[prost(string, tag = "3")]
     pub token: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ToggleTokenConversionProposal {
+    const NAME: &'static str = "ToggleTokenConversionProposal";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.ToggleTokenConversionProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.ToggleTokenConversionProposal".into()
+    }
+}
 /// Owner enumerates the ownership of a ERC20 contract.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -94,7 +143,18 @@ impl Owner {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for Owner {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// GenesisState defines the module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// module parameters
@@ -104,7 +164,19 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "2")]
     pub token_pairs: ::prost::alloc::vec::Vec<TokenPair>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.GenesisState".into()
+    }
+}
 /// Params defines the erc20 module params
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Params {
     /// parameter to enable the conversion of Cosmos coins <--> ERC20 tokens.
@@ -116,8 +188,20 @@ pub struct Params {
     #[prost(bool, tag = "2")]
     pub enable_evm_hook: bool,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.Params".into()
+    }
+}
 /// QueryTokenPairsRequest is the request type for the Query/TokenPairs RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTokenPairsRequest {
     /// pagination defines an optional pagination for the request.
@@ -126,8 +210,20 @@ pub struct QueryTokenPairsRequest {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryTokenPairsRequest {
+    const NAME: &'static str = "QueryTokenPairsRequest";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.QueryTokenPairsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.QueryTokenPairsRequest".into()
+    }
+}
 /// QueryTokenPairsResponse is the response type for the Query/TokenPairs RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTokenPairsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -138,7 +234,19 @@ pub struct QueryTokenPairsResponse {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryTokenPairsResponse {
+    const NAME: &'static str = "QueryTokenPairsResponse";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.QueryTokenPairsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.QueryTokenPairsResponse".into()
+    }
+}
 /// QueryTokenPairRequest is the request type for the Query/TokenPair RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTokenPairRequest {
     /// token identifier can be either the hex contract address of the ERC20 or the
@@ -146,23 +254,71 @@ pub struct QueryTokenPairRequest {
     #[prost(string, tag = "1")]
     pub token: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryTokenPairRequest {
+    const NAME: &'static str = "QueryTokenPairRequest";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.QueryTokenPairRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.QueryTokenPairRequest".into()
+    }
+}
 /// QueryTokenPairResponse is the response type for the Query/TokenPair RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTokenPairResponse {
     #[prost(message, optional, tag = "1")]
     pub token_pair: ::core::option::Option<TokenPair>,
 }
+impl ::prost::Name for QueryTokenPairResponse {
+    const NAME: &'static str = "QueryTokenPairResponse";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.QueryTokenPairResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.QueryTokenPairResponse".into()
+    }
+}
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is the response type for the Query/Params RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.QueryParamsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -333,6 +489,8 @@ pub mod query_client {
     }
 }
 /// MsgConvertCoin defines a Msg to convert a native Cosmos coin to a ERC20 token
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgConvertCoin {
     /// Cosmos coin which denomination is registered in a token pair. The coin
@@ -346,11 +504,35 @@ pub struct MsgConvertCoin {
     #[prost(string, tag = "3")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgConvertCoin {
+    const NAME: &'static str = "MsgConvertCoin";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.MsgConvertCoin".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.MsgConvertCoin".into()
+    }
+}
 /// MsgConvertCoinResponse returns no fields
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgConvertCoinResponse {}
+impl ::prost::Name for MsgConvertCoinResponse {
+    const NAME: &'static str = "MsgConvertCoinResponse";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.MsgConvertCoinResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.MsgConvertCoinResponse".into()
+    }
+}
 /// MsgConvertERC20 defines a Msg to convert a ERC20 token to a native Cosmos
 /// coin.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgConvertErc20 {
     /// ERC20 token contract address registered in a token pair
@@ -366,9 +548,33 @@ pub struct MsgConvertErc20 {
     #[prost(string, tag = "4")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgConvertErc20 {
+    const NAME: &'static str = "MsgConvertERC20";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.MsgConvertERC20".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.MsgConvertERC20".into()
+    }
+}
 /// MsgConvertERC20Response returns no fields
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgConvertErc20Response {}
+impl ::prost::Name for MsgConvertErc20Response {
+    const NAME: &'static str = "MsgConvertERC20Response";
+    const PACKAGE: &'static str = "canto.erc20.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.erc20.v1.MsgConvertERC20Response".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.erc20.v1.MsgConvertERC20Response".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -515,3 +721,546 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// TokenPairs retrieves registered token pairs
+        async fn token_pairs(
+            &self,
+            request: tonic::Request<super::QueryTokenPairsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryTokenPairsResponse>,
+            tonic::Status,
+        >;
+        /// TokenPair retrieves a registered token pair
+        async fn token_pair(
+            &self,
+            request: tonic::Request<super::QueryTokenPairRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryTokenPairResponse>,
+            tonic::Status,
+        >;
+        /// Params retrieves the erc20 module params
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.erc20.v1.Query/TokenPairs" => {
+                    #[allow(non_camel_case_types)]
+                    struct TokenPairsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryTokenPairsRequest>
+                    for TokenPairsSvc<T> {
+                        type Response = super::QueryTokenPairsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTokenPairsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::token_pairs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TokenPairsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.erc20.v1.Query/TokenPair" => {
+                    #[allow(non_camel_case_types)]
+                    struct TokenPairSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryTokenPairRequest>
+                    for TokenPairSvc<T> {
+                        type Response = super::QueryTokenPairResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTokenPairRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::token_pair(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TokenPairSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.erc20.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.erc20.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// ConvertCoin mints a ERC20 representation of the native Cosmos coin denom
+        /// that is registered on the token mapping.
+        async fn convert_coin(
+            &self,
+            request: tonic::Request<super::MsgConvertCoin>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgConvertCoinResponse>,
+            tonic::Status,
+        >;
+        /// ConvertERC20 mints a native Cosmos coin representation of the ERC20 token
+        /// contract that is registered on the token mapping.
+        async fn convert_erc20(
+            &self,
+            request: tonic::Request<super::MsgConvertErc20>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgConvertErc20Response>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the erc20 Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.erc20.v1.Msg/ConvertCoin" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConvertCoinSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgConvertCoin>
+                    for ConvertCoinSvc<T> {
+                        type Response = super::MsgConvertCoinResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgConvertCoin>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::convert_coin(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ConvertCoinSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.erc20.v1.Msg/ConvertERC20" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConvertERC20Svc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgConvertErc20>
+                    for ConvertERC20Svc<T> {
+                        type Response = super::MsgConvertErc20Response;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgConvertErc20>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::convert_erc20(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ConvertERC20Svc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.erc20.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<TokenPair>();
+    registry.register::<RegisterCoinProposal>();
+    registry.register::<RegisterErc20Proposal>();
+    registry.register::<ToggleTokenConversionProposal>();
+    registry.register::<GenesisState>();
+    registry.register::<Params>();
+    registry.register::<QueryTokenPairsRequest>();
+    registry.register::<QueryTokenPairsResponse>();
+    registry.register::<QueryTokenPairRequest>();
+    registry.register::<QueryTokenPairResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<MsgConvertCoin>();
+    registry.register::<MsgConvertCoinResponse>();
+    registry.register::<MsgConvertErc20>();
+    registry.register::<MsgConvertErc20Response>();
+}
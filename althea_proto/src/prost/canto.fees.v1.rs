@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// Fee defines an instance that organizes fee distribution conditions for the
 /// owner of a given smart contract
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Fee {
     /// hex address of registered contract
@@ -14,7 +16,19 @@ pub struct Fee {
     #[prost(string, tag = "3")]
     pub withdraw_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Fee {
+    const NAME: &'static str = "Fee";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.Fee".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.Fee".into()
+    }
+}
 /// GenesisState defines the module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// module parameters
@@ -24,7 +38,19 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "2")]
     pub fees: ::prost::alloc::vec::Vec<Fee>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.GenesisState".into()
+    }
+}
 /// Params defines the fees module params
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// parameter to enable fees
@@ -37,9 +63,22 @@ pub struct Params {
     /// addr_derivation_cost_create defines the cost of address derivation for
     /// verifying the contract deployer at fee registration
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub addr_derivation_cost_create: u64,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.Params".into()
+    }
+}
 /// QueryFeesRequest is the request type for the Query/Fees RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeesRequest {
     /// pagination defines an optional pagination for the request.
@@ -48,7 +87,19 @@ pub struct QueryFeesRequest {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryFeesRequest {
+    const NAME: &'static str = "QueryFeesRequest";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryFeesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryFeesRequest".into()
+    }
+}
 /// QueryFeesResponse is the response type for the Query/Fees RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeesResponse {
     #[prost(message, repeated, tag = "1")]
@@ -59,30 +110,90 @@ pub struct QueryFeesResponse {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryFeesResponse {
+    const NAME: &'static str = "QueryFeesResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryFeesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryFeesResponse".into()
+    }
+}
 /// QueryFeeRequest is the request type for the Query/Fee RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeeRequest {
     /// contract identifier is the hex contract address of a contract
     #[prost(string, tag = "1")]
     pub contract_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryFeeRequest {
+    const NAME: &'static str = "QueryFeeRequest";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryFeeRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryFeeRequest".into()
+    }
+}
 /// QueryFeeResponse is the response type for the Query/Fee RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryFeeResponse {
     #[prost(message, optional, tag = "1")]
     pub fee: ::core::option::Option<Fee>,
 }
+impl ::prost::Name for QueryFeeResponse {
+    const NAME: &'static str = "QueryFeeResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryFeeResponse".into()
+    }
+}
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryParamsResponse".into()
+    }
+}
 /// QueryDeployerFeesRequest is the request type for the Query/DeployerFees RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDeployerFeesRequest {
     /// deployer bech32 address
@@ -94,8 +205,20 @@ pub struct QueryDeployerFeesRequest {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryDeployerFeesRequest {
+    const NAME: &'static str = "QueryDeployerFeesRequest";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryDeployerFeesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryDeployerFeesRequest".into()
+    }
+}
 /// QueryDeployerFeesResponse is the response type for the Query/DeployerFees RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDeployerFeesResponse {
     #[prost(message, repeated, tag = "1")]
@@ -106,6 +229,18 @@ pub struct QueryDeployerFeesResponse {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryDeployerFeesResponse {
+    const NAME: &'static str = "QueryDeployerFeesResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.QueryDeployerFeesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.QueryDeployerFeesResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -297,6 +432,8 @@ pub mod query_client {
     }
 }
 /// MsgRegisterFee defines a message that registers a Fee
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRegisterFee {
     /// contract hex address
@@ -313,12 +450,37 @@ pub struct MsgRegisterFee {
     /// the nonce that determines the contract's address - it can be an EOA nonce
     /// or a factory contract nonce
     #[prost(uint64, repeated, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64_vec"))]
     pub nonces: ::prost::alloc::vec::Vec<u64>,
 }
+impl ::prost::Name for MsgRegisterFee {
+    const NAME: &'static str = "MsgRegisterFee";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.MsgRegisterFee".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.MsgRegisterFee".into()
+    }
+}
 /// MsgRegisterFeeResponse defines the MsgRegisterFee response type
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgRegisterFeeResponse {}
+impl ::prost::Name for MsgRegisterFeeResponse {
+    const NAME: &'static str = "MsgRegisterFeeResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.MsgRegisterFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.MsgRegisterFeeResponse".into()
+    }
+}
 /// MsgCancelFee defines a message that cancels a registered a Fee
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCancelFee {
     /// contract hex address
@@ -328,11 +490,35 @@ pub struct MsgCancelFee {
     #[prost(string, tag = "2")]
     pub deployer_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgCancelFee {
+    const NAME: &'static str = "MsgCancelFee";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.MsgCancelFee".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.MsgCancelFee".into()
+    }
+}
 /// MsgCancelFeeResponse defines the MsgCancelFee response type
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgCancelFeeResponse {}
+impl ::prost::Name for MsgCancelFeeResponse {
+    const NAME: &'static str = "MsgCancelFeeResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.MsgCancelFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.MsgCancelFeeResponse".into()
+    }
+}
 /// MsgUpdateFee defines a message that updates the withdraw address for a
 /// registered Fee
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgUpdateFee {
     /// contract hex address
@@ -345,9 +531,33 @@ pub struct MsgUpdateFee {
     #[prost(string, tag = "3")]
     pub withdraw_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgUpdateFee {
+    const NAME: &'static str = "MsgUpdateFee";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.MsgUpdateFee".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.MsgUpdateFee".into()
+    }
+}
 /// MsgUpdateFeeResponse defines the MsgUpdateFee response type
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgUpdateFeeResponse {}
+impl ::prost::Name for MsgUpdateFeeResponse {
+    const NAME: &'static str = "MsgUpdateFeeResponse";
+    const PACKAGE: &'static str = "canto.fees.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.fees.v1.MsgUpdateFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.fees.v1.MsgUpdateFeeResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -518,3 +728,648 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Fees retrieves all registered contracts for fee distribution
+        async fn fees(
+            &self,
+            request: tonic::Request<super::QueryFeesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryFeesResponse>,
+            tonic::Status,
+        >;
+        /// Fee retrieves a registered contract for fee distribution for a given
+        /// address
+        async fn fee(
+            &self,
+            request: tonic::Request<super::QueryFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryFeeResponse>,
+            tonic::Status,
+        >;
+        /// Params retrieves the fees module params
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// DeployerFees retrieves all contracts that a given deployer has registered
+        /// for fee distribution
+        async fn deployer_fees(
+            &self,
+            request: tonic::Request<super::QueryDeployerFeesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDeployerFeesResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.fees.v1.Query/Fees" => {
+                    #[allow(non_camel_case_types)]
+                    struct FeesSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryFeesRequest>
+                    for FeesSvc<T> {
+                        type Response = super::QueryFeesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryFeesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::fees(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.fees.v1.Query/Fee" => {
+                    #[allow(non_camel_case_types)]
+                    struct FeeSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryFeeRequest>
+                    for FeeSvc<T> {
+                        type Response = super::QueryFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.fees.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.fees.v1.Query/DeployerFees" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeployerFeesSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDeployerFeesRequest>
+                    for DeployerFeesSvc<T> {
+                        type Response = super::QueryDeployerFeesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDeployerFeesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::deployer_fees(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeployerFeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.fees.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// RegisterFee registers a new contract for receiving transaction fees
+        async fn register_fee(
+            &self,
+            request: tonic::Request<super::MsgRegisterFee>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgRegisterFeeResponse>,
+            tonic::Status,
+        >;
+        /// CancelFee cancels a contract's fee registration and further receival of
+        /// transaction fees
+        async fn cancel_fee(
+            &self,
+            request: tonic::Request<super::MsgCancelFee>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgCancelFeeResponse>,
+            tonic::Status,
+        >;
+        /// UpdateFee updates the withdraw address
+        async fn update_fee(
+            &self,
+            request: tonic::Request<super::MsgUpdateFee>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgUpdateFeeResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the fees Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.fees.v1.Msg/RegisterFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterFeeSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgRegisterFee>
+                    for RegisterFeeSvc<T> {
+                        type Response = super::MsgRegisterFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgRegisterFee>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::register_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.fees.v1.Msg/CancelFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelFeeSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgCancelFee>
+                    for CancelFeeSvc<T> {
+                        type Response = super::MsgCancelFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgCancelFee>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::cancel_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.fees.v1.Msg/UpdateFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateFeeSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgUpdateFee>
+                    for UpdateFeeSvc<T> {
+                        type Response = super::MsgUpdateFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgUpdateFee>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::update_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.fees.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Fee>();
+    registry.register::<GenesisState>();
+    registry.register::<Params>();
+    registry.register::<QueryFeesRequest>();
+    registry.register::<QueryFeesResponse>();
+    registry.register::<QueryFeeRequest>();
+    registry.register::<QueryFeeResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryDeployerFeesRequest>();
+    registry.register::<QueryDeployerFeesResponse>();
+    registry.register::<MsgRegisterFee>();
+    registry.register::<MsgRegisterFeeResponse>();
+    registry.register::<MsgCancelFee>();
+    registry.register::<MsgCancelFeeResponse>();
+    registry.register::<MsgUpdateFee>();
+    registry.register::<MsgUpdateFeeResponse>();
+}
@@ -6,6 +6,8 @@
 /// Model like this:
 /// mintDistribution1 = distribution1 / (1 - teamVestingDistribution)
 /// 0.5333333         = 40%           / (1 - 25%)
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InflationDistribution {
     /// staking_rewards defines the proportion of the minted minted_denom that is
@@ -23,11 +25,23 @@ pub struct InflationDistribution {
     #[prost(string, tag = "3")]
     pub community_pool: ::prost::alloc::string::String,
 }
+impl ::prost::Name for InflationDistribution {
+    const NAME: &'static str = "InflationDistribution";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.InflationDistribution".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.InflationDistribution".into()
+    }
+}
 /// ExponentialCalculation holds factors to calculate exponential inflation on
 /// each period. Calculation reference:
 /// periodProvision = exponentialDecay       *  bondingIncentive
 /// f(x)            = (a * (1 - r) ^ x + c)  *  (1 + max_variance - bondedRatio *
 /// (max_variance / bonding_target))
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExponentialCalculation {
     /// initial value
@@ -46,7 +60,19 @@ pub struct ExponentialCalculation {
     #[prost(string, tag = "5")]
     pub max_variance: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ExponentialCalculation {
+    const NAME: &'static str = "ExponentialCalculation";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.ExponentialCalculation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.ExponentialCalculation".into()
+    }
+}
 /// GenesisState defines the inflation module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// params defines all the paramaters of the module.
@@ -54,18 +80,33 @@ pub struct GenesisState {
     pub params: ::core::option::Option<Params>,
     /// amount of past periods, based on the epochs per period param
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub period: u64,
     /// inflation epoch identifier
     #[prost(string, tag = "3")]
     pub epoch_identifier: ::prost::alloc::string::String,
     /// number of epochs after which inflation is recalculated
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub epochs_per_period: i64,
     /// number of epochs that have passed while inflation is disabled
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub skipped_epochs: u64,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.GenesisState".into()
+    }
+}
 /// Params holds parameters for the inflation module.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// type of coin to mint
@@ -81,22 +122,71 @@ pub struct Params {
     #[prost(bool, tag = "4")]
     pub enable_inflation: bool,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.Params".into()
+    }
+}
 /// QueryPeriodRequest is the request type for the Query/Period RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryPeriodRequest {}
+impl ::prost::Name for QueryPeriodRequest {
+    const NAME: &'static str = "QueryPeriodRequest";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryPeriodRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryPeriodRequest".into()
+    }
+}
 /// QueryPeriodResponse is the response type for the Query/Period RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryPeriodResponse {
     /// period is the current minting per epoch provision value.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub period: u64,
 }
+impl ::prost::Name for QueryPeriodResponse {
+    const NAME: &'static str = "QueryPeriodResponse";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryPeriodResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryPeriodResponse".into()
+    }
+}
 /// QueryEpochMintProvisionRequest is the request type for the
 /// Query/EpochMintProvision RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryEpochMintProvisionRequest {}
+impl ::prost::Name for QueryEpochMintProvisionRequest {
+    const NAME: &'static str = "QueryEpochMintProvisionRequest";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryEpochMintProvisionRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryEpochMintProvisionRequest".into()
+    }
+}
 /// QueryEpochMintProvisionResponse is the response type for the
 /// Query/EpochMintProvision RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryEpochMintProvisionResponse {
     /// epoch_mint_provision is the current minting per epoch provision value.
@@ -105,24 +195,73 @@ pub struct QueryEpochMintProvisionResponse {
         cosmos_sdk_proto::cosmos::base::v1beta1::DecCoin,
     >,
 }
+impl ::prost::Name for QueryEpochMintProvisionResponse {
+    const NAME: &'static str = "QueryEpochMintProvisionResponse";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryEpochMintProvisionResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryEpochMintProvisionResponse".into()
+    }
+}
 /// QuerySkippedEpochsRequest is the request type for the Query/SkippedEpochs RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QuerySkippedEpochsRequest {}
+impl ::prost::Name for QuerySkippedEpochsRequest {
+    const NAME: &'static str = "QuerySkippedEpochsRequest";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QuerySkippedEpochsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QuerySkippedEpochsRequest".into()
+    }
+}
 /// QuerySkippedEpochsResponse is the response type for the Query/SkippedEpochs
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QuerySkippedEpochsResponse {
     /// number of epochs that the inflation module has been disabled.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub skipped_epochs: u64,
 }
+impl ::prost::Name for QuerySkippedEpochsResponse {
+    const NAME: &'static str = "QuerySkippedEpochsResponse";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QuerySkippedEpochsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QuerySkippedEpochsResponse".into()
+    }
+}
 /// QueryCirculatingSupplyRequest is the request type for the
 /// Query/CirculatingSupply RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryCirculatingSupplyRequest {}
+impl ::prost::Name for QueryCirculatingSupplyRequest {
+    const NAME: &'static str = "QueryCirculatingSupplyRequest";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryCirculatingSupplyRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryCirculatingSupplyRequest".into()
+    }
+}
 /// QueryCirculatingSupplyResponse is the response type for the
 /// Query/CirculatingSupply RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCirculatingSupplyResponse {
     /// total amount of coins in circulation
@@ -131,28 +270,88 @@ pub struct QueryCirculatingSupplyResponse {
         cosmos_sdk_proto::cosmos::base::v1beta1::DecCoin,
     >,
 }
+impl ::prost::Name for QueryCirculatingSupplyResponse {
+    const NAME: &'static str = "QueryCirculatingSupplyResponse";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryCirculatingSupplyResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryCirculatingSupplyResponse".into()
+    }
+}
 /// QueryInflationRateRequest is the request type for the Query/InflationRate RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryInflationRateRequest {}
+impl ::prost::Name for QueryInflationRateRequest {
+    const NAME: &'static str = "QueryInflationRateRequest";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryInflationRateRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryInflationRateRequest".into()
+    }
+}
 /// QueryInflationRateResponse is the response type for the Query/InflationRate
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryInflationRateResponse {
     /// rate by which the total supply increases within one period
     #[prost(string, tag = "1")]
     pub inflation_rate: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryInflationRateResponse {
+    const NAME: &'static str = "QueryInflationRateResponse";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryInflationRateResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryInflationRateResponse".into()
+    }
+}
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// params defines the parameters of the module.
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "canto.inflation.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.inflation.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.inflation.v1.QueryParamsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -402,3 +601,471 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Period retrieves current period.
+        async fn period(
+            &self,
+            request: tonic::Request<super::QueryPeriodRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryPeriodResponse>,
+            tonic::Status,
+        >;
+        /// EpochMintProvision retrieves current minting epoch provision value.
+        async fn epoch_mint_provision(
+            &self,
+            request: tonic::Request<super::QueryEpochMintProvisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryEpochMintProvisionResponse>,
+            tonic::Status,
+        >;
+        /// SkippedEpochs retrieves the total number of skipped epochs.
+        async fn skipped_epochs(
+            &self,
+            request: tonic::Request<super::QuerySkippedEpochsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QuerySkippedEpochsResponse>,
+            tonic::Status,
+        >;
+        /// CirculatingSupply retrieves the total number of tokens that are in
+        /// circulation (i.e. excluding unvested tokens).
+        async fn circulating_supply(
+            &self,
+            request: tonic::Request<super::QueryCirculatingSupplyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCirculatingSupplyResponse>,
+            tonic::Status,
+        >;
+        /// InflationRate retrieves the inflation rate of the current period.
+        async fn inflation_rate(
+            &self,
+            request: tonic::Request<super::QueryInflationRateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryInflationRateResponse>,
+            tonic::Status,
+        >;
+        /// Params retrieves the total set of minting parameters.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query provides defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.inflation.v1.Query/Period" => {
+                    #[allow(non_camel_case_types)]
+                    struct PeriodSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryPeriodRequest>
+                    for PeriodSvc<T> {
+                        type Response = super::QueryPeriodResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryPeriodRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::period(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PeriodSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.inflation.v1.Query/EpochMintProvision" => {
+                    #[allow(non_camel_case_types)]
+                    struct EpochMintProvisionSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryEpochMintProvisionRequest>
+                    for EpochMintProvisionSvc<T> {
+                        type Response = super::QueryEpochMintProvisionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryEpochMintProvisionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::epoch_mint_provision(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EpochMintProvisionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.inflation.v1.Query/SkippedEpochs" => {
+                    #[allow(non_camel_case_types)]
+                    struct SkippedEpochsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QuerySkippedEpochsRequest>
+                    for SkippedEpochsSvc<T> {
+                        type Response = super::QuerySkippedEpochsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QuerySkippedEpochsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::skipped_epochs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SkippedEpochsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.inflation.v1.Query/CirculatingSupply" => {
+                    #[allow(non_camel_case_types)]
+                    struct CirculatingSupplySvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCirculatingSupplyRequest>
+                    for CirculatingSupplySvc<T> {
+                        type Response = super::QueryCirculatingSupplyResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCirculatingSupplyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::circulating_supply(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CirculatingSupplySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.inflation.v1.Query/InflationRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct InflationRateSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryInflationRateRequest>
+                    for InflationRateSvc<T> {
+                        type Response = super::QueryInflationRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryInflationRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::inflation_rate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = InflationRateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.inflation.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.inflation.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<InflationDistribution>();
+    registry.register::<ExponentialCalculation>();
+    registry.register::<GenesisState>();
+    registry.register::<Params>();
+    registry.register::<QueryPeriodRequest>();
+    registry.register::<QueryPeriodResponse>();
+    registry.register::<QueryEpochMintProvisionRequest>();
+    registry.register::<QueryEpochMintProvisionResponse>();
+    registry.register::<QuerySkippedEpochsRequest>();
+    registry.register::<QuerySkippedEpochsResponse>();
+    registry.register::<QueryCirculatingSupplyRequest>();
+    registry.register::<QueryCirculatingSupplyResponse>();
+    registry.register::<QueryInflationRateRequest>();
+    registry.register::<QueryInflationRateResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+}
@@ -1,13 +1,27 @@
 // This file is @generated by prost-build.
 /// QueryBalancesRequest is the request type for the Query/Balances RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBalancesRequest {
     /// address of the clawback vesting account
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryBalancesRequest {
+    const NAME: &'static str = "QueryBalancesRequest";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.QueryBalancesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.QueryBalancesRequest".into()
+    }
+}
 /// QueryBalancesResponse is the response type for the Query/Balances RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBalancesResponse {
     /// current amount of locked tokens
@@ -26,6 +40,18 @@ pub struct QueryBalancesResponse {
         cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
     >,
 }
+impl ::prost::Name for QueryBalancesResponse {
+    const NAME: &'static str = "QueryBalancesResponse";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.QueryBalancesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.QueryBalancesResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -147,6 +173,8 @@ pub mod query_client {
 }
 /// MsgCreateClawbackVestingAccount defines a message that enables creating a
 /// ClawbackVestingAccount.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCreateClawbackVestingAccount {
     /// from_address specifies the account to provide the funds and sign the
@@ -158,6 +186,7 @@ pub struct MsgCreateClawbackVestingAccount {
     pub to_address: ::prost::alloc::string::String,
     /// start_time defines the time at which the vesting period begins
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// lockup_periods defines the unlocking schedule relative to the start_time
     #[prost(message, repeated, tag = "4")]
@@ -177,12 +206,36 @@ pub struct MsgCreateClawbackVestingAccount {
     #[prost(bool, tag = "6")]
     pub merge: bool,
 }
+impl ::prost::Name for MsgCreateClawbackVestingAccount {
+    const NAME: &'static str = "MsgCreateClawbackVestingAccount";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.MsgCreateClawbackVestingAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.MsgCreateClawbackVestingAccount".into()
+    }
+}
 /// MsgCreateClawbackVestingAccountResponse defines the
 /// MsgCreateClawbackVestingAccount response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgCreateClawbackVestingAccountResponse {}
+impl ::prost::Name for MsgCreateClawbackVestingAccountResponse {
+    const NAME: &'static str = "MsgCreateClawbackVestingAccountResponse";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.MsgCreateClawbackVestingAccountResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.MsgCreateClawbackVestingAccountResponse".into()
+    }
+}
 /// MsgClawback defines a message that removes unvested tokens from a
 /// ClawbackVestingAccount.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgClawback {
     /// funder_address is the address which funded the account
@@ -198,9 +251,33 @@ pub struct MsgClawback {
     #[prost(string, tag = "3")]
     pub dest_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgClawback {
+    const NAME: &'static str = "MsgClawback";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.MsgClawback".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.MsgClawback".into()
+    }
+}
 /// MsgClawbackResponse defines the MsgClawback response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgClawbackResponse {}
+impl ::prost::Name for MsgClawbackResponse {
+    const NAME: &'static str = "MsgClawbackResponse";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.MsgClawbackResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.MsgClawbackResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -351,10 +428,435 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Retrieves the unvested, vested and locked tokens for a vesting account
+        async fn balances(
+            &self,
+            request: tonic::Request<super::QueryBalancesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryBalancesResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.vesting.v1.Query/Balances" => {
+                    #[allow(non_camel_case_types)]
+                    struct BalancesSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryBalancesRequest>
+                    for BalancesSvc<T> {
+                        type Response = super::QueryBalancesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBalancesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::balances(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BalancesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.vesting.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// CreateClawbackVestingAccount creats a vesting account that is subject to
+        /// clawback and the configuration of vesting and lockup schedules.
+        async fn create_clawback_vesting_account(
+            &self,
+            request: tonic::Request<super::MsgCreateClawbackVestingAccount>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgCreateClawbackVestingAccountResponse>,
+            tonic::Status,
+        >;
+        /// Clawback removes the unvested tokens from a ClawbackVestingAccount.
+        async fn clawback(
+            &self,
+            request: tonic::Request<super::MsgClawback>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgClawbackResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the vesting Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/canto.vesting.v1.Msg/CreateClawbackVestingAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateClawbackVestingAccountSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgCreateClawbackVestingAccount>
+                    for CreateClawbackVestingAccountSvc<T> {
+                        type Response = super::MsgCreateClawbackVestingAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::MsgCreateClawbackVestingAccount,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::create_clawback_vesting_account(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateClawbackVestingAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/canto.vesting.v1.Msg/Clawback" => {
+                    #[allow(non_camel_case_types)]
+                    struct ClawbackSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgClawback>
+                    for ClawbackSvc<T> {
+                        type Response = super::MsgClawbackResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgClawback>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::clawback(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ClawbackSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "canto.vesting.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
 /// ClawbackVestingAccount implements the VestingAccount interface. It provides
 /// an account that can hold contributions subject to "lockup" (like a
 /// PeriodicVestingAccount), or vesting which is subject to clawback
 /// of unvested tokens, or a combination (tokens vest, but are still locked).
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClawbackVestingAccount {
     /// base_vesting_account implements the VestingAccount interface. It contains
@@ -368,6 +870,7 @@ pub struct ClawbackVestingAccount {
     pub funder_address: ::prost::alloc::string::String,
     /// start_time defines the time at which the vesting period begins
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub start_time: ::core::option::Option<::prost_types::Timestamp>,
     /// lockup_periods defines the unlocking schedule relative to the start_time
     #[prost(message, repeated, tag = "4")]
@@ -380,3 +883,24 @@ pub struct ClawbackVestingAccount {
         cosmos_sdk_proto::cosmos::vesting::v1beta1::Period,
     >,
 }
+impl ::prost::Name for ClawbackVestingAccount {
+    const NAME: &'static str = "ClawbackVestingAccount";
+    const PACKAGE: &'static str = "canto.vesting.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "canto.vesting.v1.ClawbackVestingAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/canto.vesting.v1.ClawbackVestingAccount".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<QueryBalancesRequest>();
+    registry.register::<QueryBalancesResponse>();
+    registry.register::<MsgCreateClawbackVestingAccount>();
+    registry.register::<MsgCreateClawbackVestingAccountResponse>();
+    registry.register::<MsgClawback>();
+    registry.register::<MsgClawbackResponse>();
+    registry.register::<ClawbackVestingAccount>();
+}
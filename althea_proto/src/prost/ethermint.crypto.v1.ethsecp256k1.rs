@@ -2,15 +2,47 @@
 /// PubKey defines a type alias for an ecdsa.PublicKey that implements
 /// Tendermint's PubKey interface. It represents the 33-byte compressed public
 /// key format.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PubKey {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for PubKey {
+    const NAME: &'static str = "PubKey";
+    const PACKAGE: &'static str = "ethermint.crypto.v1.ethsecp256k1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.crypto.v1.ethsecp256k1.PubKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.crypto.v1.ethsecp256k1.PubKey".into()
+    }
+}
 /// PrivKey defines a type alias for an ecdsa.PrivateKey that implements
 /// Tendermint's PrivateKey interface.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PrivKey {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for PrivKey {
+    const NAME: &'static str = "PrivKey";
+    const PACKAGE: &'static str = "ethermint.crypto.v1.ethsecp256k1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.crypto.v1.ethsecp256k1.PrivKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.crypto.v1.ethsecp256k1.PrivKey".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<PubKey>();
+    registry.register::<PrivKey>();
+}
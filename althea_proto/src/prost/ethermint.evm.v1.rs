@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// Params defines the EVM module parameters
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// evm denom represents the token denomination used to run the EVM state
@@ -14,6 +16,7 @@ pub struct Params {
     pub enable_call: bool,
     /// extra eips defines the additional EIPs for the vm.Config
     #[prost(int64, repeated, packed = "false", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64_vec"))]
     pub extra_eips: ::prost::alloc::vec::Vec<i64>,
     /// chain config defines the EVM chain configuration parameters
     #[prost(message, optional, tag = "5")]
@@ -23,8 +26,20 @@ pub struct Params {
     #[prost(bool, tag = "6")]
     pub allow_unprotected_txs: bool,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.Params".into()
+    }
+}
 /// ChainConfig defines the Ethereum ChainConfig parameters using *sdk.Int values
 /// instead of *big.Int.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChainConfig {
     /// Homestead switch block (nil no fork, 0 = already homestead)
@@ -80,7 +95,19 @@ pub struct ChainConfig {
     #[prost(string, tag = "21")]
     pub merge_netsplit_block: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ChainConfig {
+    const NAME: &'static str = "ChainConfig";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.ChainConfig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.ChainConfig".into()
+    }
+}
 /// State represents a single Storage key value pair item.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct State {
     #[prost(string, tag = "1")]
@@ -88,9 +115,21 @@ pub struct State {
     #[prost(string, tag = "2")]
     pub value: ::prost::alloc::string::String,
 }
+impl ::prost::Name for State {
+    const NAME: &'static str = "State";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.State".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.State".into()
+    }
+}
 /// TransactionLogs define the logs generated from a transaction execution
 /// with a given hash. It it used for import/export data as transactions are not
 /// persisted on blockchain state after an upgrade.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionLogs {
     #[prost(string, tag = "1")]
@@ -98,11 +137,23 @@ pub struct TransactionLogs {
     #[prost(message, repeated, tag = "2")]
     pub logs: ::prost::alloc::vec::Vec<Log>,
 }
+impl ::prost::Name for TransactionLogs {
+    const NAME: &'static str = "TransactionLogs";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.TransactionLogs".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.TransactionLogs".into()
+    }
+}
 /// Log represents an protobuf compatible Ethereum Log that defines a contract
 /// log event. These events are generated by the LOG opcode and stored/indexed by
 /// the node.
 ///
 /// Consensus fields:
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Log {
     /// address of the contract that generated the event
@@ -113,21 +164,25 @@ pub struct Log {
     pub topics: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     /// supplied by the contract, usually ABI-encoded
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     /// block in which the transaction was included
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block_number: u64,
     /// hash of the transaction
     #[prost(string, tag = "5")]
     pub tx_hash: ::prost::alloc::string::String,
     /// index of the transaction in the block
     #[prost(uint64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub tx_index: u64,
     /// hash of the block in which the transaction was included
     #[prost(string, tag = "7")]
     pub block_hash: ::prost::alloc::string::String,
     /// index of the log in the block
     #[prost(uint64, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: u64,
     /// The Removed field is true if this log was reverted due to a chain
     /// reorganisation. You must pay attention to this field if you receive logs
@@ -135,7 +190,19 @@ pub struct Log {
     #[prost(bool, tag = "9")]
     pub removed: bool,
 }
+impl ::prost::Name for Log {
+    const NAME: &'static str = "Log";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.Log".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.Log".into()
+    }
+}
 /// TxResult stores results of Tx execution.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxResult {
     /// contract_address contains the ethereum address of the created contract (if
@@ -145,6 +212,7 @@ pub struct TxResult {
     pub contract_address: ::prost::alloc::string::String,
     /// bloom represents the bloom filter bytes
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub bloom: ::prost::alloc::vec::Vec<u8>,
     /// tx_logs contains the transaction hash and the proto-compatible ethereum
     /// logs.
@@ -152,15 +220,29 @@ pub struct TxResult {
     pub tx_logs: ::core::option::Option<TransactionLogs>,
     /// ret defines the bytes from the execution.
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub ret: ::prost::alloc::vec::Vec<u8>,
     /// reverted flag is set to true when the call has been reverted
     #[prost(bool, tag = "5")]
     pub reverted: bool,
     /// gas_used notes the amount of gas consumed while execution
     #[prost(uint64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: u64,
 }
+impl ::prost::Name for TxResult {
+    const NAME: &'static str = "TxResult";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.TxResult".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.TxResult".into()
+    }
+}
 /// AccessTuple is the element type of an access list.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AccessTuple {
     /// hex formatted ethereum address
@@ -170,7 +252,19 @@ pub struct AccessTuple {
     #[prost(string, repeated, tag = "2")]
     pub storage_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for AccessTuple {
+    const NAME: &'static str = "AccessTuple";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.AccessTuple".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.AccessTuple".into()
+    }
+}
 /// TraceConfig holds extra parameters to trace functions.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TraceConfig {
     /// custom javascript tracer
@@ -182,6 +276,7 @@ pub struct TraceConfig {
     pub timeout: ::prost::alloc::string::String,
     /// number of blocks the tracer is willing to go back
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub reexec: u64,
     /// disable stack capture
     #[prost(bool, tag = "5")]
@@ -205,7 +300,19 @@ pub struct TraceConfig {
     #[prost(bool, tag = "12")]
     pub enable_return_data: bool,
 }
+impl ::prost::Name for TraceConfig {
+    const NAME: &'static str = "TraceConfig";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.TraceConfig".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.TraceConfig".into()
+    }
+}
 /// GenesisState defines the evm module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// accounts is an array containing the ethereum genesis accounts.
@@ -215,9 +322,21 @@ pub struct GenesisState {
     #[prost(message, optional, tag = "2")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.GenesisState".into()
+    }
+}
 /// GenesisAccount defines an account to be initialized in the genesis state.
 /// Its main difference between with Geth's GenesisAccount is that it uses a
 /// custom storage type and that it doesn't contain the private key field.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisAccount {
     /// address defines an ethereum hex formated address of an account
@@ -230,13 +349,26 @@ pub struct GenesisAccount {
     #[prost(message, repeated, tag = "3")]
     pub storage: ::prost::alloc::vec::Vec<State>,
 }
+impl ::prost::Name for GenesisAccount {
+    const NAME: &'static str = "GenesisAccount";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.GenesisAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.GenesisAccount".into()
+    }
+}
 /// MsgEthereumTx encapsulates an Ethereum transaction as an SDK message.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgEthereumTx {
     /// inner transaction data
     ///
     /// caches
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub data: ::core::option::Option<::prost_types::Any>,
     /// DEPRECATED: encoded storage size of the transaction
     #[prost(double, tag = "2")]
@@ -250,19 +382,33 @@ pub struct MsgEthereumTx {
     #[prost(string, tag = "4")]
     pub from: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgEthereumTx {
+    const NAME: &'static str = "MsgEthereumTx";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.MsgEthereumTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.MsgEthereumTx".into()
+    }
+}
 /// LegacyTx is the transaction data of regular Ethereum transactions.
 /// NOTE: All non-protected transactions (i.e non EIP155 signed) will fail if the
 /// AllowUnprotectedTxs parameter is disabled.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LegacyTx {
     /// nonce corresponds to the account nonce (transaction sequence).
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     /// gas price defines the value for each gas unit
     #[prost(string, tag = "2")]
     pub gas_price: ::prost::alloc::string::String,
     /// gas defines the gas limit defined for the transaction.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas: u64,
     /// hex formatted address of the recipient
     #[prost(string, tag = "4")]
@@ -272,18 +418,34 @@ pub struct LegacyTx {
     pub value: ::prost::alloc::string::String,
     /// input defines the data payload bytes of the transaction.
     #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     /// v defines the signature value
     #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub v: ::prost::alloc::vec::Vec<u8>,
     /// r defines the signature value
     #[prost(bytes = "vec", tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub r: ::prost::alloc::vec::Vec<u8>,
     /// s define the signature value
     #[prost(bytes = "vec", tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub s: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for LegacyTx {
+    const NAME: &'static str = "LegacyTx";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.LegacyTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.LegacyTx".into()
+    }
+}
 /// AccessListTx is the data of EIP-2930 access list transactions.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AccessListTx {
     /// destination EVM chain ID
@@ -291,12 +453,14 @@ pub struct AccessListTx {
     pub chain_id: ::prost::alloc::string::String,
     /// nonce corresponds to the account nonce (transaction sequence).
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     /// gas price defines the value for each gas unit
     #[prost(string, tag = "3")]
     pub gas_price: ::prost::alloc::string::String,
     /// gas defines the gas limit defined for the transaction.
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas: u64,
     /// hex formatted address of the recipient
     #[prost(string, tag = "5")]
@@ -306,20 +470,36 @@ pub struct AccessListTx {
     pub value: ::prost::alloc::string::String,
     /// input defines the data payload bytes of the transaction.
     #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, repeated, tag = "8")]
     pub accesses: ::prost::alloc::vec::Vec<AccessTuple>,
     /// v defines the signature value
     #[prost(bytes = "vec", tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub v: ::prost::alloc::vec::Vec<u8>,
     /// r defines the signature value
     #[prost(bytes = "vec", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub r: ::prost::alloc::vec::Vec<u8>,
     /// s define the signature value
     #[prost(bytes = "vec", tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub s: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for AccessListTx {
+    const NAME: &'static str = "AccessListTx";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.AccessListTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.AccessListTx".into()
+    }
+}
 /// DynamicFeeTx is the data of EIP-1559 dinamic fee transactions.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DynamicFeeTx {
     /// destination EVM chain ID
@@ -327,6 +507,7 @@ pub struct DynamicFeeTx {
     pub chain_id: ::prost::alloc::string::String,
     /// nonce corresponds to the account nonce (transaction sequence).
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     /// gas tip cap defines the max value for the gas tip
     #[prost(string, tag = "3")]
@@ -336,6 +517,7 @@ pub struct DynamicFeeTx {
     pub gas_fee_cap: ::prost::alloc::string::String,
     /// gas defines the gas limit defined for the transaction.
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas: u64,
     /// hex formatted address of the recipient
     #[prost(string, tag = "6")]
@@ -345,22 +527,50 @@ pub struct DynamicFeeTx {
     pub value: ::prost::alloc::string::String,
     /// input defines the data payload bytes of the transaction.
     #[prost(bytes = "vec", tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, repeated, tag = "9")]
     pub accesses: ::prost::alloc::vec::Vec<AccessTuple>,
     /// v defines the signature value
     #[prost(bytes = "vec", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub v: ::prost::alloc::vec::Vec<u8>,
     /// r defines the signature value
     #[prost(bytes = "vec", tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub r: ::prost::alloc::vec::Vec<u8>,
     /// s define the signature value
     #[prost(bytes = "vec", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub s: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for DynamicFeeTx {
+    const NAME: &'static str = "DynamicFeeTx";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.DynamicFeeTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.DynamicFeeTx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ExtensionOptionsEthereumTx {}
+impl ::prost::Name for ExtensionOptionsEthereumTx {
+    const NAME: &'static str = "ExtensionOptionsEthereumTx";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.ExtensionOptionsEthereumTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.ExtensionOptionsEthereumTx".into()
+    }
+}
 /// MsgEthereumTxResponse defines the Msg/EthereumTx response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgEthereumTxResponse {
     /// ethereum transaction hash in hex format. This hash differs from the
@@ -375,14 +585,28 @@ pub struct MsgEthereumTxResponse {
     /// returned data from evm function (result or data supplied with revert
     /// opcode)
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub ret: ::prost::alloc::vec::Vec<u8>,
     /// vm error is the error returned by vm execution
     #[prost(string, tag = "4")]
     pub vm_error: ::prost::alloc::string::String,
     /// gas consumed by the transaction
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: u64,
 }
+impl ::prost::Name for MsgEthereumTxResponse {
+    const NAME: &'static str = "MsgEthereumTxResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.MsgEthereumTxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.MsgEthereumTxResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -503,13 +727,27 @@ pub mod msg_client {
     }
 }
 /// QueryAccountRequest is the request type for the Query/Account RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountRequest {
     /// address is the ethereum hex address to query the account for.
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryAccountRequest {
+    const NAME: &'static str = "QueryAccountRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryAccountRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryAccountRequest".into()
+    }
+}
 /// QueryAccountResponse is the response type for the Query/Account RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAccountResponse {
     /// balance is the balance of the EVM denomination.
@@ -520,18 +758,43 @@ pub struct QueryAccountResponse {
     pub code_hash: ::prost::alloc::string::String,
     /// nonce is the account's sequence number.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
 }
+impl ::prost::Name for QueryAccountResponse {
+    const NAME: &'static str = "QueryAccountResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryAccountResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryAccountResponse".into()
+    }
+}
 /// QueryCosmosAccountRequest is the request type for the Query/CosmosAccount RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCosmosAccountRequest {
     /// address is the ethereum hex address to query the account for.
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryCosmosAccountRequest {
+    const NAME: &'static str = "QueryCosmosAccountRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryCosmosAccountRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryCosmosAccountRequest".into()
+    }
+}
 /// QueryCosmosAccountResponse is the response type for the Query/CosmosAccount
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCosmosAccountResponse {
     /// cosmos_address is the cosmos address of the account.
@@ -539,21 +802,47 @@ pub struct QueryCosmosAccountResponse {
     pub cosmos_address: ::prost::alloc::string::String,
     /// sequence is the account's sequence number.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     /// account_number is the account numbert
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub account_number: u64,
 }
+impl ::prost::Name for QueryCosmosAccountResponse {
+    const NAME: &'static str = "QueryCosmosAccountResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryCosmosAccountResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryCosmosAccountResponse".into()
+    }
+}
 /// QueryValidatorAccountRequest is the request type for the
 /// Query/ValidatorAccount RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorAccountRequest {
     /// cons_address is the validator cons address to query the account for.
     #[prost(string, tag = "1")]
     pub cons_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryValidatorAccountRequest {
+    const NAME: &'static str = "QueryValidatorAccountRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryValidatorAccountRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryValidatorAccountRequest".into()
+    }
+}
 /// QueryValidatorAccountResponse is the response type for the
 /// Query/ValidatorAccount RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorAccountResponse {
     /// account_address is the cosmos address of the account in bech32 format.
@@ -561,26 +850,64 @@ pub struct QueryValidatorAccountResponse {
     pub account_address: ::prost::alloc::string::String,
     /// sequence is the account's sequence number.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     /// account_number is the account number
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub account_number: u64,
 }
+impl ::prost::Name for QueryValidatorAccountResponse {
+    const NAME: &'static str = "QueryValidatorAccountResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryValidatorAccountResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryValidatorAccountResponse".into()
+    }
+}
 /// QueryBalanceRequest is the request type for the Query/Balance RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBalanceRequest {
     /// address is the ethereum hex address to query the balance for.
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryBalanceRequest {
+    const NAME: &'static str = "QueryBalanceRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryBalanceRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryBalanceRequest".into()
+    }
+}
 /// QueryBalanceResponse is the response type for the Query/Balance RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBalanceResponse {
     /// balance is the balance of the EVM denomination.
     #[prost(string, tag = "1")]
     pub balance: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryBalanceResponse {
+    const NAME: &'static str = "QueryBalanceResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryBalanceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryBalanceResponse".into()
+    }
+}
 /// QueryStorageRequest is the request type for the Query/Storage RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryStorageRequest {
     /// / address is the ethereum hex address to query the storage state for.
@@ -590,30 +917,79 @@ pub struct QueryStorageRequest {
     #[prost(string, tag = "2")]
     pub key: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryStorageRequest {
+    const NAME: &'static str = "QueryStorageRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryStorageRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryStorageRequest".into()
+    }
+}
 /// QueryStorageResponse is the response type for the Query/Storage RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryStorageResponse {
     /// key defines the storage state value hash associated with the given key.
     #[prost(string, tag = "1")]
     pub value: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryStorageResponse {
+    const NAME: &'static str = "QueryStorageResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryStorageResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryStorageResponse".into()
+    }
+}
 /// QueryCodeRequest is the request type for the Query/Code RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCodeRequest {
     /// address is the ethereum hex address to query the code for.
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryCodeRequest {
+    const NAME: &'static str = "QueryCodeRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryCodeRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryCodeRequest".into()
+    }
+}
 /// QueryCodeResponse is the response type for the Query/Code RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCodeResponse {
     /// code represents the code bytes from an ethereum address.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub code: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for QueryCodeResponse {
+    const NAME: &'static str = "QueryCodeResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryCodeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryCodeResponse".into()
+    }
+}
 /// QueryTxLogsRequest is the request type for the Query/TxLogs RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTxLogsRequest {
     /// hash is the ethereum transaction hex hash to query the logs for.
@@ -625,7 +1001,19 @@ pub struct QueryTxLogsRequest {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryTxLogsRequest {
+    const NAME: &'static str = "QueryTxLogsRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryTxLogsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryTxLogsRequest".into()
+    }
+}
 /// QueryTxLogs is the response type for the Query/TxLogs RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTxLogsResponse {
     /// logs represents the ethereum logs generated from the given transaction.
@@ -637,34 +1025,97 @@ pub struct QueryTxLogsResponse {
         cosmos_sdk_proto::cosmos::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryTxLogsResponse {
+    const NAME: &'static str = "QueryTxLogsResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryTxLogsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryTxLogsResponse".into()
+    }
+}
 /// QueryParamsRequest defines the request type for querying x/evm parameters.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse defines the response type for querying x/evm parameters.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// params define the evm module parameters.
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryParamsResponse".into()
+    }
+}
 /// EthCallRequest defines EthCall request
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EthCallRequest {
     /// same json format as the json rpc api.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub args: ::prost::alloc::vec::Vec<u8>,
     /// the default gas cap to be used
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_cap: u64,
 }
+impl ::prost::Name for EthCallRequest {
+    const NAME: &'static str = "EthCallRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.EthCallRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.EthCallRequest".into()
+    }
+}
 /// EstimateGasResponse defines EstimateGas response
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct EstimateGasResponse {
     /// the estimated gas
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas: u64,
 }
+impl ::prost::Name for EstimateGasResponse {
+    const NAME: &'static str = "EstimateGasResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.EstimateGasResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.EstimateGasResponse".into()
+    }
+}
 /// QueryTraceTxRequest defines TraceTx request
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTraceTxRequest {
     /// msgEthereumTx for the requested transaction
@@ -679,22 +1130,49 @@ pub struct QueryTraceTxRequest {
     pub predecessors: ::prost::alloc::vec::Vec<MsgEthereumTx>,
     /// block number of requested transaction
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block_number: i64,
     /// block hex hash of requested transaction
     #[prost(string, tag = "6")]
     pub block_hash: ::prost::alloc::string::String,
     /// block time of requested transaction
     #[prost(message, optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub block_time: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for QueryTraceTxRequest {
+    const NAME: &'static str = "QueryTraceTxRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryTraceTxRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryTraceTxRequest".into()
+    }
+}
 /// QueryTraceTxResponse defines TraceTx response
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTraceTxResponse {
     /// response serialized in bytes
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for QueryTraceTxResponse {
+    const NAME: &'static str = "QueryTraceTxResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryTraceTxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryTraceTxResponse".into()
+    }
+}
 /// QueryTraceBlockRequest defines TraceTx request
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTraceBlockRequest {
     /// txs messages in the block
@@ -705,30 +1183,81 @@ pub struct QueryTraceBlockRequest {
     pub trace_config: ::core::option::Option<TraceConfig>,
     /// block number
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block_number: i64,
     /// block hex hash
     #[prost(string, tag = "6")]
     pub block_hash: ::prost::alloc::string::String,
     /// block time
     #[prost(message, optional, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub block_time: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for QueryTraceBlockRequest {
+    const NAME: &'static str = "QueryTraceBlockRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryTraceBlockRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryTraceBlockRequest".into()
+    }
+}
 /// QueryTraceBlockResponse defines TraceBlock response
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTraceBlockResponse {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for QueryTraceBlockResponse {
+    const NAME: &'static str = "QueryTraceBlockResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryTraceBlockResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryTraceBlockResponse".into()
+    }
+}
 /// QueryBaseFeeRequest defines the request type for querying the EIP1559 base
 /// fee.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryBaseFeeRequest {}
+impl ::prost::Name for QueryBaseFeeRequest {
+    const NAME: &'static str = "QueryBaseFeeRequest";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryBaseFeeRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryBaseFeeRequest".into()
+    }
+}
 /// BaseFeeResponse returns the EIP1559 base fee.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBaseFeeResponse {
     #[prost(string, tag = "1")]
     pub base_fee: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryBaseFeeResponse {
+    const NAME: &'static str = "QueryBaseFeeResponse";
+    const PACKAGE: &'static str = "ethermint.evm.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.evm.v1.QueryBaseFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.evm.v1.QueryBaseFeeResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -1126,3 +1655,991 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// EthereumTx defines a method submitting Ethereum transactions.
+        async fn ethereum_tx(
+            &self,
+            request: tonic::Request<super::MsgEthereumTx>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgEthereumTxResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the evm Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/ethermint.evm.v1.Msg/EthereumTx" => {
+                    #[allow(non_camel_case_types)]
+                    struct EthereumTxSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgEthereumTx>
+                    for EthereumTxSvc<T> {
+                        type Response = super::MsgEthereumTxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgEthereumTx>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::ethereum_tx(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EthereumTxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "ethermint.evm.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Account queries an Ethereum account.
+        async fn account(
+            &self,
+            request: tonic::Request<super::QueryAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAccountResponse>,
+            tonic::Status,
+        >;
+        /// CosmosAccount queries an Ethereum account's Cosmos Address.
+        async fn cosmos_account(
+            &self,
+            request: tonic::Request<super::QueryCosmosAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCosmosAccountResponse>,
+            tonic::Status,
+        >;
+        /// ValidatorAccount queries an Ethereum account's from a validator consensus
+        /// Address.
+        async fn validator_account(
+            &self,
+            request: tonic::Request<super::QueryValidatorAccountRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorAccountResponse>,
+            tonic::Status,
+        >;
+        /// Balance queries the balance of a the EVM denomination for a single
+        /// EthAccount.
+        async fn balance(
+            &self,
+            request: tonic::Request<super::QueryBalanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryBalanceResponse>,
+            tonic::Status,
+        >;
+        /// Storage queries the balance of all coins for a single account.
+        async fn storage(
+            &self,
+            request: tonic::Request<super::QueryStorageRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryStorageResponse>,
+            tonic::Status,
+        >;
+        /// Code queries the balance of all coins for a single account.
+        async fn code(
+            &self,
+            request: tonic::Request<super::QueryCodeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCodeResponse>,
+            tonic::Status,
+        >;
+        /// Params queries the parameters of x/evm module.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// EthCall implements the `eth_call` rpc api
+        async fn eth_call(
+            &self,
+            request: tonic::Request<super::EthCallRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgEthereumTxResponse>,
+            tonic::Status,
+        >;
+        /// EstimateGas implements the `eth_estimateGas` rpc api
+        async fn estimate_gas(
+            &self,
+            request: tonic::Request<super::EthCallRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateGasResponse>,
+            tonic::Status,
+        >;
+        /// TraceTx implements the `debug_traceTransaction` rpc api
+        async fn trace_tx(
+            &self,
+            request: tonic::Request<super::QueryTraceTxRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryTraceTxResponse>,
+            tonic::Status,
+        >;
+        /// TraceBlock implements the `debug_traceBlockByNumber` and `debug_traceBlockByHash` rpc api
+        async fn trace_block(
+            &self,
+            request: tonic::Request<super::QueryTraceBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryTraceBlockResponse>,
+            tonic::Status,
+        >;
+        /// BaseFee queries the base fee of the parent block of the current block,
+        /// it's similar to feemarket module's method, but also checks london hardfork status.
+        async fn base_fee(
+            &self,
+            request: tonic::Request<super::QueryBaseFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryBaseFeeResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/ethermint.evm.v1.Query/Account" => {
+                    #[allow(non_camel_case_types)]
+                    struct AccountSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAccountRequest>
+                    for AccountSvc<T> {
+                        type Response = super::QueryAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/CosmosAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct CosmosAccountSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCosmosAccountRequest>
+                    for CosmosAccountSvc<T> {
+                        type Response = super::QueryCosmosAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCosmosAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::cosmos_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CosmosAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/ValidatorAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorAccountSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValidatorAccountRequest>
+                    for ValidatorAccountSvc<T> {
+                        type Response = super::QueryValidatorAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryValidatorAccountRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/Balance" => {
+                    #[allow(non_camel_case_types)]
+                    struct BalanceSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryBalanceRequest>
+                    for BalanceSvc<T> {
+                        type Response = super::QueryBalanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBalanceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::balance(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BalanceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/Storage" => {
+                    #[allow(non_camel_case_types)]
+                    struct StorageSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryStorageRequest>
+                    for StorageSvc<T> {
+                        type Response = super::QueryStorageResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryStorageRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::storage(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StorageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/Code" => {
+                    #[allow(non_camel_case_types)]
+                    struct CodeSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryCodeRequest>
+                    for CodeSvc<T> {
+                        type Response = super::QueryCodeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCodeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::code(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/EthCall" => {
+                    #[allow(non_camel_case_types)]
+                    struct EthCallSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::EthCallRequest>
+                    for EthCallSvc<T> {
+                        type Response = super::MsgEthereumTxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EthCallRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::eth_call(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EthCallSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/EstimateGas" => {
+                    #[allow(non_camel_case_types)]
+                    struct EstimateGasSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::EthCallRequest>
+                    for EstimateGasSvc<T> {
+                        type Response = super::EstimateGasResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EthCallRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::estimate_gas(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EstimateGasSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/TraceTx" => {
+                    #[allow(non_camel_case_types)]
+                    struct TraceTxSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryTraceTxRequest>
+                    for TraceTxSvc<T> {
+                        type Response = super::QueryTraceTxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTraceTxRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::trace_tx(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TraceTxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/TraceBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct TraceBlockSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryTraceBlockRequest>
+                    for TraceBlockSvc<T> {
+                        type Response = super::QueryTraceBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTraceBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::trace_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TraceBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ethermint.evm.v1.Query/BaseFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct BaseFeeSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryBaseFeeRequest>
+                    for BaseFeeSvc<T> {
+                        type Response = super::QueryBaseFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBaseFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::base_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BaseFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "ethermint.evm.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Params>();
+    registry.register::<ChainConfig>();
+    registry.register::<State>();
+    registry.register::<TransactionLogs>();
+    registry.register::<Log>();
+    registry.register::<TxResult>();
+    registry.register::<AccessTuple>();
+    registry.register::<TraceConfig>();
+    registry.register::<GenesisState>();
+    registry.register::<GenesisAccount>();
+    registry.register::<MsgEthereumTx>();
+    registry.register::<LegacyTx>();
+    registry.register::<AccessListTx>();
+    registry.register::<DynamicFeeTx>();
+    registry.register::<ExtensionOptionsEthereumTx>();
+    registry.register::<MsgEthereumTxResponse>();
+    registry.register::<QueryAccountRequest>();
+    registry.register::<QueryAccountResponse>();
+    registry.register::<QueryCosmosAccountRequest>();
+    registry.register::<QueryCosmosAccountResponse>();
+    registry.register::<QueryValidatorAccountRequest>();
+    registry.register::<QueryValidatorAccountResponse>();
+    registry.register::<QueryBalanceRequest>();
+    registry.register::<QueryBalanceResponse>();
+    registry.register::<QueryStorageRequest>();
+    registry.register::<QueryStorageResponse>();
+    registry.register::<QueryCodeRequest>();
+    registry.register::<QueryCodeResponse>();
+    registry.register::<QueryTxLogsRequest>();
+    registry.register::<QueryTxLogsResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<EthCallRequest>();
+    registry.register::<EstimateGasResponse>();
+    registry.register::<QueryTraceTxRequest>();
+    registry.register::<QueryTraceTxResponse>();
+    registry.register::<QueryTraceBlockRequest>();
+    registry.register::<QueryTraceBlockResponse>();
+    registry.register::<QueryBaseFeeRequest>();
+    registry.register::<QueryBaseFeeResponse>();
+}
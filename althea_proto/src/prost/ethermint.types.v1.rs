@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// EthAccount implements the authtypes.AccountI interface and embeds an
 /// authtypes.BaseAccount type. It is compatible with the auth AccountKeeper.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EthAccount {
     #[prost(message, optional, tag = "1")]
@@ -10,11 +12,24 @@ pub struct EthAccount {
     #[prost(string, tag = "2")]
     pub code_hash: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EthAccount {
+    const NAME: &'static str = "EthAccount";
+    const PACKAGE: &'static str = "ethermint.types.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.types.v1.EthAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.types.v1.EthAccount".into()
+    }
+}
 /// TxResult is the value stored in eth tx indexer
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct TxResult {
     /// the block height
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     /// cosmos tx index
     #[prost(uint32, tag = "2")]
@@ -32,16 +47,31 @@ pub struct TxResult {
     /// gas used by tx, if exceeds block gas limit,
     /// it's set to gas limit which is what's actually deducted by ante handler.
     #[prost(uint64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: u64,
     /// the cumulative gas used within current batch tx
     #[prost(uint64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub cumulative_gas_used: u64,
 }
+impl ::prost::Name for TxResult {
+    const NAME: &'static str = "TxResult";
+    const PACKAGE: &'static str = "ethermint.types.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.types.v1.TxResult".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.types.v1.TxResult".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExtensionOptionsWeb3Tx {
     /// typed data chain id used only in EIP712 Domain and should match
     /// Ethereum network ID in a Web3 provider (e.g. Metamask).
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub typed_data_chain_id: u64,
     /// fee payer is an account address for the fee payer. It will be validated
     /// during EIP712 signature checking.
@@ -50,5 +80,23 @@ pub struct ExtensionOptionsWeb3Tx {
     /// fee payer sig is a signature data from the fee paying account,
     /// allows to perform fee delegation when using EIP712 Domain.
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub fee_payer_sig: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for ExtensionOptionsWeb3Tx {
+    const NAME: &'static str = "ExtensionOptionsWeb3Tx";
+    const PACKAGE: &'static str = "ethermint.types.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ethermint.types.v1.ExtensionOptionsWeb3Tx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ethermint.types.v1.ExtensionOptionsWeb3Tx".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<EthAccount>();
+    registry.register::<TxResult>();
+    registry.register::<ExtensionOptionsWeb3Tx>();
+}
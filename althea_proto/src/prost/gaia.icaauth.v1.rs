@@ -1,4 +1,6 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventRegisterInterchainAccount {
     #[prost(string, tag = "1")]
@@ -8,6 +10,18 @@ pub struct EventRegisterInterchainAccount {
     #[prost(string, tag = "3")]
     pub version: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventRegisterInterchainAccount {
+    const NAME: &'static str = "EventRegisterInterchainAccount";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.EventRegisterInterchainAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.EventRegisterInterchainAccount".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSubmitTx {
     #[prost(string, tag = "1")]
@@ -15,9 +29,22 @@ pub struct EventSubmitTx {
     #[prost(string, tag = "2")]
     pub connection_id: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub msgs: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for EventSubmitTx {
+    const NAME: &'static str = "EventSubmitTx";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.EventSubmitTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.EventSubmitTx".into()
+    }
+}
 /// QueryInterchainAccountFromAddressRequest is the request type for the Query/InterchainAccountAddress RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryInterchainAccountFromAddressRequest {
     #[prost(string, tag = "1")]
@@ -25,19 +52,55 @@ pub struct QueryInterchainAccountFromAddressRequest {
     #[prost(string, tag = "2")]
     pub connection_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryInterchainAccountFromAddressRequest {
+    const NAME: &'static str = "QueryInterchainAccountFromAddressRequest";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.QueryInterchainAccountFromAddressRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.QueryInterchainAccountFromAddressRequest".into()
+    }
+}
 /// QueryInterchainAccountFromAddressResponse the response type for the Query/InterchainAccountFromAddress RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryInterchainAccountFromAddressResponse {
     #[prost(string, tag = "1")]
     pub interchain_account_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryInterchainAccountFromAddressResponse {
+    const NAME: &'static str = "QueryInterchainAccountFromAddressResponse";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.QueryInterchainAccountFromAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.QueryInterchainAccountFromAddressResponse".into()
+    }
+}
 /// QueryInterchainAccountsWithConnectionRequest is the request type for the Query/InterchainAccountsWithConnection RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryInterchainAccountsWithConnectionRequest {
     #[prost(string, tag = "1")]
     pub connection_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryInterchainAccountsWithConnectionRequest {
+    const NAME: &'static str = "QueryInterchainAccountsWithConnectionRequest";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.QueryInterchainAccountsWithConnectionRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.QueryInterchainAccountsWithConnectionRequest".into()
+    }
+}
 /// QueryInterchainAccountFromAddressResponse the response type for the Query/InterchainAccountsWithConnection RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryInterchainAccountsWithConnectionResponse {
     #[prost(message, repeated, tag = "1")]
@@ -45,6 +108,18 @@ pub struct QueryInterchainAccountsWithConnectionResponse {
         cosmos_sdk_proto::ibc::applications::interchain_accounts::v1::RegisteredInterchainAccount,
     >,
 }
+impl ::prost::Name for QueryInterchainAccountsWithConnectionResponse {
+    const NAME: &'static str = "QueryInterchainAccountsWithConnectionResponse";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.QueryInterchainAccountsWithConnectionResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.QueryInterchainAccountsWithConnectionResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -204,6 +279,8 @@ pub mod query_client {
     }
 }
 /// MsgRegisterAccount defines the payload for Msg/RegisterAccount
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRegisterAccount {
     #[prost(string, tag = "1")]
@@ -213,10 +290,34 @@ pub struct MsgRegisterAccount {
     #[prost(string, tag = "3")]
     pub version: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgRegisterAccount {
+    const NAME: &'static str = "MsgRegisterAccount";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.MsgRegisterAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.MsgRegisterAccount".into()
+    }
+}
 /// MsgRegisterAccountResponse defines the response for Msg/RegisterAccount
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgRegisterAccountResponse {}
+impl ::prost::Name for MsgRegisterAccountResponse {
+    const NAME: &'static str = "MsgRegisterAccountResponse";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.MsgRegisterAccountResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.MsgRegisterAccountResponse".into()
+    }
+}
 /// MsgSubmitTx defines the payload for Msg/SubmitTx
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSubmitTx {
     #[prost(string, tag = "1")]
@@ -225,11 +326,36 @@ pub struct MsgSubmitTx {
     pub connection_id: ::prost::alloc::string::String,
     /// msgs represents the transactions to be submitted to the host chain
     #[prost(message, repeated, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub msgs: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for MsgSubmitTx {
+    const NAME: &'static str = "MsgSubmitTx";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.MsgSubmitTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.MsgSubmitTx".into()
+    }
+}
 /// MsgSubmitTxResponse defines the response for Msg/SubmitTx
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSubmitTxResponse {}
+impl ::prost::Name for MsgSubmitTxResponse {
+    const NAME: &'static str = "MsgSubmitTxResponse";
+    const PACKAGE: &'static str = "gaia.icaauth.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gaia.icaauth.v1.MsgSubmitTxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gaia.icaauth.v1.MsgSubmitTxResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -374,3 +500,501 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// QueryInterchainAccountFromAddress returns the interchain account for given owner address on a given connection
+        async fn interchain_account_from_address(
+            &self,
+            request: tonic::Request<super::QueryInterchainAccountFromAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryInterchainAccountFromAddressResponse>,
+            tonic::Status,
+        >;
+        /// QueryInterchainAccountsWithConnection returns all the interchain accounts on a given connection
+        async fn interchain_accounts_with_connection(
+            &self,
+            request: tonic::Request<super::QueryInterchainAccountsWithConnectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryInterchainAccountsWithConnectionResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/gaia.icaauth.v1.Query/InterchainAccountFromAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct InterchainAccountFromAddressSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryInterchainAccountFromAddressRequest,
+                    > for InterchainAccountFromAddressSvc<T> {
+                        type Response = super::QueryInterchainAccountFromAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryInterchainAccountFromAddressRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::interchain_account_from_address(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = InterchainAccountFromAddressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gaia.icaauth.v1.Query/InterchainAccountsWithConnection" => {
+                    #[allow(non_camel_case_types)]
+                    struct InterchainAccountsWithConnectionSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryInterchainAccountsWithConnectionRequest,
+                    > for InterchainAccountsWithConnectionSvc<T> {
+                        type Response = super::QueryInterchainAccountsWithConnectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryInterchainAccountsWithConnectionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::interchain_accounts_with_connection(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = InterchainAccountsWithConnectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "gaia.icaauth.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// Register defines a rpc handler for MsgRegisterAccount
+        async fn register_account(
+            &self,
+            request: tonic::Request<super::MsgRegisterAccount>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgRegisterAccountResponse>,
+            tonic::Status,
+        >;
+        /// SubmitTx defines a rpc handler for MsgSubmitTx
+        async fn submit_tx(
+            &self,
+            request: tonic::Request<super::MsgSubmitTx>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSubmitTxResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the icaauth Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/gaia.icaauth.v1.Msg/RegisterAccount" => {
+                    #[allow(non_camel_case_types)]
+                    struct RegisterAccountSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgRegisterAccount>
+                    for RegisterAccountSvc<T> {
+                        type Response = super::MsgRegisterAccountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgRegisterAccount>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::register_account(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RegisterAccountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gaia.icaauth.v1.Msg/SubmitTx" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitTxSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgSubmitTx>
+                    for SubmitTxSvc<T> {
+                        type Response = super::MsgSubmitTxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSubmitTx>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::submit_tx(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubmitTxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "gaia.icaauth.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<EventRegisterInterchainAccount>();
+    registry.register::<EventSubmitTx>();
+    registry.register::<QueryInterchainAccountFromAddressRequest>();
+    registry.register::<QueryInterchainAccountFromAddressResponse>();
+    registry.register::<QueryInterchainAccountsWithConnectionRequest>();
+    registry.register::<QueryInterchainAccountsWithConnectionResponse>();
+    registry.register::<MsgRegisterAccount>();
+    registry.register::<MsgRegisterAccountResponse>();
+    registry.register::<MsgSubmitTx>();
+    registry.register::<MsgSubmitTxResponse>();
+}
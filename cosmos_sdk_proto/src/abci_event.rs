@@ -0,0 +1,415 @@
+//! Decoding of ABCI events into the typed event messages modules emit with EmitTypedEvent. A typed event's type is
+//! the full name of its message (e.g. "gravity.v1.EventSendToCosmos") and each field of the message becomes an
+//! attribute whose value is the field's JSON encoding, so a string field "nonce" appears as `nonce="\"7\""`. Events
+//! which were emitted with plain string attributes, and the StringEvents of a TxResponse's logs, are decoded as well.
+//!
+//! Modules implement [TypedEvent] for their event messages, usually through the [typed_event](crate::typed_event)
+//! macro, then decode an event with [decode_event].
+
+use crate::cosmos::base::abci::v1beta1::{StringEvent, TxResponse};
+use crate::cosmos::base::v1beta1::Coin;
+use crate::tendermint::abci::Event;
+use serde_json::Value;
+use std::fmt;
+
+/// An error decoding an event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventError {
+    /// The event is of a different type than the message being decoded
+    WrongType { expected: String, found: String },
+    /// The attribute's value could not be decoded into the message field
+    InvalidAttribute { key: String, value: String },
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventError::WrongType { expected, found } => {
+                write!(f, "expected a {expected} event, found {found}")
+            }
+            EventError::InvalidAttribute { key, value } => {
+                write!(f, "invalid value {value:?} for event attribute {key}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventError {}
+
+/// The type and string attributes of an event, from either a tendermint.abci.Event or a StringEvent
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventAttributes {
+    pub r#type: String,
+    pub attributes: Vec<(String, String)>,
+}
+
+impl From<&Event> for EventAttributes {
+    fn from(event: &Event) -> Self {
+        EventAttributes {
+            r#type: event.r#type.clone(),
+            attributes: event
+                .attributes
+                .iter()
+                .map(|a| {
+                    (
+                        String::from_utf8_lossy(&a.key).into_owned(),
+                        String::from_utf8_lossy(&a.value).into_owned(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&StringEvent> for EventAttributes {
+    fn from(event: &StringEvent) -> Self {
+        EventAttributes {
+            r#type: event.r#type.clone(),
+            attributes: event
+                .attributes
+                .iter()
+                .map(|a| (a.key.clone(), a.value.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl EventAttributes {
+    /// The raw value of the first attribute with the given key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Decodes the attribute with the given key into a message field, a missing attribute leaves the field at its
+    /// default value as the JSON decoding of typed events does
+    pub fn field<T: EventValue + Default>(&self, key: &str) -> Result<T, EventError> {
+        // raw identifiers like r#type are passed in by the typed_event macro
+        let key = key.trim_start_matches("r#");
+        match self.get(key) {
+            None => Ok(T::default()),
+            Some(value) => T::from_event_value(value).ok_or_else(|| EventError::InvalidAttribute {
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// The events of a transaction. Newer nodes return them in TxResponse::events, older nodes only in the StringEvents
+/// of each message's log
+pub fn tx_response_events(tx_response: &TxResponse) -> Vec<EventAttributes> {
+    if !tx_response.events.is_empty() {
+        return tx_response
+            .events
+            .iter()
+            .map(EventAttributes::from)
+            .collect();
+    }
+    tx_response
+        .logs
+        .iter()
+        .flat_map(|log| log.events.iter().map(EventAttributes::from))
+        .collect()
+}
+
+/// A message field type which can be decoded from an event attribute
+pub trait EventValue: Sized {
+    /// Decodes either the JSON encoding of a typed event or the plain string of a legacy event
+    fn from_event_value(value: &str) -> Option<Self>;
+}
+
+/// Parses the value as JSON if it looks like the JSON encoding of a typed event
+fn json_value(value: &str) -> Option<Value> {
+    match value.trim_start().chars().next() {
+        Some('"' | '[' | '{') => serde_json::from_str(value).ok(),
+        _ => None,
+    }
+}
+
+impl EventValue for String {
+    fn from_event_value(value: &str) -> Option<Self> {
+        match json_value(value) {
+            Some(Value::String(s)) => Some(s),
+            Some(_) => None,
+            None => Some(value.to_string()),
+        }
+    }
+}
+
+fn coin_from_json(value: &Value) -> Option<Coin> {
+    Some(Coin {
+        denom: value.get("denom")?.as_str()?.to_string(),
+        amount: value.get("amount")?.as_str()?.to_string(),
+    })
+}
+
+/// Parses the sdk.Coin string form, the amount followed by the denom (e.g. "100aalthea")
+fn coin_from_str(value: &str) -> Option<Coin> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, denom) = value.split_at(split);
+    if amount.is_empty() || denom.is_empty() {
+        return None;
+    }
+    Some(Coin {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+    })
+}
+
+impl EventValue for Option<Coin> {
+    fn from_event_value(value: &str) -> Option<Self> {
+        match json_value(value) {
+            Some(Value::Null) => Some(None),
+            Some(Value::String(s)) => Some(Some(coin_from_str(&s)?)),
+            Some(v) => Some(Some(coin_from_json(&v)?)),
+            // an unset message field is encoded as null
+            None if value.is_empty() || value == "null" => Some(None),
+            None => Some(Some(coin_from_str(value)?)),
+        }
+    }
+}
+
+impl EventValue for Vec<Coin> {
+    fn from_event_value(value: &str) -> Option<Self> {
+        match json_value(value) {
+            Some(Value::Array(coins)) => coins.iter().map(coin_from_json).collect(),
+            Some(Value::String(s)) => Self::from_event_value(&s),
+            Some(_) => None,
+            // sdk.Coins are written comma separated
+            None => value
+                .split(',')
+                .filter(|c| !c.is_empty())
+                .map(coin_from_str)
+                .collect(),
+        }
+    }
+}
+
+/// A typed event message which can be decoded from its ABCI event
+pub trait TypedEvent: prost::Name + Sized {
+    /// Decodes the message from the attributes, the event type has already been checked
+    fn from_attributes(attributes: &EventAttributes) -> Result<Self, EventError>;
+}
+
+/// Decodes an event into the typed event message E, the event's type must be the full name of E
+pub fn decode_event<E: TypedEvent>(event: &EventAttributes) -> Result<E, EventError> {
+    let expected = E::full_name();
+    if event.r#type != expected {
+        return Err(EventError::WrongType {
+            expected,
+            found: event.r#type.clone(),
+        });
+    }
+    E::from_attributes(event)
+}
+
+/// Implements [TypedEvent] for messages by decoding each of the listed fields from the attribute of the same name
+#[macro_export]
+macro_rules! typed_event {
+    ($($ty:ty { $($field:ident),* $(,)? }),* $(,)?) => {
+        $(
+            impl $crate::abci_event::TypedEvent for $ty {
+                fn from_attributes(
+                    attributes: &$crate::abci_event::EventAttributes,
+                ) -> Result<Self, $crate::abci_event::EventError> {
+                    Ok(Self {
+                        $($field: attributes.field(stringify!($field))?,)*
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::authz::v1beta1::EventGrant;
+    use crate::cosmos::base::abci::v1beta1::{AbciMessageLog, Attribute};
+    use crate::tendermint::abci::EventAttribute;
+
+    crate::typed_event!(EventGrant {
+        msg_type_url,
+        granter,
+        grantee
+    });
+
+    fn event(r#type: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            r#type: r#type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| EventAttribute {
+                    key: k.as_bytes().to_vec(),
+                    value: v.as_bytes().to_vec(),
+                    index: true,
+                })
+                .collect(),
+        }
+    }
+
+    fn string_event(r#type: &str, attributes: &[(&str, &str)]) -> StringEvent {
+        StringEvent {
+            r#type: r#type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| Attribute {
+                    key: k.to_string(),
+                    value: v.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn coin(amount: &str, denom: &str) -> Coin {
+        Coin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    fn grant() -> EventGrant {
+        EventGrant {
+            msg_type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            granter: "cosmos1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3pahzj0".to_string(),
+            grantee: "cosmos1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeg5thmd".to_string(),
+        }
+    }
+
+    #[test]
+    fn typed_event_with_json_attributes() {
+        let event = event(
+            "cosmos.authz.v1beta1.EventGrant",
+            &[
+                ("msg_type_url", "\"/cosmos.bank.v1beta1.MsgSend\""),
+                (
+                    "granter",
+                    "\"cosmos1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3pahzj0\"",
+                ),
+                (
+                    "grantee",
+                    "\"cosmos1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeg5thmd\"",
+                ),
+            ],
+        );
+        let decoded: EventGrant = decode_event(&EventAttributes::from(&event)).unwrap();
+        assert_eq!(decoded, grant());
+    }
+
+    #[test]
+    fn legacy_plain_string_event() {
+        let event = string_event(
+            "cosmos.authz.v1beta1.EventGrant",
+            &[
+                ("msg_type_url", "/cosmos.bank.v1beta1.MsgSend"),
+                ("granter", "cosmos1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3pahzj0"),
+                ("grantee", "cosmos1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeg5thmd"),
+            ],
+        );
+        let decoded: EventGrant = decode_event(&EventAttributes::from(&event)).unwrap();
+        assert_eq!(decoded, grant());
+    }
+
+    #[test]
+    fn wrong_type_and_missing_attributes() {
+        let attributes = EventAttributes::from(&event("message", &[("module", "authz")]));
+        assert_eq!(
+            decode_event::<EventGrant>(&attributes),
+            Err(EventError::WrongType {
+                expected: "cosmos.authz.v1beta1.EventGrant".to_string(),
+                found: "message".to_string(),
+            })
+        );
+        let attributes = EventAttributes::from(&event("cosmos.authz.v1beta1.EventGrant", &[]));
+        assert_eq!(
+            decode_event::<EventGrant>(&attributes),
+            Ok(EventGrant::default())
+        );
+        let attributes = EventAttributes::from(&event(
+            "cosmos.authz.v1beta1.EventGrant",
+            &[("granter", "7")],
+        ));
+        assert_eq!(attributes.field::<String>("granter"), Ok("7".to_string()));
+        let attributes = EventAttributes::from(&event(
+            "cosmos.authz.v1beta1.EventGrant",
+            &[("granter", "[1]")],
+        ));
+        assert_eq!(
+            decode_event::<EventGrant>(&attributes),
+            Err(EventError::InvalidAttribute {
+                key: "granter".to_string(),
+                value: "[1]".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn coin_values() {
+        assert_eq!(
+            Vec::<Coin>::from_event_value(
+                r#"[{"denom":"aalthea","amount":"100"},{"denom":"uatom","amount":"5"}]"#
+            ),
+            Some(vec![coin("100", "aalthea"), coin("5", "uatom")])
+        );
+        assert_eq!(
+            Vec::<Coin>::from_event_value("100aalthea,5uatom"),
+            Some(vec![coin("100", "aalthea"), coin("5", "uatom")])
+        );
+        assert_eq!(Vec::<Coin>::from_event_value(""), Some(vec![]));
+        assert_eq!(Vec::<Coin>::from_event_value("aalthea"), None);
+        assert_eq!(
+            Option::<Coin>::from_event_value(r#"{"denom":"aalthea","amount":"100"}"#),
+            Some(Some(coin("100", "aalthea")))
+        );
+        assert_eq!(
+            Option::<Coin>::from_event_value("\"100aalthea\""),
+            Some(Some(coin("100", "aalthea")))
+        );
+        assert_eq!(
+            Option::<Coin>::from_event_value(
+                "100ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+            ),
+            Some(Some(coin(
+                "100",
+                "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+            )))
+        );
+        assert_eq!(Option::<Coin>::from_event_value("null"), Some(None));
+        assert_eq!(Option::<Coin>::from_event_value(""), Some(None));
+    }
+
+    #[test]
+    fn tx_response_events_fall_back_to_logs() {
+        let log_event = string_event("message", &[("module", "bank")]);
+        let mut tx_response = TxResponse {
+            logs: vec![
+                AbciMessageLog {
+                    msg_index: 0,
+                    log: String::new(),
+                    events: vec![log_event.clone()],
+                },
+                AbciMessageLog {
+                    msg_index: 1,
+                    log: String::new(),
+                    events: vec![log_event.clone()],
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            tx_response_events(&tx_response),
+            vec![EventAttributes::from(&log_event); 2]
+        );
+
+        let event = event("tx", &[("fee", "5uatom")]);
+        tx_response.events = vec![event.clone()];
+        assert_eq!(
+            tx_response_events(&tx_response),
+            vec![EventAttributes::from(&event)]
+        );
+    }
+}
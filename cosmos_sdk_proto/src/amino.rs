@@ -0,0 +1,1137 @@
+//! Legacy Amino JSON encoding, used to produce the sign bytes for SIGN_MODE_LEGACY_AMINO_JSON. This is the only sign
+//! mode supported by Ledger devices and many wallets. The sign bytes are a StdSignDoc serialized as JSON with sorted
+//! keys, where every message is wrapped as `{"type": <amino name>, "value": <message fields>}`.
+//!
+//! The encoding follows the legacy amino codec of the Cosmos SDK:
+//! * field names are the proto field names, fields with default values are omitted except for lists of coins, which the
+//!   SDK declares non-nullable and so always encodes, as `[]` when empty
+//! * 64 bit integers are strings, 32 bit integers and enums are numbers
+//! * bytes are base64 and timestamps are RFC 3339 strings with trailing zeros trimmed from the fractional seconds
+//! * Any is replaced by the amino encoding of the message it contains, which requires the contained type to be known,
+//!   see [register](crate::amino::register) for types defined outside of this crate
+
+use crate::cosmos::authz::v1beta1 as authz;
+use crate::cosmos::bank::v1beta1 as bank;
+use crate::cosmos::base::v1beta1::{Coin, DecCoin};
+use crate::cosmos::crypto::{ed25519, secp256k1};
+use crate::cosmos::distribution::v1beta1 as distribution;
+use crate::cosmos::gov::{v1 as gov_v1, v1beta1 as gov};
+use crate::cosmos::params::v1beta1 as params;
+use crate::cosmos::staking::v1beta1 as staking;
+use crate::cosmos::tx::v1beta1::{Fee, TxBody};
+use crate::cosmos::upgrade::v1beta1 as upgrade;
+use base64::Engine;
+use prost_types::{Any, Timestamp};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::RwLock;
+
+/// Errors which can occur while producing the Amino JSON encoding of a message
+#[derive(Debug)]
+pub enum AminoError {
+    /// An Any contained a type url with no known amino encoding
+    UnknownType(String),
+    /// An Any could not be decoded as the type named by its type url
+    Decode(prost::DecodeError),
+}
+
+impl fmt::Display for AminoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AminoError::UnknownType(type_url) => {
+                write!(f, "No amino encoding registered for {type_url}")
+            }
+            AminoError::Decode(e) => write!(f, "Failed to decode Any: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AminoError {}
+
+impl From<prost::DecodeError> for AminoError {
+    fn from(e: prost::DecodeError) -> Self {
+        AminoError::Decode(e)
+    }
+}
+
+/// Types with an Amino JSON encoding
+pub trait AminoJson {
+    /// Returns the Amino JSON encoding of the fields of this value
+    fn amino_json(&self) -> Result<Value, AminoError>;
+}
+
+/// Types registered with a name in the legacy amino codec, like messages, proposal contents and authorizations
+pub trait AminoMsg: AminoJson {
+    /// The name this type is registered under, e.g. "cosmos-sdk/MsgSend"
+    fn amino_type(&self) -> &'static str;
+
+    /// Returns the `{"type": ..., "value": ...}` encoding used wherever this type appears as a message or an Any
+    fn to_amino(&self) -> Result<Value, AminoError> {
+        let mut wrapped = Map::new();
+        wrapped.insert(
+            "type".to_string(),
+            Value::String(self.amino_type().to_string()),
+        );
+        wrapped.insert("value".to_string(), self.amino_json()?);
+        Ok(Value::Object(wrapped))
+    }
+}
+
+/// Decodes the bytes of an Any and returns their `{"type": ..., "value": ...}` encoding
+type AnyDecoder = fn(&[u8]) -> Result<Value, AminoError>;
+
+/// Type url -> decoder for the types registered with [register]
+static AMINO_REGISTRY: RwLock<BTreeMap<String, AnyDecoder>> = RwLock::new(BTreeMap::new());
+
+fn decode_any<M: AminoMsg + prost::Message + Default>(value: &[u8]) -> Result<Value, AminoError> {
+    M::decode(value)?.to_amino()
+}
+
+/// Registers a type defined outside of this crate so that it can be encoded when it appears inside an Any, for example
+/// a Gravity message executed through authz MsgExec. The Cosmos SDK types implemented in this module are always known.
+pub fn register<M>()
+where
+    M: AminoMsg + prost::Name + prost::Message + Default,
+{
+    AMINO_REGISTRY
+        .write()
+        .unwrap()
+        .insert(M::type_url(), decode_any::<M>);
+}
+
+/// Returns the Amino JSON encoding of the message contained in an Any
+pub fn any_to_amino(any: &Any) -> Result<Value, AminoError> {
+    if let Some(decoder) = builtin_decoder(&any.type_url) {
+        return decoder(&any.value);
+    }
+    let registered = AMINO_REGISTRY.read().unwrap().get(&any.type_url).copied();
+    match registered {
+        Some(decoder) => decoder(&any.value),
+        None => Err(AminoError::UnknownType(any.type_url.clone())),
+    }
+}
+
+fn builtin_decoder(type_url: &str) -> Option<AnyDecoder> {
+    macro_rules! builtin {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if type_url == <$ty as prost::Name>::type_url() {
+                    return Some(decode_any::<$ty>);
+                }
+            )*
+        };
+    }
+    builtin!(
+        bank::MsgSend,
+        bank::MsgMultiSend,
+        bank::SendAuthorization,
+        staking::MsgCreateValidator,
+        staking::MsgEditValidator,
+        staking::MsgDelegate,
+        staking::MsgUndelegate,
+        staking::MsgBeginRedelegate,
+        staking::StakeAuthorization,
+        distribution::MsgSetWithdrawAddress,
+        distribution::MsgWithdrawDelegatorReward,
+        distribution::MsgWithdrawValidatorCommission,
+        distribution::MsgFundCommunityPool,
+        distribution::CommunityPoolSpendProposal,
+        gov::MsgSubmitProposal,
+        gov::MsgDeposit,
+        gov::MsgVote,
+        gov::MsgVoteWeighted,
+        gov::TextProposal,
+        gov_v1::MsgSubmitProposal,
+        gov_v1::MsgExecLegacyContent,
+        gov_v1::MsgDeposit,
+        gov_v1::MsgVote,
+        gov_v1::MsgVoteWeighted,
+        params::ParameterChangeProposal,
+        upgrade::SoftwareUpgradeProposal,
+        upgrade::CancelSoftwareUpgradeProposal,
+        authz::MsgGrant,
+        authz::MsgRevoke,
+        authz::MsgExec,
+        authz::GenericAuthorization,
+        ed25519::PubKey,
+        secp256k1::PubKey,
+    );
+    None
+}
+
+/// A StdSignDoc, the document signed in SIGN_MODE_LEGACY_AMINO_JSON
+#[derive(Clone, Debug, PartialEq)]
+pub struct StdSignDoc {
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub timeout_height: u64,
+    pub fee: Fee,
+    pub memo: String,
+    /// The `{"type": ..., "value": ...}` encoding of each message
+    pub msgs: Vec<Value>,
+}
+
+impl StdSignDoc {
+    /// Creates a StdSignDoc for the given messages
+    pub fn new(
+        chain_id: String,
+        account_number: u64,
+        sequence: u64,
+        fee: Fee,
+        memo: String,
+        msgs: &[&dyn AminoMsg],
+    ) -> Result<Self, AminoError> {
+        Ok(StdSignDoc {
+            chain_id,
+            account_number,
+            sequence,
+            timeout_height: 0,
+            fee,
+            memo,
+            msgs: msgs
+                .iter()
+                .map(|m| m.to_amino())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Creates the StdSignDoc for a protobuf TxBody, this fails if any of its messages has no known amino encoding
+    pub fn from_tx_body(
+        chain_id: String,
+        account_number: u64,
+        sequence: u64,
+        fee: Fee,
+        body: &TxBody,
+    ) -> Result<Self, AminoError> {
+        Ok(StdSignDoc {
+            chain_id,
+            account_number,
+            sequence,
+            timeout_height: body.timeout_height,
+            fee,
+            memo: body.memo.clone(),
+            msgs: body
+                .messages
+                .iter()
+                .map(any_to_amino)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Returns the JSON representation of this StdSignDoc
+    pub fn to_json(&self) -> Value {
+        // unlike messages the StdSignDoc and StdFee fields are always present, except for the optional ones
+        let mut fee = Map::new();
+        fee.insert("amount".to_string(), Value::Array(coins(&self.fee.amount)));
+        fee.insert(
+            "gas".to_string(),
+            Value::String(self.fee.gas_limit.to_string()),
+        );
+        let fee = AminoObject(fee)
+            .string("payer", &self.fee.payer)
+            .string("granter", &self.fee.granter);
+
+        let mut doc = Map::new();
+        doc.insert(
+            "account_number".to_string(),
+            Value::String(self.account_number.to_string()),
+        );
+        doc.insert("chain_id".to_string(), Value::String(self.chain_id.clone()));
+        doc.insert("fee".to_string(), fee.finish());
+        doc.insert("memo".to_string(), Value::String(self.memo.clone()));
+        doc.insert("msgs".to_string(), Value::Array(self.msgs.clone()));
+        doc.insert(
+            "sequence".to_string(),
+            Value::String(self.sequence.to_string()),
+        );
+        AminoObject(doc)
+            .uint64("timeout_height", self.timeout_height)
+            .finish()
+    }
+
+    /// Returns the bytes to sign: the JSON representation with sorted keys and no whitespace, with the HTML characters
+    /// and line separators escaped the way Go's encoding/json does
+    pub fn sign_bytes(&self) -> Vec<u8> {
+        let mut out = String::new();
+        write_sorted(&self.to_json(), &mut out);
+        out.into_bytes()
+    }
+}
+
+/// Writes a JSON value with sorted object keys, independent of whether serde_json preserves insertion order
+fn write_sorted(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_sorted(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_sorted(value, out);
+            }
+            out.push(']');
+        }
+        Value::String(s) => write_string(s, out),
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+/// Writes a JSON string escaped as Go's encoding/json does, which also escapes the HTML characters and the line and
+/// paragraph separators
+fn write_string(s: &str, out: &mut String) {
+    let escaped = Value::String(s.to_string()).to_string();
+    out.push_str(
+        &escaped
+            .replace('&', "\\u0026")
+            .replace('<', "\\u003c")
+            .replace('>', "\\u003e")
+            .replace('\u{2028}', "\\u2028")
+            .replace('\u{2029}', "\\u2029"),
+    );
+}
+
+/// Builds the Amino JSON encoding of a message, omitting fields with default values
+#[derive(Debug, Default)]
+pub struct AminoObject(Map<String, Value>);
+
+impl AminoObject {
+    pub fn new() -> Self {
+        AminoObject::default()
+    }
+
+    /// Adds a field even if it has a default value
+    pub fn insert(mut self, key: &str, value: Value) -> Self {
+        self.0.insert(key.to_string(), value);
+        self
+    }
+
+    pub fn string(self, key: &str, value: &str) -> Self {
+        if value.is_empty() {
+            return self;
+        }
+        self.insert(key, Value::String(value.to_string()))
+    }
+
+    pub fn strings(self, key: &str, values: &[String]) -> Self {
+        if values.is_empty() {
+            return self;
+        }
+        let values = values.iter().map(|v| Value::String(v.clone())).collect();
+        self.insert(key, Value::Array(values))
+    }
+
+    pub fn uint64(self, key: &str, value: u64) -> Self {
+        if value == 0 {
+            return self;
+        }
+        self.insert(key, Value::String(value.to_string()))
+    }
+
+    pub fn int64(self, key: &str, value: i64) -> Self {
+        if value == 0 {
+            return self;
+        }
+        self.insert(key, Value::String(value.to_string()))
+    }
+
+    pub fn boolean(self, key: &str, value: bool) -> Self {
+        if !value {
+            return self;
+        }
+        self.insert(key, Value::Bool(value))
+    }
+
+    /// Adds a 32 bit integer or enum field
+    pub fn int32(self, key: &str, value: i32) -> Self {
+        if value == 0 {
+            return self;
+        }
+        self.insert(key, Value::from(value))
+    }
+
+    pub fn bytes(self, key: &str, value: &[u8]) -> Self {
+        if value.is_empty() {
+            return self;
+        }
+        let encoded = base64::engine::general_purpose::STANDARD.encode(value);
+        self.insert(key, Value::String(encoded))
+    }
+
+    pub fn timestamp(self, key: &str, value: &Option<Timestamp>) -> Self {
+        match value {
+            Some(t) => self.insert(key, Value::String(rfc3339_nano(t))),
+            None => self,
+        }
+    }
+
+    /// Adds a list of coins, which is present even when empty
+    pub fn coins(self, key: &str, value: &[Coin]) -> Self {
+        self.insert(key, Value::Array(coins(value)))
+    }
+
+    pub fn message<T: AminoJson>(self, key: &str, value: &Option<T>) -> Result<Self, AminoError> {
+        Ok(match value {
+            Some(v) => self.insert(key, v.amino_json()?),
+            None => self,
+        })
+    }
+
+    pub fn messages<T: AminoJson>(self, key: &str, values: &[T]) -> Result<Self, AminoError> {
+        if values.is_empty() {
+            return Ok(self);
+        }
+        let values = values
+            .iter()
+            .map(AminoJson::amino_json)
+            .collect::<Result<_, _>>()?;
+        Ok(self.insert(key, Value::Array(values)))
+    }
+
+    pub fn any(self, key: &str, value: &Option<Any>) -> Result<Self, AminoError> {
+        Ok(match value {
+            Some(v) => self.insert(key, any_to_amino(v)?),
+            None => self,
+        })
+    }
+
+    pub fn anys(self, key: &str, values: &[Any]) -> Result<Self, AminoError> {
+        if values.is_empty() {
+            return Ok(self);
+        }
+        let values = values.iter().map(any_to_amino).collect::<Result<_, _>>()?;
+        Ok(self.insert(key, Value::Array(values)))
+    }
+
+    pub fn finish(self) -> Value {
+        Value::Object(self.0)
+    }
+}
+
+/// Formats a timestamp the way Go's time.RFC3339Nano does, trimming the trailing zeros of the fractional seconds
+/// (e.g. 2021-01-01T00:00:20.5Z) where prost always pads them to 3, 6 or 9 digits
+fn rfc3339_nano(timestamp: &Timestamp) -> String {
+    let mut timestamp = *timestamp;
+    timestamp.normalize();
+    let seconds = Timestamp {
+        seconds: timestamp.seconds,
+        nanos: 0,
+    }
+    .to_string();
+    let seconds = seconds.trim_end_matches('Z');
+    if timestamp.nanos == 0 {
+        return format!("{seconds}Z");
+    }
+    let nanos = format!("{:09}", timestamp.nanos);
+    format!("{seconds}.{}Z", nanos.trim_end_matches('0'))
+}
+
+fn coins(value: &[Coin]) -> Vec<Value> {
+    value
+        .iter()
+        .map(|c| {
+            AminoObject::new()
+                .string("amount", &c.amount)
+                .string("denom", &c.denom)
+                .finish()
+        })
+        .collect()
+}
+
+/// Implements AminoMsg with the given amino names
+macro_rules! amino_msg {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            impl AminoMsg for $ty {
+                fn amino_type(&self) -> &'static str {
+                    $name
+                }
+            }
+        )*
+    };
+}
+
+amino_msg!(
+    bank::MsgSend => "cosmos-sdk/MsgSend",
+    bank::MsgMultiSend => "cosmos-sdk/MsgMultiSend",
+    bank::SendAuthorization => "cosmos-sdk/SendAuthorization",
+    staking::MsgCreateValidator => "cosmos-sdk/MsgCreateValidator",
+    staking::MsgEditValidator => "cosmos-sdk/MsgEditValidator",
+    staking::MsgDelegate => "cosmos-sdk/MsgDelegate",
+    staking::MsgUndelegate => "cosmos-sdk/MsgUndelegate",
+    staking::MsgBeginRedelegate => "cosmos-sdk/MsgBeginRedelegate",
+    staking::StakeAuthorization => "cosmos-sdk/StakeAuthorization",
+    distribution::MsgSetWithdrawAddress => "cosmos-sdk/MsgModifyWithdrawAddress",
+    distribution::MsgWithdrawDelegatorReward => "cosmos-sdk/MsgWithdrawDelegationReward",
+    distribution::MsgWithdrawValidatorCommission => "cosmos-sdk/MsgWithdrawValCommission",
+    distribution::MsgFundCommunityPool => "cosmos-sdk/MsgFundCommunityPool",
+    distribution::CommunityPoolSpendProposal => "cosmos-sdk/CommunityPoolSpendProposal",
+    gov::MsgSubmitProposal => "cosmos-sdk/MsgSubmitProposal",
+    gov::MsgDeposit => "cosmos-sdk/MsgDeposit",
+    gov::MsgVote => "cosmos-sdk/MsgVote",
+    gov::MsgVoteWeighted => "cosmos-sdk/MsgVoteWeighted",
+    gov::TextProposal => "cosmos-sdk/TextProposal",
+    gov_v1::MsgSubmitProposal => "cosmos-sdk/v1/MsgSubmitProposal",
+    gov_v1::MsgExecLegacyContent => "cosmos-sdk/v1/MsgExecLegacyContent",
+    gov_v1::MsgDeposit => "cosmos-sdk/v1/MsgDeposit",
+    gov_v1::MsgVote => "cosmos-sdk/v1/MsgVote",
+    gov_v1::MsgVoteWeighted => "cosmos-sdk/v1/MsgVoteWeighted",
+    params::ParameterChangeProposal => "cosmos-sdk/ParameterChangeProposal",
+    upgrade::SoftwareUpgradeProposal => "cosmos-sdk/SoftwareUpgradeProposal",
+    upgrade::CancelSoftwareUpgradeProposal => "cosmos-sdk/CancelSoftwareUpgradeProposal",
+    authz::MsgGrant => "cosmos-sdk/MsgGrant",
+    authz::MsgRevoke => "cosmos-sdk/MsgRevoke",
+    authz::MsgExec => "cosmos-sdk/MsgExec",
+    authz::GenericAuthorization => "cosmos-sdk/GenericAuthorization",
+    ed25519::PubKey => "tendermint/PubKeyEd25519",
+    secp256k1::PubKey => "tendermint/PubKeySecp256k1",
+);
+
+impl AminoJson for Coin {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("amount", &self.amount)
+            .string("denom", &self.denom)
+            .finish())
+    }
+}
+
+impl AminoJson for DecCoin {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("amount", &self.amount)
+            .string("denom", &self.denom)
+            .finish())
+    }
+}
+
+// Public keys are encoded as the base64 key bytes rather than an object
+impl AminoJson for ed25519::PubKey {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&self.key);
+        Ok(Value::String(encoded))
+    }
+}
+
+impl AminoJson for secp256k1::PubKey {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&self.key);
+        Ok(Value::String(encoded))
+    }
+}
+
+// Bank
+
+impl AminoJson for bank::MsgSend {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("from_address", &self.from_address)
+            .string("to_address", &self.to_address)
+            .coins("amount", &self.amount)
+            .finish())
+    }
+}
+
+impl AminoJson for bank::Input {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("address", &self.address)
+            .coins("coins", &self.coins)
+            .finish())
+    }
+}
+
+impl AminoJson for bank::Output {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("address", &self.address)
+            .coins("coins", &self.coins)
+            .finish())
+    }
+}
+
+impl AminoJson for bank::MsgMultiSend {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .messages("inputs", &self.inputs)?
+            .messages("outputs", &self.outputs)?
+            .finish())
+    }
+}
+
+impl AminoJson for bank::SendAuthorization {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .coins("spend_limit", &self.spend_limit)
+            .finish())
+    }
+}
+
+// Staking
+
+impl AminoJson for staking::Description {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("moniker", &self.moniker)
+            .string("identity", &self.identity)
+            .string("website", &self.website)
+            .string("security_contact", &self.security_contact)
+            .string("details", &self.details)
+            .finish())
+    }
+}
+
+impl AminoJson for staking::CommissionRates {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("rate", &self.rate)
+            .string("max_rate", &self.max_rate)
+            .string("max_change_rate", &self.max_change_rate)
+            .finish())
+    }
+}
+
+impl AminoJson for staking::MsgCreateValidator {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .message("description", &self.description)?
+            .message("commission", &self.commission)?
+            .string("min_self_delegation", &self.min_self_delegation)
+            .string("delegator_address", &self.delegator_address)
+            .string("validator_address", &self.validator_address)
+            .any("pubkey", &self.pubkey)?
+            .message("value", &self.value)?
+            .finish())
+    }
+}
+
+impl AminoJson for staking::MsgEditValidator {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .message("description", &self.description)?
+            .string("validator_address", &self.validator_address)
+            .string("commission_rate", &self.commission_rate)
+            .string("min_self_delegation", &self.min_self_delegation)
+            .finish())
+    }
+}
+
+impl AminoJson for staking::MsgDelegate {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("delegator_address", &self.delegator_address)
+            .string("validator_address", &self.validator_address)
+            .message("amount", &self.amount)?
+            .finish())
+    }
+}
+
+impl AminoJson for staking::MsgUndelegate {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("delegator_address", &self.delegator_address)
+            .string("validator_address", &self.validator_address)
+            .message("amount", &self.amount)?
+            .finish())
+    }
+}
+
+impl AminoJson for staking::MsgBeginRedelegate {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("delegator_address", &self.delegator_address)
+            .string("validator_src_address", &self.validator_src_address)
+            .string("validator_dst_address", &self.validator_dst_address)
+            .message("amount", &self.amount)?
+            .finish())
+    }
+}
+
+impl AminoJson for staking::stake_authorization::Validators {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .strings("address", &self.address)
+            .finish())
+    }
+}
+
+impl AminoJson for staking::StakeAuthorization {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        use staking::stake_authorization::ValidatorsEnum;
+        // the validators oneof is an amino interface, encoded under the Go field name
+        let validators = match &self.validators {
+            Some(ValidatorsEnum::AllowList(v)) => Some((
+                "cosmos-sdk/StakeAuthorization/AllowList",
+                AminoObject::new().insert("allow_list", v.amino_json()?),
+            )),
+            Some(ValidatorsEnum::DenyList(v)) => Some((
+                "cosmos-sdk/StakeAuthorization/DenyList",
+                AminoObject::new().insert("deny_list", v.amino_json()?),
+            )),
+            None => None,
+        };
+        let mut object = AminoObject::new()
+            .message("max_tokens", &self.max_tokens)?
+            .int32("authorization_type", self.authorization_type);
+        if let Some((amino_type, value)) = validators {
+            let wrapped = AminoObject::new()
+                .string("type", amino_type)
+                .insert("value", value.finish());
+            object = object.insert("Validators", wrapped.finish());
+        }
+        Ok(object.finish())
+    }
+}
+
+// Distribution
+
+impl AminoJson for distribution::MsgSetWithdrawAddress {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("delegator_address", &self.delegator_address)
+            .string("withdraw_address", &self.withdraw_address)
+            .finish())
+    }
+}
+
+impl AminoJson for distribution::MsgWithdrawDelegatorReward {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("delegator_address", &self.delegator_address)
+            .string("validator_address", &self.validator_address)
+            .finish())
+    }
+}
+
+impl AminoJson for distribution::MsgWithdrawValidatorCommission {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("validator_address", &self.validator_address)
+            .finish())
+    }
+}
+
+impl AminoJson for distribution::MsgFundCommunityPool {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .coins("amount", &self.amount)
+            .string("depositor", &self.depositor)
+            .finish())
+    }
+}
+
+impl AminoJson for distribution::CommunityPoolSpendProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("title", &self.title)
+            .string("description", &self.description)
+            .string("recipient", &self.recipient)
+            .coins("amount", &self.amount)
+            .finish())
+    }
+}
+
+// Gov
+
+impl AminoJson for gov::MsgSubmitProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .any("content", &self.content)?
+            .coins("initial_deposit", &self.initial_deposit)
+            .string("proposer", &self.proposer)
+            .finish())
+    }
+}
+
+impl AminoJson for gov::MsgDeposit {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("proposal_id", self.proposal_id)
+            .string("depositor", &self.depositor)
+            .coins("amount", &self.amount)
+            .finish())
+    }
+}
+
+impl AminoJson for gov::MsgVote {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("proposal_id", self.proposal_id)
+            .string("voter", &self.voter)
+            .int32("option", self.option)
+            .finish())
+    }
+}
+
+impl AminoJson for gov::WeightedVoteOption {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .int32("option", self.option)
+            .string("weight", &self.weight)
+            .finish())
+    }
+}
+
+impl AminoJson for gov::MsgVoteWeighted {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("proposal_id", self.proposal_id)
+            .string("voter", &self.voter)
+            .messages("options", &self.options)?
+            .finish())
+    }
+}
+
+impl AminoJson for gov::TextProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("title", &self.title)
+            .string("description", &self.description)
+            .finish())
+    }
+}
+
+impl AminoJson for gov_v1::MsgSubmitProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .anys("messages", &self.messages)?
+            .coins("initial_deposit", &self.initial_deposit)
+            .string("proposer", &self.proposer)
+            .string("metadata", &self.metadata)
+            .finish())
+    }
+}
+
+impl AminoJson for gov_v1::MsgExecLegacyContent {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .any("content", &self.content)?
+            .string("authority", &self.authority)
+            .finish())
+    }
+}
+
+impl AminoJson for gov_v1::MsgDeposit {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("proposal_id", self.proposal_id)
+            .string("depositor", &self.depositor)
+            .coins("amount", &self.amount)
+            .finish())
+    }
+}
+
+impl AminoJson for gov_v1::MsgVote {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("proposal_id", self.proposal_id)
+            .string("voter", &self.voter)
+            .int32("option", self.option)
+            .string("metadata", &self.metadata)
+            .finish())
+    }
+}
+
+impl AminoJson for gov_v1::WeightedVoteOption {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .int32("option", self.option)
+            .string("weight", &self.weight)
+            .finish())
+    }
+}
+
+impl AminoJson for gov_v1::MsgVoteWeighted {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("proposal_id", self.proposal_id)
+            .string("voter", &self.voter)
+            .messages("options", &self.options)?
+            .string("metadata", &self.metadata)
+            .finish())
+    }
+}
+
+impl AminoJson for params::ParamChange {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("subspace", &self.subspace)
+            .string("key", &self.key)
+            .string("value", &self.value)
+            .finish())
+    }
+}
+
+impl AminoJson for params::ParameterChangeProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("title", &self.title)
+            .string("description", &self.description)
+            .messages("changes", &self.changes)?
+            .finish())
+    }
+}
+
+impl AminoJson for upgrade::Plan {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            // time and upgraded_client_state are deprecated and must be empty
+            .string("name", &self.name)
+            .int64("height", self.height)
+            .string("info", &self.info)
+            .finish())
+    }
+}
+
+impl AminoJson for upgrade::SoftwareUpgradeProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("title", &self.title)
+            .string("description", &self.description)
+            .message("plan", &self.plan)?
+            .finish())
+    }
+}
+
+impl AminoJson for upgrade::CancelSoftwareUpgradeProposal {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("title", &self.title)
+            .string("description", &self.description)
+            .finish())
+    }
+}
+
+// Authz
+
+impl AminoJson for authz::Grant {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .any("authorization", &self.authorization)?
+            .timestamp("expiration", &self.expiration)
+            .finish())
+    }
+}
+
+impl AminoJson for authz::MsgGrant {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("granter", &self.granter)
+            .string("grantee", &self.grantee)
+            .message("grant", &self.grant)?
+            .finish())
+    }
+}
+
+impl AminoJson for authz::MsgRevoke {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("granter", &self.granter)
+            .string("grantee", &self.grantee)
+            .string("msg_type_url", &self.msg_type_url)
+            .finish())
+    }
+}
+
+impl AminoJson for authz::MsgExec {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("grantee", &self.grantee)
+            .anys("msgs", &self.msgs)?
+            .finish())
+    }
+}
+
+impl AminoJson for authz::GenericAuthorization {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new().string("msg", &self.msg).finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(amount: &str, denom: &str) -> Coin {
+        Coin {
+            amount: amount.to_string(),
+            denom: denom.to_string(),
+        }
+    }
+
+    fn json(value: &Value) -> String {
+        let mut out = String::new();
+        write_sorted(value, &mut out);
+        out
+    }
+
+    /// The StdSignBytes("foo", 1, 1, 1, StdFee{}, msgs, "memo", nil) doc used by the SDK's authz TestAminoJSON
+    fn authz_sign_bytes(msg: &dyn AminoMsg) -> String {
+        let mut doc =
+            StdSignDoc::new("foo".into(), 1, 1, Fee::default(), "memo".into(), &[msg]).unwrap();
+        doc.timeout_height = 1;
+        String::from_utf8(doc.sign_bytes()).unwrap()
+    }
+
+    fn msg_send(from: &str, to: &str, amount: Vec<Coin>) -> bank::MsgSend {
+        bank::MsgSend {
+            from_address: from.to_string(),
+            to_address: to.to_string(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn msg_send_with_fee_granter() {
+        // The bank module's TestMsgSendGetSignBytes, sdk.AccAddress("input") to sdk.AccAddress("output")
+        let msg = msg_send(
+            "cosmos1d9h8qat57ljhcm",
+            "cosmos1da6hgur4wsmpnjyg",
+            vec![coin("10", "atom")],
+        );
+        assert_eq!(
+            json(&msg.to_amino().unwrap()),
+            r#"{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"10","denom":"atom"}],"from_address":"cosmos1d9h8qat57ljhcm","to_address":"cosmos1da6hgur4wsmpnjyg"}}"#
+        );
+
+        // The fee of the legacytx TestStdSignBytes granter case
+        let fee = Fee {
+            amount: vec![coin("150", "atom")],
+            gas_limit: 100000,
+            payer: String::new(),
+            granter: "cosmos1d9h8qat57ljhcm".to_string(),
+        };
+        let doc = StdSignDoc::new("1234".into(), 3, 6, fee, "memo".into(), &[&msg]).unwrap();
+        assert_eq!(
+            String::from_utf8(doc.sign_bytes()).unwrap(),
+            r#"{"account_number":"3","chain_id":"1234","fee":{"amount":[{"amount":"150","denom":"atom"}],"gas":"100000","granter":"cosmos1d9h8qat57ljhcm"},"memo":"memo","msgs":[{"type":"cosmos-sdk/MsgSend","value":{"amount":[{"amount":"10","denom":"atom"}],"from_address":"cosmos1d9h8qat57ljhcm","to_address":"cosmos1da6hgur4wsmpnjyg"}}],"sequence":"6"}"#
+        );
+    }
+
+    #[test]
+    fn msg_grant_with_expiration() {
+        // time.Date(1, 1, 1, 1, 1, 1, 1, time.UTC).Add(time.Hour)
+        let expiration = Timestamp {
+            seconds: -62_135_596_800 + 2 * 3600 + 60 + 1,
+            nanos: 1,
+        };
+        let authorization = authz::GenericAuthorization {
+            msg: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+        };
+        let msg = authz::MsgGrant {
+            granter: "cosmos1abc".to_string(),
+            grantee: "cosmos1def".to_string(),
+            grant: Some(authz::Grant {
+                authorization: Some(Any::from_msg(&authorization).unwrap()),
+                expiration: Some(expiration),
+            }),
+        };
+        assert_eq!(
+            authz_sign_bytes(&msg),
+            r#"{"account_number":"1","chain_id":"foo","fee":{"amount":[],"gas":"0"},"memo":"memo","msgs":[{"type":"cosmos-sdk/MsgGrant","value":{"grant":{"authorization":{"type":"cosmos-sdk/GenericAuthorization","value":{"msg":"/cosmos.bank.v1beta1.MsgSend"}},"expiration":"0001-01-01T02:01:01.000000001Z"},"grantee":"cosmos1def","granter":"cosmos1abc"}}],"sequence":"1","timeout_height":"1"}"#
+        );
+    }
+
+    #[test]
+    fn msg_exec_with_empty_coins() {
+        let send = msg_send("cosmos1ghi", "cosmos1jkl", vec![]);
+        let msg = authz::MsgExec {
+            grantee: "cosmos1def".to_string(),
+            msgs: vec![Any::from_msg(&send).unwrap()],
+        };
+        assert_eq!(
+            authz_sign_bytes(&msg),
+            r#"{"account_number":"1","chain_id":"foo","fee":{"amount":[],"gas":"0"},"memo":"memo","msgs":[{"type":"cosmos-sdk/MsgExec","value":{"grantee":"cosmos1def","msgs":[{"type":"cosmos-sdk/MsgSend","value":{"amount":[],"from_address":"cosmos1ghi","to_address":"cosmos1jkl"}}]}}],"sequence":"1","timeout_height":"1"}"#
+        );
+    }
+
+    #[test]
+    fn gov_proposals() {
+        // The gov v1 TestMsgSubmitProposal_GetSignBytes cases, sdk.AccAddress("test1")
+        let vote = gov_v1::MsgVote {
+            proposal_id: 1,
+            voter: "cosmos1w3jhxap3gempvr".to_string(),
+            option: gov_v1::VoteOption::Yes as i32,
+            metadata: String::new(),
+        };
+        let send = msg_send("cosmos1w3jhxap3gempvr", "cosmos1w3jhxap3gempvr", vec![]);
+        let proposal = |msg: Any| gov_v1::MsgSubmitProposal {
+            messages: vec![msg],
+            initial_deposit: vec![],
+            proposer: String::new(),
+            metadata: String::new(),
+        };
+        assert_eq!(
+            json(&proposal(Any::from_msg(&vote).unwrap()).to_amino().unwrap()),
+            r#"{"type":"cosmos-sdk/v1/MsgSubmitProposal","value":{"initial_deposit":[],"messages":[{"type":"cosmos-sdk/v1/MsgVote","value":{"option":1,"proposal_id":"1","voter":"cosmos1w3jhxap3gempvr"}}]}}"#
+        );
+        assert_eq!(
+            json(&proposal(Any::from_msg(&send).unwrap()).to_amino().unwrap()),
+            r#"{"type":"cosmos-sdk/v1/MsgSubmitProposal","value":{"initial_deposit":[],"messages":[{"type":"cosmos-sdk/MsgSend","value":{"amount":[],"from_address":"cosmos1w3jhxap3gempvr","to_address":"cosmos1w3jhxap3gempvr"}}]}}"#
+        );
+
+        // A v1beta1 proposal, whose Content is encoded like any other Any
+        let content = gov::TextProposal {
+            title: "Test".to_string(),
+            description: "description".to_string(),
+        };
+        let msg = gov::MsgSubmitProposal {
+            content: Some(Any::from_msg(&content).unwrap()),
+            initial_deposit: vec![coin("1000", "stake")],
+            proposer: "cosmos1v9jxgu33kfsgr5".to_string(),
+        };
+        assert_eq!(
+            json(&msg.to_amino().unwrap()),
+            r#"{"type":"cosmos-sdk/MsgSubmitProposal","value":{"content":{"type":"cosmos-sdk/TextProposal","value":{"description":"description","title":"Test"}},"initial_deposit":[{"amount":"1000","denom":"stake"}],"proposer":"cosmos1v9jxgu33kfsgr5"}}"#
+        );
+    }
+
+    #[test]
+    fn timestamps_trim_fractional_seconds() {
+        let format = |seconds, nanos| rfc3339_nano(&Timestamp { seconds, nanos });
+        assert_eq!(format(1_609_459_220, 0), "2021-01-01T00:00:20Z");
+        assert_eq!(format(1_609_459_220, 500_000_000), "2021-01-01T00:00:20.5Z");
+        assert_eq!(
+            format(1_609_459_220, 123_450_000),
+            "2021-01-01T00:00:20.12345Z"
+        );
+        assert_eq!(format(1_609_459_220, 1), "2021-01-01T00:00:20.000000001Z");
+        // negative nanos are normalized first
+        assert_eq!(
+            format(1_609_459_221, -500_000_000),
+            "2021-01-01T00:00:20.5Z"
+        );
+    }
+
+    #[test]
+    fn escapes_as_go_does() {
+        let doc = StdSignDoc::new(
+            "foo".into(),
+            0,
+            0,
+            Fee::default(),
+            "<a & b>\u{2028}\u{2029}".into(),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(doc.sign_bytes()).unwrap(),
+            r#"{"account_number":"0","chain_id":"foo","fee":{"amount":[],"gas":"0"},"memo":"\u003ca \u0026 b\u003e\u2028\u2029","msgs":[],"sequence":"0"}"#
+        );
+    }
+}
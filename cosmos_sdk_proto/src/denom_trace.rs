@@ -0,0 +1,232 @@
+//! ICS20 denom traces, following ibc-go's transfer module. A token which has crossed IBC channels is held under the
+//! denom `ibc/{SHA256(path/base_denom)}`, where the path lists the port and channel of each hop it took, most recent
+//! first. When a token is sent back over the channel it arrived on the receiving chain is its source, and the hop is
+//! removed from the path rather than added.
+
+use crate::ibc::applications::transfer::v1::DenomTrace;
+use crate::ibc::applications::transfer::v2::FungibleTokenPacketData;
+use crate::ibc::core::channel::v1::Packet;
+use sha2::Digest;
+
+/// The prefix of every IBC voucher denom
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// The prefix a hop over the port and channel adds to a denom path, "{port}/{channel}/"
+pub fn denom_prefix(port: &str, channel: &str) -> String {
+    format!("{port}/{channel}/")
+}
+
+/// If the channel identifier has the form ibc-go's IsValidChannelID accepts, "channel-" followed by a decimal sequence
+/// which fits in a u64
+fn is_channel_id(id: &str) -> bool {
+    // parse alone would also accept a leading '+'
+    id.strip_prefix("channel-")
+        .is_some_and(|n| n.bytes().all(|b| b.is_ascii_digit()) && n.parse::<u64>().is_ok())
+}
+
+/// If a packet sent from the source port and channel returns the token to the receiving chain, that is if the denom
+/// was last received over that port and channel
+pub fn receiver_chain_is_source(source_port: &str, source_channel: &str, denom: &str) -> bool {
+    denom.starts_with(&denom_prefix(source_port, source_channel))
+}
+
+/// If the sending chain is the source of the token, in which case the sender escrows it rather than burning a voucher
+pub fn sender_chain_is_source(source_port: &str, source_channel: &str, denom: &str) -> bool {
+    !receiver_chain_is_source(source_port, source_channel, denom)
+}
+
+/// The hash of an ibc/{hash} denom, None if the denom is not an IBC voucher or the hash is not 32 hex encoded bytes
+pub fn parse_ibc_denom(denom: &str) -> Option<[u8; 32]> {
+    let hex = denom.strip_prefix(IBC_DENOM_PREFIX)?;
+    if hex.len() != 64 || !hex.is_ascii() {
+        return None;
+    }
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(hash)
+}
+
+impl DenomTrace {
+    /// Parses a full denom path such as "transfer/channel-0/uatom" into its path and base denom. Pairs are taken as
+    /// hops while their second element is a channel identifier, everything after them is the base denom, which may
+    /// itself contain slashes (e.g. "gamm/pool/1")
+    pub fn parse(full_denom_path: &str) -> Self {
+        let items: Vec<&str> = full_denom_path.split('/').collect();
+        let mut hops = 0;
+        while hops * 2 + 1 < items.len() && items.len() > 2 && is_channel_id(items[hops * 2 + 1]) {
+            hops += 1;
+        }
+        DenomTrace {
+            path: items[..hops * 2].join("/"),
+            base_denom: items[hops * 2..].join("/"),
+        }
+    }
+
+    /// If the token has not crossed any channel
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// The path followed by the base denom, the denom carried in packets
+    pub fn full_denom_path(&self) -> String {
+        if self.is_native() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// SHA256 of the full denom path
+    pub fn hash(&self) -> [u8; 32] {
+        sha2::Sha256::digest(self.full_denom_path().as_bytes()).into()
+    }
+
+    /// The denom the token is held under, ibc/{HASH} in upper case hex or the base denom of a native token
+    pub fn ibc_denom(&self) -> String {
+        if self.is_native() {
+            return self.base_denom.clone();
+        }
+        let hex: String = self.hash().iter().map(|b| format!("{b:02X}")).collect();
+        format!("{IBC_DENOM_PREFIX}{hex}")
+    }
+
+    /// The trace after the token is received over the port and channel from a chain which is not its source
+    pub fn add_prefix(&self, port: &str, channel: &str) -> Self {
+        DenomTrace {
+            path: if self.is_native() {
+                format!("{port}/{channel}")
+            } else {
+                format!("{port}/{channel}/{}", self.path)
+            },
+            base_denom: self.base_denom.clone(),
+        }
+    }
+
+    /// The trace after the token returns over the port and channel it last arrived on, None if that was not its
+    /// last hop
+    pub fn strip_prefix(&self, port: &str, channel: &str) -> Option<Self> {
+        let hop = format!("{port}/{channel}");
+        let path = if self.path == hop {
+            String::new()
+        } else {
+            self.path.strip_prefix(&format!("{hop}/"))?.to_string()
+        };
+        Some(DenomTrace {
+            path,
+            base_denom: self.base_denom.clone(),
+        })
+    }
+}
+
+impl FungibleTokenPacketData {
+    /// If the chain sending the packet is the token's source
+    pub fn sender_chain_is_source(&self, source_port: &str, source_channel: &str) -> bool {
+        sender_chain_is_source(source_port, source_channel, &self.denom)
+    }
+
+    /// The trace of the token the receiving chain credits for the packet, its ibc_denom is the denom received. A
+    /// returning token has the sender's hop removed, any other token gains the receiver's port and channel
+    pub fn received_denom_trace(&self, packet: &Packet) -> DenomTrace {
+        let source_prefix = denom_prefix(&packet.source_port, &packet.source_channel);
+        match self.denom.strip_prefix(&source_prefix) {
+            Some(unprefixed) => DenomTrace::parse(unprefixed),
+            None => DenomTrace::parse(&format!(
+                "{}{}",
+                denom_prefix(&packet.destination_port, &packet.destination_channel),
+                self.denom
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(path: &str, base_denom: &str) -> DenomTrace {
+        DenomTrace {
+            path: path.to_string(),
+            base_denom: base_denom.to_string(),
+        }
+    }
+
+    #[test]
+    fn atom_ibc_denom() {
+        let atom = DenomTrace::parse("transfer/channel-0/uatom");
+        assert_eq!(atom, trace("transfer/channel-0", "uatom"));
+        assert_eq!(
+            atom.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(parse_ibc_denom(&atom.ibc_denom()), Some(atom.hash()));
+        assert_eq!(DenomTrace::parse("uatom").ibc_denom(), "uatom");
+    }
+
+    #[test]
+    fn parse_splits_hops_from_the_base_denom() {
+        for (full_path, path, base_denom) in [
+            ("uatom", "", "uatom"),
+            (
+                "transfer/channel-0/transfer/channel-1/uatom",
+                "transfer/channel-0/transfer/channel-1",
+                "uatom",
+            ),
+            (
+                "transfer/channel-0/gamm/pool/1",
+                "transfer/channel-0",
+                "gamm/pool/1",
+            ),
+            ("gamm/pool/1", "", "gamm/pool/1"),
+            ("transfer/channelToA/uatom", "", "transfer/channelToA/uatom"),
+            ("transfer/channel-0", "", "transfer/channel-0"),
+            ("transfer/channel-0/", "transfer/channel-0", ""),
+            (
+                "transfer/channel-18446744073709551615/uatom",
+                "transfer/channel-18446744073709551615",
+                "uatom",
+            ),
+            (
+                "transfer/channel-99999999999999999999/uatom",
+                "",
+                "transfer/channel-99999999999999999999/uatom",
+            ),
+            ("transfer/channel-+1/uatom", "", "transfer/channel-+1/uatom"),
+            ("transfer/channel-/uatom", "", "transfer/channel-/uatom"),
+        ] {
+            let parsed = DenomTrace::parse(full_path);
+            assert_eq!(parsed, trace(path, base_denom), "{full_path}");
+            assert_eq!(parsed.full_denom_path(), full_path);
+        }
+    }
+
+    #[test]
+    fn prefixes() {
+        let atom = trace("transfer/channel-0", "uatom");
+        let forwarded = atom.add_prefix("transfer", "channel-5");
+        assert_eq!(
+            forwarded,
+            trace("transfer/channel-5/transfer/channel-0", "uatom")
+        );
+        assert_eq!(
+            forwarded.strip_prefix("transfer", "channel-5"),
+            Some(atom.clone())
+        );
+        assert_eq!(forwarded.strip_prefix("transfer", "channel-0"), None);
+        assert_eq!(
+            atom.strip_prefix("transfer", "channel-0"),
+            Some(trace("", "uatom"))
+        );
+        assert!(receiver_chain_is_source(
+            "transfer",
+            "channel-0",
+            "transfer/channel-0/uatom"
+        ));
+        assert!(sender_chain_is_source(
+            "transfer",
+            "channel-0",
+            "transfer/channel-01/uatom"
+        ));
+    }
+}
@@ -0,0 +1,1323 @@
+//! Verification of ICS23 commitment proofs, as returned by ABCI queries made with prove=true. This follows the
+//! reference implementation in cosmos/ics23: an [ExistenceProof] hashes its key and value into a leaf and then applies
+//! each [InnerOp] of its path, and the result must equal the trusted root. A [NonExistenceProof] proves the existence of
+//! the keys immediately to the left and right of the missing key, and that the two are neighbors in the tree.
+//!
+//! Every proof is checked against a [ProofSpec] describing the tree which produced it, otherwise a malicious prover
+//! could reinterpret a leaf as an inner node. Use [iavl_spec] for the Cosmos SDK stores and [tendermint_spec] for the
+//! simple merkle tree of store roots in the app hash.
+
+use crate::ics23::{
+    batch_entry, commitment_proof, compressed_batch_entry, BatchEntry, BatchProof, CommitmentProof,
+    CompressedBatchProof, CompressedExistenceProof, CompressedNonExistenceProof, ExistenceProof,
+    HashOp, InnerOp, InnerSpec, LeafOp, LengthOp, NonExistenceProof, ProofSpec,
+};
+use sha2::Digest;
+use std::fmt;
+
+/// An error verifying a proof
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// A required field of the proof or spec is not set
+    MissingField(&'static str),
+    /// The hash operation is not supported
+    UnsupportedHash(i32),
+    /// The length operation is not supported
+    UnsupportedLength(i32),
+    /// The data does not have the length required by the length operation
+    InvalidLength { op: LengthOp, length: usize },
+    /// The key or value of an existence proof is empty
+    EmptyLeaf,
+    /// An inner operation was applied to an empty child
+    EmptyChild,
+    /// The proof does not have the shape its spec requires, the reason is given
+    SpecMismatch(&'static str),
+    /// The proof is for a different key
+    KeyMismatch,
+    /// The proof is for a different value
+    ValueMismatch,
+    /// The proof does not hash to the trusted root
+    RootMismatch,
+    /// A non-existence proof's key is not between its left and right keys
+    KeyOutOfRange,
+    /// The left and right proofs of a non-existence proof are not neighbors in the tree
+    NotNeighbors,
+    /// The commitment proof has no entry for the key
+    NoProofForKey,
+    /// A compressed proof refers to an inner operation which is not in its lookup table
+    InvalidLookup(i32),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::MissingField(field) => write!(f, "proof is missing its {field}"),
+            ProofError::UnsupportedHash(op) => write!(f, "unsupported hash op {op}"),
+            ProofError::UnsupportedLength(op) => write!(f, "unsupported length op {op}"),
+            ProofError::InvalidLength { op, length } => {
+                write!(
+                    f,
+                    "{} requires a different length than {length}",
+                    op.as_str_name()
+                )
+            }
+            ProofError::EmptyLeaf => write!(f, "existence proof has an empty key or value"),
+            ProofError::EmptyChild => write!(f, "inner op applied to an empty child"),
+            ProofError::SpecMismatch(reason) => write!(f, "proof does not match spec: {reason}"),
+            ProofError::KeyMismatch => write!(f, "proof is for a different key"),
+            ProofError::ValueMismatch => write!(f, "proof is for a different value"),
+            ProofError::RootMismatch => write!(f, "proof does not match the root"),
+            ProofError::KeyOutOfRange => {
+                write!(f, "key is not between the keys of the non-existence proof")
+            }
+            ProofError::NotNeighbors => write!(f, "left and right proofs are not neighbors"),
+            ProofError::NoProofForKey => write!(f, "commitment proof has no entry for the key"),
+            ProofError::InvalidLookup(i) => write!(f, "compressed proof has no inner op {i}"),
+        }
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+/// The spec of the IAVL trees of the Cosmos SDK stores
+pub fn iavl_spec() -> ProofSpec {
+    ProofSpec {
+        leaf_spec: Some(LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix: vec![0],
+        }),
+        inner_spec: Some(InnerSpec {
+            child_order: vec![0, 1],
+            child_size: 33,
+            min_prefix_length: 4,
+            max_prefix_length: 12,
+            empty_child: Vec::new(),
+            hash: HashOp::Sha256.into(),
+        }),
+        max_depth: 0,
+        min_depth: 0,
+    }
+}
+
+/// The spec of Tendermint's simple merkle tree, which the multistore uses to commit the roots of its stores into the
+/// app hash
+pub fn tendermint_spec() -> ProofSpec {
+    ProofSpec {
+        leaf_spec: Some(LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix: vec![0],
+        }),
+        inner_spec: Some(InnerSpec {
+            child_order: vec![0, 1],
+            child_size: 32,
+            min_prefix_length: 1,
+            max_prefix_length: 1,
+            empty_child: Vec::new(),
+            hash: HashOp::Sha256.into(),
+        }),
+        max_depth: 0,
+        min_depth: 0,
+    }
+}
+
+fn hash_op(op: i32) -> Result<HashOp, ProofError> {
+    HashOp::try_from(op).map_err(|_| ProofError::UnsupportedHash(op))
+}
+
+fn length_op(op: i32) -> Result<LengthOp, ProofError> {
+    LengthOp::try_from(op).map_err(|_| ProofError::UnsupportedLength(op))
+}
+
+fn do_hash(op: HashOp, data: &[u8]) -> Vec<u8> {
+    match op {
+        HashOp::NoHash => data.to_vec(),
+        HashOp::Sha256 => sha2::Sha256::digest(data).to_vec(),
+        HashOp::Sha512 => sha2::Sha512::digest(data).to_vec(),
+        HashOp::Keccak => sha3::Keccak256::digest(data).to_vec(),
+        HashOp::Ripemd160 => ripemd::Ripemd160::digest(data).to_vec(),
+        HashOp::Bitcoin => ripemd::Ripemd160::digest(sha2::Sha256::digest(data)).to_vec(),
+    }
+}
+
+fn do_length(op: LengthOp, data: &[u8]) -> Result<Vec<u8>, ProofError> {
+    let length = data.len();
+    let mut out = Vec::with_capacity(length + 8);
+    match op {
+        LengthOp::NoPrefix => {}
+        LengthOp::VarProto => prost::encoding::encode_varint(length as u64, &mut out),
+        LengthOp::Fixed32Big => out.extend_from_slice(&(length as u32).to_be_bytes()),
+        LengthOp::Fixed32Little => out.extend_from_slice(&(length as u32).to_le_bytes()),
+        LengthOp::Fixed64Big => out.extend_from_slice(&(length as u64).to_be_bytes()),
+        LengthOp::Fixed64Little => out.extend_from_slice(&(length as u64).to_le_bytes()),
+        LengthOp::Require32Bytes if length == 32 => {}
+        LengthOp::Require64Bytes if length == 64 => {}
+        LengthOp::Require32Bytes | LengthOp::Require64Bytes => {
+            return Err(ProofError::InvalidLength { op, length })
+        }
+        LengthOp::VarRlp => return Err(ProofError::UnsupportedLength(op as i32)),
+    }
+    out.extend_from_slice(data);
+    Ok(out)
+}
+
+fn prepare_leaf_data(prehash: i32, length: i32, data: &[u8]) -> Result<Vec<u8>, ProofError> {
+    do_length(length_op(length)?, &do_hash(hash_op(prehash)?, data))
+}
+
+/// The hash of a leaf holding the key and value
+pub fn apply_leaf(leaf: &LeafOp, key: &[u8], value: &[u8]) -> Result<Vec<u8>, ProofError> {
+    let mut data = leaf.prefix.clone();
+    data.extend(prepare_leaf_data(leaf.prehash_key, leaf.length, key)?);
+    data.extend(prepare_leaf_data(leaf.prehash_value, leaf.length, value)?);
+    Ok(do_hash(hash_op(leaf.hash)?, &data))
+}
+
+/// The hash of an inner node with the given child, hash(prefix || child || suffix)
+pub fn apply_inner(inner: &InnerOp, child: &[u8]) -> Result<Vec<u8>, ProofError> {
+    if child.is_empty() {
+        return Err(ProofError::EmptyChild);
+    }
+    let mut data = inner.prefix.clone();
+    data.extend_from_slice(child);
+    data.extend_from_slice(&inner.suffix);
+    Ok(do_hash(hash_op(inner.hash)?, &data))
+}
+
+/// The root an existence proof hashes to. This does not check the proof against a spec, use [verify_existence]
+pub fn calculate_existence_root(proof: &ExistenceProof) -> Result<Vec<u8>, ProofError> {
+    if proof.key.is_empty() || proof.value.is_empty() {
+        return Err(ProofError::EmptyLeaf);
+    }
+    let leaf = proof
+        .leaf
+        .as_ref()
+        .ok_or(ProofError::MissingField("leaf"))?;
+    proof
+        .path
+        .iter()
+        .try_fold(apply_leaf(leaf, &proof.key, &proof.value)?, |hash, step| {
+            apply_inner(step, &hash)
+        })
+}
+
+/// The root a commitment proof hashes to, for a non-existence proof this is the root of either of its neighbors and
+/// for a batch the root of its first entry. This does not check the proof against a spec
+pub fn calculate_root(proof: &CommitmentProof) -> Result<Vec<u8>, ProofError> {
+    let non_existence_root = |proof: &NonExistenceProof| match (&proof.left, &proof.right) {
+        (Some(left), _) => calculate_existence_root(left),
+        (None, Some(right)) => calculate_existence_root(right),
+        (None, None) => Err(ProofError::MissingField("left or right proof")),
+    };
+    match proof
+        .proof
+        .as_ref()
+        .ok_or(ProofError::MissingField("proof"))?
+    {
+        commitment_proof::Proof::Exist(exist) => calculate_existence_root(exist),
+        commitment_proof::Proof::Nonexist(nonexist) => non_existence_root(nonexist),
+        commitment_proof::Proof::Batch(batch) => {
+            match batch.entries.first().and_then(|e| e.proof.as_ref()) {
+                Some(batch_entry::Proof::Exist(exist)) => calculate_existence_root(exist),
+                Some(batch_entry::Proof::Nonexist(nonexist)) => non_existence_root(nonexist),
+                None => Err(ProofError::MissingField("batch entry")),
+            }
+        }
+        commitment_proof::Proof::Compressed(compressed) => calculate_root(&decompress(compressed)?),
+    }
+}
+
+fn specs(spec: &ProofSpec) -> Result<(&LeafOp, &InnerSpec), ProofError> {
+    Ok((
+        spec.leaf_spec
+            .as_ref()
+            .ok_or(ProofError::MissingField("leaf spec"))?,
+        spec.inner_spec
+            .as_ref()
+            .ok_or(ProofError::MissingField("inner spec"))?,
+    ))
+}
+
+fn check_leaf(leaf: &LeafOp, spec: &LeafOp) -> Result<(), ProofError> {
+    if leaf.hash != spec.hash {
+        return Err(ProofError::SpecMismatch("unexpected leaf hash"));
+    }
+    if leaf.prehash_key != spec.prehash_key {
+        return Err(ProofError::SpecMismatch("unexpected leaf prehash key"));
+    }
+    if leaf.prehash_value != spec.prehash_value {
+        return Err(ProofError::SpecMismatch("unexpected leaf prehash value"));
+    }
+    if leaf.length != spec.length {
+        return Err(ProofError::SpecMismatch("unexpected leaf length op"));
+    }
+    if !leaf.prefix.starts_with(&spec.prefix) {
+        return Err(ProofError::SpecMismatch("unexpected leaf prefix"));
+    }
+    Ok(())
+}
+
+fn check_inner(inner: &InnerOp, leaf_prefix: &[u8], spec: &InnerSpec) -> Result<(), ProofError> {
+    if inner.hash != spec.hash {
+        return Err(ProofError::SpecMismatch("unexpected inner hash"));
+    }
+    // an inner node which could be read as a leaf would let a prover forge leaves
+    if !leaf_prefix.is_empty() && inner.prefix.starts_with(leaf_prefix) {
+        return Err(ProofError::SpecMismatch(
+            "inner prefix starts with the leaf prefix",
+        ));
+    }
+    if inner.prefix.len() < spec.min_prefix_length as usize {
+        return Err(ProofError::SpecMismatch("inner prefix too short"));
+    }
+    let child_size = spec.child_size as usize;
+    let max_left_child_bytes = spec.child_order.len().saturating_sub(1) * child_size;
+    if inner.prefix.len() > spec.max_prefix_length as usize + max_left_child_bytes {
+        return Err(ProofError::SpecMismatch("inner prefix too long"));
+    }
+    if child_size == 0 || inner.suffix.len() % child_size != 0 {
+        return Err(ProofError::SpecMismatch(
+            "inner suffix is not a whole number of children",
+        ));
+    }
+    Ok(())
+}
+
+/// Reads a zigzag encoded varint as Go's binary.ReadVarint does, IAVL encodes node heights, sizes and versions
+/// this way
+fn read_iavl_varint(data: &mut &[u8]) -> Result<i64, ProofError> {
+    let unsigned = prost::encoding::decode_varint(data)
+        .map_err(|_| ProofError::SpecMismatch("invalid IAVL varint"))?;
+    Ok((unsigned >> 1) as i64 ^ -((unsigned & 1) as i64))
+}
+
+/// Checks the prefix of an IAVL leaf (layer 0) or inner op holds the node's height, size and version, followed by
+/// nothing for a leaf or by the length prefixed left child for an inner op, as ics23's validateIavlOps does. Without
+/// this a prover could hide extra data in the prefix and prove a different key than the one stored
+fn check_iavl_op(prefix: &[u8], hash: i32, layer: usize) -> Result<(), ProofError> {
+    let mut rest = prefix;
+    let height = read_iavl_varint(&mut rest)?;
+    let size = read_iavl_varint(&mut rest)?;
+    let version = read_iavl_varint(&mut rest)?;
+    if height < 0 || size < 0 || version < 0 {
+        return Err(ProofError::SpecMismatch(
+            "negative IAVL height, size or version",
+        ));
+    }
+    if height < layer as i64 {
+        return Err(ProofError::SpecMismatch("IAVL height is below its layer"));
+    }
+    if layer == 0 {
+        if !rest.is_empty() {
+            return Err(ProofError::SpecMismatch("IAVL leaf prefix has extra bytes"));
+        }
+        return Ok(());
+    }
+    // the length of the child hash, preceded by the left child and its length when the child is on the right
+    if rest.len() != 1 && rest.len() != 34 {
+        return Err(ProofError::SpecMismatch(
+            "IAVL inner prefix has extra bytes",
+        ));
+    }
+    if hash != HashOp::Sha256 as i32 {
+        return Err(ProofError::SpecMismatch("IAVL inner op must use sha256"));
+    }
+    Ok(())
+}
+
+fn is_iavl_spec(spec: &ProofSpec) -> bool {
+    let iavl = iavl_spec();
+    spec.leaf_spec == iavl.leaf_spec && spec.inner_spec == iavl.inner_spec
+}
+
+fn check_existence_spec(proof: &ExistenceProof, spec: &ProofSpec) -> Result<(), ProofError> {
+    let (leaf_spec, inner_spec) = specs(spec)?;
+    let leaf = proof
+        .leaf
+        .as_ref()
+        .ok_or(ProofError::MissingField("leaf"))?;
+    check_leaf(leaf, leaf_spec)?;
+    let iavl = is_iavl_spec(spec);
+    if iavl {
+        check_iavl_op(&leaf.prefix, leaf.hash, 0)?;
+    }
+    if spec.min_depth > 0 && proof.path.len() < spec.min_depth as usize {
+        return Err(ProofError::SpecMismatch("path shorter than the min depth"));
+    }
+    if spec.max_depth > 0 && proof.path.len() > spec.max_depth as usize {
+        return Err(ProofError::SpecMismatch("path longer than the max depth"));
+    }
+    for (i, step) in proof.path.iter().enumerate() {
+        check_inner(step, &leaf_spec.prefix, inner_spec)?;
+        if iavl {
+            check_iavl_op(&step.prefix, step.hash, i + 1)?;
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the existence proof proves the key holds the value in the tree with the given root
+pub fn verify_existence(
+    proof: &ExistenceProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    key: &[u8],
+    value: &[u8],
+) -> Result<(), ProofError> {
+    check_existence_spec(proof, spec)?;
+    if proof.key != key {
+        return Err(ProofError::KeyMismatch);
+    }
+    if proof.value != value {
+        return Err(ProofError::ValueMismatch);
+    }
+    if calculate_existence_root(proof)? != root {
+        return Err(ProofError::RootMismatch);
+    }
+    Ok(())
+}
+
+/// Verifies the non-existence proof proves the key is not in the tree with the given root
+pub fn verify_non_existence(
+    proof: &NonExistenceProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    key: &[u8],
+) -> Result<(), ProofError> {
+    let (_, inner_spec) = specs(spec)?;
+    if let Some(left) = &proof.left {
+        verify_existence(left, spec, root, &left.key, &left.value)?;
+        if key <= left.key.as_slice() {
+            return Err(ProofError::KeyOutOfRange);
+        }
+    }
+    if let Some(right) = &proof.right {
+        verify_existence(right, spec, root, &right.key, &right.value)?;
+        if key >= right.key.as_slice() {
+            return Err(ProofError::KeyOutOfRange);
+        }
+    }
+    let neighbors = match (&proof.left, &proof.right) {
+        (None, None) => return Err(ProofError::MissingField("left or right proof")),
+        (None, Some(right)) => is_left_most(inner_spec, &right.path),
+        (Some(left), None) => is_right_most(inner_spec, &left.path),
+        (Some(left), Some(right)) => is_left_neighbor(inner_spec, &left.path, &right.path),
+    };
+    if !neighbors {
+        return Err(ProofError::NotNeighbors);
+    }
+    Ok(())
+}
+
+/// The position of the branch in the child order
+fn position(order: &[i32], branch: usize) -> Option<usize> {
+    order.iter().position(|&b| b as usize == branch)
+}
+
+/// The (min prefix, max prefix, suffix) lengths of an inner op whose child is the given branch
+fn padding(spec: &InnerSpec, branch: usize) -> Option<(usize, usize, usize)> {
+    let index = position(&spec.child_order, branch)?;
+    let child_size = spec.child_size as usize;
+    let prefix = index * child_size;
+    Some((
+        prefix + spec.min_prefix_length as usize,
+        prefix + spec.max_prefix_length as usize,
+        (spec.child_order.len() - 1 - index) * child_size,
+    ))
+}
+
+fn has_padding(inner: &InnerOp, (min_prefix, max_prefix, suffix): (usize, usize, usize)) -> bool {
+    (min_prefix..=max_prefix).contains(&inner.prefix.len()) && inner.suffix.len() == suffix
+}
+
+/// The branch an inner op's child is on, found from the lengths of its prefix and suffix
+fn order_from_padding(spec: &InnerSpec, inner: &InnerOp) -> Option<usize> {
+    (0..spec.child_order.len())
+        .find(|&branch| padding(spec, branch).is_some_and(|p| has_padding(inner, p)))
+}
+
+/// If every branch to the left of the inner op's child is an empty subtree
+fn left_branches_are_empty(spec: &InnerSpec, inner: &InnerOp) -> bool {
+    let child_size = spec.child_size as usize;
+    let left_branches = match order_from_padding(spec, inner) {
+        Some(0) | None => return false,
+        Some(index) => index,
+    };
+    let Some(actual_prefix) = inner.prefix.len().checked_sub(left_branches * child_size) else {
+        return false;
+    };
+    (0..left_branches).all(|branch| {
+        position(&spec.child_order, branch).is_some_and(|index| {
+            let from = actual_prefix + index * child_size;
+            inner.prefix.get(from..from + child_size) == Some(spec.empty_child.as_slice())
+        })
+    })
+}
+
+/// If every branch to the right of the inner op's child is an empty subtree
+fn right_branches_are_empty(spec: &InnerSpec, inner: &InnerOp) -> bool {
+    let child_size = spec.child_size as usize;
+    let right_branches = match order_from_padding(spec, inner) {
+        Some(index) if index + 1 < spec.child_order.len() => spec.child_order.len() - 1 - index,
+        _ => return false,
+    };
+    if inner.suffix.len() != right_branches * child_size {
+        return false;
+    }
+    (0..right_branches).all(|branch| {
+        position(&spec.child_order, branch).is_some_and(|index| {
+            let from = index * child_size;
+            inner.suffix.get(from..from + child_size) == Some(spec.empty_child.as_slice())
+        })
+    })
+}
+
+/// If the path is the leftmost path of the tree
+fn is_left_most(spec: &InnerSpec, path: &[InnerOp]) -> bool {
+    let Some(pad) = padding(spec, 0) else {
+        return false;
+    };
+    path.iter()
+        .all(|step| has_padding(step, pad) || left_branches_are_empty(spec, step))
+}
+
+/// If the path is the rightmost path of the tree
+fn is_right_most(spec: &InnerSpec, path: &[InnerOp]) -> bool {
+    let Some(pad) = padding(spec, spec.child_order.len().saturating_sub(1)) else {
+        return false;
+    };
+    path.iter()
+        .all(|step| has_padding(step, pad) || right_branches_are_empty(spec, step))
+}
+
+/// If the right inner op's child is the branch immediately to the right of the left inner op's child
+fn is_left_step(spec: &InnerSpec, left: &InnerOp, right: &InnerOp) -> bool {
+    match (
+        order_from_padding(spec, left),
+        order_from_padding(spec, right),
+    ) {
+        (Some(left), Some(right)) => left + 1 == right,
+        _ => false,
+    }
+}
+
+/// If the two paths lead to adjacent leaves. The paths share their top steps down to the node where they split, there
+/// the left path must take the branch before the right one, and below it the left path must be rightmost and the
+/// right path leftmost
+fn is_left_neighbor(spec: &InnerSpec, left: &[InnerOp], right: &[InnerOp]) -> bool {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    let mut top_left = left.pop();
+    let mut top_right = right.pop();
+    while let (Some(l), Some(r)) = (&top_left, &top_right) {
+        if l.prefix != r.prefix || l.suffix != r.suffix {
+            break;
+        }
+        top_left = left.pop();
+        top_right = right.pop();
+    }
+    match (top_left, top_right) {
+        (Some(l), Some(r)) => {
+            is_left_step(spec, &l, &r) && is_right_most(spec, &left) && is_left_most(spec, &right)
+        }
+        _ => false,
+    }
+}
+
+fn decompress_existence(
+    proof: &CompressedExistenceProof,
+    lookup: &[InnerOp],
+) -> Result<ExistenceProof, ProofError> {
+    Ok(ExistenceProof {
+        key: proof.key.clone(),
+        value: proof.value.clone(),
+        leaf: proof.leaf.clone(),
+        path: proof
+            .path
+            .iter()
+            .map(|&i| {
+                usize::try_from(i)
+                    .ok()
+                    .and_then(|i| lookup.get(i))
+                    .cloned()
+                    .ok_or(ProofError::InvalidLookup(i))
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn decompress_non_existence(
+    proof: &CompressedNonExistenceProof,
+    lookup: &[InnerOp],
+) -> Result<NonExistenceProof, ProofError> {
+    let side = |p: &Option<CompressedExistenceProof>| {
+        p.as_ref()
+            .map(|p| decompress_existence(p, lookup))
+            .transpose()
+    };
+    Ok(NonExistenceProof {
+        key: proof.key.clone(),
+        left: side(&proof.left)?,
+        right: side(&proof.right)?,
+    })
+}
+
+/// Expands a compressed batch proof, whose inner ops are indexes into a shared lookup table, into a batch proof
+pub fn decompress(proof: &CompressedBatchProof) -> Result<CommitmentProof, ProofError> {
+    let entries = proof
+        .entries
+        .iter()
+        .map(|entry| {
+            let proof = match entry.proof.as_ref() {
+                Some(compressed_batch_entry::Proof::Exist(exist)) => Some(
+                    batch_entry::Proof::Exist(decompress_existence(exist, &proof.lookup_inners)?),
+                ),
+                Some(compressed_batch_entry::Proof::Nonexist(nonexist)) => {
+                    Some(batch_entry::Proof::Nonexist(decompress_non_existence(
+                        nonexist,
+                        &proof.lookup_inners,
+                    )?))
+                }
+                None => None,
+            };
+            Ok(BatchEntry { proof })
+        })
+        .collect::<Result<_, ProofError>>()?;
+    Ok(CommitmentProof {
+        proof: Some(commitment_proof::Proof::Batch(BatchProof { entries })),
+    })
+}
+
+/// Decompresses the proof if it is compressed, otherwise returns it unchanged
+fn decompressed(
+    proof: &CommitmentProof,
+) -> Result<std::borrow::Cow<'_, CommitmentProof>, ProofError> {
+    match &proof.proof {
+        Some(commitment_proof::Proof::Compressed(compressed)) => {
+            Ok(std::borrow::Cow::Owned(decompress(compressed)?))
+        }
+        _ => Ok(std::borrow::Cow::Borrowed(proof)),
+    }
+}
+
+fn existence_proof_for<'a>(proof: &'a CommitmentProof, key: &[u8]) -> Option<&'a ExistenceProof> {
+    match proof.proof.as_ref()? {
+        commitment_proof::Proof::Exist(exist) if exist.key == key => Some(exist),
+        commitment_proof::Proof::Batch(batch) => batch.entries.iter().find_map(|entry| match entry
+            .proof
+            .as_ref()?
+        {
+            batch_entry::Proof::Exist(exist) if exist.key == key => Some(exist),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+fn non_existence_proof_for<'a>(
+    proof: &'a CommitmentProof,
+    key: &[u8],
+) -> Option<&'a NonExistenceProof> {
+    let in_range = |nonexist: &NonExistenceProof| {
+        nonexist
+            .left
+            .as_ref()
+            .map_or(true, |l| l.key.as_slice() < key)
+            && nonexist
+                .right
+                .as_ref()
+                .map_or(true, |r| r.key.as_slice() > key)
+    };
+    match proof.proof.as_ref()? {
+        commitment_proof::Proof::Nonexist(nonexist) => Some(nonexist),
+        commitment_proof::Proof::Batch(batch) => batch.entries.iter().find_map(|entry| match entry
+            .proof
+            .as_ref()?
+        {
+            batch_entry::Proof::Nonexist(nonexist) if in_range(nonexist) => Some(nonexist),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Verifies the commitment proof proves the key holds the value in the tree with the given root. The proof may be a
+/// single existence proof or a batch, compressed or not, with an entry for the key
+pub fn verify_membership(
+    proof: &CommitmentProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    key: &[u8],
+    value: &[u8],
+) -> Result<(), ProofError> {
+    let proof = decompressed(proof)?;
+    let exist = existence_proof_for(&proof, key).ok_or(ProofError::NoProofForKey)?;
+    verify_existence(exist, spec, root, key, value)
+}
+
+/// Verifies the commitment proof proves the key is not in the tree with the given root. The proof may be a single
+/// non-existence proof or a batch, compressed or not, with an entry covering the key
+pub fn verify_non_membership(
+    proof: &CommitmentProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    key: &[u8],
+) -> Result<(), ProofError> {
+    let proof = decompressed(proof)?;
+    let nonexist = non_existence_proof_for(&proof, key).ok_or(ProofError::NoProofForKey)?;
+    verify_non_existence(nonexist, spec, root, key)
+}
+
+/// Verifies a batch proof proves each key holds its value, the proof is only decompressed once
+pub fn verify_batch_membership<'a>(
+    proof: &CommitmentProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    items: impl IntoIterator<Item = (&'a [u8], &'a [u8])>,
+) -> Result<(), ProofError> {
+    let proof = decompressed(proof)?;
+    items
+        .into_iter()
+        .try_for_each(|(key, value)| verify_membership(&proof, spec, root, key, value))
+}
+
+/// Verifies a batch proof proves none of the keys are in the tree, the proof is only decompressed once
+pub fn verify_batch_non_membership<'a>(
+    proof: &CommitmentProof,
+    spec: &ProofSpec,
+    root: &[u8],
+    keys: impl IntoIterator<Item = &'a [u8]>,
+) -> Result<(), ProofError> {
+    let proof = decompressed(proof)?;
+    keys.into_iter()
+        .try_for_each(|key| verify_non_membership(&proof, spec, root, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics23::CompressedBatchEntry;
+
+    /// A leaf of a test tree and the path from it to the root: (key, value, leaf prefix, [(inner prefix, suffix)]).
+    /// The values of the Tendermint tree are hex encoded store roots, and its leaves have only the spec's prefix
+    type Fixture = (
+        &'static str,
+        &'static str,
+        &'static str,
+        &'static [(&'static str, &'static str)],
+    );
+
+    /// An IAVL tree of seven balances, built as IAVL hashes its nodes with versions 1 to 3
+    const IAVL_ROOT: &str = "eda1ffd3c75218a0443ea92ef65002e4d9ce7772ee4a92eaf2a124ae5aa3c987";
+    const IAVL_LEAVES: &[Fixture] = &[
+        (
+            "alice",
+            "100uatom",
+            "000202",
+            &[
+                (
+                    "02040420",
+                    "20365a3d7c85cd4579ac42a68d349e897c869d28ade09918226f2ec5abcf2af823",
+                ),
+                (
+                    "04080620",
+                    "20a7376a89fb73b339233931b00082627082f07b51461d38c02979b2cd566d4cef",
+                ),
+                (
+                    "060e0620",
+                    "2025bc146095e6df84c63ec60563b05ff1d9f0797dafdf2e677b42ba1925ab0816",
+                ),
+            ],
+        ),
+        (
+            "bob",
+            "250uatom",
+            "000204",
+            &[
+                (
+                    "020404207a659476b667b5adae264e6769f29df604f89f384070bdf44a44bbfeadf2c38220",
+                    "",
+                ),
+                (
+                    "04080620",
+                    "20a7376a89fb73b339233931b00082627082f07b51461d38c02979b2cd566d4cef",
+                ),
+                (
+                    "060e0620",
+                    "2025bc146095e6df84c63ec60563b05ff1d9f0797dafdf2e677b42ba1925ab0816",
+                ),
+            ],
+        ),
+        (
+            "carol",
+            "7uatom",
+            "000206",
+            &[
+                (
+                    "02040620",
+                    "201f2111f3fc7a004c6bd2d9cbaeed5d65a4f6fedfc9a33b9c29751baf511ef53b",
+                ),
+                (
+                    "040806201cc803754f3faf674f367b8026dadb3cf664c5020a402f19f9a457a601a962c520",
+                    "",
+                ),
+                (
+                    "060e0620",
+                    "2025bc146095e6df84c63ec60563b05ff1d9f0797dafdf2e677b42ba1925ab0816",
+                ),
+            ],
+        ),
+        (
+            "dave",
+            "42uatom",
+            "000202",
+            &[
+                (
+                    "02040620ff4462f8f97f98f5ce17f271aa0a9e74fde24c57d895a47ff02d7d1cb173e0a020",
+                    "",
+                ),
+                (
+                    "040806201cc803754f3faf674f367b8026dadb3cf664c5020a402f19f9a457a601a962c520",
+                    "",
+                ),
+                (
+                    "060e0620",
+                    "2025bc146095e6df84c63ec60563b05ff1d9f0797dafdf2e677b42ba1925ab0816",
+                ),
+            ],
+        ),
+        (
+            "erin",
+            "1uatom",
+            "000204",
+            &[
+                (
+                    "02040620",
+                    "20cb6ee620e91f2bf8d689ec75db3de0ca4599e0c1a83bf361faf6c2c6a253f807",
+                ),
+                (
+                    "04060620",
+                    "2039ae286a6fd6f86088399151ab05f328276995620d2624d8adae8db5e6e6f913",
+                ),
+                (
+                    "060e0620ee2d9e89ac1c3c06b1f03783e933bfd6b0aabc2c5e195b1bf150f1327183691120",
+                    "",
+                ),
+            ],
+        ),
+        (
+            "frank",
+            "999uatom",
+            "000206",
+            &[
+                (
+                    "020406208dabffcca8d549af058569769395564cb8408166cd523f7507d0e758f192a23020",
+                    "",
+                ),
+                (
+                    "04060620",
+                    "2039ae286a6fd6f86088399151ab05f328276995620d2624d8adae8db5e6e6f913",
+                ),
+                (
+                    "060e0620ee2d9e89ac1c3c06b1f03783e933bfd6b0aabc2c5e195b1bf150f1327183691120",
+                    "",
+                ),
+            ],
+        ),
+        (
+            "grace",
+            "3uatom",
+            "000202",
+            &[
+                (
+                    "040606207a10f4bcc2f95c7876a76da459213b6d19ca5888c20eb2911690e594f116758c20",
+                    "",
+                ),
+                (
+                    "060e0620ee2d9e89ac1c3c06b1f03783e933bfd6b0aabc2c5e195b1bf150f1327183691120",
+                    "",
+                ),
+            ],
+        ),
+    ];
+
+    /// A multistore app hash committing to seven store roots
+    const TENDERMINT_ROOT: &str =
+        "864bca867cb5138107a34cccced7a99046404d47ca77f09ce8c60660da24c21d";
+    const TENDERMINT_LEAVES: &[Fixture] = &[
+        (
+            "acc",
+            "22855cb4b3a2bf543cbcdd8fa42024d8d15cade073218b053d4e55cb380bea43",
+            "",
+            &[
+                (
+                    "01",
+                    "3a8d163f6b5f5a375f5594ff3320b15996ef8f76d7e1c1693489fa7a42802e5f",
+                ),
+                (
+                    "01",
+                    "75b6922b43ff045b5738698c9b247f770065f8813fd052bbf98aead0b84098dd",
+                ),
+                (
+                    "01",
+                    "4450903518c9693298134f33ef601b88b10378e241fa5a3a66544b9cc3671938",
+                ),
+            ],
+        ),
+        (
+            "bank",
+            "d5b32af3876975f15c14159e4965b5eb6f6fb665c513b10b5da112d9583b9a3d",
+            "",
+            &[
+                (
+                    "0154af7b42696a34692ec60aa36c6ceb52e27eece335c2329c23d92afa4c5d55a4",
+                    "",
+                ),
+                (
+                    "01",
+                    "75b6922b43ff045b5738698c9b247f770065f8813fd052bbf98aead0b84098dd",
+                ),
+                (
+                    "01",
+                    "4450903518c9693298134f33ef601b88b10378e241fa5a3a66544b9cc3671938",
+                ),
+            ],
+        ),
+        (
+            "distribution",
+            "7ec07b850a19f5f1b4e172095665e00ade806b50b4f4714ea87fcf36e47f9a01",
+            "",
+            &[
+                (
+                    "01",
+                    "e8b10b5719775e12d09b85e04cfc0fa023c94f6fae9886e805119971d44baaa2",
+                ),
+                (
+                    "018207da1976ce2e4c0ea6cf2c3b8d149f06cfac380d1d83191cf573fcf3373d30",
+                    "",
+                ),
+                (
+                    "01",
+                    "4450903518c9693298134f33ef601b88b10378e241fa5a3a66544b9cc3671938",
+                ),
+            ],
+        ),
+        (
+            "gov",
+            "5164c358a448f50e17a322ae9e35748842bf8924cc2cc6b34c4ee75d9ff232ad",
+            "",
+            &[
+                (
+                    "01fca6c843f5a40e0089d35502dcaa96795afe682b5ea65f4f9f4db67f3b8e1bf8",
+                    "",
+                ),
+                (
+                    "018207da1976ce2e4c0ea6cf2c3b8d149f06cfac380d1d83191cf573fcf3373d30",
+                    "",
+                ),
+                (
+                    "01",
+                    "4450903518c9693298134f33ef601b88b10378e241fa5a3a66544b9cc3671938",
+                ),
+            ],
+        ),
+        (
+            "ibc",
+            "9c55be8540b9c79c5ec2699556cbdcf6411984bd8b13311f08377492ad3a848e",
+            "",
+            &[
+                (
+                    "01",
+                    "33dbfdf2db0d1757feb2aa1bf155c07254f5b5280cba75e6b58b12b4ffa87115",
+                ),
+                (
+                    "01",
+                    "b12633a0d376702b0dac5f498b8123f1f7b488b577898a1eea405cea411ed521",
+                ),
+                (
+                    "01d3bca153bc29fd409b206bf249a3730b562c22e93e477981f66ffd5bed0d93a3",
+                    "",
+                ),
+            ],
+        ),
+        (
+            "staking",
+            "abbd8635301c58623a16bff1461c398935e4ad61b2b6e16b0bf176d2342f79f8",
+            "",
+            &[
+                (
+                    "01009c196ed00269a4937359fbbfed880a512218fd843da5ab3308ac8b674d24e9",
+                    "",
+                ),
+                (
+                    "01",
+                    "b12633a0d376702b0dac5f498b8123f1f7b488b577898a1eea405cea411ed521",
+                ),
+                (
+                    "01d3bca153bc29fd409b206bf249a3730b562c22e93e477981f66ffd5bed0d93a3",
+                    "",
+                ),
+            ],
+        ),
+        (
+            "upgrade",
+            "4259ebb5bcd7be6db217ac864d775ce55d27a162fcc925593bc8e29008e6dc8a",
+            "",
+            &[
+                (
+                    "01067f21d0237da447039d75bd255190cc56674800c42c57d3bf54b820130c30ed",
+                    "",
+                ),
+                (
+                    "01d3bca153bc29fd409b206bf249a3730b562c22e93e477981f66ffd5bed0d93a3",
+                    "",
+                ),
+            ],
+        ),
+    ];
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn existence_proof(
+        key: &[u8],
+        value: Vec<u8>,
+        leaf: LeafOp,
+        path: &[(&str, &str)],
+    ) -> ExistenceProof {
+        ExistenceProof {
+            key: key.to_vec(),
+            value,
+            leaf: Some(leaf),
+            path: path
+                .iter()
+                .map(|(prefix, suffix)| InnerOp {
+                    hash: HashOp::Sha256.into(),
+                    prefix: hex(prefix),
+                    suffix: hex(suffix),
+                })
+                .collect(),
+        }
+    }
+
+    fn iavl_proof(key: &str) -> ExistenceProof {
+        let (key, value, prefix, path) = IAVL_LEAVES.iter().find(|l| l.0 == key).unwrap();
+        let leaf = LeafOp {
+            prefix: hex(prefix),
+            ..iavl_spec().leaf_spec.unwrap()
+        };
+        existence_proof(key.as_bytes(), value.as_bytes().to_vec(), leaf, path)
+    }
+
+    fn tendermint_proof(key: &str) -> ExistenceProof {
+        let (key, value, _, path) = TENDERMINT_LEAVES.iter().find(|l| l.0 == key).unwrap();
+        let leaf = tendermint_spec().leaf_spec.unwrap();
+        existence_proof(key.as_bytes(), hex(value), leaf, path)
+    }
+
+    fn non_existence_proof(
+        key: &str,
+        left: Option<&str>,
+        right: Option<&str>,
+    ) -> NonExistenceProof {
+        NonExistenceProof {
+            key: key.as_bytes().to_vec(),
+            left: left.map(iavl_proof),
+            right: right.map(iavl_proof),
+        }
+    }
+
+    fn exist(proof: ExistenceProof) -> CommitmentProof {
+        CommitmentProof {
+            proof: Some(commitment_proof::Proof::Exist(proof)),
+        }
+    }
+
+    fn nonexist(proof: NonExistenceProof) -> CommitmentProof {
+        CommitmentProof {
+            proof: Some(commitment_proof::Proof::Nonexist(proof)),
+        }
+    }
+
+    fn batch() -> BatchProof {
+        let entries = [
+            batch_entry::Proof::Exist(iavl_proof("alice")),
+            batch_entry::Proof::Exist(iavl_proof("dave")),
+            batch_entry::Proof::Nonexist(non_existence_proof("bobby", Some("bob"), Some("carol"))),
+            batch_entry::Proof::Nonexist(non_existence_proof("zed", Some("grace"), None)),
+        ];
+        BatchProof {
+            entries: entries
+                .into_iter()
+                .map(|proof| BatchEntry { proof: Some(proof) })
+                .collect(),
+        }
+    }
+
+    /// Compresses a batch as ics23's Compress does, every distinct inner op is stored once in the lookup table
+    fn compress(batch: &BatchProof) -> CompressedBatchProof {
+        let mut lookup_inners: Vec<InnerOp> = Vec::new();
+        let mut compress_existence = |proof: &ExistenceProof| CompressedExistenceProof {
+            key: proof.key.clone(),
+            value: proof.value.clone(),
+            leaf: proof.leaf.clone(),
+            path: proof
+                .path
+                .iter()
+                .map(|op| match lookup_inners.iter().position(|o| o == op) {
+                    Some(i) => i as i32,
+                    None => {
+                        lookup_inners.push(op.clone());
+                        lookup_inners.len() as i32 - 1
+                    }
+                })
+                .collect(),
+        };
+        let entries = batch
+            .entries
+            .iter()
+            .map(|entry| {
+                let proof = match entry.proof.as_ref().unwrap() {
+                    batch_entry::Proof::Exist(exist) => {
+                        compressed_batch_entry::Proof::Exist(compress_existence(exist))
+                    }
+                    batch_entry::Proof::Nonexist(nonexist) => {
+                        compressed_batch_entry::Proof::Nonexist(CompressedNonExistenceProof {
+                            key: nonexist.key.clone(),
+                            left: nonexist.left.as_ref().map(&mut compress_existence),
+                            right: nonexist.right.as_ref().map(&mut compress_existence),
+                        })
+                    }
+                };
+                CompressedBatchEntry { proof: Some(proof) }
+            })
+            .collect();
+        CompressedBatchProof {
+            entries,
+            lookup_inners,
+        }
+    }
+
+    #[test]
+    fn iavl_existence() {
+        let root = hex(IAVL_ROOT);
+        for (key, value, _, _) in IAVL_LEAVES {
+            let proof = exist(iavl_proof(key));
+            assert_eq!(calculate_root(&proof).unwrap(), root);
+            verify_membership(
+                &proof,
+                &iavl_spec(),
+                &root,
+                key.as_bytes(),
+                value.as_bytes(),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn iavl_non_existence() {
+        let root = hex(IAVL_ROOT);
+        let cases = [
+            ("aaron", None, Some("alice")),
+            ("bobby", Some("bob"), Some("carol")),
+            // the neighbors are in different subtrees of the root
+            ("dexter", Some("dave"), Some("erin")),
+            ("zed", Some("grace"), None),
+        ];
+        for (key, left, right) in cases {
+            let proof = nonexist(non_existence_proof(key, left, right));
+            verify_non_membership(&proof, &iavl_spec(), &root, key.as_bytes()).unwrap();
+        }
+    }
+
+    #[test]
+    fn iavl_batch() {
+        let root = hex(IAVL_ROOT);
+        let spec = iavl_spec();
+        let batch = batch();
+        let proofs = [
+            commitment_proof::Proof::Compressed(compress(&batch)),
+            commitment_proof::Proof::Batch(batch),
+        ];
+        for proof in proofs {
+            let proof = CommitmentProof { proof: Some(proof) };
+            assert_eq!(calculate_root(&proof).unwrap(), root);
+            let items = [
+                (b"alice".as_slice(), b"100uatom".as_slice()),
+                (b"dave".as_slice(), b"42uatom".as_slice()),
+            ];
+            verify_batch_membership(&proof, &spec, &root, items).unwrap();
+            let keys = [b"bobby".as_slice(), b"zed".as_slice()];
+            verify_batch_non_membership(&proof, &spec, &root, keys).unwrap();
+            assert_eq!(
+                verify_membership(&proof, &spec, &root, b"carol", b"7uatom"),
+                Err(ProofError::NoProofForKey)
+            );
+            assert_eq!(
+                verify_non_membership(&proof, &spec, &root, b"dexter"),
+                Err(ProofError::NoProofForKey)
+            );
+        }
+    }
+
+    #[test]
+    fn compressed_lookup_out_of_range() {
+        let mut compressed = compress(&batch());
+        compressed.lookup_inners.pop();
+        let missing = compressed.lookup_inners.len() as i32;
+        assert_eq!(
+            decompress(&compressed),
+            Err(ProofError::InvalidLookup(missing))
+        );
+    }
+
+    #[test]
+    fn tendermint_existence_and_non_existence() {
+        let root = hex(TENDERMINT_ROOT);
+        let spec = tendermint_spec();
+        for (key, value, _, _) in TENDERMINT_LEAVES {
+            let proof = exist(tendermint_proof(key));
+            assert_eq!(calculate_root(&proof).unwrap(), root);
+            verify_membership(&proof, &spec, &root, key.as_bytes(), &hex(value)).unwrap();
+        }
+        let cases = [
+            ("evidence", Some("distribution"), Some("gov")),
+            ("wasm", Some("upgrade"), None),
+        ];
+        for (key, left, right) in cases {
+            let proof = nonexist(NonExistenceProof {
+                key: key.as_bytes().to_vec(),
+                left: left.map(tendermint_proof),
+                right: right.map(tendermint_proof),
+            });
+            verify_non_membership(&proof, &spec, &root, key.as_bytes()).unwrap();
+        }
+    }
+
+    #[test]
+    fn proofs_are_checked_against_their_spec() {
+        let proof = tendermint_proof("bank");
+        let root = hex(TENDERMINT_ROOT);
+        assert!(matches!(
+            verify_existence(&proof, &iavl_spec(), &root, &proof.key, &proof.value),
+            Err(ProofError::SpecMismatch(_))
+        ));
+        let proof = iavl_proof("bob");
+        let root = hex(IAVL_ROOT);
+        assert!(matches!(
+            verify_existence(&proof, &tendermint_spec(), &root, &proof.key, &proof.value),
+            Err(ProofError::SpecMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn tampered_existence_proofs_are_rejected() {
+        let root = hex(IAVL_ROOT);
+        let spec = iavl_spec();
+        let verify = |proof: &ExistenceProof| {
+            verify_existence(proof, &spec, &root, &proof.key, &proof.value)
+        };
+        let tampered = |tamper: fn(&mut ExistenceProof)| {
+            let mut proof = iavl_proof("carol");
+            tamper(&mut proof);
+            verify(&proof)
+        };
+
+        assert_eq!(
+            verify_existence(&iavl_proof("carol"), &spec, &root, b"carol", b"8uatom"),
+            Err(ProofError::ValueMismatch)
+        );
+        assert_eq!(
+            verify_existence(&iavl_proof("carol"), &spec, &root, b"bob", b"7uatom"),
+            Err(ProofError::KeyMismatch)
+        );
+        assert_eq!(
+            tampered(|p| p.value = b"8uatom".to_vec()),
+            Err(ProofError::RootMismatch)
+        );
+        assert_eq!(
+            tampered(|p| p.path[0].suffix[1] ^= 1),
+            Err(ProofError::RootMismatch)
+        );
+        assert_eq!(
+            tampered(|p| {
+                p.path.pop();
+            }),
+            Err(ProofError::RootMismatch)
+        );
+
+        // a version zero leaf is well formed, as it is in ics23, it just hashes to a different root
+        assert_eq!(
+            tampered(|p| p.leaf.as_mut().unwrap().prefix = hex("000200")),
+            Err(ProofError::RootMismatch)
+        );
+
+        let spec_mismatches: [fn(&mut ExistenceProof); 4] = [
+            // data hidden after the height, size and version of the leaf
+            |p| p.leaf.as_mut().unwrap().prefix.push(0),
+            // an inner node with a height below its layer
+            |p| p.path[1].prefix[0] = 0x02,
+            // data hidden between the node's version and the length of the child
+            |p| p.path[0].prefix.insert(3, 0),
+            // an inner op which does not use sha256
+            |p| p.path[0].hash = HashOp::Sha512.into(),
+        ];
+        for tamper in spec_mismatches {
+            assert!(matches!(tampered(tamper), Err(ProofError::SpecMismatch(_))));
+        }
+    }
+
+    #[test]
+    fn invalid_non_existence_proofs_are_rejected() {
+        let root = hex(IAVL_ROOT);
+        let spec = iavl_spec();
+        let cases = [
+            // bob is between the proven keys
+            (
+                non_existence_proof("bobby", Some("alice"), Some("carol")),
+                ProofError::NotNeighbors,
+            ),
+            // alice is not the right most key
+            (
+                non_existence_proof("zed", Some("alice"), None),
+                ProofError::NotNeighbors,
+            ),
+            // grace is not the left most key
+            (
+                non_existence_proof("aaron", None, Some("grace")),
+                ProofError::NotNeighbors,
+            ),
+            (
+                non_existence_proof("carol", Some("bob"), Some("carol")),
+                ProofError::KeyOutOfRange,
+            ),
+            (
+                non_existence_proof("bob", Some("bob"), Some("carol")),
+                ProofError::KeyOutOfRange,
+            ),
+        ];
+        for (proof, error) in cases {
+            assert_eq!(
+                verify_non_existence(&proof, &spec, &root, &proof.key),
+                Err(error)
+            );
+        }
+
+        let mut proof = non_existence_proof("bobby", Some("bob"), Some("carol"));
+        proof.right.as_mut().unwrap().value = b"8uatom".to_vec();
+        assert_eq!(
+            verify_non_existence(&proof, &spec, &root, b"bobby"),
+            Err(ProofError::RootMismatch)
+        );
+    }
+}
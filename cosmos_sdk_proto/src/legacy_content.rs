@@ -0,0 +1,149 @@
+/// Helpers for submitting v1beta1 Content proposals (TextProposal, ParameterChangeProposal, SoftwareUpgradeProposal,
+/// or chain specific proposals like the Althea nativedex proposals) through the v1 governance API.
+/// The v1 gov module executes the wrapped Content using the legacy proposal router, so the behavior on chain is
+/// identical to a v1beta1 MsgSubmitProposal.
+impl MsgExecLegacyContent {
+    /// Wraps any v1beta1 Content proposal into a MsgExecLegacyContent, authority must be the bech32 address
+    /// of the gov module account on the target chain
+    pub fn new<C: ::prost::Name>(
+        content: &C,
+        authority: String,
+    ) -> Result<Self, ::prost::EncodeError> {
+        Ok(MsgExecLegacyContent {
+            content: Some(::prost_types::Any::from_msg(content)?),
+            authority,
+        })
+    }
+
+    /// Decodes the wrapped Content as the given proposal type, returns None if there is no content or if the content
+    /// is of a different type
+    pub fn decode_content<C: ::prost::Name + Default>(
+        &self,
+    ) -> Result<Option<C>, ::prost::DecodeError> {
+        match &self.content {
+            Some(content) if content.type_url == C::type_url() => content.to_msg().map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl MsgSubmitProposal {
+    /// Creates a v1 MsgSubmitProposal which executes the given MsgExecLegacyContent messages
+    pub fn from_legacy_content(
+        contents: &[MsgExecLegacyContent],
+        initial_deposit: Vec<super::super::base::v1beta1::Coin>,
+        proposer: String,
+        metadata: String,
+    ) -> Result<Self, ::prost::EncodeError> {
+        let messages = contents
+            .iter()
+            .map(::prost_types::Any::from_msg)
+            .collect::<Result<_, _>>()?;
+        Ok(MsgSubmitProposal {
+            messages,
+            initial_deposit,
+            proposer,
+            metadata,
+        })
+    }
+
+    /// Converts a v1beta1 MsgSubmitProposal into the equivalent v1 MsgSubmitProposal, wrapping its Content
+    /// in a MsgExecLegacyContent. A v1beta1 proposal with no content becomes a v1 proposal with no messages
+    pub fn from_v1beta1(
+        proposal: super::v1beta1::MsgSubmitProposal,
+        authority: String,
+        metadata: String,
+    ) -> Result<Self, ::prost::EncodeError> {
+        let contents: Vec<MsgExecLegacyContent> = proposal
+            .content
+            .into_iter()
+            .map(|content| MsgExecLegacyContent {
+                content: Some(content),
+                authority: authority.clone(),
+            })
+            .collect();
+        Self::from_legacy_content(
+            &contents,
+            proposal.initial_deposit,
+            proposal.proposer,
+            metadata,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::base::v1beta1::Coin;
+    use crate::cosmos::gov::v1beta1::TextProposal;
+    use crate::cosmos::params::v1beta1::{ParamChange, ParameterChangeProposal};
+
+    const AUTHORITY: &str = "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn";
+
+    fn text_proposal() -> TextProposal {
+        TextProposal {
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+        }
+    }
+
+    #[test]
+    fn content_round_trips_through_any() {
+        let msg = MsgExecLegacyContent::new(&text_proposal(), AUTHORITY.to_string()).unwrap();
+        let content = msg.content.as_ref().unwrap();
+        assert_eq!(content.type_url, "/cosmos.gov.v1beta1.TextProposal");
+        assert_eq!(msg.authority, AUTHORITY);
+        assert_eq!(
+            msg.decode_content::<TextProposal>(),
+            Ok(Some(text_proposal()))
+        );
+        assert_eq!(msg.decode_content::<ParameterChangeProposal>(), Ok(None));
+
+        let empty = MsgExecLegacyContent {
+            content: None,
+            authority: AUTHORITY.to_string(),
+        };
+        assert_eq!(empty.decode_content::<TextProposal>(), Ok(None));
+    }
+
+    #[test]
+    fn v1beta1_proposal_conversion() {
+        let params = ParameterChangeProposal {
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+            changes: vec![ParamChange {
+                subspace: "staking".to_string(),
+                key: "MaxValidators".to_string(),
+                value: "100".to_string(),
+            }],
+        };
+        let deposit = vec![Coin {
+            denom: "uatom".to_string(),
+            amount: "1000".to_string(),
+        }];
+        let v1beta1 = crate::cosmos::gov::v1beta1::MsgSubmitProposal {
+            content: Some(::prost_types::Any::from_msg(&params).unwrap()),
+            initial_deposit: deposit.clone(),
+            proposer: "cosmos1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3pahzj0".to_string(),
+        };
+        let v1 =
+            MsgSubmitProposal::from_v1beta1(v1beta1, AUTHORITY.to_string(), String::new()).unwrap();
+        assert_eq!(v1.initial_deposit, deposit);
+        assert_eq!(v1.messages.len(), 1);
+        assert_eq!(
+            v1.messages[0].type_url,
+            "/cosmos.gov.v1.MsgExecLegacyContent"
+        );
+        let exec: MsgExecLegacyContent = v1.messages[0].to_msg().unwrap();
+        assert_eq!(exec.authority, AUTHORITY);
+        assert_eq!(
+            exec.decode_content::<ParameterChangeProposal>(),
+            Ok(Some(params))
+        );
+
+        let no_content = crate::cosmos::gov::v1beta1::MsgSubmitProposal::default();
+        let v1 = MsgSubmitProposal::from_v1beta1(no_content, AUTHORITY.to_string(), String::new())
+            .unwrap();
+        assert!(v1.messages.is_empty());
+    }
+}
@@ -6,11 +6,58 @@
 #![forbid(unsafe_code)]
 #![warn(trivial_casts, trivial_numeric_casts, unused_import_braces)]
 
+// Allows the generated code to refer to cosmos_sdk_proto::proto_json from this crate as well as dependent crates
+extern crate self as cosmos_sdk_proto;
+
+/// Proto3 JSON mapping helpers used by the serde implementations
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod proto_json;
+
+/// Legacy Amino JSON encoding for SIGN_MODE_LEGACY_AMINO_JSON
+#[cfg(feature = "amino")]
+#[cfg_attr(docsrs, doc(cfg(feature = "amino")))]
+pub mod amino;
+
+/// Decoding of ABCI events into typed event messages
+#[cfg(feature = "events")]
+#[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+pub mod abci_event;
+
+/// Verification of ICS23 commitment proofs
+#[cfg(feature = "proofs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proofs")))]
+pub mod ics23_proof;
+
+/// Verification of IBC MerkleProofs against a commitment root
+#[cfg(feature = "proofs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proofs")))]
+pub mod merkle_proof;
+
+/// ICS20 denom trace parsing and hashing
+#[cfg(feature = "denom-trace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "denom-trace")))]
+pub mod denom_trace;
+
+/// IBC packet commitments, store paths and ICS20 escrow addresses
+#[cfg(feature = "commitments")]
+#[cfg_attr(docsrs, doc(cfg(feature = "commitments")))]
+pub mod packet_commitment;
+
+/// Tendermint light client header verification
+#[cfg(feature = "light-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "light-client")))]
+pub mod light_client;
+
+/// Builder for SIGN_MODE_DIRECT transactions
+pub mod tx_builder;
+
 /// Bech32ibc protobuf definitions
 #[cfg(feature = "bech32ibc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bech32ibc")))]
 pub mod bech32ibc {
     /// Bech32 prefix -> IBC Channel mapping
+    #[allow(clippy::module_inception)]
     pub mod bech32ibc {
         pub mod v1 {
             include!("prost/bech32ibc.bech32ibc.v1beta1.rs");
@@ -153,6 +200,10 @@ pub mod cosmos {
 
     /// Messages and services handling governance
     pub mod gov {
+        pub mod v1 {
+            include!("prost/cosmos.gov.v1.rs");
+            include!("legacy_content.rs");
+        }
         pub mod v1beta1 {
             include!("prost/cosmos.gov.v1beta1.rs");
         }
@@ -182,7 +233,7 @@ pub mod cosmos {
     /// Proof-of-Stake layer for public blockchains.
     pub mod staking {
         pub mod v1beta1 {
-            // WARNING: This file is problematic due to a namespace conflict, see the README for more info
+            // The Validators oneof of StakeAuthorization is renamed to ValidatorsEnum by proto_build, see the README
             include!("prost/cosmos.staking.v1beta1.rs");
         }
     }
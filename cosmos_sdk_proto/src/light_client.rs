@@ -0,0 +1,1338 @@
+//! Verification of Tendermint headers as ibc-go's 07-tendermint light client performs it, so that a header relayed to
+//! (or fetched for) an IBC client can be checked against a trusted [ConsensusState] without trusting the node it came
+//! from.
+//!
+//! A header directly above the trusted height is adjacent, its validator set must be the trusted next validator set and
+//! more than 2/3 of its voting power must have signed the commit. Any later header is verified by skipping, more than
+//! the client's trust level of the trusted validators' power must have signed the commit as well as more than 2/3 of
+//! the header's own validators. Either way the trusted state must be within the trusting period and the header may not
+//! be ahead of the local clock by more than the max clock drift.
+//!
+//! Only ed25519 validator keys are supported, as used by Cosmos SDK chains.
+
+use crate::ibc::core::client::v1::Height;
+use crate::ibc::core::commitment::v1::MerkleRoot;
+use crate::ibc::lightclients::tendermint::v1::{ClientState, ConsensusState, Fraction, Header};
+use crate::tendermint::crypto::public_key;
+use crate::tendermint::types::{
+    BlockId, BlockIdFlag, CanonicalBlockId, CanonicalPartSetHeader, CanonicalVote, Commit,
+    SignedHeader, SignedMsgType, SimpleValidator, Validator, ValidatorSet,
+};
+use prost::Message;
+use prost_types::{Duration, Timestamp};
+use sha2::Digest;
+use std::fmt;
+
+/// An error verifying a header
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LightClientError {
+    /// A required field is unset
+    MissingField(&'static str),
+    /// The client was frozen for misbehaviour
+    Frozen,
+    /// The trust level is not between 1/3 and 1
+    InvalidTrustLevel { numerator: u64, denominator: u64 },
+    /// The validator set has no validators
+    EmptyValidatorSet,
+    /// The validator at the index does not have a valid ed25519 key
+    UnsupportedKey { index: usize },
+    /// The address of the validator at the index is not derived from its key
+    AddressMismatch { index: usize },
+    /// The validator at the index has negative voting power
+    NegativeVotingPower { index: usize },
+    /// The total voting power of the validator set overflows
+    VotingPowerOverflow,
+    /// The header's revision differs from the trusted height's
+    RevisionMismatch { trusted: u64, header: u64 },
+    /// The header's height is negative
+    InvalidHeight(i64),
+    /// The header is not above the trusted height
+    NotNewer { trusted: i64, header: i64 },
+    /// Adjacent verification was given a header which is not directly above the trusted height
+    NotAdjacent { trusted: i64, header: i64 },
+    /// Non-adjacent verification was given a header directly above the trusted height
+    Adjacent { trusted: i64, header: i64 },
+    /// The header is for another chain
+    ChainIdMismatch { expected: String, found: String },
+    /// The commit is for a different height than the header
+    CommitHeightMismatch { header: i64, commit: i64 },
+    /// The commit is not for the header's block
+    BlockIdMismatch,
+    /// The trusted consensus state is older than the trusting period
+    TrustedStateExpired,
+    /// The header's time is not after the trusted time
+    NonIncreasingTime,
+    /// The header's time is beyond the local clock plus the max clock drift
+    HeaderFromFuture,
+    /// The header's validator set does not hash to its validators hash
+    ValidatorsHashMismatch,
+    /// The adjacent header's validators hash is not the trusted next validators hash
+    NextValidatorsHashMismatch,
+    /// The trusted validators do not hash to the trusted next validators hash
+    TrustedValidatorsHashMismatch,
+    /// The commit does not have exactly one signature for each validator
+    SignatureCountMismatch {
+        validators: usize,
+        signatures: usize,
+    },
+    /// The commit signature at the index does not verify
+    InvalidSignature { index: usize },
+    /// The validator at the index signed the commit more than once
+    DuplicateVote { index: usize },
+    /// The validators whose signatures verified do not hold enough voting power
+    InsufficientVotingPower { tallied: i64, needed: i64 },
+}
+
+impl fmt::Display for LightClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LightClientError::MissingField(field) => write!(f, "missing {field}"),
+            LightClientError::Frozen => write!(f, "client is frozen"),
+            LightClientError::InvalidTrustLevel {
+                numerator,
+                denominator,
+            } => write!(
+                f,
+                "trust level {numerator}/{denominator} is not between 1/3 and 1"
+            ),
+            LightClientError::EmptyValidatorSet => write!(f, "validator set is empty"),
+            LightClientError::UnsupportedKey { index } => {
+                write!(f, "validator {index} does not have a valid ed25519 key")
+            }
+            LightClientError::AddressMismatch { index } => {
+                write!(f, "validator {index} address does not match its key")
+            }
+            LightClientError::NegativeVotingPower { index } => {
+                write!(f, "validator {index} has negative voting power")
+            }
+            LightClientError::VotingPowerOverflow => write!(f, "total voting power overflows"),
+            LightClientError::RevisionMismatch { trusted, header } => write!(
+                f,
+                "header revision {header} does not match trusted revision {trusted}"
+            ),
+            LightClientError::InvalidHeight(height) => write!(f, "invalid height {height}"),
+            LightClientError::NotNewer { trusted, header } => write!(
+                f,
+                "header height {header} is not above trusted height {trusted}"
+            ),
+            LightClientError::NotAdjacent { trusted, header } => write!(
+                f,
+                "header height {header} is not adjacent to trusted height {trusted}"
+            ),
+            LightClientError::Adjacent { trusted, header } => write!(
+                f,
+                "header height {header} is adjacent to trusted height {trusted}"
+            ),
+            LightClientError::ChainIdMismatch { expected, found } => {
+                write!(f, "expected chain id {expected}, found {found}")
+            }
+            LightClientError::CommitHeightMismatch { header, commit } => write!(
+                f,
+                "commit height {commit} does not match header height {header}"
+            ),
+            LightClientError::BlockIdMismatch => write!(f, "commit is not for the header"),
+            LightClientError::TrustedStateExpired => {
+                write!(f, "trusted state is past the trusting period")
+            }
+            LightClientError::NonIncreasingTime => {
+                write!(f, "header time is not after the trusted time")
+            }
+            LightClientError::HeaderFromFuture => write!(f, "header time is in the future"),
+            LightClientError::ValidatorsHashMismatch => {
+                write!(
+                    f,
+                    "validator set does not match the header's validators hash"
+                )
+            }
+            LightClientError::NextValidatorsHashMismatch => write!(
+                f,
+                "header's validators hash does not match the trusted next validators hash"
+            ),
+            LightClientError::TrustedValidatorsHashMismatch => write!(
+                f,
+                "trusted validators do not match the trusted next validators hash"
+            ),
+            LightClientError::SignatureCountMismatch {
+                validators,
+                signatures,
+            } => write!(
+                f,
+                "commit has {signatures} signatures for {validators} validators"
+            ),
+            LightClientError::InvalidSignature { index } => {
+                write!(f, "commit signature {index} is invalid")
+            }
+            LightClientError::DuplicateVote { index } => {
+                write!(f, "validator {index} voted more than once")
+            }
+            LightClientError::InsufficientVotingPower { tallied, needed } => write!(
+                f,
+                "signed voting power {tallied} does not exceed the required {needed}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LightClientError {}
+
+/// The parameters of the client which bound how far a header may be trusted
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrustOptions {
+    /// The fraction of the trusted validators' voting power which must sign a non-adjacent header
+    pub trust_level: Fraction,
+    /// How long after its time a trusted header may be used to verify a new one
+    pub trusting_period: Duration,
+    /// How far a header's time may be ahead of the local clock
+    pub max_clock_drift: Duration,
+}
+
+fn timestamp_nanos(t: &Timestamp) -> i128 {
+    i128::from(t.seconds) * 1_000_000_000 + i128::from(t.nanos)
+}
+
+fn duration_nanos(d: &Duration) -> i128 {
+    i128::from(d.seconds) * 1_000_000_000 + i128::from(d.nanos)
+}
+
+/// The revision name and number of a chain id in ibc-go's revision format "{name}-{revision}", where the revision has
+/// no leading zeros
+fn split_revision(chain_id: &str) -> Option<(&str, u64)> {
+    let (name, revision) = chain_id.rsplit_once('-')?;
+    if name.is_empty() || name.ends_with('-') || name.contains('\n') {
+        return None;
+    }
+    if revision.is_empty()
+        || revision.starts_with('0')
+        || !revision.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((name, revision.parse().ok()?))
+}
+
+/// The revision number of the chain id, e.g. 1 for "althea_258432-1", zero for a chain id not in revision format
+pub fn chain_id_revision(chain_id: &str) -> u64 {
+    split_revision(chain_id).map_or(0, |(_, revision)| revision)
+}
+
+/// The chain id with its revision replaced, unchanged if it is not in revision format
+pub fn set_chain_id_revision(chain_id: &str, revision: u64) -> String {
+    match split_revision(chain_id) {
+        Some((name, _)) => format!("{name}-{revision}"),
+        None => chain_id.to_string(),
+    }
+}
+
+fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update([0]);
+    hasher.update(leaf);
+    hasher.finalize().into()
+}
+
+fn inner_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update([1]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// The root of Tendermint's RFC 6962 simple merkle tree over the items, the left subtree holding the largest power of
+/// two items less than their number
+pub fn simple_hash_from_byte_vectors(items: &[Vec<u8>]) -> [u8; 32] {
+    match items.len() {
+        0 => sha2::Sha256::digest([]).into(),
+        1 => leaf_hash(&items[0]),
+        n => {
+            let split = 1 << (usize::BITS - (n - 1).leading_zeros() - 1);
+            inner_hash(
+                &simple_hash_from_byte_vectors(&items[..split]),
+                &simple_hash_from_byte_vectors(&items[split..]),
+            )
+        }
+    }
+}
+
+/// The block id with its part set header always encoded, as gogoproto writes the non-nullable field
+fn encoded_block_id(block_id: Option<&BlockId>) -> Vec<u8> {
+    let block_id = block_id.cloned().unwrap_or_default();
+    BlockId {
+        hash: block_id.hash,
+        part_set_header: Some(block_id.part_set_header.unwrap_or_default()),
+    }
+    .encode_to_vec()
+}
+
+impl crate::tendermint::types::Header {
+    /// The block hash, the simple merkle root of the header's fields each encoded as Tendermint does
+    pub fn hash(&self) -> [u8; 32] {
+        simple_hash_from_byte_vectors(&[
+            self.version.unwrap_or_default().encode_to_vec(),
+            self.chain_id.encode_to_vec(),
+            self.height.encode_to_vec(),
+            self.time.unwrap_or_default().encode_to_vec(),
+            encoded_block_id(self.last_block_id.as_ref()),
+            self.last_commit_hash.encode_to_vec(),
+            self.data_hash.encode_to_vec(),
+            self.validators_hash.encode_to_vec(),
+            self.next_validators_hash.encode_to_vec(),
+            self.consensus_hash.encode_to_vec(),
+            self.app_hash.encode_to_vec(),
+            self.last_results_hash.encode_to_vec(),
+            self.evidence_hash.encode_to_vec(),
+            self.proposer_address.encode_to_vec(),
+        ])
+    }
+}
+
+impl Validator {
+    /// The validator's ed25519 key
+    fn ed25519_key(
+        &self,
+        index: usize,
+    ) -> Result<ed25519_consensus::VerificationKey, LightClientError> {
+        match self.pub_key.as_ref().and_then(|k| k.sum.as_ref()) {
+            Some(public_key::Sum::Ed25519(key)) => {
+                ed25519_consensus::VerificationKey::try_from(key.as_slice())
+                    .map_err(|_| LightClientError::UnsupportedKey { index })
+            }
+            _ => Err(LightClientError::UnsupportedKey { index }),
+        }
+    }
+}
+
+impl ValidatorSet {
+    /// The validators hash of a header with this validator set, the simple merkle root of each validator's key and
+    /// voting power
+    pub fn hash(&self) -> [u8; 32] {
+        let items: Vec<Vec<u8>> = self
+            .validators
+            .iter()
+            .map(|v| {
+                SimpleValidator {
+                    pub_key: v.pub_key.clone(),
+                    voting_power: v.voting_power,
+                }
+                .encode_to_vec()
+            })
+            .collect();
+        simple_hash_from_byte_vectors(&items)
+    }
+
+    /// Checks the set is non-empty, has a proposer as Tendermint's ValidateBasic requires, and each validator has a
+    /// valid ed25519 key, the address derived from it (the addresses are not part of the hash) and non-negative
+    /// voting power
+    pub fn validate(&self) -> Result<(), LightClientError> {
+        if self.validators.is_empty() {
+            return Err(LightClientError::EmptyValidatorSet);
+        }
+        if self.proposer.is_none() {
+            return Err(LightClientError::MissingField("proposer"));
+        }
+        for (index, validator) in self.validators.iter().enumerate() {
+            let key = validator.ed25519_key(index)?;
+            let address = sha2::Sha256::digest(key.as_bytes());
+            if validator.address != address[..20] {
+                return Err(LightClientError::AddressMismatch { index });
+            }
+            if validator.voting_power < 0 {
+                return Err(LightClientError::NegativeVotingPower { index });
+            }
+        }
+        Ok(())
+    }
+
+    /// The sum of the validators' voting power
+    pub fn total_voting_power(&self) -> Result<i64, LightClientError> {
+        self.validators.iter().try_fold(0i64, |total, v| {
+            total
+                .checked_add(v.voting_power)
+                .ok_or(LightClientError::VotingPowerOverflow)
+        })
+    }
+
+    /// Verifies more than 2/3 of the set's voting power signed the commit for the block at the height, with the
+    /// commit's signatures in the order of the set
+    pub fn verify_commit_light(
+        &self,
+        chain_id: &str,
+        block_id: &BlockId,
+        height: i64,
+        commit: &Commit,
+    ) -> Result<(), LightClientError> {
+        self.validate()?;
+        if self.validators.len() != commit.signatures.len() {
+            return Err(LightClientError::SignatureCountMismatch {
+                validators: self.validators.len(),
+                signatures: commit.signatures.len(),
+            });
+        }
+        if height != commit.height {
+            return Err(LightClientError::CommitHeightMismatch {
+                header: height,
+                commit: commit.height,
+            });
+        }
+        if commit.block_id.as_ref() != Some(block_id) {
+            return Err(LightClientError::BlockIdMismatch);
+        }
+        let needed = self
+            .total_voting_power()?
+            .checked_mul(2)
+            .ok_or(LightClientError::VotingPowerOverflow)?
+            / 3;
+        let mut tallied = 0i64;
+        for (index, sig) in commit.signatures.iter().enumerate() {
+            if sig.block_id_flag != BlockIdFlag::Commit as i32 {
+                continue;
+            }
+            let validator = &self.validators[index];
+            verify_commit_sig(validator, index, chain_id, commit, index)?;
+            tallied += validator.voting_power;
+            if tallied > needed {
+                return Ok(());
+            }
+        }
+        Err(LightClientError::InsufficientVotingPower { tallied, needed })
+    }
+
+    /// Verifies more than the trust level of the set's voting power signed the commit, which may be for a later
+    /// validator set. Signers are matched to the set by address
+    pub fn verify_commit_light_trusting(
+        &self,
+        chain_id: &str,
+        commit: &Commit,
+        trust_level: &Fraction,
+    ) -> Result<(), LightClientError> {
+        self.validate()?;
+        validate_trust_level(trust_level)?;
+        let needed = i128::from(self.total_voting_power()?) * i128::from(trust_level.numerator)
+            / i128::from(trust_level.denominator);
+        let needed = i64::try_from(needed).map_err(|_| LightClientError::VotingPowerOverflow)?;
+        let mut seen = vec![false; self.validators.len()];
+        let mut tallied = 0i64;
+        for (index, sig) in commit.signatures.iter().enumerate() {
+            if sig.block_id_flag != BlockIdFlag::Commit as i32 {
+                continue;
+            }
+            let Some(validator_index) = self
+                .validators
+                .iter()
+                .position(|v| v.address == sig.validator_address)
+            else {
+                continue;
+            };
+            if seen[validator_index] {
+                return Err(LightClientError::DuplicateVote {
+                    index: validator_index,
+                });
+            }
+            seen[validator_index] = true;
+            let validator = &self.validators[validator_index];
+            verify_commit_sig(validator, validator_index, chain_id, commit, index)?;
+            tallied += validator.voting_power;
+            if tallied > needed {
+                return Ok(());
+            }
+        }
+        Err(LightClientError::InsufficientVotingPower { tallied, needed })
+    }
+}
+
+/// Checks the validator's signature at the index of the commit
+fn verify_commit_sig(
+    validator: &Validator,
+    validator_index: usize,
+    chain_id: &str,
+    commit: &Commit,
+    index: usize,
+) -> Result<(), LightClientError> {
+    let key = validator.ed25519_key(validator_index)?;
+    let sign_bytes = commit
+        .vote_sign_bytes(chain_id, index)
+        .ok_or(LightClientError::InvalidSignature { index })?;
+    let signature =
+        ed25519_consensus::Signature::try_from(commit.signatures[index].signature.as_slice())
+            .map_err(|_| LightClientError::InvalidSignature { index })?;
+    key.verify(&signature, &sign_bytes)
+        .map_err(|_| LightClientError::InvalidSignature { index })
+}
+
+impl Commit {
+    /// The bytes the validator at the index signed, its length-delimited CanonicalVote for the precommit. None if
+    /// there is no signature at the index
+    pub fn vote_sign_bytes(&self, chain_id: &str, index: usize) -> Option<Vec<u8>> {
+        let sig = self.signatures.get(index)?;
+        // only votes for the block carry its id, votes for nil sign an empty one
+        let block_id = if sig.block_id_flag == BlockIdFlag::Commit as i32 {
+            self.block_id.clone().unwrap_or_default()
+        } else {
+            BlockId::default()
+        };
+        let part_set_header = block_id.part_set_header.unwrap_or_default();
+        let canonical_block_id = if block_id.hash.is_empty()
+            && part_set_header.total == 0
+            && part_set_header.hash.is_empty()
+        {
+            None
+        } else {
+            Some(CanonicalBlockId {
+                hash: block_id.hash,
+                part_set_header: Some(CanonicalPartSetHeader {
+                    total: part_set_header.total,
+                    hash: part_set_header.hash,
+                }),
+            })
+        };
+        let vote = CanonicalVote {
+            r#type: SignedMsgType::Precommit as i32,
+            height: self.height,
+            round: self.round.into(),
+            block_id: canonical_block_id,
+            timestamp: Some(sig.timestamp.unwrap_or_default()),
+            chain_id: chain_id.to_string(),
+        };
+        Some(vote.encode_length_delimited_to_vec())
+    }
+}
+
+fn validate_trust_level(trust_level: &Fraction) -> Result<(), LightClientError> {
+    let Fraction {
+        numerator,
+        denominator,
+    } = *trust_level;
+    // 1/3 <= numerator/denominator <= 1, in u128 so the products cannot overflow
+    if denominator == 0
+        || numerator > denominator
+        || u128::from(numerator) * 3 < u128::from(denominator)
+    {
+        return Err(LightClientError::InvalidTrustLevel {
+            numerator,
+            denominator,
+        });
+    }
+    Ok(())
+}
+
+/// The header and commit of a signed header
+fn split_signed_header(
+    signed_header: &SignedHeader,
+) -> Result<(&crate::tendermint::types::Header, &Commit), LightClientError> {
+    let header = signed_header
+        .header
+        .as_ref()
+        .ok_or(LightClientError::MissingField("header"))?;
+    let commit = signed_header
+        .commit
+        .as_ref()
+        .ok_or(LightClientError::MissingField("commit"))?;
+    Ok((header, commit))
+}
+
+/// Checks the trusted header is within the trusting period, then the untrusted header is for the trusted chain, above
+/// and after the trusted header, not from the future, committed and signed by its validator set
+fn verify_new_header_and_validators(
+    trusted_header: &crate::tendermint::types::Header,
+    untrusted: &SignedHeader,
+    untrusted_validators: &ValidatorSet,
+    options: &TrustOptions,
+    now: &Timestamp,
+) -> Result<(), LightClientError> {
+    let trusted_time = timestamp_nanos(
+        trusted_header
+            .time
+            .as_ref()
+            .ok_or(LightClientError::MissingField("trusted time"))?,
+    );
+    if trusted_time + duration_nanos(&options.trusting_period) <= timestamp_nanos(now) {
+        return Err(LightClientError::TrustedStateExpired);
+    }
+    let (header, commit) = split_signed_header(untrusted)?;
+    if header.chain_id != trusted_header.chain_id {
+        return Err(LightClientError::ChainIdMismatch {
+            expected: trusted_header.chain_id.clone(),
+            found: header.chain_id.clone(),
+        });
+    }
+    if header.height != commit.height {
+        return Err(LightClientError::CommitHeightMismatch {
+            header: header.height,
+            commit: commit.height,
+        });
+    }
+    let block_id = commit
+        .block_id
+        .as_ref()
+        .ok_or(LightClientError::MissingField("commit block id"))?;
+    if block_id.hash != header.hash() {
+        return Err(LightClientError::BlockIdMismatch);
+    }
+    if header.height <= trusted_header.height {
+        return Err(LightClientError::NotNewer {
+            trusted: trusted_header.height,
+            header: header.height,
+        });
+    }
+    let time = timestamp_nanos(
+        header
+            .time
+            .as_ref()
+            .ok_or(LightClientError::MissingField("header time"))?,
+    );
+    if time <= trusted_time {
+        return Err(LightClientError::NonIncreasingTime);
+    }
+    if time >= timestamp_nanos(now) + duration_nanos(&options.max_clock_drift) {
+        return Err(LightClientError::HeaderFromFuture);
+    }
+    if header.validators_hash != untrusted_validators.hash() {
+        return Err(LightClientError::ValidatorsHashMismatch);
+    }
+    Ok(())
+}
+
+/// Verifies the header directly above the trusted header, whose validators must be the trusted next validators
+pub fn verify_adjacent(
+    trusted_header: &crate::tendermint::types::Header,
+    untrusted: &SignedHeader,
+    untrusted_validators: &ValidatorSet,
+    options: &TrustOptions,
+    now: &Timestamp,
+) -> Result<(), LightClientError> {
+    let (header, commit) = split_signed_header(untrusted)?;
+    if header.height != trusted_header.height + 1 {
+        return Err(LightClientError::NotAdjacent {
+            trusted: trusted_header.height,
+            header: header.height,
+        });
+    }
+    verify_new_header_and_validators(
+        trusted_header,
+        untrusted,
+        untrusted_validators,
+        options,
+        now,
+    )?;
+    if header.validators_hash != trusted_header.next_validators_hash {
+        return Err(LightClientError::NextValidatorsHashMismatch);
+    }
+    let block_id = commit
+        .block_id
+        .as_ref()
+        .ok_or(LightClientError::MissingField("commit block id"))?;
+    untrusted_validators.verify_commit_light(
+        &trusted_header.chain_id,
+        block_id,
+        header.height,
+        commit,
+    )
+}
+
+/// Verifies a header more than one block above the trusted header, which the trusted validators must have signed
+/// with more than the trust level of their voting power
+pub fn verify_non_adjacent(
+    trusted_header: &crate::tendermint::types::Header,
+    trusted_validators: &ValidatorSet,
+    untrusted: &SignedHeader,
+    untrusted_validators: &ValidatorSet,
+    options: &TrustOptions,
+    now: &Timestamp,
+) -> Result<(), LightClientError> {
+    let (header, commit) = split_signed_header(untrusted)?;
+    if header.height == trusted_header.height + 1 {
+        return Err(LightClientError::Adjacent {
+            trusted: trusted_header.height,
+            header: header.height,
+        });
+    }
+    verify_new_header_and_validators(
+        trusted_header,
+        untrusted,
+        untrusted_validators,
+        options,
+        now,
+    )?;
+    trusted_validators.verify_commit_light_trusting(
+        &trusted_header.chain_id,
+        commit,
+        &options.trust_level,
+    )?;
+    let block_id = commit
+        .block_id
+        .as_ref()
+        .ok_or(LightClientError::MissingField("commit block id"))?;
+    untrusted_validators.verify_commit_light(
+        &trusted_header.chain_id,
+        block_id,
+        header.height,
+        commit,
+    )
+}
+
+/// Verifies the untrusted header against the trusted header, adjacently if it is the next block and by skipping
+/// otherwise
+pub fn verify(
+    trusted_header: &crate::tendermint::types::Header,
+    trusted_validators: &ValidatorSet,
+    untrusted: &SignedHeader,
+    untrusted_validators: &ValidatorSet,
+    options: &TrustOptions,
+    now: &Timestamp,
+) -> Result<(), LightClientError> {
+    let (header, _) = split_signed_header(untrusted)?;
+    if header.height == trusted_header.height + 1 {
+        verify_adjacent(
+            trusted_header,
+            untrusted,
+            untrusted_validators,
+            options,
+            now,
+        )
+    } else {
+        verify_non_adjacent(
+            trusted_header,
+            trusted_validators,
+            untrusted,
+            untrusted_validators,
+            options,
+            now,
+        )
+    }
+}
+
+impl ClientState {
+    /// The client's trust level, trusting period and max clock drift
+    pub fn trust_options(&self) -> Result<TrustOptions, LightClientError> {
+        let trust_level = self
+            .trust_level
+            .ok_or(LightClientError::MissingField("trust level"))?;
+        validate_trust_level(&trust_level)?;
+        Ok(TrustOptions {
+            trust_level,
+            trusting_period: self
+                .trusting_period
+                .ok_or(LightClientError::MissingField("trusting period"))?,
+            max_clock_drift: self
+                .max_clock_drift
+                .ok_or(LightClientError::MissingField("max clock drift"))?,
+        })
+    }
+
+    /// If the client was frozen for misbehaviour
+    pub fn is_frozen(&self) -> bool {
+        self.frozen_height
+            .is_some_and(|h| h.revision_number != 0 || h.revision_height != 0)
+    }
+
+    /// Verifies the header as an update of the client, as ibc-go's checkValidity does. trusted is the client's
+    /// consensus state at the header's trusted height and now is the local time
+    pub fn verify_header(
+        &self,
+        trusted: &ConsensusState,
+        header: &Header,
+        now: &Timestamp,
+    ) -> Result<(), LightClientError> {
+        if self.is_frozen() {
+            return Err(LightClientError::Frozen);
+        }
+        let options = self.trust_options()?;
+        let signed_header = header
+            .signed_header
+            .as_ref()
+            .ok_or(LightClientError::MissingField("signed header"))?;
+        let validator_set = header
+            .validator_set
+            .as_ref()
+            .ok_or(LightClientError::MissingField("validator set"))?;
+        let trusted_validators = header
+            .trusted_validators
+            .as_ref()
+            .ok_or(LightClientError::MissingField("trusted validators"))?;
+        let trusted_height = header
+            .trusted_height
+            .ok_or(LightClientError::MissingField("trusted height"))?;
+        if trusted_validators.hash()[..] != trusted.next_validators_hash[..] {
+            return Err(LightClientError::TrustedValidatorsHashMismatch);
+        }
+        let height = header.height()?;
+        if height.revision_number != trusted_height.revision_number {
+            return Err(LightClientError::RevisionMismatch {
+                trusted: trusted_height.revision_number,
+                header: height.revision_number,
+            });
+        }
+        if height.revision_height <= trusted_height.revision_height {
+            return Err(LightClientError::NotNewer {
+                trusted: i64::try_from(trusted_height.revision_height).unwrap_or(i64::MAX),
+                header: height.revision_height as i64,
+            });
+        }
+        // the trusted header as far as the consensus state records it, its height is below the header's so it fits
+        // in an i64
+        let trusted_header = crate::tendermint::types::Header {
+            chain_id: set_chain_id_revision(&self.chain_id, height.revision_number),
+            height: trusted_height.revision_height as i64,
+            time: trusted.timestamp,
+            next_validators_hash: trusted.next_validators_hash.clone(),
+            ..Default::default()
+        };
+        verify(
+            &trusted_header,
+            trusted_validators,
+            signed_header,
+            validator_set,
+            &options,
+            now,
+        )
+    }
+}
+
+impl Header {
+    /// The header's height, its revision taken from the chain id
+    pub fn height(&self) -> Result<Height, LightClientError> {
+        let header = self
+            .signed_header
+            .as_ref()
+            .and_then(|h| h.header.as_ref())
+            .ok_or(LightClientError::MissingField("header"))?;
+        Ok(Height {
+            revision_number: chain_id_revision(&header.chain_id),
+            revision_height: u64::try_from(header.height)
+                .map_err(|_| LightClientError::InvalidHeight(header.height))?,
+        })
+    }
+
+    /// The consensus state the client stores once the header is verified, trusted to verify later headers and to
+    /// check proofs against its app hash
+    pub fn consensus_state(&self) -> Result<ConsensusState, LightClientError> {
+        let header = self
+            .signed_header
+            .as_ref()
+            .and_then(|h| h.header.as_ref())
+            .ok_or(LightClientError::MissingField("header"))?;
+        Ok(ConsensusState {
+            timestamp: header.time,
+            root: Some(MerkleRoot {
+                hash: header.app_hash.clone(),
+            }),
+            next_validators_hash: header.next_validators_hash.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tendermint::crypto::PublicKey;
+    use crate::tendermint::types::{CommitSig, PartSetHeader};
+    use crate::tendermint::version::Consensus;
+    use ed25519_consensus::SigningKey;
+
+    const CHAIN_ID: &str = "testchain-1";
+    /// The time of the trusted header
+    const TRUSTED_TIME: i64 = 1_700_000_000;
+    /// The hashes of the validator sets [1, 2, 3, 4] and [2, 3, 4, 5], keyed by seed, computed independently with
+    /// node's ed25519 and sha256
+    const VALIDATORS_HASH_1234: &str =
+        "988cdea842ab3b9f582749dc389c3297c61d8a00fc3c6ee77075de178c83225e";
+    const VALIDATORS_HASH_2345: &str =
+        "b70667366f7a7a7f2a531078019541adbf1597f69cb1d0b48dd2db91860d71c7";
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn tmhash(data: &str) -> Vec<u8> {
+        sha2::Sha256::digest(data).to_vec()
+    }
+
+    fn time(offset: i64) -> Timestamp {
+        Timestamp {
+            seconds: TRUSTED_TIME + offset,
+            nanos: 0,
+        }
+    }
+
+    /// A validator whose ed25519 key has a seed of 32 copies of the byte
+    fn validator(seed: u8, voting_power: i64) -> (SigningKey, Validator) {
+        let key = SigningKey::from([seed; 32]);
+        let pub_key = key.verification_key().to_bytes().to_vec();
+        let validator = Validator {
+            address: sha2::Sha256::digest(&pub_key)[..20].to_vec(),
+            pub_key: Some(PublicKey {
+                sum: Some(public_key::Sum::Ed25519(pub_key)),
+            }),
+            voting_power,
+            proposer_priority: 0,
+        };
+        (key, validator)
+    }
+
+    fn vals_1234() -> Vec<(SigningKey, Validator)> {
+        (1..=4).map(|seed| validator(seed, 10)).collect()
+    }
+
+    fn vals_2345() -> Vec<(SigningKey, Validator)> {
+        vec![
+            validator(2, 10),
+            validator(3, 10),
+            validator(4, 10),
+            validator(5, 30),
+        ]
+    }
+
+    fn validator_set(vals: &[(SigningKey, Validator)]) -> ValidatorSet {
+        ValidatorSet {
+            validators: vals.iter().map(|(_, v)| v.clone()).collect(),
+            proposer: Some(vals[0].1.clone()),
+            total_voting_power: 0,
+        }
+    }
+
+    /// A header at the height, seconds after the trusted time, committed by the signers' precommits
+    fn signed_header(
+        height: i64,
+        offset: i64,
+        vals: &[(SigningKey, Validator)],
+        next_vals: &[(SigningKey, Validator)],
+        signers: &[usize],
+    ) -> SignedHeader {
+        let header = crate::tendermint::types::Header {
+            version: Some(Consensus { block: 11, app: 0 }),
+            chain_id: CHAIN_ID.to_string(),
+            height,
+            time: Some(time(offset)),
+            validators_hash: validator_set(vals).hash().to_vec(),
+            next_validators_hash: validator_set(next_vals).hash().to_vec(),
+            app_hash: tmhash(&format!("app_hash {height}")),
+            proposer_address: vals[0].1.address.clone(),
+            ..Default::default()
+        };
+        let block_id = BlockId {
+            hash: header.hash().to_vec(),
+            part_set_header: Some(PartSetHeader {
+                total: 1,
+                hash: tmhash(&format!("parts {height}")),
+            }),
+        };
+        let mut commit = Commit {
+            height,
+            round: 0,
+            block_id: Some(block_id),
+            signatures: vals
+                .iter()
+                .enumerate()
+                .map(|(i, (_, v))| {
+                    if signers.contains(&i) {
+                        CommitSig {
+                            block_id_flag: BlockIdFlag::Commit.into(),
+                            validator_address: v.address.clone(),
+                            timestamp: Some(time(offset)),
+                            signature: Vec::new(),
+                        }
+                    } else {
+                        CommitSig {
+                            block_id_flag: BlockIdFlag::Absent.into(),
+                            ..Default::default()
+                        }
+                    }
+                })
+                .collect(),
+        };
+        for &i in signers {
+            let sign_bytes = commit.vote_sign_bytes(CHAIN_ID, i).unwrap();
+            commit.signatures[i].signature = vals[i].0.sign(&sign_bytes).to_bytes().to_vec();
+        }
+        SignedHeader {
+            header: Some(header),
+            commit: Some(commit),
+        }
+    }
+
+    /// The trusted header at height 5, whose validators and next validators are [1, 2, 3, 4]
+    fn trusted_header() -> crate::tendermint::types::Header {
+        let vals = vals_1234();
+        signed_header(5, 0, &vals, &vals, &[0, 1, 2, 3])
+            .header
+            .unwrap()
+    }
+
+    fn options() -> TrustOptions {
+        TrustOptions {
+            trust_level: Fraction {
+                numerator: 1,
+                denominator: 3,
+            },
+            trusting_period: Duration {
+                seconds: 14 * 24 * 60 * 60,
+                nanos: 0,
+            },
+            max_clock_drift: Duration {
+                seconds: 10,
+                nanos: 0,
+            },
+        }
+    }
+
+    // types/block_test.go TestHeaderHash in Tendermint
+    #[test]
+    fn header_hash_vector() {
+        let header = crate::tendermint::types::Header {
+            version: Some(Consensus { block: 1, app: 2 }),
+            chain_id: "chainId".to_string(),
+            height: 3,
+            time: Some(Timestamp {
+                seconds: 1_570_983_284,
+                nanos: 0,
+            }),
+            last_block_id: Some(BlockId {
+                hash: vec![0; 32],
+                part_set_header: Some(PartSetHeader {
+                    total: 6,
+                    hash: vec![0; 32],
+                }),
+            }),
+            last_commit_hash: tmhash("last_commit_hash"),
+            data_hash: tmhash("data_hash"),
+            validators_hash: tmhash("validators_hash"),
+            next_validators_hash: tmhash("next_validators_hash"),
+            consensus_hash: tmhash("consensus_hash"),
+            app_hash: tmhash("app_hash"),
+            last_results_hash: tmhash("last_results_hash"),
+            evidence_hash: tmhash("evidence_hash"),
+            proposer_address: tmhash("proposer_address")[..20].to_vec(),
+        };
+        assert_eq!(
+            header.hash().to_vec(),
+            hex("f740121f553b5418c3efbd343c2dbfe9e007bb67b0d020a0741374bab65242a4")
+        );
+    }
+
+    // types/vote_test.go TestVoteSignBytesTestVectors in Tendermint, a precommit for nil at the zero Go time
+    #[test]
+    fn vote_sign_bytes_vector() {
+        let commit = Commit {
+            height: 1,
+            round: 1,
+            block_id: None,
+            signatures: vec![CommitSig {
+                block_id_flag: BlockIdFlag::Nil.into(),
+                validator_address: Vec::new(),
+                timestamp: Some(Timestamp {
+                    seconds: -62_135_596_800,
+                    nanos: 0,
+                }),
+                signature: Vec::new(),
+            }],
+        };
+        assert_eq!(
+            commit.vote_sign_bytes("", 0).unwrap(),
+            hex("2108021101000000000000001901000000000000002a0b088092b8c398feffffff01")
+        );
+        assert_eq!(commit.vote_sign_bytes("", 1), None);
+    }
+
+    #[test]
+    fn validator_set_hash() {
+        let vals = validator_set(&vals_1234());
+        vals.validate().unwrap();
+        assert_eq!(vals.hash().to_vec(), hex(VALIDATORS_HASH_1234));
+        assert_eq!(vals.total_voting_power(), Ok(40));
+        assert_eq!(
+            validator_set(&vals_2345()).hash().to_vec(),
+            hex(VALIDATORS_HASH_2345)
+        );
+
+        let mut invalid = vals.clone();
+        invalid.validators[1].address[0] ^= 1;
+        assert_eq!(
+            invalid.validate(),
+            Err(LightClientError::AddressMismatch { index: 1 })
+        );
+        let mut invalid = vals.clone();
+        invalid.validators[2].voting_power = -1;
+        assert_eq!(
+            invalid.validate(),
+            Err(LightClientError::NegativeVotingPower { index: 2 })
+        );
+        let mut invalid = vals;
+        invalid.proposer = None;
+        assert_eq!(
+            invalid.validate(),
+            Err(LightClientError::MissingField("proposer"))
+        );
+    }
+
+    #[test]
+    fn adjacent_and_non_adjacent_verification() {
+        let (a, b) = (vals_1234(), vals_2345());
+        let trusted = trusted_header();
+        let now = time(30);
+
+        // adjacent, three of the four validators signed
+        let next = signed_header(6, 5, &a, &a, &[0, 1, 3]);
+        verify_adjacent(&trusted, &next, &validator_set(&a), &options(), &now).unwrap();
+        verify(
+            &trusted,
+            &validator_set(&a),
+            &next,
+            &validator_set(&a),
+            &options(),
+            &now,
+        )
+        .unwrap();
+
+        // skipping to a new validator set, 30 of the trusted 40 voting power signed
+        let skipped = signed_header(10, 25, &b, &b, &[0, 1, 2, 3]);
+        verify_non_adjacent(
+            &trusted,
+            &validator_set(&a),
+            &skipped,
+            &validator_set(&b),
+            &options(),
+            &now,
+        )
+        .unwrap();
+        verify(
+            &trusted,
+            &validator_set(&a),
+            &skipped,
+            &validator_set(&b),
+            &options(),
+            &now,
+        )
+        .unwrap();
+
+        assert_eq!(
+            verify_adjacent(&trusted, &skipped, &validator_set(&b), &options(), &now),
+            Err(LightClientError::NotAdjacent {
+                trusted: 5,
+                header: 10
+            })
+        );
+        assert_eq!(
+            verify_non_adjacent(
+                &trusted,
+                &validator_set(&a),
+                &next,
+                &validator_set(&a),
+                &options(),
+                &now
+            ),
+            Err(LightClientError::Adjacent {
+                trusted: 5,
+                header: 6
+            })
+        );
+    }
+
+    #[test]
+    fn client_state_verify_header() {
+        let (a, b) = (vals_1234(), vals_2345());
+        let client_state = ClientState {
+            chain_id: CHAIN_ID.to_string(),
+            trust_level: Some(options().trust_level),
+            trusting_period: Some(options().trusting_period),
+            max_clock_drift: Some(options().max_clock_drift),
+            ..Default::default()
+        };
+        let trusted = ConsensusState {
+            timestamp: Some(time(0)),
+            root: None,
+            next_validators_hash: hex(VALIDATORS_HASH_1234),
+        };
+        let header = Header {
+            signed_header: Some(signed_header(10, 25, &b, &b, &[0, 1, 2, 3])),
+            validator_set: Some(validator_set(&b)),
+            trusted_height: Some(Height {
+                revision_number: 1,
+                revision_height: 5,
+            }),
+            trusted_validators: Some(validator_set(&a)),
+        };
+        client_state
+            .verify_header(&trusted, &header, &time(30))
+            .unwrap();
+        assert_eq!(
+            header.height(),
+            Ok(Height {
+                revision_number: 1,
+                revision_height: 10
+            })
+        );
+
+        // the verified header is trusted to verify the next one
+        let trusted = header.consensus_state().unwrap();
+        assert_eq!(trusted.next_validators_hash, hex(VALIDATORS_HASH_2345));
+        let next = Header {
+            signed_header: Some(signed_header(11, 30, &b, &b, &[1, 2, 3])),
+            validator_set: Some(validator_set(&b)),
+            trusted_height: Some(Height {
+                revision_number: 1,
+                revision_height: 10,
+            }),
+            trusted_validators: Some(validator_set(&b)),
+        };
+        client_state
+            .verify_header(&trusted, &next, &time(35))
+            .unwrap();
+
+        // the trusted validators must be the ones the consensus state committed to
+        let wrong_trusted_validators = Header {
+            trusted_validators: Some(validator_set(&a)),
+            ..next
+        };
+        assert_eq!(
+            client_state.verify_header(&trusted, &wrong_trusted_validators, &time(35)),
+            Err(LightClientError::TrustedValidatorsHashMismatch)
+        );
+
+        let frozen = ClientState {
+            frozen_height: Some(Height {
+                revision_number: 0,
+                revision_height: 1,
+            }),
+            ..client_state
+        };
+        assert_eq!(
+            frozen.verify_header(&trusted, &header, &time(30)),
+            Err(LightClientError::Frozen)
+        );
+    }
+
+    #[test]
+    fn expired_trusted_state_is_rejected() {
+        let a = vals_1234();
+        let next = signed_header(6, 5, &a, &a, &[0, 1, 2, 3]);
+        let expired = Timestamp {
+            seconds: TRUSTED_TIME + options().trusting_period.seconds,
+            nanos: 0,
+        };
+        assert_eq!(
+            verify_adjacent(
+                &trusted_header(),
+                &next,
+                &validator_set(&a),
+                &options(),
+                &expired
+            ),
+            Err(LightClientError::TrustedStateExpired)
+        );
+    }
+
+    #[test]
+    fn header_beyond_clock_drift_is_rejected() {
+        let a = vals_1234();
+        let next = signed_header(6, 5, &a, &a, &[0, 1, 2, 3]);
+        // the header is max_clock_drift ahead of the local clock
+        let now = time(5 - options().max_clock_drift.seconds);
+        assert_eq!(
+            verify_adjacent(
+                &trusted_header(),
+                &next,
+                &validator_set(&a),
+                &options(),
+                &now
+            ),
+            Err(LightClientError::HeaderFromFuture)
+        );
+        let now = time(6 - options().max_clock_drift.seconds);
+        verify_adjacent(
+            &trusted_header(),
+            &next,
+            &validator_set(&a),
+            &options(),
+            &now,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn wrong_next_validators_hash_is_rejected() {
+        let (a, b) = (vals_1234(), vals_2345());
+        // the trusted header committed to [2, 3, 4, 5] as the next validators, but [1, 2, 3, 4] signed
+        let trusted = crate::tendermint::types::Header {
+            next_validators_hash: hex(VALIDATORS_HASH_2345),
+            ..trusted_header()
+        };
+        let next = signed_header(6, 5, &a, &a, &[0, 1, 2, 3]);
+        assert_eq!(
+            verify_adjacent(&trusted, &next, &validator_set(&a), &options(), &time(30)),
+            Err(LightClientError::NextValidatorsHashMismatch)
+        );
+        // the validator set given with the header is not the one it commits to
+        assert_eq!(
+            verify_adjacent(
+                &trusted_header(),
+                &next,
+                &validator_set(&b),
+                &options(),
+                &time(30)
+            ),
+            Err(LightClientError::ValidatorsHashMismatch)
+        );
+    }
+
+    #[test]
+    fn insufficient_voting_power_is_rejected() {
+        let (a, b) = (vals_1234(), vals_2345());
+        let trusted = trusted_header();
+        let now = time(30);
+
+        // 20 of 40 is not more than 2/3
+        let next = signed_header(6, 5, &a, &a, &[0, 2]);
+        assert_eq!(
+            verify_adjacent(&trusted, &next, &validator_set(&a), &options(), &now),
+            Err(LightClientError::InsufficientVotingPower {
+                tallied: 20,
+                needed: 26
+            })
+        );
+
+        // only validator 5, unknown to the trusted set, signed
+        let skipped = signed_header(10, 25, &b, &b, &[3]);
+        assert_eq!(
+            verify_non_adjacent(
+                &trusted,
+                &validator_set(&a),
+                &skipped,
+                &validator_set(&b),
+                &options(),
+                &now
+            ),
+            Err(LightClientError::InsufficientVotingPower {
+                tallied: 0,
+                needed: 13
+            })
+        );
+
+        // 10 of the trusted 40 is not more than 1/3
+        let skipped = signed_header(10, 25, &b, &b, &[2, 3]);
+        assert_eq!(
+            verify_non_adjacent(
+                &trusted,
+                &validator_set(&a),
+                &skipped,
+                &validator_set(&b),
+                &options(),
+                &now
+            ),
+            Err(LightClientError::InsufficientVotingPower {
+                tallied: 10,
+                needed: 13
+            })
+        );
+
+        // a forged signature does not count
+        let mut forged = signed_header(6, 5, &a, &a, &[0, 1, 2, 3]);
+        forged.commit.as_mut().unwrap().signatures[1].signature[0] ^= 1;
+        assert_eq!(
+            verify_adjacent(&trusted, &forged, &validator_set(&a), &options(), &now),
+            Err(LightClientError::InvalidSignature { index: 1 })
+        );
+    }
+}
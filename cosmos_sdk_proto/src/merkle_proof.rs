@@ -0,0 +1,385 @@
+//! Verification of the chained proofs in an IBC [MerkleProof], as ibc-go's commitment module does. A Cosmos SDK chain
+//! proves a key with two ICS23 proofs: the first proves the key in its store's IAVL tree, the second proves that
+//! store's root under the store name in the multistore, whose root is the app hash of the block. The [MerklePath] holds
+//! the keys root first (store name, then key) while the proofs are ordered from the leaf up.
+//!
+//! The root is the `MerkleRoot` of an IBC `ConsensusState`, or the app hash of the header following the queried
+//! height.
+
+use crate::ibc::core::commitment::v1::{MerklePath, MerklePrefix, MerkleProof, MerkleRoot};
+use crate::ics23::{commitment_proof, CommitmentProof, ProofSpec};
+use crate::ics23_proof::{
+    calculate_root, iavl_spec, tendermint_spec, verify_membership, verify_non_membership,
+    ProofError,
+};
+use std::fmt;
+
+/// An error verifying a MerkleProof
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MerkleProofError {
+    /// The proof, root or path is empty
+    Empty(&'static str),
+    /// A membership proof was given an empty value
+    EmptyValue,
+    /// There is not exactly one spec and one key for each proof
+    LengthMismatch {
+        specs: usize,
+        proofs: usize,
+        keys: usize,
+    },
+    /// A key of the path is not a valid percent-encoded path segment
+    InvalidKey(String),
+    /// The proof at this index is not the kind the verification requires
+    UnexpectedProofType { index: usize },
+    /// The proof at this index failed to verify
+    Proof { index: usize, error: ProofError },
+    /// The chained proofs do not lead to the root
+    RootMismatch,
+}
+
+impl fmt::Display for MerkleProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MerkleProofError::Empty(what) => write!(f, "{what} is empty"),
+            MerkleProofError::EmptyValue => write!(f, "value is empty"),
+            MerkleProofError::LengthMismatch {
+                specs,
+                proofs,
+                keys,
+            } => write!(
+                f,
+                "expected equal numbers of specs ({specs}), proofs ({proofs}) and keys ({keys})"
+            ),
+            MerkleProofError::InvalidKey(key) => write!(f, "invalid path key {key:?}"),
+            MerkleProofError::UnexpectedProofType { index } => {
+                write!(f, "proof {index} is of an unexpected type")
+            }
+            MerkleProofError::Proof { index, error } => write!(f, "proof {index}: {error}"),
+            MerkleProofError::RootMismatch => write!(f, "proof does not match the root"),
+        }
+    }
+}
+
+impl std::error::Error for MerkleProofError {}
+
+/// The specs of a Cosmos SDK chain's proofs, the IAVL store followed by the multistore
+pub fn sdk_specs() -> Vec<ProofSpec> {
+    vec![iavl_spec(), tendermint_spec()]
+}
+
+/// Decodes the %XX escapes of a path segment as Go's url.PathUnescape does, keys may be arbitrary bytes once decoded
+fn path_unescape(key: &str) -> Result<Vec<u8>, MerkleProofError> {
+    let invalid = || MerkleProofError::InvalidKey(key.to_string());
+    let bytes = key.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes.get(i + 1..i + 3).ok_or_else(invalid)?;
+            // from_str_radix would also accept a sign, url.PathUnescape only takes two hex digits
+            if !hex.iter().all(u8::is_ascii_hexdigit) {
+                return Err(invalid());
+            }
+            let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+            out.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Ok(out)
+}
+
+/// Escapes every byte other than the unreserved URL characters, so that any key survives path_unescape
+fn path_escape(key: &[u8]) -> String {
+    key.iter()
+        .map(|&b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+impl MerklePath {
+    /// A path of raw keys, root first, escaped so that binary keys such as bank balance keys are preserved
+    pub fn from_keys<K: AsRef<[u8]>>(keys: impl IntoIterator<Item = K>) -> Self {
+        MerklePath {
+            key_path: keys.into_iter().map(|k| path_escape(k.as_ref())).collect(),
+        }
+    }
+
+    /// The decoded key at the index, counted from the root
+    pub fn key(&self, index: usize) -> Result<Vec<u8>, MerkleProofError> {
+        let key = self
+            .key_path
+            .get(index)
+            .ok_or(MerkleProofError::Empty("path"))?;
+        path_unescape(key)
+    }
+}
+
+impl MerklePrefix {
+    /// Prepends the prefix (the store name, e.g. "ibc") to the path
+    pub fn apply_prefix(&self, path: &MerklePath) -> MerklePath {
+        let mut key_path = vec![path_escape(&self.key_prefix)];
+        key_path.extend(path.key_path.iter().cloned());
+        MerklePath { key_path }
+    }
+}
+
+impl MerkleProof {
+    fn check_lengths(
+        &self,
+        specs: &[ProofSpec],
+        root: &MerkleRoot,
+        path: &MerklePath,
+    ) -> Result<(), MerkleProofError> {
+        if self.proofs.is_empty() {
+            return Err(MerkleProofError::Empty("proof"));
+        }
+        if root.hash.is_empty() {
+            return Err(MerkleProofError::Empty("root"));
+        }
+        if specs.len() != self.proofs.len() || path.key_path.len() != self.proofs.len() {
+            return Err(MerkleProofError::LengthMismatch {
+                specs: specs.len(),
+                proofs: self.proofs.len(),
+                keys: path.key_path.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies the proof proves the key at the end of the path holds the value under the root
+    pub fn verify_membership(
+        &self,
+        specs: &[ProofSpec],
+        root: &MerkleRoot,
+        path: &MerklePath,
+        value: &[u8],
+    ) -> Result<(), MerkleProofError> {
+        self.check_lengths(specs, root, path)?;
+        if value.is_empty() {
+            return Err(MerkleProofError::EmptyValue);
+        }
+        verify_chained_membership(&root.hash, specs, &self.proofs, path, value.to_vec(), 0)
+    }
+
+    /// Verifies the proof proves the key at the end of the path is absent under the root. Only the first proof is a
+    /// non-existence proof, the store itself must still be proven to exist
+    pub fn verify_non_membership(
+        &self,
+        specs: &[ProofSpec],
+        root: &MerkleRoot,
+        path: &MerklePath,
+    ) -> Result<(), MerkleProofError> {
+        self.check_lengths(specs, root, path)?;
+        let proof = &self.proofs[0];
+        if !matches!(proof.proof, Some(commitment_proof::Proof::Nonexist(_))) {
+            return Err(MerkleProofError::UnexpectedProofType { index: 0 });
+        }
+        let proof_error = |error| MerkleProofError::Proof { index: 0, error };
+        let subroot = calculate_root(proof).map_err(proof_error)?;
+        let key = path.key(path.key_path.len() - 1)?;
+        verify_non_membership(proof, &specs[0], &subroot, &key).map_err(proof_error)?;
+        verify_chained_membership(&root.hash, specs, &self.proofs, path, subroot, 1)
+    }
+}
+
+/// Verifies each proof from start proves the previous root (or the value) under its key, and that the last root is
+/// the trusted root
+fn verify_chained_membership(
+    root: &[u8],
+    specs: &[ProofSpec],
+    proofs: &[CommitmentProof],
+    path: &MerklePath,
+    mut value: Vec<u8>,
+    start: usize,
+) -> Result<(), MerkleProofError> {
+    for (index, proof) in proofs.iter().enumerate().skip(start) {
+        if !matches!(proof.proof, Some(commitment_proof::Proof::Exist(_))) {
+            return Err(MerkleProofError::UnexpectedProofType { index });
+        }
+        let proof_error = |error| MerkleProofError::Proof { index, error };
+        let subroot = calculate_root(proof).map_err(proof_error)?;
+        // the keys are root first while the proofs are leaf first
+        let key = path.key(path.key_path.len() - 1 - index)?;
+        verify_membership(proof, &specs[index], &subroot, &key, &value).map_err(proof_error)?;
+        value = subroot;
+    }
+    if value != root {
+        return Err(MerkleProofError::RootMismatch);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics23::{ExistenceProof, HashOp, InnerOp, LeafOp};
+
+    /// A multistore of two stores, "bank" holding the IAVL tree of the ics23_proof tests and "ibc"
+    const APP_HASH: &str = "cc69c517289d3a9fafccd97b8de74580902bb2d56f87e4246427711a70e92834";
+    const IAVL_ROOT: &str = "eda1ffd3c75218a0443ea92ef65002e4d9ce7772ee4a92eaf2a124ae5aa3c987";
+    /// The hash of the "ibc" leaf, the right sibling of "bank"
+    const IBC_LEAF: &str = "009c196ed00269a4937359fbbfed880a512218fd843da5ab3308ac8b674d24e9";
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn inner(prefix: &str, suffix: &str) -> InnerOp {
+        InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: hex(prefix),
+            suffix: hex(suffix),
+        }
+    }
+
+    fn exist(key: &[u8], value: Vec<u8>, leaf: LeafOp, path: Vec<InnerOp>) -> CommitmentProof {
+        CommitmentProof {
+            proof: Some(commitment_proof::Proof::Exist(ExistenceProof {
+                key: key.to_vec(),
+                value,
+                leaf: Some(leaf),
+                path,
+            })),
+        }
+    }
+
+    /// "alice" holding "100uatom" in the bank store, proven up to the app hash
+    fn alice_proof() -> MerkleProof {
+        let iavl_leaf = LeafOp {
+            prefix: hex("000202"),
+            ..iavl_spec().leaf_spec.unwrap()
+        };
+        let iavl = exist(
+            b"alice",
+            b"100uatom".to_vec(),
+            iavl_leaf,
+            vec![
+                inner(
+                    "02040420",
+                    "20365a3d7c85cd4579ac42a68d349e897c869d28ade09918226f2ec5abcf2af823",
+                ),
+                inner(
+                    "04080620",
+                    "20a7376a89fb73b339233931b00082627082f07b51461d38c02979b2cd566d4cef",
+                ),
+                inner(
+                    "060e0620",
+                    "2025bc146095e6df84c63ec60563b05ff1d9f0797dafdf2e677b42ba1925ab0816",
+                ),
+            ],
+        );
+        let multistore = exist(
+            b"bank",
+            hex(IAVL_ROOT),
+            tendermint_spec().leaf_spec.unwrap(),
+            vec![inner("01", IBC_LEAF)],
+        );
+        MerkleProof {
+            proofs: vec![iavl, multistore],
+        }
+    }
+
+    fn root() -> MerkleRoot {
+        MerkleRoot {
+            hash: hex(APP_HASH),
+        }
+    }
+
+    fn bank_path(key: &[u8]) -> MerklePath {
+        let prefix = MerklePrefix {
+            key_prefix: b"bank".to_vec(),
+        };
+        prefix.apply_prefix(&MerklePath::from_keys([key]))
+    }
+
+    #[test]
+    fn chained_membership() {
+        let proof = alice_proof();
+        let path = bank_path(b"alice");
+        assert_eq!(path.key_path, vec!["bank", "alice"]);
+        assert_eq!(
+            proof.verify_membership(&sdk_specs(), &root(), &path, b"100uatom"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn chained_membership_failures() {
+        let proof = alice_proof();
+        let specs = sdk_specs();
+        let path = bank_path(b"alice");
+        assert!(matches!(
+            proof.verify_membership(&specs, &root(), &path, b"101uatom"),
+            Err(MerkleProofError::Proof { index: 0, .. })
+        ));
+        assert!(matches!(
+            proof.verify_membership(&specs, &root(), &bank_path(b"bob"), b"100uatom"),
+            Err(MerkleProofError::Proof { index: 0, .. })
+        ));
+        let ibc_path = MerklePath {
+            key_path: vec!["ibc".to_string(), "alice".to_string()],
+        };
+        assert!(matches!(
+            proof.verify_membership(&specs, &root(), &ibc_path, b"100uatom"),
+            Err(MerkleProofError::Proof { index: 1, .. })
+        ));
+        let other_root = MerkleRoot {
+            hash: hex(IAVL_ROOT),
+        };
+        assert_eq!(
+            proof.verify_membership(&specs, &other_root, &path, b"100uatom"),
+            Err(MerkleProofError::RootMismatch)
+        );
+        assert_eq!(
+            proof.verify_membership(&specs, &root(), &path, b""),
+            Err(MerkleProofError::EmptyValue)
+        );
+        assert_eq!(
+            proof.verify_membership(&specs[..1], &root(), &path, b"100uatom"),
+            Err(MerkleProofError::LengthMismatch {
+                specs: 1,
+                proofs: 2,
+                keys: 2
+            })
+        );
+        assert_eq!(
+            proof.verify_non_membership(&specs, &root(), &path),
+            Err(MerkleProofError::UnexpectedProofType { index: 0 })
+        );
+    }
+
+    #[test]
+    fn path_escaping() {
+        // a bank balance key: the prefix, the length prefixed address and the denom
+        let key = b"\x02\x14ab/c%~ibc/27394FB0";
+        let path = MerklePath::from_keys([&key[..]]);
+        assert_eq!(path.key_path, vec!["%02%14ab%2Fc%25~ibc%2F27394FB0"]);
+        assert_eq!(path.key(0).unwrap(), key);
+        assert_eq!(path.key(1), Err(MerkleProofError::Empty("path")));
+
+        for key in ["%2", "%g1", "%+1", "abc%"] {
+            let path = MerklePath {
+                key_path: vec![key.to_string()],
+            };
+            assert_eq!(
+                path.key(0),
+                Err(MerkleProofError::InvalidKey(key.to_string()))
+            );
+        }
+        // unescaped keys are taken as they are, and lower case escapes are accepted
+        let path = MerklePath {
+            key_path: vec!["ports/transfer%2fx".to_string()],
+        };
+        assert_eq!(path.key(0).unwrap(), b"ports/transfer/x");
+    }
+}
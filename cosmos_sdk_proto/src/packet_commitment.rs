@@ -0,0 +1,229 @@
+//! The values and store keys ibc-go writes for packets, so that the commitments a node reports (or proves with a
+//! [MerkleProof](crate::ibc::core::commitment::v1::MerkleProof)) can be checked without trusting its query output, and
+//! the ICS20 escrow address of a channel.
+//!
+//! A packet's commitment is written on the sending chain under its source port and channel and deleted once the
+//! packet is acknowledged or timed out. The receipt and acknowledgement are written on the receiving chain under the
+//! destination port and channel. All of these live in the "ibc" store.
+
+use crate::ibc::applications::transfer::v1::QueryEscrowAddressRequest;
+use crate::ibc::core::channel::v1::{Packet, PacketState};
+use sha2::Digest;
+
+/// The ICS20 version, part of the preimage of every escrow address
+pub const TRANSFER_VERSION: &str = "ics20-1";
+
+/// The value of a packet receipt, written when a packet is received on an unordered channel
+pub const PACKET_RECEIPT: &[u8] = &[1];
+
+/// The address which holds the tokens sent out over a transfer channel while their vouchers exist on the other chain,
+/// the first 20 bytes of SHA256("ics20-1" || 0x00 || "{port}/{channel}")
+pub fn escrow_address(port: &str, channel: &str) -> [u8; 20] {
+    let mut preimage = TRANSFER_VERSION.as_bytes().to_vec();
+    preimage.push(0);
+    preimage.extend_from_slice(format!("{port}/{channel}").as_bytes());
+    let hash = sha2::Sha256::digest(&preimage);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[..20]);
+    address
+}
+
+/// The escrow address of the channel bech32 encoded with the chain's account prefix, as QueryEscrowAddress returns it
+pub fn bech32_escrow_address(
+    port: &str,
+    channel: &str,
+    prefix: &str,
+) -> Result<String, bech32::EncodeError> {
+    let hrp = bech32::Hrp::parse_unchecked(prefix);
+    bech32::encode::<bech32::Bech32>(hrp, &escrow_address(port, channel))
+}
+
+impl QueryEscrowAddressRequest {
+    /// Answers the query offline, with the chain's bech32 account prefix
+    pub fn escrow_address(&self, prefix: &str) -> Result<String, bech32::EncodeError> {
+        bech32_escrow_address(&self.port_id, &self.channel_id, prefix)
+    }
+}
+
+/// "commitments/ports/{port}/channels/{channel}/sequences/{sequence}"
+pub fn packet_commitment_path(port: &str, channel: &str, sequence: u64) -> String {
+    format!("commitments/ports/{port}/channels/{channel}/sequences/{sequence}")
+}
+
+/// "acks/ports/{port}/channels/{channel}/sequences/{sequence}"
+pub fn packet_acknowledgement_path(port: &str, channel: &str, sequence: u64) -> String {
+    format!("acks/ports/{port}/channels/{channel}/sequences/{sequence}")
+}
+
+/// "receipts/ports/{port}/channels/{channel}/sequences/{sequence}"
+pub fn packet_receipt_path(port: &str, channel: &str, sequence: u64) -> String {
+    format!("receipts/ports/{port}/channels/{channel}/sequences/{sequence}")
+}
+
+/// "nextSequenceSend/ports/{port}/channels/{channel}"
+pub fn next_sequence_send_path(port: &str, channel: &str) -> String {
+    format!("nextSequenceSend/ports/{port}/channels/{channel}")
+}
+
+/// "nextSequenceRecv/ports/{port}/channels/{channel}"
+pub fn next_sequence_recv_path(port: &str, channel: &str) -> String {
+    format!("nextSequenceRecv/ports/{port}/channels/{channel}")
+}
+
+/// "nextSequenceAck/ports/{port}/channels/{channel}"
+pub fn next_sequence_ack_path(port: &str, channel: &str) -> String {
+    format!("nextSequenceAck/ports/{port}/channels/{channel}")
+}
+
+/// "channelEnds/ports/{port}/channels/{channel}"
+pub fn channel_path(port: &str, channel: &str) -> String {
+    format!("channelEnds/ports/{port}/channels/{channel}")
+}
+
+/// The commitment of an acknowledgement, SHA256 of the acknowledgement bytes the receiving module wrote
+pub fn acknowledgement_commitment(acknowledgement: &[u8]) -> [u8; 32] {
+    sha2::Sha256::digest(acknowledgement).into()
+}
+
+impl Packet {
+    /// The commitment of the packet, SHA256(timeout timestamp || timeout revision number || timeout revision height
+    /// || SHA256(data)) with each number as 8 big endian bytes. An unset timeout height is zero
+    pub fn commitment(&self) -> [u8; 32] {
+        let height = self.timeout_height.unwrap_or_default();
+        let mut preimage = Vec::with_capacity(56);
+        preimage.extend_from_slice(&self.timeout_timestamp.to_be_bytes());
+        preimage.extend_from_slice(&height.revision_number.to_be_bytes());
+        preimage.extend_from_slice(&height.revision_height.to_be_bytes());
+        preimage.extend_from_slice(&sha2::Sha256::digest(&self.data));
+        sha2::Sha256::digest(&preimage).into()
+    }
+
+    /// The key of the packet's commitment on the sending chain
+    pub fn commitment_path(&self) -> String {
+        packet_commitment_path(&self.source_port, &self.source_channel, self.sequence)
+    }
+
+    /// The key of the packet's acknowledgement on the receiving chain
+    pub fn acknowledgement_path(&self) -> String {
+        packet_acknowledgement_path(
+            &self.destination_port,
+            &self.destination_channel,
+            self.sequence,
+        )
+    }
+
+    /// The key of the packet's receipt on the receiving chain
+    pub fn receipt_path(&self) -> String {
+        packet_receipt_path(
+            &self.destination_port,
+            &self.destination_channel,
+            self.sequence,
+        )
+    }
+
+    /// The PacketState the sending chain reports for the packet's commitment
+    pub fn commitment_state(&self) -> PacketState {
+        PacketState {
+            port_id: self.source_port.clone(),
+            channel_id: self.source_channel.clone(),
+            sequence: self.sequence,
+            data: self.commitment().to_vec(),
+        }
+    }
+
+    /// The PacketState the receiving chain reports for the packet's acknowledgement
+    pub fn acknowledgement_state(&self, acknowledgement: &[u8]) -> PacketState {
+        PacketState {
+            port_id: self.destination_port.clone(),
+            channel_id: self.destination_channel.clone(),
+            sequence: self.sequence,
+            data: acknowledgement_commitment(acknowledgement).to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ibc::core::client::v1::Height;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn packet(timeout_height: Option<Height>) -> Packet {
+        Packet {
+            sequence: 7,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            destination_port: "transfer".to_string(),
+            destination_channel: "channel-141".to_string(),
+            data: br#"{"amount":"100","denom":"uatom","receiver":"cosmos1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeg5thmd","sender":"althea1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg34ljv72"}"#.to_vec(),
+            timeout_height,
+            timeout_timestamp: 1_700_000_000_000_000_000,
+        }
+    }
+
+    #[test]
+    fn escrow_addresses() {
+        assert_eq!(
+            hex(&escrow_address("transfer", "channel-141")),
+            "352bf5cc8839ecfccb763916e1ddb1faf67a9b37"
+        );
+        assert_eq!(
+            bech32_escrow_address("transfer", "channel-141", "cosmos").unwrap(),
+            "cosmos1x54ltnyg88k0ejmk8ytwrhd3ltm84xehrnlslf"
+        );
+        let request = QueryEscrowAddressRequest {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+        };
+        assert_eq!(
+            request.escrow_address("althea").unwrap(),
+            "althea1a53udazy8ayufvy0s434pfwjcedzqv34zw8t8t"
+        );
+    }
+
+    #[test]
+    fn packet_commitments() {
+        let with_height = packet(Some(Height {
+            revision_number: 1,
+            revision_height: 1000,
+        }));
+        assert_eq!(
+            hex(&with_height.commitment()),
+            "b4c765e40fb916a0bc4ba02d6839b47e94de9eb5ded83a5ce586b2da8af80132"
+        );
+        assert_eq!(
+            hex(&packet(None).commitment()),
+            "1c8fe6ba7ed2d78e26551e03f41c351c7e6c8b376c46092af5b1dd55f699435f"
+        );
+        // the commitment of the successful ICS20 acknowledgement
+        assert_eq!(
+            hex(&acknowledgement_commitment(br#"{"result":"AQ=="}"#)),
+            "08f7557ed51826fe18d84512bf24ec75001edbaf2123a477df72a0a9f3640a7c"
+        );
+    }
+
+    #[test]
+    fn packet_paths() {
+        let packet = packet(None);
+        assert_eq!(
+            packet.commitment_path(),
+            "commitments/ports/transfer/channels/channel-0/sequences/7"
+        );
+        assert_eq!(
+            packet.acknowledgement_path(),
+            "acks/ports/transfer/channels/channel-141/sequences/7"
+        );
+        assert_eq!(
+            packet.receipt_path(),
+            "receipts/ports/transfer/channels/channel-141/sequences/7"
+        );
+        let state = packet.commitment_state();
+        assert_eq!(
+            (state.channel_id.as_str(), state.data),
+            ("channel-0", packet.commitment().to_vec())
+        );
+    }
+}
@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// An HrpIbcRecord maps a bech32 human-readable prefix to an IBC source
 /// channel
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HrpIbcRecord {
     /// The bech32 human readable prefix that serves as the key
@@ -10,10 +12,24 @@ pub struct HrpIbcRecord {
     #[prost(string, tag = "2")]
     pub source_channel: ::prost::alloc::string::String,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub ics_to_height_offset: u64,
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub ics_to_time_offset: ::core::option::Option<::prost_types::Duration>,
 }
+impl ::prost::Name for HrpIbcRecord {
+    const NAME: &'static str = "HrpIbcRecord";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.HrpIbcRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.HrpIbcRecord".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(string, tag = "1")]
@@ -21,11 +37,23 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "2")]
     pub hrp_ibc_records: ::prost::alloc::vec::Vec<HrpIbcRecord>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.GenesisState".into()
+    }
+}
 /// UpdateHrpIBCRecordProposal is a gov Content type for adding a new record
 /// between a bech32 prefix and an IBC (port, channel).
 /// It can be used to add a new record to the set. It can also be.
 /// used to update the IBC channel to associate with a specific denom. If channel
 /// is set to "", it will remove the record from the set.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateHrpIbcChannelProposal {
     #[prost(string, tag = "1")]
@@ -37,34 +65,120 @@ pub struct UpdateHrpIbcChannelProposal {
     #[prost(string, tag = "4")]
     pub source_channel: ::prost::alloc::string::String,
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub ics_to_height_offset: u64,
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub ics_to_time_offset: ::core::option::Option<::prost_types::Duration>,
 }
+impl ::prost::Name for UpdateHrpIbcChannelProposal {
+    const NAME: &'static str = "UpdateHrpIbcChannelProposal";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.UpdateHrpIbcChannelProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.UpdateHrpIbcChannelProposal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryHrpIbcRecordsRequest {}
+impl ::prost::Name for QueryHrpIbcRecordsRequest {
+    const NAME: &'static str = "QueryHrpIbcRecordsRequest";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryHrpIbcRecordsResponse {
     #[prost(message, repeated, tag = "1")]
     pub hrp_ibc_records: ::prost::alloc::vec::Vec<HrpIbcRecord>,
 }
+impl ::prost::Name for QueryHrpIbcRecordsResponse {
+    const NAME: &'static str = "QueryHrpIbcRecordsResponse";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryHrpIbcRecordRequest {
     #[prost(string, tag = "1")]
     pub hrp: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryHrpIbcRecordRequest {
+    const NAME: &'static str = "QueryHrpIbcRecordRequest";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryHrpIbcRecordResponse {
     #[prost(message, optional, tag = "1")]
     pub hrp_ibc_record: ::core::option::Option<HrpIbcRecord>,
 }
+impl ::prost::Name for QueryHrpIbcRecordResponse {
+    const NAME: &'static str = "QueryHrpIbcRecordResponse";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.QueryHrpIbcRecordResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryNativeHrpRequest {}
+impl ::prost::Name for QueryNativeHrpRequest {
+    const NAME: &'static str = "QueryNativeHrpRequest";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.QueryNativeHrpRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.QueryNativeHrpRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryNativeHrpResponse {
     #[prost(string, tag = "1")]
     pub native_hrp: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryNativeHrpResponse {
+    const NAME: &'static str = "QueryNativeHrpResponse";
+    const PACKAGE: &'static str = "bech32ibc.bech32ibc.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "bech32ibc.bech32ibc.v1beta1.QueryNativeHrpResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/bech32ibc.bech32ibc.v1beta1.QueryNativeHrpResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -239,3 +353,305 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// HrpIbcRecords returns to full list of records
+        async fn hrp_ibc_records(
+            &self,
+            request: tonic::Request<super::QueryHrpIbcRecordsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryHrpIbcRecordsResponse>,
+            tonic::Status,
+        >;
+        /// HrpIbcRecord returns the record for a requested HRP
+        async fn hrp_ibc_record(
+            &self,
+            request: tonic::Request<super::QueryHrpIbcRecordRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryHrpIbcRecordResponse>,
+            tonic::Status,
+        >;
+        /// NativeHrp returns the chain's native HRP
+        async fn native_hrp(
+            &self,
+            request: tonic::Request<super::QueryNativeHrpRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryNativeHrpResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/bech32ibc.bech32ibc.v1beta1.Query/HrpIbcRecords" => {
+                    #[allow(non_camel_case_types)]
+                    struct HrpIbcRecordsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryHrpIbcRecordsRequest>
+                    for HrpIbcRecordsSvc<T> {
+                        type Response = super::QueryHrpIbcRecordsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryHrpIbcRecordsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::hrp_ibc_records(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HrpIbcRecordsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bech32ibc.bech32ibc.v1beta1.Query/HrpIbcRecord" => {
+                    #[allow(non_camel_case_types)]
+                    struct HrpIbcRecordSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryHrpIbcRecordRequest>
+                    for HrpIbcRecordSvc<T> {
+                        type Response = super::QueryHrpIbcRecordResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryHrpIbcRecordRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::hrp_ibc_record(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HrpIbcRecordSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/bech32ibc.bech32ibc.v1beta1.Query/NativeHrp" => {
+                    #[allow(non_camel_case_types)]
+                    struct NativeHrpSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryNativeHrpRequest>
+                    for NativeHrpSvc<T> {
+                        type Response = super::QueryNativeHrpResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryNativeHrpRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::native_hrp(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = NativeHrpSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "bech32ibc.bech32ibc.v1beta1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<HrpIbcRecord>();
+    registry.register::<GenesisState>();
+    registry.register::<UpdateHrpIbcChannelProposal>();
+    registry.register::<QueryHrpIbcRecordsRequest>();
+    registry.register::<QueryHrpIbcRecordsResponse>();
+    registry.register::<QueryHrpIbcRecordRequest>();
+    registry.register::<QueryHrpIbcRecordResponse>();
+    registry.register::<QueryNativeHrpRequest>();
+    registry.register::<QueryNativeHrpResponse>();
+}
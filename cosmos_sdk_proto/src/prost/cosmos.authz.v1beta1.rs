@@ -1,26 +1,54 @@
 // This file is @generated by prost-build.
 /// GenericAuthorization gives the grantee unrestricted permissions to execute
 /// the provided method on behalf of the granter's account.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenericAuthorization {
     /// Msg, identified by it's type URL, to grant unrestricted permissions to execute
     #[prost(string, tag = "1")]
     pub msg: ::prost::alloc::string::String,
 }
+impl ::prost::Name for GenericAuthorization {
+    const NAME: &'static str = "GenericAuthorization";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.GenericAuthorization".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.GenericAuthorization".into()
+    }
+}
 /// Grant gives permissions to execute
 /// the provide method with expiration time.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Grant {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub authorization: ::core::option::Option<::prost_types::Any>,
     /// time when the grant will expire and will be pruned. If null, then the grant
     /// doesn't have a time expiration (other conditions  in `authorization`
     /// may apply to invalidate the grant)
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub expiration: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for Grant {
+    const NAME: &'static str = "Grant";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.Grant".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.Grant".into()
+    }
+}
 /// GrantAuthorization extends a grant with both the addresses of the grantee and granter.
 /// It is used in genesis.proto and query.proto
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GrantAuthorization {
     #[prost(string, tag = "1")]
@@ -28,18 +56,44 @@ pub struct GrantAuthorization {
     #[prost(string, tag = "2")]
     pub grantee: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub authorization: ::core::option::Option<::prost_types::Any>,
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub expiration: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for GrantAuthorization {
+    const NAME: &'static str = "GrantAuthorization";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.GrantAuthorization".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.GrantAuthorization".into()
+    }
+}
 /// GrantQueueItem contains the list of TypeURL of a sdk.Msg.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GrantQueueItem {
     /// msg_type_urls contains the list of TypeURL of a sdk.Msg.
     #[prost(string, repeated, tag = "1")]
     pub msg_type_urls: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for GrantQueueItem {
+    const NAME: &'static str = "GrantQueueItem";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.GrantQueueItem".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.GrantQueueItem".into()
+    }
+}
 /// EventGrant is emitted on Msg/Grant
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventGrant {
     /// Msg type URL for which an autorization is granted
@@ -52,7 +106,19 @@ pub struct EventGrant {
     #[prost(string, tag = "4")]
     pub grantee: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventGrant {
+    const NAME: &'static str = "EventGrant";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.EventGrant".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.EventGrant".into()
+    }
+}
 /// EventRevoke is emitted on Msg/Revoke
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventRevoke {
     /// Msg type URL for which an autorization is revoked
@@ -65,13 +131,37 @@ pub struct EventRevoke {
     #[prost(string, tag = "4")]
     pub grantee: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventRevoke {
+    const NAME: &'static str = "EventRevoke";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.EventRevoke".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.EventRevoke".into()
+    }
+}
 /// GenesisState defines the authz module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, repeated, tag = "1")]
     pub authorization: ::prost::alloc::vec::Vec<GrantAuthorization>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.GenesisState".into()
+    }
+}
 /// QueryGrantsRequest is the request type for the Query/Grants RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryGrantsRequest {
     #[prost(string, tag = "1")]
@@ -87,7 +177,19 @@ pub struct QueryGrantsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryGrantsRequest {
+    const NAME: &'static str = "QueryGrantsRequest";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.QueryGrantsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.QueryGrantsRequest".into()
+    }
+}
 /// QueryGrantsResponse is the response type for the Query/Authorizations RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryGrantsResponse {
     /// authorizations is a list of grants granted for grantee by granter.
@@ -99,7 +201,19 @@ pub struct QueryGrantsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryGrantsResponse {
+    const NAME: &'static str = "QueryGrantsResponse";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.QueryGrantsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.QueryGrantsResponse".into()
+    }
+}
 /// QueryGranterGrantsRequest is the request type for the Query/GranterGrants RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryGranterGrantsRequest {
     #[prost(string, tag = "1")]
@@ -110,7 +224,19 @@ pub struct QueryGranterGrantsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryGranterGrantsRequest {
+    const NAME: &'static str = "QueryGranterGrantsRequest";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.QueryGranterGrantsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.QueryGranterGrantsRequest".into()
+    }
+}
 /// QueryGranterGrantsResponse is the response type for the Query/GranterGrants RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryGranterGrantsResponse {
     /// grants is a list of grants granted by the granter.
@@ -122,7 +248,19 @@ pub struct QueryGranterGrantsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryGranterGrantsResponse {
+    const NAME: &'static str = "QueryGranterGrantsResponse";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.QueryGranterGrantsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.QueryGranterGrantsResponse".into()
+    }
+}
 /// QueryGranteeGrantsRequest is the request type for the Query/IssuedGrants RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryGranteeGrantsRequest {
     #[prost(string, tag = "1")]
@@ -133,7 +271,19 @@ pub struct QueryGranteeGrantsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryGranteeGrantsRequest {
+    const NAME: &'static str = "QueryGranteeGrantsRequest";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.QueryGranteeGrantsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.QueryGranteeGrantsRequest".into()
+    }
+}
 /// QueryGranteeGrantsResponse is the response type for the Query/GranteeGrants RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryGranteeGrantsResponse {
     /// grants is a list of grants granted to the grantee.
@@ -145,6 +295,18 @@ pub struct QueryGranteeGrantsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryGranteeGrantsResponse {
+    const NAME: &'static str = "QueryGranteeGrantsResponse";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.QueryGranteeGrantsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.QueryGranteeGrantsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -320,6 +482,8 @@ pub mod query_client {
 }
 /// MsgGrant is a request type for Grant method. It declares authorization to the grantee
 /// on behalf of the granter with the provided expiration time.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgGrant {
     #[prost(string, tag = "1")]
@@ -329,15 +493,40 @@ pub struct MsgGrant {
     #[prost(message, optional, tag = "3")]
     pub grant: ::core::option::Option<Grant>,
 }
+impl ::prost::Name for MsgGrant {
+    const NAME: &'static str = "MsgGrant";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.MsgGrant".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.MsgGrant".into()
+    }
+}
 /// MsgExecResponse defines the Msg/MsgExecResponse response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgExecResponse {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub results: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for MsgExecResponse {
+    const NAME: &'static str = "MsgExecResponse";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.MsgExecResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.MsgExecResponse".into()
+    }
+}
 /// MsgExec attempts to execute the provided messages using
 /// authorizations granted to the grantee. Each message should have only
 /// one signer corresponding to the granter of the authorization.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgExec {
     #[prost(string, tag = "1")]
@@ -346,13 +535,38 @@ pub struct MsgExec {
     /// The x/authz will try to find a grant matching (msg.signers\[0\], grantee, MsgTypeURL(msg))
     /// triple and validate it.
     #[prost(message, repeated, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub msgs: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for MsgExec {
+    const NAME: &'static str = "MsgExec";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.MsgExec".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.MsgExec".into()
+    }
+}
 /// MsgGrantResponse defines the Msg/MsgGrant response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgGrantResponse {}
+impl ::prost::Name for MsgGrantResponse {
+    const NAME: &'static str = "MsgGrantResponse";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.MsgGrantResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.MsgGrantResponse".into()
+    }
+}
 /// MsgRevoke revokes any authorization with the provided sdk.Msg type on the
 /// granter's account with that has been granted to the grantee.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRevoke {
     #[prost(string, tag = "1")]
@@ -362,9 +576,33 @@ pub struct MsgRevoke {
     #[prost(string, tag = "3")]
     pub msg_type_url: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgRevoke {
+    const NAME: &'static str = "MsgRevoke";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.MsgRevoke".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.MsgRevoke".into()
+    }
+}
 /// MsgRevokeResponse defines the Msg/MsgRevokeResponse response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgRevokeResponse {}
+impl ::prost::Name for MsgRevokeResponse {
+    const NAME: &'static str = "MsgRevokeResponse";
+    const PACKAGE: &'static str = "cosmos.authz.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.authz.v1beta1.MsgRevokeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.authz.v1beta1.MsgRevokeResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -540,3 +778,605 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Returns list of `Authorization`, granted to the grantee by the granter.
+        async fn grants(
+            &self,
+            request: tonic::Request<super::QueryGrantsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryGrantsResponse>,
+            tonic::Status,
+        >;
+        /// GranterGrants returns list of `GrantAuthorization`, granted by granter.
+        ///
+        /// Since: cosmos-sdk 0.46
+        async fn granter_grants(
+            &self,
+            request: tonic::Request<super::QueryGranterGrantsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryGranterGrantsResponse>,
+            tonic::Status,
+        >;
+        /// GranteeGrants returns a list of `GrantAuthorization` by grantee.
+        ///
+        /// Since: cosmos-sdk 0.46
+        async fn grantee_grants(
+            &self,
+            request: tonic::Request<super::QueryGranteeGrantsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryGranteeGrantsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.authz.v1beta1.Query/Grants" => {
+                    #[allow(non_camel_case_types)]
+                    struct GrantsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryGrantsRequest>
+                    for GrantsSvc<T> {
+                        type Response = super::QueryGrantsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryGrantsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::grants(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GrantsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.authz.v1beta1.Query/GranterGrants" => {
+                    #[allow(non_camel_case_types)]
+                    struct GranterGrantsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryGranterGrantsRequest>
+                    for GranterGrantsSvc<T> {
+                        type Response = super::QueryGranterGrantsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryGranterGrantsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::granter_grants(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GranterGrantsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.authz.v1beta1.Query/GranteeGrants" => {
+                    #[allow(non_camel_case_types)]
+                    struct GranteeGrantsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryGranteeGrantsRequest>
+                    for GranteeGrantsSvc<T> {
+                        type Response = super::QueryGranteeGrantsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryGranteeGrantsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::grantee_grants(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GranteeGrantsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.authz.v1beta1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// Grant grants the provided authorization to the grantee on the granter's
+        /// account with the provided expiration time. If there is already a grant
+        /// for the given (granter, grantee, Authorization) triple, then the grant
+        /// will be overwritten.
+        async fn grant(
+            &self,
+            request: tonic::Request<super::MsgGrant>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgGrantResponse>,
+            tonic::Status,
+        >;
+        /// Exec attempts to execute the provided messages using
+        /// authorizations granted to the grantee. Each message should have only
+        /// one signer corresponding to the granter of the authorization.
+        async fn exec(
+            &self,
+            request: tonic::Request<super::MsgExec>,
+        ) -> std::result::Result<tonic::Response<super::MsgExecResponse>, tonic::Status>;
+        /// Revoke revokes any authorization corresponding to the provided method name on the
+        /// granter's account that has been granted to the grantee.
+        async fn revoke(
+            &self,
+            request: tonic::Request<super::MsgRevoke>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgRevokeResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the authz Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.authz.v1beta1.Msg/Grant" => {
+                    #[allow(non_camel_case_types)]
+                    struct GrantSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgGrant>
+                    for GrantSvc<T> {
+                        type Response = super::MsgGrantResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgGrant>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::grant(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GrantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.authz.v1beta1.Msg/Exec" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgExec>
+                    for ExecSvc<T> {
+                        type Response = super::MsgExecResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgExec>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::exec(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExecSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.authz.v1beta1.Msg/Revoke" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgRevoke>
+                    for RevokeSvc<T> {
+                        type Response = super::MsgRevokeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgRevoke>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::revoke(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevokeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.authz.v1beta1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<GenericAuthorization>();
+    registry.register::<Grant>();
+    registry.register::<GrantAuthorization>();
+    registry.register::<GrantQueueItem>();
+    registry.register::<EventGrant>();
+    registry.register::<EventRevoke>();
+    registry.register::<GenesisState>();
+    registry.register::<QueryGrantsRequest>();
+    registry.register::<QueryGrantsResponse>();
+    registry.register::<QueryGranterGrantsRequest>();
+    registry.register::<QueryGranterGrantsResponse>();
+    registry.register::<QueryGranteeGrantsRequest>();
+    registry.register::<QueryGranteeGrantsResponse>();
+    registry.register::<MsgGrant>();
+    registry.register::<MsgExecResponse>();
+    registry.register::<MsgExec>();
+    registry.register::<MsgGrantResponse>();
+    registry.register::<MsgRevoke>();
+    registry.register::<MsgRevokeResponse>();
+}
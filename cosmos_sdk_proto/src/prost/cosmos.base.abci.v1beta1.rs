@@ -1,10 +1,13 @@
 // This file is @generated by prost-build.
 /// TxResponse defines a structure containing relevant tx data and metadata. The
 /// tags are stringified and the log is JSON decoded.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxResponse {
     /// The block height
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     /// The transaction hash.
     #[prost(string, tag = "2")]
@@ -30,12 +33,15 @@ pub struct TxResponse {
     pub info: ::prost::alloc::string::String,
     /// Amount of gas requested for transaction.
     #[prost(int64, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_wanted: i64,
     /// Amount of gas consumed by transaction.
     #[prost(int64, tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: i64,
     /// The request transaction bytes.
     #[prost(message, optional, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub tx: ::core::option::Option<::prost_types::Any>,
     /// Time of the previous block. For heights > 1, it's the weighted median of
     /// the timestamps of the valid votes in the block.LastCommit. For height == 1,
@@ -53,7 +59,19 @@ pub struct TxResponse {
         crate::tendermint::abci::Event,
     >,
 }
+impl ::prost::Name for TxResponse {
+    const NAME: &'static str = "TxResponse";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.TxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.TxResponse".into()
+    }
+}
 /// ABCIMessageLog defines a structure containing an indexed tx ABCI message log.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciMessageLog {
     #[prost(uint32, tag = "1")]
@@ -65,8 +83,20 @@ pub struct AbciMessageLog {
     #[prost(message, repeated, tag = "3")]
     pub events: ::prost::alloc::vec::Vec<StringEvent>,
 }
+impl ::prost::Name for AbciMessageLog {
+    const NAME: &'static str = "ABCIMessageLog";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.ABCIMessageLog".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.ABCIMessageLog".into()
+    }
+}
 /// StringEvent defines en Event object wrapper where all the attributes
 /// contain key/value pairs that are strings instead of raw bytes.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StringEvent {
     #[prost(string, tag = "1")]
@@ -74,8 +104,20 @@ pub struct StringEvent {
     #[prost(message, repeated, tag = "2")]
     pub attributes: ::prost::alloc::vec::Vec<Attribute>,
 }
+impl ::prost::Name for StringEvent {
+    const NAME: &'static str = "StringEvent";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.StringEvent".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.StringEvent".into()
+    }
+}
 /// Attribute defines an attribute wrapper where the key and value are
 /// strings instead of raw bytes.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Attribute {
     #[prost(string, tag = "1")]
@@ -83,17 +125,43 @@ pub struct Attribute {
     #[prost(string, tag = "2")]
     pub value: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Attribute {
+    const NAME: &'static str = "Attribute";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.Attribute".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.Attribute".into()
+    }
+}
 /// GasInfo defines tx execution gas context.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GasInfo {
     /// GasWanted is the maximum units of work we allow this tx to perform.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_wanted: u64,
     /// GasUsed is the amount of gas actually consumed.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: u64,
 }
+impl ::prost::Name for GasInfo {
+    const NAME: &'static str = "GasInfo";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.GasInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.GasInfo".into()
+    }
+}
 /// Result is the union of ResponseFormat and ResponseCheckTx.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Result {
     /// Data is any data returned from message or handler execution. It MUST be
@@ -102,6 +170,7 @@ pub struct Result {
     /// because it also contains the Msg response typeURL.
     #[deprecated]
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     /// Log contains the log information from message or handler execution.
     #[prost(string, tag = "2")]
@@ -116,10 +185,23 @@ pub struct Result {
     ///
     /// Since: cosmos-sdk 0.46
     #[prost(message, repeated, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub msg_responses: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for Result {
+    const NAME: &'static str = "Result";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.Result".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.Result".into()
+    }
+}
 /// SimulationResponse defines the response generated when a transaction is
 /// successfully simulated.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulationResponse {
     #[prost(message, optional, tag = "1")]
@@ -127,17 +209,42 @@ pub struct SimulationResponse {
     #[prost(message, optional, tag = "2")]
     pub result: ::core::option::Option<Result>,
 }
+impl ::prost::Name for SimulationResponse {
+    const NAME: &'static str = "SimulationResponse";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.SimulationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.SimulationResponse".into()
+    }
+}
 /// MsgData defines the data returned in a Result object during message
 /// execution.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgData {
     #[prost(string, tag = "1")]
     pub msg_type: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for MsgData {
+    const NAME: &'static str = "MsgData";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.MsgData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.MsgData".into()
+    }
+}
 /// TxMsgData defines a list of MsgData. A transaction will have a MsgData object
 /// for each message.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxMsgData {
     /// data field is deprecated and not populated.
@@ -148,27 +255,69 @@ pub struct TxMsgData {
     ///
     /// Since: cosmos-sdk 0.46
     #[prost(message, repeated, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub msg_responses: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for TxMsgData {
+    const NAME: &'static str = "TxMsgData";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.TxMsgData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.TxMsgData".into()
+    }
+}
 /// SearchTxsResult defines a structure for querying txs pageable
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchTxsResult {
     /// Count of all txs
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub total_count: u64,
     /// Count of txs in current page
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub count: u64,
     /// Index of current page, start from 1
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub page_number: u64,
     /// Count of total pages
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub page_total: u64,
     /// Max count txs per page
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub limit: u64,
     /// List of txs in current page
     #[prost(message, repeated, tag = "6")]
     pub txs: ::prost::alloc::vec::Vec<TxResponse>,
 }
+impl ::prost::Name for SearchTxsResult {
+    const NAME: &'static str = "SearchTxsResult";
+    const PACKAGE: &'static str = "cosmos.base.abci.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.abci.v1beta1.SearchTxsResult".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.abci.v1beta1.SearchTxsResult".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<TxResponse>();
+    registry.register::<AbciMessageLog>();
+    registry.register::<StringEvent>();
+    registry.register::<Attribute>();
+    registry.register::<GasInfo>();
+    registry.register::<Result>();
+    registry.register::<SimulationResponse>();
+    registry.register::<MsgData>();
+    registry.register::<TxMsgData>();
+    registry.register::<SearchTxsResult>();
+}
@@ -1,15 +1,47 @@
 // This file is @generated by prost-build.
 /// Pairs defines a repeated slice of Pair objects.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pairs {
     #[prost(message, repeated, tag = "1")]
     pub pairs: ::prost::alloc::vec::Vec<Pair>,
 }
+impl ::prost::Name for Pairs {
+    const NAME: &'static str = "Pairs";
+    const PACKAGE: &'static str = "cosmos.base.kv.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.kv.v1beta1.Pairs".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.kv.v1beta1.Pairs".into()
+    }
+}
 /// Pair defines a key/value bytes tuple.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pair {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for Pair {
+    const NAME: &'static str = "Pair";
+    const PACKAGE: &'static str = "cosmos.base.kv.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.kv.v1beta1.Pair".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.kv.v1beta1.Pair".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Pairs>();
+    registry.register::<Pair>();
+}
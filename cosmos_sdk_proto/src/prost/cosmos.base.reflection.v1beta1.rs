@@ -1,24 +1,62 @@
 // This file is @generated by prost-build.
 /// ListAllInterfacesRequest is the request type of the ListAllInterfaces RPC.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ListAllInterfacesRequest {}
+impl ::prost::Name for ListAllInterfacesRequest {
+    const NAME: &'static str = "ListAllInterfacesRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v1beta1.ListAllInterfacesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v1beta1.ListAllInterfacesRequest".into()
+    }
+}
 /// ListAllInterfacesResponse is the response type of the ListAllInterfaces RPC.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListAllInterfacesResponse {
     /// interface_names is an array of all the registered interfaces.
     #[prost(string, repeated, tag = "1")]
     pub interface_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for ListAllInterfacesResponse {
+    const NAME: &'static str = "ListAllInterfacesResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v1beta1.ListAllInterfacesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v1beta1.ListAllInterfacesResponse".into()
+    }
+}
 /// ListImplementationsRequest is the request type of the ListImplementations
 /// RPC.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListImplementationsRequest {
     /// interface_name defines the interface to query the implementations for.
     #[prost(string, tag = "1")]
     pub interface_name: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ListImplementationsRequest {
+    const NAME: &'static str = "ListImplementationsRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v1beta1.ListImplementationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v1beta1.ListImplementationsRequest".into()
+    }
+}
 /// ListImplementationsResponse is the response type of the ListImplementations
 /// RPC.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListImplementationsResponse {
     #[prost(string, repeated, tag = "1")]
@@ -26,6 +64,18 @@ pub struct ListImplementationsResponse {
         ::prost::alloc::string::String,
     >,
 }
+impl ::prost::Name for ListImplementationsResponse {
+    const NAME: &'static str = "ListImplementationsResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v1beta1.ListImplementationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v1beta1.ListImplementationsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod reflection_service_client {
     #![allow(
@@ -182,3 +232,258 @@ pub mod reflection_service_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod reflection_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ReflectionServiceServer.
+    #[async_trait]
+    pub trait ReflectionService: std::marker::Send + std::marker::Sync + 'static {
+        /// ListAllInterfaces lists all the interfaces registered in the interface
+        /// registry.
+        async fn list_all_interfaces(
+            &self,
+            request: tonic::Request<super::ListAllInterfacesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListAllInterfacesResponse>,
+            tonic::Status,
+        >;
+        /// ListImplementations list all the concrete types that implement a given
+        /// interface.
+        async fn list_implementations(
+            &self,
+            request: tonic::Request<super::ListImplementationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListImplementationsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// ReflectionService defines a service for interface reflection.
+    #[derive(Debug)]
+    pub struct ReflectionServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ReflectionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ReflectionServiceServer<T>
+    where
+        T: ReflectionService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.base.reflection.v1beta1.ReflectionService/ListAllInterfaces" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAllInterfacesSvc<T: ReflectionService>(pub Arc<T>);
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<super::ListAllInterfacesRequest>
+                    for ListAllInterfacesSvc<T> {
+                        type Response = super::ListAllInterfacesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAllInterfacesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::list_all_interfaces(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListAllInterfacesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.reflection.v1beta1.ReflectionService/ListImplementations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListImplementationsSvc<T: ReflectionService>(pub Arc<T>);
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<super::ListImplementationsRequest>
+                    for ListImplementationsSvc<T> {
+                        type Response = super::ListImplementationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListImplementationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::list_implementations(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListImplementationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ReflectionServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.base.reflection.v1beta1.ReflectionService";
+    impl<T> tonic::server::NamedService for ReflectionServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<ListAllInterfacesRequest>();
+    registry.register::<ListAllInterfacesResponse>();
+    registry.register::<ListImplementationsRequest>();
+    registry.register::<ListImplementationsResponse>();
+}
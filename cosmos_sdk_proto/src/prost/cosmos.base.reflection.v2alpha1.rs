@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// AppDescriptor describes a cosmos-sdk based application
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AppDescriptor {
     /// AuthnDescriptor provides information on how to authenticate transactions on the application
@@ -22,7 +24,19 @@ pub struct AppDescriptor {
     #[prost(message, optional, tag = "6")]
     pub tx: ::core::option::Option<TxDescriptor>,
 }
+impl ::prost::Name for AppDescriptor {
+    const NAME: &'static str = "AppDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.AppDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.AppDescriptor".into()
+    }
+}
 /// TxDescriptor describes the accepted transaction type
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxDescriptor {
     /// fullname is the protobuf fullname of the raw transaction type (for instance the tx.Tx type)
@@ -34,18 +48,42 @@ pub struct TxDescriptor {
     #[prost(message, repeated, tag = "2")]
     pub msgs: ::prost::alloc::vec::Vec<MsgDescriptor>,
 }
+impl ::prost::Name for TxDescriptor {
+    const NAME: &'static str = "TxDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.TxDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.TxDescriptor".into()
+    }
+}
 /// AuthnDescriptor provides information on how to sign transactions without relying
 /// on the online RPCs GetTxMetadata and CombineUnsignedTxAndSignatures
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthnDescriptor {
     /// sign_modes defines the supported signature algorithm
     #[prost(message, repeated, tag = "1")]
     pub sign_modes: ::prost::alloc::vec::Vec<SigningModeDescriptor>,
 }
+impl ::prost::Name for AuthnDescriptor {
+    const NAME: &'static str = "AuthnDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.AuthnDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.AuthnDescriptor".into()
+    }
+}
 /// SigningModeDescriptor provides information on a signing flow of the application
 /// NOTE(fdymylja): here we could go as far as providing an entire flow on how
 /// to sign a message given a SigningModeDescriptor, but it's better to think about
 /// this another time
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SigningModeDescriptor {
     /// name defines the unique name of the signing mode
@@ -59,21 +97,57 @@ pub struct SigningModeDescriptor {
     #[prost(string, tag = "3")]
     pub authn_info_provider_method_fullname: ::prost::alloc::string::String,
 }
+impl ::prost::Name for SigningModeDescriptor {
+    const NAME: &'static str = "SigningModeDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.SigningModeDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.SigningModeDescriptor".into()
+    }
+}
 /// ChainDescriptor describes chain information of the application
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChainDescriptor {
     /// id is the chain id
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ChainDescriptor {
+    const NAME: &'static str = "ChainDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.ChainDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.ChainDescriptor".into()
+    }
+}
 /// CodecDescriptor describes the registered interfaces and provides metadata information on the types
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CodecDescriptor {
     /// interfaces is a list of the registerted interfaces descriptors
     #[prost(message, repeated, tag = "1")]
     pub interfaces: ::prost::alloc::vec::Vec<InterfaceDescriptor>,
 }
+impl ::prost::Name for CodecDescriptor {
+    const NAME: &'static str = "CodecDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.CodecDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.CodecDescriptor".into()
+    }
+}
 /// InterfaceDescriptor describes the implementation of an interface
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceDescriptor {
     /// fullname is the name of the interface
@@ -89,7 +163,19 @@ pub struct InterfaceDescriptor {
     #[prost(message, repeated, tag = "3")]
     pub interface_implementers: ::prost::alloc::vec::Vec<InterfaceImplementerDescriptor>,
 }
+impl ::prost::Name for InterfaceDescriptor {
+    const NAME: &'static str = "InterfaceDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.InterfaceDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.InterfaceDescriptor".into()
+    }
+}
 /// InterfaceImplementerDescriptor describes an interface implementer
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceImplementerDescriptor {
     /// fullname is the protobuf queryable name of the interface implementer
@@ -102,8 +188,20 @@ pub struct InterfaceImplementerDescriptor {
     #[prost(string, tag = "2")]
     pub type_url: ::prost::alloc::string::String,
 }
+impl ::prost::Name for InterfaceImplementerDescriptor {
+    const NAME: &'static str = "InterfaceImplementerDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.InterfaceImplementerDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.InterfaceImplementerDescriptor".into()
+    }
+}
 /// InterfaceAcceptingMessageDescriptor describes a protobuf message which contains
 /// an interface represented as a google.protobuf.Any
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterfaceAcceptingMessageDescriptor {
     /// fullname is the protobuf fullname of the type containing the interface
@@ -115,74 +213,242 @@ pub struct InterfaceAcceptingMessageDescriptor {
     #[prost(string, repeated, tag = "2")]
     pub field_descriptor_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for InterfaceAcceptingMessageDescriptor {
+    const NAME: &'static str = "InterfaceAcceptingMessageDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.InterfaceAcceptingMessageDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.InterfaceAcceptingMessageDescriptor".into()
+    }
+}
 /// ConfigurationDescriptor contains metadata information on the sdk.Config
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfigurationDescriptor {
     /// bech32_account_address_prefix is the account address prefix
     #[prost(string, tag = "1")]
     pub bech32_account_address_prefix: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ConfigurationDescriptor {
+    const NAME: &'static str = "ConfigurationDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.ConfigurationDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.ConfigurationDescriptor".into()
+    }
+}
 /// MsgDescriptor describes a cosmos-sdk message that can be delivered with a transaction
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgDescriptor {
     /// msg_type_url contains the TypeURL of a sdk.Msg.
     #[prost(string, tag = "1")]
     pub msg_type_url: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgDescriptor {
+    const NAME: &'static str = "MsgDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.MsgDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.MsgDescriptor".into()
+    }
+}
 /// GetAuthnDescriptorRequest is the request used for the GetAuthnDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetAuthnDescriptorRequest {}
+impl ::prost::Name for GetAuthnDescriptorRequest {
+    const NAME: &'static str = "GetAuthnDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetAuthnDescriptorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetAuthnDescriptorRequest".into()
+    }
+}
 /// GetAuthnDescriptorResponse is the response returned by the GetAuthnDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetAuthnDescriptorResponse {
     /// authn describes how to authenticate to the application when sending transactions
     #[prost(message, optional, tag = "1")]
     pub authn: ::core::option::Option<AuthnDescriptor>,
 }
+impl ::prost::Name for GetAuthnDescriptorResponse {
+    const NAME: &'static str = "GetAuthnDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetAuthnDescriptorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetAuthnDescriptorResponse".into()
+    }
+}
 /// GetChainDescriptorRequest is the request used for the GetChainDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetChainDescriptorRequest {}
+impl ::prost::Name for GetChainDescriptorRequest {
+    const NAME: &'static str = "GetChainDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetChainDescriptorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetChainDescriptorRequest".into()
+    }
+}
 /// GetChainDescriptorResponse is the response returned by the GetChainDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetChainDescriptorResponse {
     /// chain describes application chain information
     #[prost(message, optional, tag = "1")]
     pub chain: ::core::option::Option<ChainDescriptor>,
 }
+impl ::prost::Name for GetChainDescriptorResponse {
+    const NAME: &'static str = "GetChainDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetChainDescriptorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetChainDescriptorResponse".into()
+    }
+}
 /// GetCodecDescriptorRequest is the request used for the GetCodecDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetCodecDescriptorRequest {}
+impl ::prost::Name for GetCodecDescriptorRequest {
+    const NAME: &'static str = "GetCodecDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetCodecDescriptorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetCodecDescriptorRequest".into()
+    }
+}
 /// GetCodecDescriptorResponse is the response returned by the GetCodecDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCodecDescriptorResponse {
     /// codec describes the application codec such as registered interfaces and implementations
     #[prost(message, optional, tag = "1")]
     pub codec: ::core::option::Option<CodecDescriptor>,
 }
+impl ::prost::Name for GetCodecDescriptorResponse {
+    const NAME: &'static str = "GetCodecDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetCodecDescriptorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetCodecDescriptorResponse".into()
+    }
+}
 /// GetConfigurationDescriptorRequest is the request used for the GetConfigurationDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetConfigurationDescriptorRequest {}
+impl ::prost::Name for GetConfigurationDescriptorRequest {
+    const NAME: &'static str = "GetConfigurationDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorRequest".into()
+    }
+}
 /// GetConfigurationDescriptorResponse is the response returned by the GetConfigurationDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetConfigurationDescriptorResponse {
     /// config describes the application's sdk.Config
     #[prost(message, optional, tag = "1")]
     pub config: ::core::option::Option<ConfigurationDescriptor>,
 }
+impl ::prost::Name for GetConfigurationDescriptorResponse {
+    const NAME: &'static str = "GetConfigurationDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetConfigurationDescriptorResponse".into()
+    }
+}
 /// GetQueryServicesDescriptorRequest is the request used for the GetQueryServicesDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetQueryServicesDescriptorRequest {}
+impl ::prost::Name for GetQueryServicesDescriptorRequest {
+    const NAME: &'static str = "GetQueryServicesDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorRequest".into()
+    }
+}
 /// GetQueryServicesDescriptorResponse is the response returned by the GetQueryServicesDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetQueryServicesDescriptorResponse {
     /// queries provides information on the available queryable services
     #[prost(message, optional, tag = "1")]
     pub queries: ::core::option::Option<QueryServicesDescriptor>,
 }
+impl ::prost::Name for GetQueryServicesDescriptorResponse {
+    const NAME: &'static str = "GetQueryServicesDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetQueryServicesDescriptorResponse".into()
+    }
+}
 /// GetTxDescriptorRequest is the request used for the GetTxDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetTxDescriptorRequest {}
+impl ::prost::Name for GetTxDescriptorRequest {
+    const NAME: &'static str = "GetTxDescriptorRequest";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetTxDescriptorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetTxDescriptorRequest".into()
+    }
+}
 /// GetTxDescriptorResponse is the response returned by the GetTxDescriptor RPC
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxDescriptorResponse {
     /// tx provides information on msgs that can be forwarded to the application
@@ -190,14 +456,38 @@ pub struct GetTxDescriptorResponse {
     #[prost(message, optional, tag = "1")]
     pub tx: ::core::option::Option<TxDescriptor>,
 }
+impl ::prost::Name for GetTxDescriptorResponse {
+    const NAME: &'static str = "GetTxDescriptorResponse";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.GetTxDescriptorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.GetTxDescriptorResponse".into()
+    }
+}
 /// QueryServicesDescriptor contains the list of cosmos-sdk queriable services
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryServicesDescriptor {
     /// query_services is a list of cosmos-sdk QueryServiceDescriptor
     #[prost(message, repeated, tag = "1")]
     pub query_services: ::prost::alloc::vec::Vec<QueryServiceDescriptor>,
 }
+impl ::prost::Name for QueryServicesDescriptor {
+    const NAME: &'static str = "QueryServicesDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.QueryServicesDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.QueryServicesDescriptor".into()
+    }
+}
 /// QueryServiceDescriptor describes a cosmos-sdk queryable service
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryServiceDescriptor {
     /// fullname is the protobuf fullname of the service descriptor
@@ -210,9 +500,21 @@ pub struct QueryServiceDescriptor {
     #[prost(message, repeated, tag = "3")]
     pub methods: ::prost::alloc::vec::Vec<QueryMethodDescriptor>,
 }
+impl ::prost::Name for QueryServiceDescriptor {
+    const NAME: &'static str = "QueryServiceDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.QueryServiceDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.QueryServiceDescriptor".into()
+    }
+}
 /// QueryMethodDescriptor describes a queryable method of a query service
 /// no other info is provided beside method name and tendermint queryable path
 /// because it would be redundant with the grpc reflection service
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryMethodDescriptor {
     /// name is the protobuf name (not fullname) of the method
@@ -223,6 +525,18 @@ pub struct QueryMethodDescriptor {
     #[prost(string, tag = "2")]
     pub full_query_path: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryMethodDescriptor {
+    const NAME: &'static str = "QueryMethodDescriptor";
+    const PACKAGE: &'static str = "cosmos.base.reflection.v2alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.reflection.v2alpha1.QueryMethodDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.reflection.v2alpha1.QueryMethodDescriptor".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod reflection_service_client {
     #![allow(
@@ -499,3 +813,515 @@ pub mod reflection_service_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod reflection_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ReflectionServiceServer.
+    #[async_trait]
+    pub trait ReflectionService: std::marker::Send + std::marker::Sync + 'static {
+        /// GetAuthnDescriptor returns information on how to authenticate transactions in the application
+        /// NOTE: this RPC is still experimental and might be subject to breaking changes or removal in
+        /// future releases of the cosmos-sdk.
+        async fn get_authn_descriptor(
+            &self,
+            request: tonic::Request<super::GetAuthnDescriptorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAuthnDescriptorResponse>,
+            tonic::Status,
+        >;
+        /// GetChainDescriptor returns the description of the chain
+        async fn get_chain_descriptor(
+            &self,
+            request: tonic::Request<super::GetChainDescriptorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetChainDescriptorResponse>,
+            tonic::Status,
+        >;
+        /// GetCodecDescriptor returns the descriptor of the codec of the application
+        async fn get_codec_descriptor(
+            &self,
+            request: tonic::Request<super::GetCodecDescriptorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCodecDescriptorResponse>,
+            tonic::Status,
+        >;
+        /// GetConfigurationDescriptor returns the descriptor for the sdk.Config of the application
+        async fn get_configuration_descriptor(
+            &self,
+            request: tonic::Request<super::GetConfigurationDescriptorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetConfigurationDescriptorResponse>,
+            tonic::Status,
+        >;
+        /// GetQueryServicesDescriptor returns the available gRPC queryable services of the application
+        async fn get_query_services_descriptor(
+            &self,
+            request: tonic::Request<super::GetQueryServicesDescriptorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetQueryServicesDescriptorResponse>,
+            tonic::Status,
+        >;
+        /// GetTxDescriptor returns information on the used transaction object and available msgs that can be used
+        async fn get_tx_descriptor(
+            &self,
+            request: tonic::Request<super::GetTxDescriptorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTxDescriptorResponse>,
+            tonic::Status,
+        >;
+    }
+    /// ReflectionService defines a service for application reflection.
+    #[derive(Debug)]
+    pub struct ReflectionServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ReflectionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ReflectionServiceServer<T>
+    where
+        T: ReflectionService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.base.reflection.v2alpha1.ReflectionService/GetAuthnDescriptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAuthnDescriptorSvc<T: ReflectionService>(pub Arc<T>);
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<super::GetAuthnDescriptorRequest>
+                    for GetAuthnDescriptorSvc<T> {
+                        type Response = super::GetAuthnDescriptorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAuthnDescriptorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::get_authn_descriptor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAuthnDescriptorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.reflection.v2alpha1.ReflectionService/GetChainDescriptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetChainDescriptorSvc<T: ReflectionService>(pub Arc<T>);
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<super::GetChainDescriptorRequest>
+                    for GetChainDescriptorSvc<T> {
+                        type Response = super::GetChainDescriptorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetChainDescriptorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::get_chain_descriptor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetChainDescriptorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.reflection.v2alpha1.ReflectionService/GetCodecDescriptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCodecDescriptorSvc<T: ReflectionService>(pub Arc<T>);
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<super::GetCodecDescriptorRequest>
+                    for GetCodecDescriptorSvc<T> {
+                        type Response = super::GetCodecDescriptorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCodecDescriptorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::get_codec_descriptor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCodecDescriptorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.reflection.v2alpha1.ReflectionService/GetConfigurationDescriptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetConfigurationDescriptorSvc<T: ReflectionService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<
+                        super::GetConfigurationDescriptorRequest,
+                    > for GetConfigurationDescriptorSvc<T> {
+                        type Response = super::GetConfigurationDescriptorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetConfigurationDescriptorRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::get_configuration_descriptor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetConfigurationDescriptorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.reflection.v2alpha1.ReflectionService/GetQueryServicesDescriptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetQueryServicesDescriptorSvc<T: ReflectionService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<
+                        super::GetQueryServicesDescriptorRequest,
+                    > for GetQueryServicesDescriptorSvc<T> {
+                        type Response = super::GetQueryServicesDescriptorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetQueryServicesDescriptorRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::get_query_services_descriptor(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetQueryServicesDescriptorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.reflection.v2alpha1.ReflectionService/GetTxDescriptor" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTxDescriptorSvc<T: ReflectionService>(pub Arc<T>);
+                    impl<
+                        T: ReflectionService,
+                    > tonic::server::UnaryService<super::GetTxDescriptorRequest>
+                    for GetTxDescriptorSvc<T> {
+                        type Response = super::GetTxDescriptorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTxDescriptorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReflectionService>::get_tx_descriptor(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTxDescriptorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ReflectionServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.base.reflection.v2alpha1.ReflectionService";
+    impl<T> tonic::server::NamedService for ReflectionServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<AppDescriptor>();
+    registry.register::<TxDescriptor>();
+    registry.register::<AuthnDescriptor>();
+    registry.register::<SigningModeDescriptor>();
+    registry.register::<ChainDescriptor>();
+    registry.register::<CodecDescriptor>();
+    registry.register::<InterfaceDescriptor>();
+    registry.register::<InterfaceImplementerDescriptor>();
+    registry.register::<InterfaceAcceptingMessageDescriptor>();
+    registry.register::<ConfigurationDescriptor>();
+    registry.register::<MsgDescriptor>();
+    registry.register::<GetAuthnDescriptorRequest>();
+    registry.register::<GetAuthnDescriptorResponse>();
+    registry.register::<GetChainDescriptorRequest>();
+    registry.register::<GetChainDescriptorResponse>();
+    registry.register::<GetCodecDescriptorRequest>();
+    registry.register::<GetCodecDescriptorResponse>();
+    registry.register::<GetConfigurationDescriptorRequest>();
+    registry.register::<GetConfigurationDescriptorResponse>();
+    registry.register::<GetQueryServicesDescriptorRequest>();
+    registry.register::<GetQueryServicesDescriptorResponse>();
+    registry.register::<GetTxDescriptorRequest>();
+    registry.register::<GetTxDescriptorResponse>();
+    registry.register::<QueryServicesDescriptor>();
+    registry.register::<QueryServiceDescriptor>();
+    registry.register::<QueryMethodDescriptor>();
+}
@@ -1,37 +1,69 @@
 // This file is @generated by prost-build.
 /// Snapshot contains Tendermint state sync snapshot info.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Snapshot {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(uint32, tag = "2")]
     pub format: u32,
     #[prost(uint32, tag = "3")]
     pub chunks: u32,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "5")]
     pub metadata: ::core::option::Option<Metadata>,
 }
+impl ::prost::Name for Snapshot {
+    const NAME: &'static str = "Snapshot";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.Snapshot".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.Snapshot".into()
+    }
+}
 /// Metadata contains SDK-specific snapshot metadata.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Metadata {
     /// SHA-256 chunk hashes
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub chunk_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.Metadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.Metadata".into()
+    }
+}
 /// SnapshotItem is an item contained in a rootmulti.Store snapshot.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotItem {
     /// item is the specific type of snapshot item.
     #[prost(oneof = "snapshot_item::Item", tags = "1, 2, 3, 4, 5, 6")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub item: ::core::option::Option<snapshot_item::Item>,
 }
 /// Nested message and enum types in `SnapshotItem`.
 pub mod snapshot_item {
     /// item is the specific type of snapshot item.
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Item {
         #[prost(message, tag = "1")]
@@ -48,33 +80,72 @@ pub mod snapshot_item {
         Schema(super::SnapshotSchema),
     }
 }
+impl ::prost::Name for SnapshotItem {
+    const NAME: &'static str = "SnapshotItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotItem".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotItem".into()
+    }
+}
 /// SnapshotStoreItem contains metadata about a snapshotted store.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotStoreItem {
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
 }
+impl ::prost::Name for SnapshotStoreItem {
+    const NAME: &'static str = "SnapshotStoreItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotStoreItem".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotStoreItem".into()
+    }
+}
 /// SnapshotIAVLItem is an exported IAVL node.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotIavlItem {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
     /// version is block height
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub version: i64,
     /// height is depth of the tree.
     #[prost(int32, tag = "4")]
     pub height: i32,
 }
+impl ::prost::Name for SnapshotIavlItem {
+    const NAME: &'static str = "SnapshotIAVLItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotIAVLItem".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotIAVLItem".into()
+    }
+}
 /// SnapshotExtensionMeta contains metadata about an external snapshotter.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotExtensionMeta {
     #[prost(string, tag = "1")]
@@ -82,29 +153,92 @@ pub struct SnapshotExtensionMeta {
     #[prost(uint32, tag = "2")]
     pub format: u32,
 }
+impl ::prost::Name for SnapshotExtensionMeta {
+    const NAME: &'static str = "SnapshotExtensionMeta";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotExtensionMeta".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotExtensionMeta".into()
+    }
+}
 /// SnapshotExtensionPayload contains payloads of an external snapshotter.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotExtensionPayload {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub payload: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for SnapshotExtensionPayload {
+    const NAME: &'static str = "SnapshotExtensionPayload";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotExtensionPayload".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotExtensionPayload".into()
+    }
+}
 /// SnapshotKVItem is an exported Key/Value Pair
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotKvItem {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for SnapshotKvItem {
+    const NAME: &'static str = "SnapshotKVItem";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotKVItem".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotKVItem".into()
+    }
+}
 /// SnapshotSchema is an exported schema of smt store
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotSchema {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for SnapshotSchema {
+    const NAME: &'static str = "SnapshotSchema";
+    const PACKAGE: &'static str = "cosmos.base.snapshots.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.snapshots.v1beta1.SnapshotSchema".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.snapshots.v1beta1.SnapshotSchema".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Snapshot>();
+    registry.register::<Metadata>();
+    registry.register::<SnapshotItem>();
+    registry.register::<SnapshotStoreItem>();
+    registry.register::<SnapshotIavlItem>();
+    registry.register::<SnapshotExtensionMeta>();
+    registry.register::<SnapshotExtensionPayload>();
+    registry.register::<SnapshotKvItem>();
+    registry.register::<SnapshotSchema>();
+}
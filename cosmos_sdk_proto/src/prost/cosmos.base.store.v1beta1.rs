@@ -1,15 +1,30 @@
 // This file is @generated by prost-build.
 /// CommitInfo defines commit information used by the multi-store when committing
 /// a version/height.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommitInfo {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub version: i64,
     #[prost(message, repeated, tag = "2")]
     pub store_infos: ::prost::alloc::vec::Vec<StoreInfo>,
 }
+impl ::prost::Name for CommitInfo {
+    const NAME: &'static str = "CommitInfo";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.store.v1beta1.CommitInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.store.v1beta1.CommitInfo".into()
+    }
+}
 /// StoreInfo defines store-specific commit information. It contains a reference
 /// between a store name and the commit ID.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreInfo {
     #[prost(string, tag = "1")]
@@ -17,20 +32,46 @@ pub struct StoreInfo {
     #[prost(message, optional, tag = "2")]
     pub commit_id: ::core::option::Option<CommitId>,
 }
+impl ::prost::Name for StoreInfo {
+    const NAME: &'static str = "StoreInfo";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.store.v1beta1.StoreInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.store.v1beta1.StoreInfo".into()
+    }
+}
 /// CommitID defines the committment information when a specific store is
 /// committed.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommitId {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub version: i64,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub hash: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for CommitId {
+    const NAME: &'static str = "CommitID";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.store.v1beta1.CommitID".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.store.v1beta1.CommitID".into()
+    }
+}
 /// StoreKVPair is a KVStore KVPair used for listening to state changes (Sets and Deletes)
 /// It optionally includes the StoreKey for the originating KVStore and a Boolean flag to distinguish between Sets and
 /// Deletes
 ///
 /// Since: cosmos-sdk 0.43
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StoreKvPair {
     /// the store key for the KVStore this pair originates from
@@ -40,12 +81,26 @@ pub struct StoreKvPair {
     #[prost(bool, tag = "2")]
     pub delete: bool,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for StoreKvPair {
+    const NAME: &'static str = "StoreKVPair";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.store.v1beta1.StoreKVPair".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.store.v1beta1.StoreKVPair".into()
+    }
+}
 /// BlockMetadata contains all the abci event data of a block
 /// the file streamer dump them into files together with the state changes.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BlockMetadata {
     #[prost(message, optional, tag = "1")]
@@ -74,6 +129,8 @@ pub struct BlockMetadata {
 /// Nested message and enum types in `BlockMetadata`.
 pub mod block_metadata {
     /// DeliverTx encapulate deliver tx request and response.
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DeliverTx {
         #[prost(message, optional, tag = "1")]
@@ -85,4 +142,34 @@ pub mod block_metadata {
             crate::tendermint::abci::ResponseDeliverTx,
         >,
     }
+    impl ::prost::Name for DeliverTx {
+        const NAME: &'static str = "DeliverTx";
+        const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.base.store.v1beta1.BlockMetadata.DeliverTx".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.base.store.v1beta1.BlockMetadata.DeliverTx".into()
+        }
+    }
+}
+impl ::prost::Name for BlockMetadata {
+    const NAME: &'static str = "BlockMetadata";
+    const PACKAGE: &'static str = "cosmos.base.store.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.store.v1beta1.BlockMetadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.store.v1beta1.BlockMetadata".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<CommitInfo>();
+    registry.register::<StoreInfo>();
+    registry.register::<CommitId>();
+    registry.register::<StoreKvPair>();
+    registry.register::<BlockMetadata>();
+    registry.register::<block_metadata::DeliverTx>();
 }
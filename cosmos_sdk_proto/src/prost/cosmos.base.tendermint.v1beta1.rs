@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// Block is tendermint type Block, with the Header proposer address
 /// field converted to bech32 string.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Block {
     #[prost(message, optional, tag = "1")]
@@ -18,7 +20,19 @@ pub struct Block {
         crate::tendermint::types::Commit,
     >,
 }
+impl ::prost::Name for Block {
+    const NAME: &'static str = "Block";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.Block".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.Block".into()
+    }
+}
 /// Header defines the structure of a Tendermint block header.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Header {
     /// basic block info
@@ -29,8 +43,10 @@ pub struct Header {
     #[prost(string, tag = "2")]
     pub chain_id: ::prost::alloc::string::String,
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// prev block info
     #[prost(message, optional, tag = "5")]
@@ -41,31 +57,39 @@ pub struct Header {
     ///
     /// commit from validators from the last block
     #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub last_commit_hash: ::prost::alloc::vec::Vec<u8>,
     /// transactions
     #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data_hash: ::prost::alloc::vec::Vec<u8>,
     /// hashes from the app output from the prev block
     ///
     /// validators for the current block
     #[prost(bytes = "vec", tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub validators_hash: ::prost::alloc::vec::Vec<u8>,
     /// validators for the next block
     #[prost(bytes = "vec", tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub next_validators_hash: ::prost::alloc::vec::Vec<u8>,
     /// consensus params for current block
     #[prost(bytes = "vec", tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub consensus_hash: ::prost::alloc::vec::Vec<u8>,
     /// state after txs from the previous block
     #[prost(bytes = "vec", tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub app_hash: ::prost::alloc::vec::Vec<u8>,
     /// root hash of all results from the txs from the previous block
     #[prost(bytes = "vec", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub last_results_hash: ::prost::alloc::vec::Vec<u8>,
     /// consensus info
     ///
     /// evidence included in the block
     #[prost(bytes = "vec", tag = "13")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub evidence_hash: ::prost::alloc::vec::Vec<u8>,
     /// proposer_address is the original block proposer address, formatted as a Bech32 string.
     /// In Tendermint, this type is `bytes`, but in the SDK, we convert it to a Bech32 string
@@ -75,21 +99,47 @@ pub struct Header {
     #[prost(string, tag = "14")]
     pub proposer_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.Header".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.Header".into()
+    }
+}
 /// GetValidatorSetByHeightRequest is the request type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetValidatorSetByHeightRequest {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     /// pagination defines an pagination for the request.
     #[prost(message, optional, tag = "2")]
     pub pagination: ::core::option::Option<super::super::query::v1beta1::PageRequest>,
 }
+impl ::prost::Name for GetValidatorSetByHeightRequest {
+    const NAME: &'static str = "GetValidatorSetByHeightRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightRequest".into()
+    }
+}
 /// GetValidatorSetByHeightResponse is the response type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetValidatorSetByHeightResponse {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block_height: i64,
     #[prost(message, repeated, tag = "2")]
     pub validators: ::prost::alloc::vec::Vec<Validator>,
@@ -97,19 +147,44 @@ pub struct GetValidatorSetByHeightResponse {
     #[prost(message, optional, tag = "3")]
     pub pagination: ::core::option::Option<super::super::query::v1beta1::PageResponse>,
 }
+impl ::prost::Name for GetValidatorSetByHeightResponse {
+    const NAME: &'static str = "GetValidatorSetByHeightResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetValidatorSetByHeightResponse".into()
+    }
+}
 /// GetLatestValidatorSetRequest is the request type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLatestValidatorSetRequest {
     /// pagination defines an pagination for the request.
     #[prost(message, optional, tag = "1")]
     pub pagination: ::core::option::Option<super::super::query::v1beta1::PageRequest>,
 }
+impl ::prost::Name for GetLatestValidatorSetRequest {
+    const NAME: &'static str = "GetLatestValidatorSetRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetLatestValidatorSetRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetLatestValidatorSetRequest".into()
+    }
+}
 /// GetLatestValidatorSetResponse is the response type for the
 /// Query/GetValidatorSetByHeight RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLatestValidatorSetResponse {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block_height: i64,
     #[prost(message, repeated, tag = "2")]
     pub validators: ::prost::alloc::vec::Vec<Validator>,
@@ -117,27 +192,67 @@ pub struct GetLatestValidatorSetResponse {
     #[prost(message, optional, tag = "3")]
     pub pagination: ::core::option::Option<super::super::query::v1beta1::PageResponse>,
 }
+impl ::prost::Name for GetLatestValidatorSetResponse {
+    const NAME: &'static str = "GetLatestValidatorSetResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetLatestValidatorSetResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetLatestValidatorSetResponse".into()
+    }
+}
 /// Validator is the type for the validator-set.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Validator {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub pub_key: ::core::option::Option<::prost_types::Any>,
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub voting_power: i64,
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposer_priority: i64,
 }
+impl ::prost::Name for Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.Validator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.Validator".into()
+    }
+}
 /// GetBlockByHeightRequest is the request type for the Query/GetBlockByHeight
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetBlockByHeightRequest {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for GetBlockByHeightRequest {
+    const NAME: &'static str = "GetBlockByHeightRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetBlockByHeightRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetBlockByHeightRequest".into()
+    }
+}
 /// GetBlockByHeightResponse is the response type for the Query/GetBlockByHeight
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag = "1")]
@@ -153,12 +268,36 @@ pub struct GetBlockByHeightResponse {
     #[prost(message, optional, tag = "3")]
     pub sdk_block: ::core::option::Option<Block>,
 }
+impl ::prost::Name for GetBlockByHeightResponse {
+    const NAME: &'static str = "GetBlockByHeightResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetBlockByHeightResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetBlockByHeightResponse".into()
+    }
+}
 /// GetLatestBlockRequest is the request type for the Query/GetLatestBlock RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetLatestBlockRequest {}
+impl ::prost::Name for GetLatestBlockRequest {
+    const NAME: &'static str = "GetLatestBlockRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetLatestBlockRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetLatestBlockRequest".into()
+    }
+}
 /// GetLatestBlockResponse is the response type for the Query/GetLatestBlock RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetLatestBlockResponse {
     #[prost(message, optional, tag = "1")]
@@ -174,20 +313,68 @@ pub struct GetLatestBlockResponse {
     #[prost(message, optional, tag = "3")]
     pub sdk_block: ::core::option::Option<Block>,
 }
+impl ::prost::Name for GetLatestBlockResponse {
+    const NAME: &'static str = "GetLatestBlockResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetLatestBlockResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetLatestBlockResponse".into()
+    }
+}
 /// GetSyncingRequest is the request type for the Query/GetSyncing RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetSyncingRequest {}
+impl ::prost::Name for GetSyncingRequest {
+    const NAME: &'static str = "GetSyncingRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetSyncingRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetSyncingRequest".into()
+    }
+}
 /// GetSyncingResponse is the response type for the Query/GetSyncing RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetSyncingResponse {
     #[prost(bool, tag = "1")]
     pub syncing: bool,
 }
+impl ::prost::Name for GetSyncingResponse {
+    const NAME: &'static str = "GetSyncingResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetSyncingResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetSyncingResponse".into()
+    }
+}
 /// GetNodeInfoRequest is the request type for the Query/GetNodeInfo RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetNodeInfoRequest {}
+impl ::prost::Name for GetNodeInfoRequest {
+    const NAME: &'static str = "GetNodeInfoRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetNodeInfoRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetNodeInfoRequest".into()
+    }
+}
 /// GetNodeInfoResponse is the response type for the Query/GetNodeInfo RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetNodeInfoResponse {
     #[prost(message, optional, tag = "1")]
@@ -197,7 +384,19 @@ pub struct GetNodeInfoResponse {
     #[prost(message, optional, tag = "2")]
     pub application_version: ::core::option::Option<VersionInfo>,
 }
+impl ::prost::Name for GetNodeInfoResponse {
+    const NAME: &'static str = "GetNodeInfoResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetNodeInfoResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetNodeInfoResponse".into()
+    }
+}
 /// VersionInfo is the type for the GetNodeInfoResponse message.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VersionInfo {
     #[prost(string, tag = "1")]
@@ -218,7 +417,19 @@ pub struct VersionInfo {
     #[prost(string, tag = "8")]
     pub cosmos_sdk_version: ::prost::alloc::string::String,
 }
+impl ::prost::Name for VersionInfo {
+    const NAME: &'static str = "VersionInfo";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.VersionInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.VersionInfo".into()
+    }
+}
 /// Module is the type for VersionInfo
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Module {
     /// module path
@@ -231,23 +442,49 @@ pub struct Module {
     #[prost(string, tag = "3")]
     pub sum: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Module {
+    const NAME: &'static str = "Module";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.Module".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.Module".into()
+    }
+}
 /// ABCIQueryRequest defines the request structure for the ABCIQuery gRPC query.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciQueryRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "2")]
     pub path: ::prost::alloc::string::String,
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(bool, tag = "4")]
     pub prove: bool,
 }
+impl ::prost::Name for AbciQueryRequest {
+    const NAME: &'static str = "ABCIQueryRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.ABCIQueryRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.ABCIQueryRequest".into()
+    }
+}
 /// ABCIQueryResponse defines the response structure for the ABCIQuery gRPC
 /// query.
 ///
 /// Note: This type is a duplicate of the ResponseQuery proto type defined in
 /// Tendermint.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciQueryResponse {
     #[prost(uint32, tag = "1")]
@@ -259,42 +496,84 @@ pub struct AbciQueryResponse {
     #[prost(string, tag = "4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: i64,
     #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "8")]
     pub proof_ops: ::core::option::Option<ProofOps>,
     #[prost(int64, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(string, tag = "10")]
     pub codespace: ::prost::alloc::string::String,
 }
+impl ::prost::Name for AbciQueryResponse {
+    const NAME: &'static str = "ABCIQueryResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.ABCIQueryResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.ABCIQueryResponse".into()
+    }
+}
 /// ProofOp defines an operation used for calculating Merkle root. The data could
 /// be arbitrary format, providing nessecary data for example neighbouring node
 /// hash.
 ///
 /// Note: This type is a duplicate of the ProofOp proto type defined in
 /// Tendermint.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofOp {
     #[prost(string, tag = "1")]
     pub r#type: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for ProofOp {
+    const NAME: &'static str = "ProofOp";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.ProofOp".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.ProofOp".into()
+    }
+}
 /// ProofOps is Merkle proof defined by the list of ProofOps.
 ///
 /// Note: This type is a duplicate of the ProofOps proto type defined in
 /// Tendermint.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofOps {
     #[prost(message, repeated, tag = "1")]
     pub ops: ::prost::alloc::vec::Vec<ProofOp>,
 }
+impl ::prost::Name for ProofOps {
+    const NAME: &'static str = "ProofOps";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.ProofOps".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.ProofOps".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod service_client {
     #![allow(
@@ -603,3 +882,536 @@ pub mod service_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ServiceServer.
+    #[async_trait]
+    pub trait Service: std::marker::Send + std::marker::Sync + 'static {
+        /// GetNodeInfo queries the current node info.
+        async fn get_node_info(
+            &self,
+            request: tonic::Request<super::GetNodeInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNodeInfoResponse>,
+            tonic::Status,
+        >;
+        /// GetSyncing queries node syncing.
+        async fn get_syncing(
+            &self,
+            request: tonic::Request<super::GetSyncingRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetSyncingResponse>,
+            tonic::Status,
+        >;
+        /// GetLatestBlock returns the latest block.
+        async fn get_latest_block(
+            &self,
+            request: tonic::Request<super::GetLatestBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestBlockResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockByHeight queries block for given height.
+        async fn get_block_by_height(
+            &self,
+            request: tonic::Request<super::GetBlockByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockByHeightResponse>,
+            tonic::Status,
+        >;
+        /// GetLatestValidatorSet queries latest validator-set.
+        async fn get_latest_validator_set(
+            &self,
+            request: tonic::Request<super::GetLatestValidatorSetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestValidatorSetResponse>,
+            tonic::Status,
+        >;
+        /// GetValidatorSetByHeight queries validator-set at a given height.
+        async fn get_validator_set_by_height(
+            &self,
+            request: tonic::Request<super::GetValidatorSetByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetValidatorSetByHeightResponse>,
+            tonic::Status,
+        >;
+        /// ABCIQuery defines a query handler that supports ABCI queries directly to
+        /// the application, bypassing Tendermint completely. The ABCI query must
+        /// contain a valid and supported path, including app, custom, p2p, and store.
+        ///
+        /// Since: cosmos-sdk 0.46
+        async fn abci_query(
+            &self,
+            request: tonic::Request<super::AbciQueryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AbciQueryResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Service defines the gRPC querier service for tendermint queries.
+    #[derive(Debug)]
+    pub struct ServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ServiceServer<T>
+    where
+        T: Service,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNodeInfoSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetNodeInfoRequest>
+                    for GetNodeInfoSvc<T> {
+                        type Response = super::GetNodeInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetNodeInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_node_info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetNodeInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/GetSyncing" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSyncingSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetSyncingRequest>
+                    for GetSyncingSvc<T> {
+                        type Response = super::GetSyncingResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSyncingRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_syncing(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetSyncingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLatestBlockSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetLatestBlockRequest>
+                    for GetLatestBlockSvc<T> {
+                        type Response = super::GetLatestBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLatestBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_latest_block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLatestBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/GetBlockByHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockByHeightSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetBlockByHeightRequest>
+                    for GetBlockByHeightSvc<T> {
+                        type Response = super::GetBlockByHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockByHeightRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_block_by_height(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockByHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/GetLatestValidatorSet" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLatestValidatorSetSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetLatestValidatorSetRequest>
+                    for GetLatestValidatorSetSvc<T> {
+                        type Response = super::GetLatestValidatorSetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetLatestValidatorSetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_latest_validator_set(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLatestValidatorSetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/GetValidatorSetByHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetValidatorSetByHeightSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetValidatorSetByHeightRequest>
+                    for GetValidatorSetByHeightSvc<T> {
+                        type Response = super::GetValidatorSetByHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetValidatorSetByHeightRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_validator_set_by_height(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetValidatorSetByHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.base.tendermint.v1beta1.Service/ABCIQuery" => {
+                    #[allow(non_camel_case_types)]
+                    struct ABCIQuerySvc<T: Service>(pub Arc<T>);
+                    impl<T: Service> tonic::server::UnaryService<super::AbciQueryRequest>
+                    for ABCIQuerySvc<T> {
+                        type Response = super::AbciQueryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AbciQueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::abci_query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ABCIQuerySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.base.tendermint.v1beta1.Service";
+    impl<T> tonic::server::NamedService for ServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Block>();
+    registry.register::<Header>();
+    registry.register::<GetValidatorSetByHeightRequest>();
+    registry.register::<GetValidatorSetByHeightResponse>();
+    registry.register::<GetLatestValidatorSetRequest>();
+    registry.register::<GetLatestValidatorSetResponse>();
+    registry.register::<Validator>();
+    registry.register::<GetBlockByHeightRequest>();
+    registry.register::<GetBlockByHeightResponse>();
+    registry.register::<GetLatestBlockRequest>();
+    registry.register::<GetLatestBlockResponse>();
+    registry.register::<GetSyncingRequest>();
+    registry.register::<GetSyncingResponse>();
+    registry.register::<GetNodeInfoRequest>();
+    registry.register::<GetNodeInfoResponse>();
+    registry.register::<VersionInfo>();
+    registry.register::<Module>();
+    registry.register::<AbciQueryRequest>();
+    registry.register::<AbciQueryResponse>();
+    registry.register::<ProofOp>();
+    registry.register::<ProofOps>();
+}
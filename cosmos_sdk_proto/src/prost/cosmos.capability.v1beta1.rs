@@ -1,13 +1,28 @@
 // This file is @generated by prost-build.
 /// Capability defines an implementation of an object capability. The index
 /// provided to a Capability must be globally unique.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Capability {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: u64,
 }
+impl ::prost::Name for Capability {
+    const NAME: &'static str = "Capability";
+    const PACKAGE: &'static str = "cosmos.capability.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.capability.v1beta1.Capability".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.capability.v1beta1.Capability".into()
+    }
+}
 /// Owner defines a single capability owner. An owner is defined by the name of
 /// capability and the module name.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Owner {
     #[prost(string, tag = "1")]
@@ -15,31 +30,88 @@ pub struct Owner {
     #[prost(string, tag = "2")]
     pub name: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Owner {
+    const NAME: &'static str = "Owner";
+    const PACKAGE: &'static str = "cosmos.capability.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.capability.v1beta1.Owner".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.capability.v1beta1.Owner".into()
+    }
+}
 /// CapabilityOwners defines a set of owners of a single Capability. The set of
 /// owners must be unique.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CapabilityOwners {
     #[prost(message, repeated, tag = "1")]
     pub owners: ::prost::alloc::vec::Vec<Owner>,
 }
+impl ::prost::Name for CapabilityOwners {
+    const NAME: &'static str = "CapabilityOwners";
+    const PACKAGE: &'static str = "cosmos.capability.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.capability.v1beta1.CapabilityOwners".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.capability.v1beta1.CapabilityOwners".into()
+    }
+}
 /// GenesisOwners defines the capability owners with their corresponding index.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisOwners {
     /// index is the index of the capability owner.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: u64,
     /// index_owners are the owners at the given index.
     #[prost(message, optional, tag = "2")]
     pub index_owners: ::core::option::Option<CapabilityOwners>,
 }
+impl ::prost::Name for GenesisOwners {
+    const NAME: &'static str = "GenesisOwners";
+    const PACKAGE: &'static str = "cosmos.capability.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.capability.v1beta1.GenesisOwners".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.capability.v1beta1.GenesisOwners".into()
+    }
+}
 /// GenesisState defines the capability module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// index is the capability global index.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: u64,
     /// owners represents a map from index to owners of the capability index
     /// index key is string to allow amino marshalling.
     #[prost(message, repeated, tag = "2")]
     pub owners: ::prost::alloc::vec::Vec<GenesisOwners>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.capability.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.capability.v1beta1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.capability.v1beta1.GenesisState".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Capability>();
+    registry.register::<Owner>();
+    registry.register::<CapabilityOwners>();
+    registry.register::<GenesisOwners>();
+    registry.register::<GenesisState>();
+}
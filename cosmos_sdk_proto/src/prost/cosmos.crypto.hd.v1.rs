@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// BIP44Params is used as path field in ledger item in Record.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Bip44Params {
     /// purpose is a constant set to 44' (or 0x8000002C) following the BIP43 recommendation
@@ -19,3 +21,18 @@ pub struct Bip44Params {
     #[prost(uint32, tag = "5")]
     pub address_index: u32,
 }
+impl ::prost::Name for Bip44Params {
+    const NAME: &'static str = "BIP44Params";
+    const PACKAGE: &'static str = "cosmos.crypto.hd.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.crypto.hd.v1.BIP44Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.crypto.hd.v1.BIP44Params".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Bip44Params>();
+}
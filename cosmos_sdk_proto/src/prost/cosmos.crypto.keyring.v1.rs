@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// Record is used for representing a key in the keyring.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Record {
     /// name represents a name of Record
@@ -7,33 +9,86 @@ pub struct Record {
     pub name: ::prost::alloc::string::String,
     /// pub_key represents a public key in any format
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub pub_key: ::core::option::Option<::prost_types::Any>,
     /// Record contains one of the following items
     #[prost(oneof = "record::Item", tags = "3, 4, 5, 6")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub item: ::core::option::Option<record::Item>,
 }
 /// Nested message and enum types in `Record`.
 pub mod record {
     /// Item is a keyring item stored in a keyring backend.
     /// Local item
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Local {
         #[prost(message, optional, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
         pub priv_key: ::core::option::Option<::prost_types::Any>,
     }
+    impl ::prost::Name for Local {
+        const NAME: &'static str = "Local";
+        const PACKAGE: &'static str = "cosmos.crypto.keyring.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.crypto.keyring.v1.Record.Local".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.crypto.keyring.v1.Record.Local".into()
+        }
+    }
     /// Ledger item
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Ledger {
         #[prost(message, optional, tag = "1")]
         pub path: ::core::option::Option<super::super::super::hd::v1::Bip44Params>,
     }
+    impl ::prost::Name for Ledger {
+        const NAME: &'static str = "Ledger";
+        const PACKAGE: &'static str = "cosmos.crypto.keyring.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.crypto.keyring.v1.Record.Ledger".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.crypto.keyring.v1.Record.Ledger".into()
+        }
+    }
     /// Multi item
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Multi {}
+    impl ::prost::Name for Multi {
+        const NAME: &'static str = "Multi";
+        const PACKAGE: &'static str = "cosmos.crypto.keyring.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.crypto.keyring.v1.Record.Multi".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.crypto.keyring.v1.Record.Multi".into()
+        }
+    }
     /// Offline item
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Offline {}
+    impl ::prost::Name for Offline {
+        const NAME: &'static str = "Offline";
+        const PACKAGE: &'static str = "cosmos.crypto.keyring.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.crypto.keyring.v1.Record.Offline".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.crypto.keyring.v1.Record.Offline".into()
+        }
+    }
     /// Record contains one of the following items
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Item {
         /// local stores the private key locally.
@@ -50,3 +105,22 @@ pub mod record {
         Offline(Offline),
     }
 }
+impl ::prost::Name for Record {
+    const NAME: &'static str = "Record";
+    const PACKAGE: &'static str = "cosmos.crypto.keyring.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.crypto.keyring.v1.Record".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.crypto.keyring.v1.Record".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Record>();
+    registry.register::<record::Local>();
+    registry.register::<record::Ledger>();
+    registry.register::<record::Multi>();
+    registry.register::<record::Offline>();
+}
@@ -2,10 +2,28 @@
 /// LegacyAminoPubKey specifies a public key type
 /// which nests multiple public keys and a threshold,
 /// it uses legacy amino address rules.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LegacyAminoPubKey {
     #[prost(uint32, tag = "1")]
     pub threshold: u32,
     #[prost(message, repeated, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub public_keys: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for LegacyAminoPubKey {
+    const NAME: &'static str = "LegacyAminoPubKey";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.crypto.multisig.LegacyAminoPubKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.crypto.multisig.LegacyAminoPubKey".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<LegacyAminoPubKey>();
+}
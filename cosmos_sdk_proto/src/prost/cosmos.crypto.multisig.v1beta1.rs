@@ -2,19 +2,51 @@
 /// MultiSignature wraps the signatures from a multisig.LegacyAminoPubKey.
 /// See cosmos.tx.v1betata1.ModeInfo.Multi for how to specify which signers
 /// signed and with which modes.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MultiSignature {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for MultiSignature {
+    const NAME: &'static str = "MultiSignature";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.crypto.multisig.v1beta1.MultiSignature".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.crypto.multisig.v1beta1.MultiSignature".into()
+    }
+}
 /// CompactBitArray is an implementation of a space efficient bit array.
 /// This is used to ensure that the encoded data takes up a minimal amount of
 /// space after proto encoding.
 /// This is not thread safe, and is not intended for concurrent usage.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompactBitArray {
     #[prost(uint32, tag = "1")]
     pub extra_bits_stored: u32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub elems: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for CompactBitArray {
+    const NAME: &'static str = "CompactBitArray";
+    const PACKAGE: &'static str = "cosmos.crypto.multisig.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.crypto.multisig.v1beta1.CompactBitArray".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.crypto.multisig.v1beta1.CompactBitArray".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<MultiSignature>();
+    registry.register::<CompactBitArray>();
+}
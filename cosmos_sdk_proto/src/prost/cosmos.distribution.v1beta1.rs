@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// Params defines the set of params for the distribution module.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     #[prost(string, tag = "1")]
@@ -11,6 +13,16 @@ pub struct Params {
     #[prost(bool, tag = "4")]
     pub withdraw_addr_enabled: bool,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.Params".into()
+    }
+}
 /// ValidatorHistoricalRewards represents historical rewards for a validator.
 /// Height is implicit within the store key.
 /// Cumulative reward ratio is the sum from the zeroeth period
@@ -23,6 +35,9 @@ pub struct Params {
 ///   + number of slashes which ended the associated period (and might need to
 ///   read that record)
 ///   + one per validator for the zeroeth period, set on initialization
+#[allow(clippy::doc_lazy_continuation)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorHistoricalRewards {
     #[prost(message, repeated, tag = "1")]
@@ -32,56 +47,142 @@ pub struct ValidatorHistoricalRewards {
     #[prost(uint32, tag = "2")]
     pub reference_count: u32,
 }
+impl ::prost::Name for ValidatorHistoricalRewards {
+    const NAME: &'static str = "ValidatorHistoricalRewards";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorHistoricalRewards".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorHistoricalRewards".into()
+    }
+}
 /// ValidatorCurrentRewards represents current rewards and current
 /// period for a validator kept as a running counter and incremented
 /// each block as long as the validator's tokens remain constant.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorCurrentRewards {
     #[prost(message, repeated, tag = "1")]
     pub rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub period: u64,
 }
+impl ::prost::Name for ValidatorCurrentRewards {
+    const NAME: &'static str = "ValidatorCurrentRewards";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorCurrentRewards".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorCurrentRewards".into()
+    }
+}
 /// ValidatorAccumulatedCommission represents accumulated commission
 /// for a validator kept as a running counter, can be withdrawn at any time.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorAccumulatedCommission {
     #[prost(message, repeated, tag = "1")]
     pub commission: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for ValidatorAccumulatedCommission {
+    const NAME: &'static str = "ValidatorAccumulatedCommission";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorAccumulatedCommission".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorAccumulatedCommission".into()
+    }
+}
 /// ValidatorOutstandingRewards represents outstanding (un-withdrawn) rewards
 /// for a validator inexpensive to track, allows simple sanity checks.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorOutstandingRewards {
     #[prost(message, repeated, tag = "1")]
     pub rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for ValidatorOutstandingRewards {
+    const NAME: &'static str = "ValidatorOutstandingRewards";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorOutstandingRewards".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorOutstandingRewards".into()
+    }
+}
 /// ValidatorSlashEvent represents a validator slash event.
 /// Height is implicit within the store key.
 /// This is needed to calculate appropriate amount of staking tokens
 /// for delegations which are withdrawn after a slash has occurred.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorSlashEvent {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub validator_period: u64,
     #[prost(string, tag = "2")]
     pub fraction: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ValidatorSlashEvent {
+    const NAME: &'static str = "ValidatorSlashEvent";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorSlashEvent".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorSlashEvent".into()
+    }
+}
 /// ValidatorSlashEvents is a collection of ValidatorSlashEvent messages.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorSlashEvents {
     #[prost(message, repeated, tag = "1")]
     pub validator_slash_events: ::prost::alloc::vec::Vec<ValidatorSlashEvent>,
 }
+impl ::prost::Name for ValidatorSlashEvents {
+    const NAME: &'static str = "ValidatorSlashEvents";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorSlashEvents".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorSlashEvents".into()
+    }
+}
 /// FeePool is the global fee pool for distribution.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FeePool {
     #[prost(message, repeated, tag = "1")]
     pub community_pool: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for FeePool {
+    const NAME: &'static str = "FeePool";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.FeePool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.FeePool".into()
+    }
+}
 /// CommunityPoolSpendProposal details a proposal for use of community funds,
 /// together with how many coins are proposed to be spent, and to which
 /// recipient account.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommunityPoolSpendProposal {
     #[prost(string, tag = "1")]
@@ -93,23 +194,49 @@ pub struct CommunityPoolSpendProposal {
     #[prost(message, repeated, tag = "4")]
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for CommunityPoolSpendProposal {
+    const NAME: &'static str = "CommunityPoolSpendProposal";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.CommunityPoolSpendProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal".into()
+    }
+}
 /// DelegatorStartingInfo represents the starting info for a delegator reward
 /// period. It tracks the previous validator period, the delegation's amount of
 /// staking token, and the creation height (to check later on if any slashes have
 /// occurred). NOTE: Even though validators are slashed to whole staking tokens,
 /// the delegators within the validator may be left with less than a full token,
 /// thus sdk.Dec is used.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegatorStartingInfo {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub previous_period: u64,
     #[prost(string, tag = "2")]
     pub stake: ::prost::alloc::string::String,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
 }
+impl ::prost::Name for DelegatorStartingInfo {
+    const NAME: &'static str = "DelegatorStartingInfo";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.DelegatorStartingInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.DelegatorStartingInfo".into()
+    }
+}
 /// DelegationDelegatorReward represents the properties
 /// of a delegator's delegation reward.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegationDelegatorReward {
     #[prost(string, tag = "1")]
@@ -117,8 +244,20 @@ pub struct DelegationDelegatorReward {
     #[prost(message, repeated, tag = "2")]
     pub reward: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for DelegationDelegatorReward {
+    const NAME: &'static str = "DelegationDelegatorReward";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.DelegationDelegatorReward".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.DelegationDelegatorReward".into()
+    }
+}
 /// CommunityPoolSpendProposalWithDeposit defines a CommunityPoolSpendProposal
 /// with a deposit
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommunityPoolSpendProposalWithDeposit {
     #[prost(string, tag = "1")]
@@ -132,9 +271,21 @@ pub struct CommunityPoolSpendProposalWithDeposit {
     #[prost(string, tag = "5")]
     pub deposit: ::prost::alloc::string::String,
 }
+impl ::prost::Name for CommunityPoolSpendProposalWithDeposit {
+    const NAME: &'static str = "CommunityPoolSpendProposalWithDeposit";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.CommunityPoolSpendProposalWithDeposit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.CommunityPoolSpendProposalWithDeposit".into()
+    }
+}
 /// DelegatorWithdrawInfo is the address for where distributions rewards are
 /// withdrawn to by default this struct is only used at genesis to feed in
 /// default withdraw addresses.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegatorWithdrawInfo {
     /// delegator_address is the address of the delegator.
@@ -144,7 +295,19 @@ pub struct DelegatorWithdrawInfo {
     #[prost(string, tag = "2")]
     pub withdraw_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for DelegatorWithdrawInfo {
+    const NAME: &'static str = "DelegatorWithdrawInfo";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.DelegatorWithdrawInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.DelegatorWithdrawInfo".into()
+    }
+}
 /// ValidatorOutstandingRewardsRecord is used for import/export via genesis json.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorOutstandingRewardsRecord {
     /// validator_address is the address of the validator.
@@ -156,8 +319,20 @@ pub struct ValidatorOutstandingRewardsRecord {
         super::super::base::v1beta1::DecCoin,
     >,
 }
+impl ::prost::Name for ValidatorOutstandingRewardsRecord {
+    const NAME: &'static str = "ValidatorOutstandingRewardsRecord";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorOutstandingRewardsRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorOutstandingRewardsRecord".into()
+    }
+}
 /// ValidatorAccumulatedCommissionRecord is used for import / export via genesis
 /// json.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorAccumulatedCommissionRecord {
     /// validator_address is the address of the validator.
@@ -167,8 +342,20 @@ pub struct ValidatorAccumulatedCommissionRecord {
     #[prost(message, optional, tag = "2")]
     pub accumulated: ::core::option::Option<ValidatorAccumulatedCommission>,
 }
+impl ::prost::Name for ValidatorAccumulatedCommissionRecord {
+    const NAME: &'static str = "ValidatorAccumulatedCommissionRecord";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorAccumulatedCommissionRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorAccumulatedCommissionRecord".into()
+    }
+}
 /// ValidatorHistoricalRewardsRecord is used for import / export via genesis
 /// json.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorHistoricalRewardsRecord {
     /// validator_address is the address of the validator.
@@ -176,12 +363,25 @@ pub struct ValidatorHistoricalRewardsRecord {
     pub validator_address: ::prost::alloc::string::String,
     /// period defines the period the historical rewards apply to.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub period: u64,
     /// rewards defines the historical rewards of a validator.
     #[prost(message, optional, tag = "3")]
     pub rewards: ::core::option::Option<ValidatorHistoricalRewards>,
 }
+impl ::prost::Name for ValidatorHistoricalRewardsRecord {
+    const NAME: &'static str = "ValidatorHistoricalRewardsRecord";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorHistoricalRewardsRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorHistoricalRewardsRecord".into()
+    }
+}
 /// ValidatorCurrentRewardsRecord is used for import / export via genesis json.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorCurrentRewardsRecord {
     /// validator_address is the address of the validator.
@@ -191,7 +391,19 @@ pub struct ValidatorCurrentRewardsRecord {
     #[prost(message, optional, tag = "2")]
     pub rewards: ::core::option::Option<ValidatorCurrentRewards>,
 }
+impl ::prost::Name for ValidatorCurrentRewardsRecord {
+    const NAME: &'static str = "ValidatorCurrentRewardsRecord";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorCurrentRewardsRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorCurrentRewardsRecord".into()
+    }
+}
 /// DelegatorStartingInfoRecord used for import / export via genesis json.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegatorStartingInfoRecord {
     /// delegator_address is the address of the delegator.
@@ -204,7 +416,19 @@ pub struct DelegatorStartingInfoRecord {
     #[prost(message, optional, tag = "3")]
     pub starting_info: ::core::option::Option<DelegatorStartingInfo>,
 }
+impl ::prost::Name for DelegatorStartingInfoRecord {
+    const NAME: &'static str = "DelegatorStartingInfoRecord";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.DelegatorStartingInfoRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.DelegatorStartingInfoRecord".into()
+    }
+}
 /// ValidatorSlashEventRecord is used for import / export via genesis json.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorSlashEventRecord {
     /// validator_address is the address of the validator.
@@ -212,15 +436,29 @@ pub struct ValidatorSlashEventRecord {
     pub validator_address: ::prost::alloc::string::String,
     /// height defines the block height at which the slash event occured.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     /// period is the period of the slash event.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub period: u64,
     /// validator_slash_event describes the slash event.
     #[prost(message, optional, tag = "4")]
     pub validator_slash_event: ::core::option::Option<ValidatorSlashEvent>,
 }
+impl ::prost::Name for ValidatorSlashEventRecord {
+    const NAME: &'static str = "ValidatorSlashEventRecord";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.ValidatorSlashEventRecord".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.ValidatorSlashEventRecord".into()
+    }
+}
 /// GenesisState defines the distribution module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// params defines all the paramaters of the module.
@@ -260,49 +498,133 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "10")]
     pub validator_slash_events: ::prost::alloc::vec::Vec<ValidatorSlashEventRecord>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.GenesisState".into()
+    }
+}
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// params defines the parameters of the module.
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryParamsResponse".into()
+    }
+}
 /// QueryValidatorOutstandingRewardsRequest is the request type for the
 /// Query/ValidatorOutstandingRewards RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorOutstandingRewardsRequest {
     /// validator_address defines the validator address to query for.
     #[prost(string, tag = "1")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryValidatorOutstandingRewardsRequest {
+    const NAME: &'static str = "QueryValidatorOutstandingRewardsRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryValidatorOutstandingRewardsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryValidatorOutstandingRewardsRequest".into()
+    }
+}
 /// QueryValidatorOutstandingRewardsResponse is the response type for the
 /// Query/ValidatorOutstandingRewards RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorOutstandingRewardsResponse {
     #[prost(message, optional, tag = "1")]
     pub rewards: ::core::option::Option<ValidatorOutstandingRewards>,
 }
+impl ::prost::Name for QueryValidatorOutstandingRewardsResponse {
+    const NAME: &'static str = "QueryValidatorOutstandingRewardsResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryValidatorOutstandingRewardsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryValidatorOutstandingRewardsResponse".into()
+    }
+}
 /// QueryValidatorCommissionRequest is the request type for the
 /// Query/ValidatorCommission RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorCommissionRequest {
     /// validator_address defines the validator address to query for.
     #[prost(string, tag = "1")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryValidatorCommissionRequest {
+    const NAME: &'static str = "QueryValidatorCommissionRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryValidatorCommissionRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryValidatorCommissionRequest".into()
+    }
+}
 /// QueryValidatorCommissionResponse is the response type for the
 /// Query/ValidatorCommission RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorCommissionResponse {
     /// commission defines the commision the validator received.
     #[prost(message, optional, tag = "1")]
     pub commission: ::core::option::Option<ValidatorAccumulatedCommission>,
 }
+impl ::prost::Name for QueryValidatorCommissionResponse {
+    const NAME: &'static str = "QueryValidatorCommissionResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryValidatorCommissionResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryValidatorCommissionResponse".into()
+    }
+}
 /// QueryValidatorSlashesRequest is the request type for the
 /// Query/ValidatorSlashes RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorSlashesRequest {
     /// validator_address defines the validator address to query for.
@@ -310,9 +632,11 @@ pub struct QueryValidatorSlashesRequest {
     pub validator_address: ::prost::alloc::string::String,
     /// starting_height defines the optional starting height to query the slashes.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub starting_height: u64,
     /// starting_height defines the optional ending height to query the slashes.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub ending_height: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag = "4")]
@@ -320,8 +644,20 @@ pub struct QueryValidatorSlashesRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryValidatorSlashesRequest {
+    const NAME: &'static str = "QueryValidatorSlashesRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryValidatorSlashesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryValidatorSlashesRequest".into()
+    }
+}
 /// QueryValidatorSlashesResponse is the response type for the
 /// Query/ValidatorSlashes RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorSlashesResponse {
     /// slashes defines the slashes the validator received.
@@ -333,8 +669,20 @@ pub struct QueryValidatorSlashesResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryValidatorSlashesResponse {
+    const NAME: &'static str = "QueryValidatorSlashesResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryValidatorSlashesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryValidatorSlashesResponse".into()
+    }
+}
 /// QueryDelegationRewardsRequest is the request type for the
 /// Query/DelegationRewards RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationRewardsRequest {
     /// delegator_address defines the delegator address to query for.
@@ -344,24 +692,60 @@ pub struct QueryDelegationRewardsRequest {
     #[prost(string, tag = "2")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegationRewardsRequest {
+    const NAME: &'static str = "QueryDelegationRewardsRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegationRewardsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegationRewardsRequest".into()
+    }
+}
 /// QueryDelegationRewardsResponse is the response type for the
 /// Query/DelegationRewards RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationRewardsResponse {
     /// rewards defines the rewards accrued by a delegation.
     #[prost(message, repeated, tag = "1")]
     pub rewards: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for QueryDelegationRewardsResponse {
+    const NAME: &'static str = "QueryDelegationRewardsResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegationRewardsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegationRewardsResponse".into()
+    }
+}
 /// QueryDelegationTotalRewardsRequest is the request type for the
 /// Query/DelegationTotalRewards RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationTotalRewardsRequest {
     /// delegator_address defines the delegator address to query for.
     #[prost(string, tag = "1")]
     pub delegator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegationTotalRewardsRequest {
+    const NAME: &'static str = "QueryDelegationTotalRewardsRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegationTotalRewardsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegationTotalRewardsRequest".into()
+    }
+}
 /// QueryDelegationTotalRewardsResponse is the response type for the
 /// Query/DelegationTotalRewards RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationTotalRewardsResponse {
     /// rewards defines all the rewards accrued by a delegator.
@@ -371,50 +755,134 @@ pub struct QueryDelegationTotalRewardsResponse {
     #[prost(message, repeated, tag = "2")]
     pub total: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for QueryDelegationTotalRewardsResponse {
+    const NAME: &'static str = "QueryDelegationTotalRewardsResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegationTotalRewardsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegationTotalRewardsResponse".into()
+    }
+}
 /// QueryDelegatorValidatorsRequest is the request type for the
 /// Query/DelegatorValidators RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorsRequest {
     /// delegator_address defines the delegator address to query for.
     #[prost(string, tag = "1")]
     pub delegator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegatorValidatorsRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorsRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegatorValidatorsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegatorValidatorsRequest".into()
+    }
+}
 /// QueryDelegatorValidatorsResponse is the response type for the
 /// Query/DelegatorValidators RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorsResponse {
     /// validators defines the validators a delegator is delegating for.
     #[prost(string, repeated, tag = "1")]
     pub validators: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for QueryDelegatorValidatorsResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorsResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegatorValidatorsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegatorValidatorsResponse".into()
+    }
+}
 /// QueryDelegatorWithdrawAddressRequest is the request type for the
 /// Query/DelegatorWithdrawAddress RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorWithdrawAddressRequest {
     /// delegator_address defines the delegator address to query for.
     #[prost(string, tag = "1")]
     pub delegator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegatorWithdrawAddressRequest {
+    const NAME: &'static str = "QueryDelegatorWithdrawAddressRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegatorWithdrawAddressRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegatorWithdrawAddressRequest".into()
+    }
+}
 /// QueryDelegatorWithdrawAddressResponse is the response type for the
 /// Query/DelegatorWithdrawAddress RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorWithdrawAddressResponse {
     /// withdraw_address defines the delegator address to query for.
     #[prost(string, tag = "1")]
     pub withdraw_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegatorWithdrawAddressResponse {
+    const NAME: &'static str = "QueryDelegatorWithdrawAddressResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryDelegatorWithdrawAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryDelegatorWithdrawAddressResponse".into()
+    }
+}
 /// QueryCommunityPoolRequest is the request type for the Query/CommunityPool RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryCommunityPoolRequest {}
+impl ::prost::Name for QueryCommunityPoolRequest {
+    const NAME: &'static str = "QueryCommunityPoolRequest";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryCommunityPoolRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryCommunityPoolRequest".into()
+    }
+}
 /// QueryCommunityPoolResponse is the response type for the Query/CommunityPool
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCommunityPoolResponse {
     /// pool defines community pool's coins.
     #[prost(message, repeated, tag = "1")]
     pub pool: ::prost::alloc::vec::Vec<super::super::base::v1beta1::DecCoin>,
 }
+impl ::prost::Name for QueryCommunityPoolResponse {
+    const NAME: &'static str = "QueryCommunityPoolResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.QueryCommunityPoolResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.QueryCommunityPoolResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -776,6 +1244,8 @@ pub mod query_client {
 }
 /// MsgSetWithdrawAddress sets the withdraw address for
 /// a delegator (or validator self-delegation).
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSetWithdrawAddress {
     #[prost(string, tag = "1")]
@@ -783,11 +1253,35 @@ pub struct MsgSetWithdrawAddress {
     #[prost(string, tag = "2")]
     pub withdraw_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgSetWithdrawAddress {
+    const NAME: &'static str = "MsgSetWithdrawAddress";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgSetWithdrawAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress".into()
+    }
+}
 /// MsgSetWithdrawAddressResponse defines the Msg/SetWithdrawAddress response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSetWithdrawAddressResponse {}
+impl ::prost::Name for MsgSetWithdrawAddressResponse {
+    const NAME: &'static str = "MsgSetWithdrawAddressResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgSetWithdrawAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgSetWithdrawAddressResponse".into()
+    }
+}
 /// MsgWithdrawDelegatorReward represents delegation withdrawal to a delegator
 /// from a single validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgWithdrawDelegatorReward {
     #[prost(string, tag = "1")]
@@ -795,29 +1289,77 @@ pub struct MsgWithdrawDelegatorReward {
     #[prost(string, tag = "2")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgWithdrawDelegatorReward {
+    const NAME: &'static str = "MsgWithdrawDelegatorReward";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward".into()
+    }
+}
 /// MsgWithdrawDelegatorRewardResponse defines the Msg/WithdrawDelegatorReward response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgWithdrawDelegatorRewardResponse {
     /// Since: cosmos-sdk 0.46
     #[prost(message, repeated, tag = "1")]
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgWithdrawDelegatorRewardResponse {
+    const NAME: &'static str = "MsgWithdrawDelegatorRewardResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgWithdrawDelegatorRewardResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorRewardResponse".into()
+    }
+}
 /// MsgWithdrawValidatorCommission withdraws the full commission to the validator
 /// address.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgWithdrawValidatorCommission {
     #[prost(string, tag = "1")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgWithdrawValidatorCommission {
+    const NAME: &'static str = "MsgWithdrawValidatorCommission";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission".into()
+    }
+}
 /// MsgWithdrawValidatorCommissionResponse defines the Msg/WithdrawValidatorCommission response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgWithdrawValidatorCommissionResponse {
     /// Since: cosmos-sdk 0.46
     #[prost(message, repeated, tag = "1")]
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgWithdrawValidatorCommissionResponse {
+    const NAME: &'static str = "MsgWithdrawValidatorCommissionResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgWithdrawValidatorCommissionResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommissionResponse".into()
+    }
+}
 /// MsgFundCommunityPool allows an account to directly
 /// fund the community pool.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgFundCommunityPool {
     #[prost(message, repeated, tag = "1")]
@@ -825,9 +1367,33 @@ pub struct MsgFundCommunityPool {
     #[prost(string, tag = "2")]
     pub depositor: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgFundCommunityPool {
+    const NAME: &'static str = "MsgFundCommunityPool";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgFundCommunityPool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgFundCommunityPool".into()
+    }
+}
 /// MsgFundCommunityPoolResponse defines the Msg/FundCommunityPool response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgFundCommunityPoolResponse {}
+impl ::prost::Name for MsgFundCommunityPoolResponse {
+    const NAME: &'static str = "MsgFundCommunityPoolResponse";
+    const PACKAGE: &'static str = "cosmos.distribution.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.distribution.v1beta1.MsgFundCommunityPoolResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.distribution.v1beta1.MsgFundCommunityPoolResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -1046,3 +1612,1024 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Params queries params of the distribution module.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// ValidatorOutstandingRewards queries rewards of a validator address.
+        async fn validator_outstanding_rewards(
+            &self,
+            request: tonic::Request<super::QueryValidatorOutstandingRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorOutstandingRewardsResponse>,
+            tonic::Status,
+        >;
+        /// ValidatorCommission queries accumulated commission for a validator.
+        async fn validator_commission(
+            &self,
+            request: tonic::Request<super::QueryValidatorCommissionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorCommissionResponse>,
+            tonic::Status,
+        >;
+        /// ValidatorSlashes queries slash events of a validator.
+        async fn validator_slashes(
+            &self,
+            request: tonic::Request<super::QueryValidatorSlashesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorSlashesResponse>,
+            tonic::Status,
+        >;
+        /// DelegationRewards queries the total rewards accrued by a delegation.
+        async fn delegation_rewards(
+            &self,
+            request: tonic::Request<super::QueryDelegationRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegationRewardsResponse>,
+            tonic::Status,
+        >;
+        /// DelegationTotalRewards queries the total rewards accrued by a each
+        /// validator.
+        async fn delegation_total_rewards(
+            &self,
+            request: tonic::Request<super::QueryDelegationTotalRewardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegationTotalRewardsResponse>,
+            tonic::Status,
+        >;
+        /// DelegatorValidators queries the validators of a delegator.
+        async fn delegator_validators(
+            &self,
+            request: tonic::Request<super::QueryDelegatorValidatorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegatorValidatorsResponse>,
+            tonic::Status,
+        >;
+        /// DelegatorWithdrawAddress queries withdraw address of a delegator.
+        async fn delegator_withdraw_address(
+            &self,
+            request: tonic::Request<super::QueryDelegatorWithdrawAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegatorWithdrawAddressResponse>,
+            tonic::Status,
+        >;
+        /// CommunityPool queries the community pool coins.
+        async fn community_pool(
+            &self,
+            request: tonic::Request<super::QueryCommunityPoolRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCommunityPoolResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service for distribution module.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.distribution.v1beta1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorOutstandingRewardsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryValidatorOutstandingRewardsRequest,
+                    > for ValidatorOutstandingRewardsSvc<T> {
+                        type Response = super::QueryValidatorOutstandingRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryValidatorOutstandingRewardsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator_outstanding_rewards(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorOutstandingRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/ValidatorCommission" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorCommissionSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValidatorCommissionRequest>
+                    for ValidatorCommissionSvc<T> {
+                        type Response = super::QueryValidatorCommissionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryValidatorCommissionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator_commission(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorCommissionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/ValidatorSlashes" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorSlashesSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValidatorSlashesRequest>
+                    for ValidatorSlashesSvc<T> {
+                        type Response = super::QueryValidatorSlashesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryValidatorSlashesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator_slashes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorSlashesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/DelegationRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegationRewardsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDelegationRewardsRequest>
+                    for DelegationRewardsSvc<T> {
+                        type Response = super::QueryDelegationRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDelegationRewardsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegation_rewards(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegationRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegationTotalRewardsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryDelegationTotalRewardsRequest,
+                    > for DelegationTotalRewardsSvc<T> {
+                        type Response = super::QueryDelegationTotalRewardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegationTotalRewardsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegation_total_rewards(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegationTotalRewardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/DelegatorValidators" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegatorValidatorsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDelegatorValidatorsRequest>
+                    for DelegatorValidatorsSvc<T> {
+                        type Response = super::QueryDelegatorValidatorsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegatorValidatorsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegator_validators(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegatorValidatorsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegatorWithdrawAddressSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryDelegatorWithdrawAddressRequest,
+                    > for DelegatorWithdrawAddressSvc<T> {
+                        type Response = super::QueryDelegatorWithdrawAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegatorWithdrawAddressRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegator_withdraw_address(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegatorWithdrawAddressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Query/CommunityPool" => {
+                    #[allow(non_camel_case_types)]
+                    struct CommunityPoolSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCommunityPoolRequest>
+                    for CommunityPoolSvc<T> {
+                        type Response = super::QueryCommunityPoolResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCommunityPoolRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::community_pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CommunityPoolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.distribution.v1beta1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// SetWithdrawAddress defines a method to change the withdraw address
+        /// for a delegator (or validator self-delegation).
+        async fn set_withdraw_address(
+            &self,
+            request: tonic::Request<super::MsgSetWithdrawAddress>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSetWithdrawAddressResponse>,
+            tonic::Status,
+        >;
+        /// WithdrawDelegatorReward defines a method to withdraw rewards of delegator
+        /// from a single validator.
+        async fn withdraw_delegator_reward(
+            &self,
+            request: tonic::Request<super::MsgWithdrawDelegatorReward>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgWithdrawDelegatorRewardResponse>,
+            tonic::Status,
+        >;
+        /// WithdrawValidatorCommission defines a method to withdraw the
+        /// full commission to the validator address.
+        async fn withdraw_validator_commission(
+            &self,
+            request: tonic::Request<super::MsgWithdrawValidatorCommission>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgWithdrawValidatorCommissionResponse>,
+            tonic::Status,
+        >;
+        /// FundCommunityPool defines a method to allow an account to directly
+        /// fund the community pool.
+        async fn fund_community_pool(
+            &self,
+            request: tonic::Request<super::MsgFundCommunityPool>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgFundCommunityPoolResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the distribution Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.distribution.v1beta1.Msg/SetWithdrawAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetWithdrawAddressSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgSetWithdrawAddress>
+                    for SetWithdrawAddressSvc<T> {
+                        type Response = super::MsgSetWithdrawAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSetWithdrawAddress>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::set_withdraw_address(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetWithdrawAddressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Msg/WithdrawDelegatorReward" => {
+                    #[allow(non_camel_case_types)]
+                    struct WithdrawDelegatorRewardSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgWithdrawDelegatorReward>
+                    for WithdrawDelegatorRewardSvc<T> {
+                        type Response = super::MsgWithdrawDelegatorRewardResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgWithdrawDelegatorReward>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::withdraw_delegator_reward(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WithdrawDelegatorRewardSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Msg/WithdrawValidatorCommission" => {
+                    #[allow(non_camel_case_types)]
+                    struct WithdrawValidatorCommissionSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgWithdrawValidatorCommission>
+                    for WithdrawValidatorCommissionSvc<T> {
+                        type Response = super::MsgWithdrawValidatorCommissionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::MsgWithdrawValidatorCommission,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::withdraw_validator_commission(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WithdrawValidatorCommissionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.distribution.v1beta1.Msg/FundCommunityPool" => {
+                    #[allow(non_camel_case_types)]
+                    struct FundCommunityPoolSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgFundCommunityPool>
+                    for FundCommunityPoolSvc<T> {
+                        type Response = super::MsgFundCommunityPoolResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgFundCommunityPool>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::fund_community_pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FundCommunityPoolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.distribution.v1beta1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Params>();
+    registry.register::<ValidatorHistoricalRewards>();
+    registry.register::<ValidatorCurrentRewards>();
+    registry.register::<ValidatorAccumulatedCommission>();
+    registry.register::<ValidatorOutstandingRewards>();
+    registry.register::<ValidatorSlashEvent>();
+    registry.register::<ValidatorSlashEvents>();
+    registry.register::<FeePool>();
+    registry.register::<CommunityPoolSpendProposal>();
+    registry.register::<DelegatorStartingInfo>();
+    registry.register::<DelegationDelegatorReward>();
+    registry.register::<CommunityPoolSpendProposalWithDeposit>();
+    registry.register::<DelegatorWithdrawInfo>();
+    registry.register::<ValidatorOutstandingRewardsRecord>();
+    registry.register::<ValidatorAccumulatedCommissionRecord>();
+    registry.register::<ValidatorHistoricalRewardsRecord>();
+    registry.register::<ValidatorCurrentRewardsRecord>();
+    registry.register::<DelegatorStartingInfoRecord>();
+    registry.register::<ValidatorSlashEventRecord>();
+    registry.register::<GenesisState>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryValidatorOutstandingRewardsRequest>();
+    registry.register::<QueryValidatorOutstandingRewardsResponse>();
+    registry.register::<QueryValidatorCommissionRequest>();
+    registry.register::<QueryValidatorCommissionResponse>();
+    registry.register::<QueryValidatorSlashesRequest>();
+    registry.register::<QueryValidatorSlashesResponse>();
+    registry.register::<QueryDelegationRewardsRequest>();
+    registry.register::<QueryDelegationRewardsResponse>();
+    registry.register::<QueryDelegationTotalRewardsRequest>();
+    registry.register::<QueryDelegationTotalRewardsResponse>();
+    registry.register::<QueryDelegatorValidatorsRequest>();
+    registry.register::<QueryDelegatorValidatorsResponse>();
+    registry.register::<QueryDelegatorWithdrawAddressRequest>();
+    registry.register::<QueryDelegatorWithdrawAddressResponse>();
+    registry.register::<QueryCommunityPoolRequest>();
+    registry.register::<QueryCommunityPoolResponse>();
+    registry.register::<MsgSetWithdrawAddress>();
+    registry.register::<MsgSetWithdrawAddressResponse>();
+    registry.register::<MsgWithdrawDelegatorReward>();
+    registry.register::<MsgWithdrawDelegatorRewardResponse>();
+    registry.register::<MsgWithdrawValidatorCommission>();
+    registry.register::<MsgWithdrawValidatorCommissionResponse>();
+    registry.register::<MsgFundCommunityPool>();
+    registry.register::<MsgFundCommunityPoolResponse>();
+}
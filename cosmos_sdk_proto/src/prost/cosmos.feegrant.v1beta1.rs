@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// BasicAllowance implements Allowance with a one-time grant of coins
 /// that optionally expires. The grantee can use up to SpendLimit to cover fees.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BasicAllowance {
     /// spend_limit specifies the maximum amount of coins that can be spent
@@ -10,10 +12,23 @@ pub struct BasicAllowance {
     pub spend_limit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
     /// expiration specifies an optional time when this allowance expires
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub expiration: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for BasicAllowance {
+    const NAME: &'static str = "BasicAllowance";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.BasicAllowance".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.BasicAllowance".into()
+    }
+}
 /// PeriodicAllowance extends Allowance to allow for both a maximum cap,
 /// as well as a limit per time period.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PeriodicAllowance {
     /// basic specifies a struct of `BasicAllowance`
@@ -22,6 +37,7 @@ pub struct PeriodicAllowance {
     /// period specifies the time duration in which period_spend_limit coins can
     /// be spent before that allowance is reset
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub period: ::core::option::Option<::prost_types::Duration>,
     /// period_spend_limit specifies the maximum number of coins that can be spent
     /// in the period
@@ -34,19 +50,45 @@ pub struct PeriodicAllowance {
     /// it is calculated from the start time of the first transaction after the
     /// last period ended
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub period_reset: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for PeriodicAllowance {
+    const NAME: &'static str = "PeriodicAllowance";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.PeriodicAllowance".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.PeriodicAllowance".into()
+    }
+}
 /// AllowedMsgAllowance creates allowance only for specified message types.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AllowedMsgAllowance {
     /// allowance can be any of basic and periodic fee allowance.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub allowance: ::core::option::Option<::prost_types::Any>,
     /// allowed_messages are the messages for which the grantee has the access.
     #[prost(string, repeated, tag = "2")]
     pub allowed_messages: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for AllowedMsgAllowance {
+    const NAME: &'static str = "AllowedMsgAllowance";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.AllowedMsgAllowance".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.AllowedMsgAllowance".into()
+    }
+}
 /// Grant is stored in the KVStore to record a grant with full context
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Grant {
     /// granter is the address of the user granting an allowance of their funds.
@@ -57,15 +99,40 @@ pub struct Grant {
     pub grantee: ::prost::alloc::string::String,
     /// allowance can be any of basic, periodic, allowed fee allowance.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub allowance: ::core::option::Option<::prost_types::Any>,
 }
+impl ::prost::Name for Grant {
+    const NAME: &'static str = "Grant";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.Grant".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.Grant".into()
+    }
+}
 /// GenesisState contains a set of fee allowances, persisted from the store
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, repeated, tag = "1")]
     pub allowances: ::prost::alloc::vec::Vec<Grant>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.GenesisState".into()
+    }
+}
 /// QueryAllowanceRequest is the request type for the Query/Allowance RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllowanceRequest {
     /// granter is the address of the user granting an allowance of their funds.
@@ -75,14 +142,38 @@ pub struct QueryAllowanceRequest {
     #[prost(string, tag = "2")]
     pub grantee: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryAllowanceRequest {
+    const NAME: &'static str = "QueryAllowanceRequest";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.QueryAllowanceRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.QueryAllowanceRequest".into()
+    }
+}
 /// QueryAllowanceResponse is the response type for the Query/Allowance RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllowanceResponse {
     /// allowance is a allowance granted for grantee by granter.
     #[prost(message, optional, tag = "1")]
     pub allowance: ::core::option::Option<Grant>,
 }
+impl ::prost::Name for QueryAllowanceResponse {
+    const NAME: &'static str = "QueryAllowanceResponse";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.QueryAllowanceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.QueryAllowanceResponse".into()
+    }
+}
 /// QueryAllowancesRequest is the request type for the Query/Allowances RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllowancesRequest {
     #[prost(string, tag = "1")]
@@ -93,7 +184,19 @@ pub struct QueryAllowancesRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryAllowancesRequest {
+    const NAME: &'static str = "QueryAllowancesRequest";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.QueryAllowancesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.QueryAllowancesRequest".into()
+    }
+}
 /// QueryAllowancesResponse is the response type for the Query/Allowances RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllowancesResponse {
     /// allowances are allowance's granted for grantee by granter.
@@ -105,9 +208,21 @@ pub struct QueryAllowancesResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryAllowancesResponse {
+    const NAME: &'static str = "QueryAllowancesResponse";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.QueryAllowancesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.QueryAllowancesResponse".into()
+    }
+}
 /// QueryAllowancesByGranterRequest is the request type for the Query/AllowancesByGranter RPC method.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllowancesByGranterRequest {
     #[prost(string, tag = "1")]
@@ -118,9 +233,21 @@ pub struct QueryAllowancesByGranterRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryAllowancesByGranterRequest {
+    const NAME: &'static str = "QueryAllowancesByGranterRequest";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.QueryAllowancesByGranterRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.QueryAllowancesByGranterRequest".into()
+    }
+}
 /// QueryAllowancesByGranterResponse is the response type for the Query/AllowancesByGranter RPC method.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllowancesByGranterResponse {
     /// allowances that have been issued by the granter.
@@ -132,6 +259,18 @@ pub struct QueryAllowancesByGranterResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryAllowancesByGranterResponse {
+    const NAME: &'static str = "QueryAllowancesByGranterResponse";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.QueryAllowancesByGranterResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.QueryAllowancesByGranterResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -310,6 +449,8 @@ pub mod query_client {
 }
 /// MsgGrantAllowance adds permission for Grantee to spend up to Allowance
 /// of fees from the account of Granter.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgGrantAllowance {
     /// granter is the address of the user granting an allowance of their funds.
@@ -320,12 +461,37 @@ pub struct MsgGrantAllowance {
     pub grantee: ::prost::alloc::string::String,
     /// allowance can be any of basic, periodic, allowed fee allowance.
     #[prost(message, optional, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub allowance: ::core::option::Option<::prost_types::Any>,
 }
+impl ::prost::Name for MsgGrantAllowance {
+    const NAME: &'static str = "MsgGrantAllowance";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.MsgGrantAllowance".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.MsgGrantAllowance".into()
+    }
+}
 /// MsgGrantAllowanceResponse defines the Msg/GrantAllowanceResponse response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgGrantAllowanceResponse {}
+impl ::prost::Name for MsgGrantAllowanceResponse {
+    const NAME: &'static str = "MsgGrantAllowanceResponse";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.MsgGrantAllowanceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.MsgGrantAllowanceResponse".into()
+    }
+}
 /// MsgRevokeAllowance removes any existing Allowance from Granter to Grantee.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRevokeAllowance {
     /// granter is the address of the user granting an allowance of their funds.
@@ -335,9 +501,33 @@ pub struct MsgRevokeAllowance {
     #[prost(string, tag = "2")]
     pub grantee: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgRevokeAllowance {
+    const NAME: &'static str = "MsgRevokeAllowance";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.MsgRevokeAllowance".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.MsgRevokeAllowance".into()
+    }
+}
 /// MsgRevokeAllowanceResponse defines the Msg/RevokeAllowanceResponse response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgRevokeAllowanceResponse {}
+impl ::prost::Name for MsgRevokeAllowanceResponse {
+    const NAME: &'static str = "MsgRevokeAllowanceResponse";
+    const PACKAGE: &'static str = "cosmos.feegrant.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.feegrant.v1beta1.MsgRevokeAllowanceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.feegrant.v1beta1.MsgRevokeAllowanceResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -488,3 +678,551 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Allowance returns fee granted to the grantee by the granter.
+        async fn allowance(
+            &self,
+            request: tonic::Request<super::QueryAllowanceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAllowanceResponse>,
+            tonic::Status,
+        >;
+        /// Allowances returns all the grants for address.
+        async fn allowances(
+            &self,
+            request: tonic::Request<super::QueryAllowancesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAllowancesResponse>,
+            tonic::Status,
+        >;
+        /// AllowancesByGranter returns all the grants given by an address
+        ///
+        /// Since: cosmos-sdk 0.46
+        async fn allowances_by_granter(
+            &self,
+            request: tonic::Request<super::QueryAllowancesByGranterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAllowancesByGranterResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.feegrant.v1beta1.Query/Allowance" => {
+                    #[allow(non_camel_case_types)]
+                    struct AllowanceSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAllowanceRequest>
+                    for AllowanceSvc<T> {
+                        type Response = super::QueryAllowanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAllowanceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::allowance(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AllowanceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.feegrant.v1beta1.Query/Allowances" => {
+                    #[allow(non_camel_case_types)]
+                    struct AllowancesSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAllowancesRequest>
+                    for AllowancesSvc<T> {
+                        type Response = super::QueryAllowancesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAllowancesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::allowances(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AllowancesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter" => {
+                    #[allow(non_camel_case_types)]
+                    struct AllowancesByGranterSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAllowancesByGranterRequest>
+                    for AllowancesByGranterSvc<T> {
+                        type Response = super::QueryAllowancesByGranterResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryAllowancesByGranterRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::allowances_by_granter(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AllowancesByGranterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.feegrant.v1beta1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// GrantAllowance grants fee allowance to the grantee on the granter's
+        /// account with the provided expiration time.
+        async fn grant_allowance(
+            &self,
+            request: tonic::Request<super::MsgGrantAllowance>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgGrantAllowanceResponse>,
+            tonic::Status,
+        >;
+        /// RevokeAllowance revokes any fee allowance of granter's account that
+        /// has been granted to the grantee.
+        async fn revoke_allowance(
+            &self,
+            request: tonic::Request<super::MsgRevokeAllowance>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgRevokeAllowanceResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the feegrant msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.feegrant.v1beta1.Msg/GrantAllowance" => {
+                    #[allow(non_camel_case_types)]
+                    struct GrantAllowanceSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgGrantAllowance>
+                    for GrantAllowanceSvc<T> {
+                        type Response = super::MsgGrantAllowanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgGrantAllowance>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::grant_allowance(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GrantAllowanceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.feegrant.v1beta1.Msg/RevokeAllowance" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeAllowanceSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgRevokeAllowance>
+                    for RevokeAllowanceSvc<T> {
+                        type Response = super::MsgRevokeAllowanceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgRevokeAllowance>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::revoke_allowance(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevokeAllowanceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.feegrant.v1beta1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<BasicAllowance>();
+    registry.register::<PeriodicAllowance>();
+    registry.register::<AllowedMsgAllowance>();
+    registry.register::<Grant>();
+    registry.register::<GenesisState>();
+    registry.register::<QueryAllowanceRequest>();
+    registry.register::<QueryAllowanceResponse>();
+    registry.register::<QueryAllowancesRequest>();
+    registry.register::<QueryAllowancesResponse>();
+    registry.register::<QueryAllowancesByGranterRequest>();
+    registry.register::<QueryAllowancesByGranterResponse>();
+    registry.register::<MsgGrantAllowance>();
+    registry.register::<MsgGrantAllowanceResponse>();
+    registry.register::<MsgRevokeAllowance>();
+    registry.register::<MsgRevokeAllowanceResponse>();
+}
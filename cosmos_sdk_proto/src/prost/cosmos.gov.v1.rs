@@ -1,31 +1,62 @@
 // This file is @generated by prost-build.
 /// WeightedVoteOption defines a unit of vote for vote split.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WeightedVoteOption {
     #[prost(enumeration = "VoteOption", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<VoteOption, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<VoteOption, _>"))]
     pub option: i32,
     #[prost(string, tag = "2")]
     pub weight: ::prost::alloc::string::String,
 }
+impl ::prost::Name for WeightedVoteOption {
+    const NAME: &'static str = "WeightedVoteOption";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.WeightedVoteOption".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.WeightedVoteOption".into()
+    }
+}
 /// Deposit defines an amount deposited by an account address to an active
 /// proposal.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Deposit {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     #[prost(string, tag = "2")]
     pub depositor: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "3")]
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for Deposit {
+    const NAME: &'static str = "Deposit";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.Deposit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.Deposit".into()
+    }
+}
 /// Proposal defines the core field members of a governance proposal.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Proposal {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub id: u64,
     #[prost(message, repeated, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     #[prost(enumeration = "ProposalStatus", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<ProposalStatus, _>"))]
     pub status: i32,
     /// final_tally_result is the final tally result of the proposal. When
     /// querying a proposal via gRPC, this field is not populated until the
@@ -33,20 +64,36 @@ pub struct Proposal {
     #[prost(message, optional, tag = "4")]
     pub final_tally_result: ::core::option::Option<TallyResult>,
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub submit_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub deposit_end_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, repeated, tag = "7")]
     pub total_deposit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
     #[prost(message, optional, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub voting_start_time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub voting_end_time: ::core::option::Option<::prost_types::Timestamp>,
     /// metadata is any arbitrary metadata attached to the proposal.
     #[prost(string, tag = "10")]
     pub metadata: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Proposal {
+    const NAME: &'static str = "Proposal";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.Proposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.Proposal".into()
+    }
+}
 /// TallyResult defines a standard tally for a governance proposal.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TallyResult {
     #[prost(string, tag = "1")]
@@ -58,11 +105,24 @@ pub struct TallyResult {
     #[prost(string, tag = "4")]
     pub no_with_veto_count: ::prost::alloc::string::String,
 }
+impl ::prost::Name for TallyResult {
+    const NAME: &'static str = "TallyResult";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.TallyResult".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.TallyResult".into()
+    }
+}
 /// Vote defines a vote on a governance proposal.
 /// A Vote consists of a proposal ID, the voter, and the vote option.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Vote {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     #[prost(string, tag = "2")]
     pub voter: ::prost::alloc::string::String,
@@ -72,7 +132,19 @@ pub struct Vote {
     #[prost(string, tag = "5")]
     pub metadata: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Vote {
+    const NAME: &'static str = "Vote";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.Vote".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.Vote".into()
+    }
+}
 /// DepositParams defines the params for deposits on governance proposals.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DepositParams {
     ///   Minimum deposit for a proposal to enter voting period.
@@ -81,16 +153,42 @@ pub struct DepositParams {
     ///   Maximum period for Atom holders to deposit on a proposal. Initial value: 2
     ///   months.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub max_deposit_period: ::core::option::Option<::prost_types::Duration>,
 }
+impl ::prost::Name for DepositParams {
+    const NAME: &'static str = "DepositParams";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.DepositParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.DepositParams".into()
+    }
+}
 /// VotingParams defines the params for voting on governance proposals.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct VotingParams {
     ///   Length of the voting period.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub voting_period: ::core::option::Option<::prost_types::Duration>,
 }
+impl ::prost::Name for VotingParams {
+    const NAME: &'static str = "VotingParams";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.VotingParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.VotingParams".into()
+    }
+}
 /// TallyParams defines the params for tallying votes on governance proposals.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TallyParams {
     ///   Minimum percentage of total stake needed to vote for a result to be
@@ -105,6 +203,16 @@ pub struct TallyParams {
     #[prost(string, tag = "3")]
     pub veto_threshold: ::prost::alloc::string::String,
 }
+impl ::prost::Name for TallyParams {
+    const NAME: &'static str = "TallyParams";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.TallyParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.TallyParams".into()
+    }
+}
 /// VoteOption enumerates the valid vote options for a given governance proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -146,6 +254,15 @@ impl VoteOption {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for VoteOption {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// ProposalStatus enumerates the valid statuses of a proposal.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -196,11 +313,23 @@ impl ProposalStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for ProposalStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// GenesisState defines the gov module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// starting_proposal_id is the ID of the starting proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub starting_proposal_id: u64,
     /// deposits defines all the deposits present at genesis.
     #[prost(message, repeated, tag = "2")]
@@ -221,24 +350,62 @@ pub struct GenesisState {
     #[prost(message, optional, tag = "7")]
     pub tally_params: ::core::option::Option<TallyParams>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.GenesisState".into()
+    }
+}
 /// QueryProposalRequest is the request type for the Query/Proposal RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryProposalRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
 }
+impl ::prost::Name for QueryProposalRequest {
+    const NAME: &'static str = "QueryProposalRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryProposalRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryProposalRequest".into()
+    }
+}
 /// QueryProposalResponse is the response type for the Query/Proposal RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryProposalResponse {
     #[prost(message, optional, tag = "1")]
     pub proposal: ::core::option::Option<Proposal>,
 }
+impl ::prost::Name for QueryProposalResponse {
+    const NAME: &'static str = "QueryProposalResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryProposalResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryProposalResponse".into()
+    }
+}
 /// QueryProposalsRequest is the request type for the Query/Proposals RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryProposalsRequest {
     /// proposal_status defines the status of the proposals.
     #[prost(enumeration = "ProposalStatus", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<ProposalStatus, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<ProposalStatus, _>"))]
     pub proposal_status: i32,
     /// voter defines the voter address for the proposals.
     #[prost(string, tag = "2")]
@@ -252,8 +419,20 @@ pub struct QueryProposalsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryProposalsRequest {
+    const NAME: &'static str = "QueryProposalsRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryProposalsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryProposalsRequest".into()
+    }
+}
 /// QueryProposalsResponse is the response type for the Query/Proposals RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryProposalsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -264,28 +443,66 @@ pub struct QueryProposalsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryProposalsResponse {
+    const NAME: &'static str = "QueryProposalsResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryProposalsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryProposalsResponse".into()
+    }
+}
 /// QueryVoteRequest is the request type for the Query/Vote RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryVoteRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     /// voter defines the voter address for the proposals.
     #[prost(string, tag = "2")]
     pub voter: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryVoteRequest {
+    const NAME: &'static str = "QueryVoteRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryVoteRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryVoteRequest".into()
+    }
+}
 /// QueryVoteResponse is the response type for the Query/Vote RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryVoteResponse {
     /// vote defined the queried vote.
     #[prost(message, optional, tag = "1")]
     pub vote: ::core::option::Option<Vote>,
 }
+impl ::prost::Name for QueryVoteResponse {
+    const NAME: &'static str = "QueryVoteResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryVoteResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryVoteResponse".into()
+    }
+}
 /// QueryVotesRequest is the request type for the Query/Votes RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryVotesRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag = "2")]
@@ -293,7 +510,19 @@ pub struct QueryVotesRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryVotesRequest {
+    const NAME: &'static str = "QueryVotesRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryVotesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryVotesRequest".into()
+    }
+}
 /// QueryVotesResponse is the response type for the Query/Votes RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryVotesResponse {
     /// votes defined the queried votes.
@@ -305,7 +534,19 @@ pub struct QueryVotesResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryVotesResponse {
+    const NAME: &'static str = "QueryVotesResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryVotesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryVotesResponse".into()
+    }
+}
 /// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {
     /// params_type defines which parameters to query for, can be one of "voting",
@@ -313,7 +554,19 @@ pub struct QueryParamsRequest {
     #[prost(string, tag = "1")]
     pub params_type: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// voting_params defines the parameters related to voting.
@@ -326,28 +579,66 @@ pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "3")]
     pub tally_params: ::core::option::Option<TallyParams>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryParamsResponse".into()
+    }
+}
 /// QueryDepositRequest is the request type for the Query/Deposit RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDepositRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     /// depositor defines the deposit addresses from the proposals.
     #[prost(string, tag = "2")]
     pub depositor: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDepositRequest {
+    const NAME: &'static str = "QueryDepositRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryDepositRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryDepositRequest".into()
+    }
+}
 /// QueryDepositResponse is the response type for the Query/Deposit RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDepositResponse {
     /// deposit defines the requested deposit.
     #[prost(message, optional, tag = "1")]
     pub deposit: ::core::option::Option<Deposit>,
 }
+impl ::prost::Name for QueryDepositResponse {
+    const NAME: &'static str = "QueryDepositResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryDepositResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryDepositResponse".into()
+    }
+}
 /// QueryDepositsRequest is the request type for the Query/Deposits RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDepositsRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     /// pagination defines an optional pagination for the request.
     #[prost(message, optional, tag = "2")]
@@ -355,7 +646,19 @@ pub struct QueryDepositsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryDepositsRequest {
+    const NAME: &'static str = "QueryDepositsRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryDepositsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryDepositsRequest".into()
+    }
+}
 /// QueryDepositsResponse is the response type for the Query/Deposits RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDepositsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -366,20 +669,57 @@ pub struct QueryDepositsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryDepositsResponse {
+    const NAME: &'static str = "QueryDepositsResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryDepositsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryDepositsResponse".into()
+    }
+}
 /// QueryTallyResultRequest is the request type for the Query/Tally RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryTallyResultRequest {
     /// proposal_id defines the unique id of the proposal.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
 }
+impl ::prost::Name for QueryTallyResultRequest {
+    const NAME: &'static str = "QueryTallyResultRequest";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryTallyResultRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryTallyResultRequest".into()
+    }
+}
 /// QueryTallyResultResponse is the response type for the Query/Tally RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryTallyResultResponse {
     /// tally defines the requested tally.
     #[prost(message, optional, tag = "1")]
     pub tally: ::core::option::Option<TallyResult>,
 }
+impl ::prost::Name for QueryTallyResultResponse {
+    const NAME: &'static str = "QueryTallyResultResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.QueryTallyResultResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.QueryTallyResultResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -672,9 +1012,12 @@ pub mod query_client {
 }
 /// MsgSubmitProposal defines an sdk.Msg type that supports submitting arbitrary
 /// proposal Content.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSubmitProposal {
     #[prost(message, repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     #[prost(message, repeated, tag = "2")]
     pub initial_deposit: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
@@ -684,45 +1027,122 @@ pub struct MsgSubmitProposal {
     #[prost(string, tag = "4")]
     pub metadata: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgSubmitProposal {
+    const NAME: &'static str = "MsgSubmitProposal";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgSubmitProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgSubmitProposal".into()
+    }
+}
 /// MsgSubmitProposalResponse defines the Msg/SubmitProposal response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSubmitProposalResponse {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
 }
+impl ::prost::Name for MsgSubmitProposalResponse {
+    const NAME: &'static str = "MsgSubmitProposalResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgSubmitProposalResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgSubmitProposalResponse".into()
+    }
+}
 /// MsgExecLegacyContent is used to wrap the legacy content field into a message.
 /// This ensures backwards compatibility with v1beta1.MsgSubmitProposal.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgExecLegacyContent {
     /// content is the proposal's content.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub content: ::core::option::Option<::prost_types::Any>,
     /// authority must be the gov module address.
     #[prost(string, tag = "2")]
     pub authority: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgExecLegacyContent {
+    const NAME: &'static str = "MsgExecLegacyContent";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgExecLegacyContent".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgExecLegacyContent".into()
+    }
+}
 /// MsgExecLegacyContentResponse defines the Msg/ExecLegacyContent response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgExecLegacyContentResponse {}
+impl ::prost::Name for MsgExecLegacyContentResponse {
+    const NAME: &'static str = "MsgExecLegacyContentResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgExecLegacyContentResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgExecLegacyContentResponse".into()
+    }
+}
 /// MsgVote defines a message to cast a vote.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgVote {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     #[prost(string, tag = "2")]
     pub voter: ::prost::alloc::string::String,
     #[prost(enumeration = "VoteOption", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<VoteOption, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<VoteOption, _>"))]
     pub option: i32,
     #[prost(string, tag = "4")]
     pub metadata: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgVote {
+    const NAME: &'static str = "MsgVote";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgVote".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgVote".into()
+    }
+}
 /// MsgVoteResponse defines the Msg/Vote response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgVoteResponse {}
+impl ::prost::Name for MsgVoteResponse {
+    const NAME: &'static str = "MsgVoteResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgVoteResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgVoteResponse".into()
+    }
+}
 /// MsgVoteWeighted defines a message to cast a vote.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgVoteWeighted {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     #[prost(string, tag = "2")]
     pub voter: ::prost::alloc::string::String,
@@ -731,22 +1151,71 @@ pub struct MsgVoteWeighted {
     #[prost(string, tag = "4")]
     pub metadata: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgVoteWeighted {
+    const NAME: &'static str = "MsgVoteWeighted";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgVoteWeighted".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgVoteWeighted".into()
+    }
+}
 /// MsgVoteWeightedResponse defines the Msg/VoteWeighted response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgVoteWeightedResponse {}
+impl ::prost::Name for MsgVoteWeightedResponse {
+    const NAME: &'static str = "MsgVoteWeightedResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgVoteWeightedResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgVoteWeightedResponse".into()
+    }
+}
 /// MsgDeposit defines a message to submit a deposit to an existing proposal.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgDeposit {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub proposal_id: u64,
     #[prost(string, tag = "2")]
     pub depositor: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "3")]
     pub amount: ::prost::alloc::vec::Vec<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgDeposit {
+    const NAME: &'static str = "MsgDeposit";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgDeposit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgDeposit".into()
+    }
+}
 /// MsgDepositResponse defines the Msg/Deposit response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgDepositResponse {}
+impl ::prost::Name for MsgDepositResponse {
+    const NAME: &'static str = "MsgDepositResponse";
+    const PACKAGE: &'static str = "cosmos.gov.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.gov.v1.MsgDepositResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.gov.v1.MsgDepositResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -963,3 +1432,975 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Proposal queries proposal details based on ProposalID.
+        async fn proposal(
+            &self,
+            request: tonic::Request<super::QueryProposalRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryProposalResponse>,
+            tonic::Status,
+        >;
+        /// Proposals queries all proposals based on given status.
+        async fn proposals(
+            &self,
+            request: tonic::Request<super::QueryProposalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryProposalsResponse>,
+            tonic::Status,
+        >;
+        /// Vote queries voted information based on proposalID, voterAddr.
+        async fn vote(
+            &self,
+            request: tonic::Request<super::QueryVoteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryVoteResponse>,
+            tonic::Status,
+        >;
+        /// Votes queries votes of a given proposal.
+        async fn votes(
+            &self,
+            request: tonic::Request<super::QueryVotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryVotesResponse>,
+            tonic::Status,
+        >;
+        /// Params queries all parameters of the gov module.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// Deposit queries single deposit information based proposalID, depositAddr.
+        async fn deposit(
+            &self,
+            request: tonic::Request<super::QueryDepositRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDepositResponse>,
+            tonic::Status,
+        >;
+        /// Deposits queries all deposits of a single proposal.
+        async fn deposits(
+            &self,
+            request: tonic::Request<super::QueryDepositsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDepositsResponse>,
+            tonic::Status,
+        >;
+        /// TallyResult queries the tally of a proposal vote.
+        async fn tally_result(
+            &self,
+            request: tonic::Request<super::QueryTallyResultRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryTallyResultResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service for gov module
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.gov.v1.Query/Proposal" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryProposalRequest>
+                    for ProposalSvc<T> {
+                        type Response = super::QueryProposalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryProposalRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::proposal(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ProposalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/Proposals" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryProposalsRequest>
+                    for ProposalsSvc<T> {
+                        type Response = super::QueryProposalsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryProposalsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::proposals(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ProposalsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/Vote" => {
+                    #[allow(non_camel_case_types)]
+                    struct VoteSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryVoteRequest>
+                    for VoteSvc<T> {
+                        type Response = super::QueryVoteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryVoteRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::vote(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VoteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/Votes" => {
+                    #[allow(non_camel_case_types)]
+                    struct VotesSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryVotesRequest>
+                    for VotesSvc<T> {
+                        type Response = super::QueryVotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryVotesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::votes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/Deposit" => {
+                    #[allow(non_camel_case_types)]
+                    struct DepositSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDepositRequest>
+                    for DepositSvc<T> {
+                        type Response = super::QueryDepositResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDepositRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::deposit(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DepositSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/Deposits" => {
+                    #[allow(non_camel_case_types)]
+                    struct DepositsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDepositsRequest>
+                    for DepositsSvc<T> {
+                        type Response = super::QueryDepositsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDepositsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::deposits(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DepositsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Query/TallyResult" => {
+                    #[allow(non_camel_case_types)]
+                    struct TallyResultSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryTallyResultRequest>
+                    for TallyResultSvc<T> {
+                        type Response = super::QueryTallyResultResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryTallyResultRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::tally_result(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TallyResultSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.gov.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// SubmitProposal defines a method to create new proposal given a content.
+        async fn submit_proposal(
+            &self,
+            request: tonic::Request<super::MsgSubmitProposal>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSubmitProposalResponse>,
+            tonic::Status,
+        >;
+        /// ExecLegacyContent defines a Msg to be in included in a MsgSubmitProposal
+        /// to execute a legacy content-based proposal.
+        async fn exec_legacy_content(
+            &self,
+            request: tonic::Request<super::MsgExecLegacyContent>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgExecLegacyContentResponse>,
+            tonic::Status,
+        >;
+        /// Vote defines a method to add a vote on a specific proposal.
+        async fn vote(
+            &self,
+            request: tonic::Request<super::MsgVote>,
+        ) -> std::result::Result<tonic::Response<super::MsgVoteResponse>, tonic::Status>;
+        /// VoteWeighted defines a method to add a weighted vote on a specific proposal.
+        async fn vote_weighted(
+            &self,
+            request: tonic::Request<super::MsgVoteWeighted>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgVoteWeightedResponse>,
+            tonic::Status,
+        >;
+        /// Deposit defines a method to add deposit on a specific proposal.
+        async fn deposit(
+            &self,
+            request: tonic::Request<super::MsgDeposit>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgDepositResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the gov Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.gov.v1.Msg/SubmitProposal" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitProposalSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgSubmitProposal>
+                    for SubmitProposalSvc<T> {
+                        type Response = super::MsgSubmitProposalResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSubmitProposal>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::submit_proposal(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubmitProposalSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Msg/ExecLegacyContent" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecLegacyContentSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgExecLegacyContent>
+                    for ExecLegacyContentSvc<T> {
+                        type Response = super::MsgExecLegacyContentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgExecLegacyContent>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::exec_legacy_content(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExecLegacyContentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Msg/Vote" => {
+                    #[allow(non_camel_case_types)]
+                    struct VoteSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgVote>
+                    for VoteSvc<T> {
+                        type Response = super::MsgVoteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgVote>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::vote(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VoteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Msg/VoteWeighted" => {
+                    #[allow(non_camel_case_types)]
+                    struct VoteWeightedSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgVoteWeighted>
+                    for VoteWeightedSvc<T> {
+                        type Response = super::MsgVoteWeightedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgVoteWeighted>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::vote_weighted(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = VoteWeightedSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.gov.v1.Msg/Deposit" => {
+                    #[allow(non_camel_case_types)]
+                    struct DepositSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgDeposit>
+                    for DepositSvc<T> {
+                        type Response = super::MsgDepositResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgDeposit>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::deposit(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DepositSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.gov.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<WeightedVoteOption>();
+    registry.register::<Deposit>();
+    registry.register::<Proposal>();
+    registry.register::<TallyResult>();
+    registry.register::<Vote>();
+    registry.register::<DepositParams>();
+    registry.register::<VotingParams>();
+    registry.register::<TallyParams>();
+    registry.register::<GenesisState>();
+    registry.register::<QueryProposalRequest>();
+    registry.register::<QueryProposalResponse>();
+    registry.register::<QueryProposalsRequest>();
+    registry.register::<QueryProposalsResponse>();
+    registry.register::<QueryVoteRequest>();
+    registry.register::<QueryVoteResponse>();
+    registry.register::<QueryVotesRequest>();
+    registry.register::<QueryVotesResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryDepositRequest>();
+    registry.register::<QueryDepositResponse>();
+    registry.register::<QueryDepositsRequest>();
+    registry.register::<QueryDepositsResponse>();
+    registry.register::<QueryTallyResultRequest>();
+    registry.register::<QueryTallyResultResponse>();
+    registry.register::<MsgSubmitProposal>();
+    registry.register::<MsgSubmitProposalResponse>();
+    registry.register::<MsgExecLegacyContent>();
+    registry.register::<MsgExecLegacyContentResponse>();
+    registry.register::<MsgVote>();
+    registry.register::<MsgVoteResponse>();
+    registry.register::<MsgVoteWeighted>();
+    registry.register::<MsgVoteWeightedResponse>();
+    registry.register::<MsgDeposit>();
+    registry.register::<MsgDepositResponse>();
+}
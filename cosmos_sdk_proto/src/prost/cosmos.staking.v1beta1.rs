@@ -2,6 +2,8 @@
 /// StakeAuthorization defines authorization for delegate/undelegate/redelegate.
 ///
 /// Since: cosmos-sdk 0.43
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StakeAuthorization {
     /// max_tokens specifies the maximum amount of tokens can be delegate to a validator. If it is
@@ -10,20 +12,36 @@ pub struct StakeAuthorization {
     pub max_tokens: ::core::option::Option<super::super::base::v1beta1::Coin>,
     /// authorization_type defines one of AuthorizationType.
     #[prost(enumeration = "AuthorizationType", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<AuthorizationType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<AuthorizationType, _>"))]
     pub authorization_type: i32,
     /// validators is the oneof that represents either allow_list or deny_list
     #[prost(oneof = "stake_authorization::ValidatorsEnum", tags = "2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub validators: ::core::option::Option<stake_authorization::ValidatorsEnum>,
 }
 /// Nested message and enum types in `StakeAuthorization`.
 pub mod stake_authorization {
     /// Validators defines list of validator addresses.
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Validators {
         #[prost(string, repeated, tag = "1")]
         pub address: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     }
+    impl ::prost::Name for Validators {
+        const NAME: &'static str = "Validators";
+        const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.staking.v1beta1.StakeAuthorization.Validators".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.staking.v1beta1.StakeAuthorization.Validators".into()
+        }
+    }
     /// validators is the oneof that represents either allow_list or deny_list
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum ValidatorsEnum {
         /// allow_list specifies list of validator addresses to whom grantee can delegate tokens on behalf of granter's
@@ -35,6 +53,16 @@ pub mod stake_authorization {
         DenyList(Validators),
     }
 }
+impl ::prost::Name for StakeAuthorization {
+    const NAME: &'static str = "StakeAuthorization";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.StakeAuthorization".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.StakeAuthorization".into()
+    }
+}
 /// AuthorizationType defines the type of staking module authorization type
 ///
 /// Since: cosmos-sdk 0.43
@@ -74,10 +102,21 @@ impl AuthorizationType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for AuthorizationType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// HistoricalInfo contains header and validator information for a given block.
 /// It is stored as part of staking module's state, which persists the `n` most
 /// recent HistoricalInfo
 /// (`n` is set by the staking module's `historical_entries` parameter).
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HistoricalInfo {
     #[prost(message, optional, tag = "1")]
@@ -85,8 +124,20 @@ pub struct HistoricalInfo {
     #[prost(message, repeated, tag = "2")]
     pub valset: ::prost::alloc::vec::Vec<Validator>,
 }
+impl ::prost::Name for HistoricalInfo {
+    const NAME: &'static str = "HistoricalInfo";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.HistoricalInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.HistoricalInfo".into()
+    }
+}
 /// CommissionRates defines the initial commission rates to be used for creating
 /// a validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommissionRates {
     /// rate is the commission rate charged to delegators, as a fraction.
@@ -99,7 +150,19 @@ pub struct CommissionRates {
     #[prost(string, tag = "3")]
     pub max_change_rate: ::prost::alloc::string::String,
 }
+impl ::prost::Name for CommissionRates {
+    const NAME: &'static str = "CommissionRates";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.CommissionRates".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.CommissionRates".into()
+    }
+}
 /// Commission defines commission parameters for a given validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Commission {
     /// commission_rates defines the initial commission rates to be used for creating a validator.
@@ -107,9 +170,22 @@ pub struct Commission {
     pub commission_rates: ::core::option::Option<CommissionRates>,
     /// update_time is the last time the commission rate was changed.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub update_time: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for Commission {
+    const NAME: &'static str = "Commission";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Commission".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Commission".into()
+    }
+}
 /// Description defines a validator description.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Description {
     /// moniker defines a human-readable name for the validator.
@@ -128,6 +204,16 @@ pub struct Description {
     #[prost(string, tag = "5")]
     pub details: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Description {
+    const NAME: &'static str = "Description";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Description".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Description".into()
+    }
+}
 /// Validator defines a validator, together with the total amount of the
 /// Validator's bond shares and their exchange rate to coins. Slashing results in
 /// a decrease in the exchange rate, allowing correct calculation of future
@@ -136,6 +222,8 @@ pub struct Description {
 /// bond shares is based on the amount of coins delegated divided by the current
 /// exchange rate. Voting power can be calculated as total bonded shares
 /// multiplied by exchange rate.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Validator {
     /// operator_address defines the address of the validator's operator; bech encoded in JSON.
@@ -143,12 +231,14 @@ pub struct Validator {
     pub operator_address: ::prost::alloc::string::String,
     /// consensus_pubkey is the consensus public key of the validator, as a Protobuf Any.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub consensus_pubkey: ::core::option::Option<::prost_types::Any>,
     /// jailed defined whether the validator has been jailed from bonded status or not.
     #[prost(bool, tag = "3")]
     pub jailed: bool,
     /// status is the validator status (bonded/unbonding/unbonded).
     #[prost(enumeration = "BondStatus", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<BondStatus, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<BondStatus, _>"))]
     pub status: i32,
     /// tokens define the delegated tokens (incl. self-delegation).
     #[prost(string, tag = "5")]
@@ -161,9 +251,11 @@ pub struct Validator {
     pub description: ::core::option::Option<Description>,
     /// unbonding_height defines, if unbonding, the height at which this validator has begun unbonding.
     #[prost(int64, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub unbonding_height: i64,
     /// unbonding_time defines, if unbonding, the min time for the validator to complete unbonding.
     #[prost(message, optional, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub unbonding_time: ::core::option::Option<::prost_types::Timestamp>,
     /// commission defines the commission parameters.
     #[prost(message, optional, tag = "10")]
@@ -174,15 +266,39 @@ pub struct Validator {
     #[prost(string, tag = "11")]
     pub min_self_delegation: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Validator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Validator".into()
+    }
+}
 /// ValAddresses defines a repeated set of validator addresses.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValAddresses {
     #[prost(string, repeated, tag = "1")]
     pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
+impl ::prost::Name for ValAddresses {
+    const NAME: &'static str = "ValAddresses";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.ValAddresses".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.ValAddresses".into()
+    }
+}
 /// DVPair is struct that just has a delegator-validator pair with no other data.
 /// It is intended to be used as a marshalable pointer. For example, a DVPair can
 /// be used to construct the key to getting an UnbondingDelegation from state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvPair {
     #[prost(string, tag = "1")]
@@ -190,16 +306,40 @@ pub struct DvPair {
     #[prost(string, tag = "2")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for DvPair {
+    const NAME: &'static str = "DVPair";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.DVPair".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.DVPair".into()
+    }
+}
 /// DVPairs defines an array of DVPair objects.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvPairs {
     #[prost(message, repeated, tag = "1")]
     pub pairs: ::prost::alloc::vec::Vec<DvPair>,
 }
+impl ::prost::Name for DvPairs {
+    const NAME: &'static str = "DVPairs";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.DVPairs".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.DVPairs".into()
+    }
+}
 /// DVVTriplet is struct that just has a delegator-validator-validator triplet
 /// with no other data. It is intended to be used as a marshalable pointer. For
 /// example, a DVVTriplet can be used to construct the key to getting a
 /// Redelegation from state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvvTriplet {
     #[prost(string, tag = "1")]
@@ -209,15 +349,39 @@ pub struct DvvTriplet {
     #[prost(string, tag = "3")]
     pub validator_dst_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for DvvTriplet {
+    const NAME: &'static str = "DVVTriplet";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.DVVTriplet".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.DVVTriplet".into()
+    }
+}
 /// DVVTriplets defines an array of DVVTriplet objects.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DvvTriplets {
     #[prost(message, repeated, tag = "1")]
     pub triplets: ::prost::alloc::vec::Vec<DvvTriplet>,
 }
+impl ::prost::Name for DvvTriplets {
+    const NAME: &'static str = "DVVTriplets";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.DVVTriplets".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.DVVTriplets".into()
+    }
+}
 /// Delegation represents the bond with tokens held by an account. It is
 /// owned by one delegator, and is associated with the voting power of one
 /// validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Delegation {
     /// delegator_address is the bech32-encoded address of the delegator.
@@ -230,8 +394,20 @@ pub struct Delegation {
     #[prost(string, tag = "3")]
     pub shares: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Delegation {
+    const NAME: &'static str = "Delegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Delegation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Delegation".into()
+    }
+}
 /// UnbondingDelegation stores all of a single delegator's unbonding bonds
 /// for a single validator in an time-ordered list.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnbondingDelegation {
     /// delegator_address is the bech32-encoded address of the delegator.
@@ -246,14 +422,28 @@ pub struct UnbondingDelegation {
     #[prost(message, repeated, tag = "3")]
     pub entries: ::prost::alloc::vec::Vec<UnbondingDelegationEntry>,
 }
+impl ::prost::Name for UnbondingDelegation {
+    const NAME: &'static str = "UnbondingDelegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.UnbondingDelegation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.UnbondingDelegation".into()
+    }
+}
 /// UnbondingDelegationEntry defines an unbonding object with relevant metadata.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnbondingDelegationEntry {
     /// creation_height is the height which the unbonding took place.
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub creation_height: i64,
     /// completion_time is the unix time for unbonding completion.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub completion_time: ::core::option::Option<::prost_types::Timestamp>,
     /// initial_balance defines the tokens initially scheduled to receive at completion.
     #[prost(string, tag = "3")]
@@ -262,14 +452,28 @@ pub struct UnbondingDelegationEntry {
     #[prost(string, tag = "4")]
     pub balance: ::prost::alloc::string::String,
 }
+impl ::prost::Name for UnbondingDelegationEntry {
+    const NAME: &'static str = "UnbondingDelegationEntry";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.UnbondingDelegationEntry".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.UnbondingDelegationEntry".into()
+    }
+}
 /// RedelegationEntry defines a redelegation object with relevant metadata.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RedelegationEntry {
     /// creation_height  defines the height which the redelegation took place.
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub creation_height: i64,
     /// completion_time defines the unix time for redelegation completion.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub completion_time: ::core::option::Option<::prost_types::Timestamp>,
     /// initial_balance defines the initial balance when redelegation started.
     #[prost(string, tag = "3")]
@@ -278,8 +482,20 @@ pub struct RedelegationEntry {
     #[prost(string, tag = "4")]
     pub shares_dst: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RedelegationEntry {
+    const NAME: &'static str = "RedelegationEntry";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.RedelegationEntry".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.RedelegationEntry".into()
+    }
+}
 /// Redelegation contains the list of a particular delegator's redelegating bonds
 /// from a particular source validator to a particular destination validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Redelegation {
     /// delegator_address is the bech32-encoded address of the delegator.
@@ -297,11 +513,24 @@ pub struct Redelegation {
     #[prost(message, repeated, tag = "4")]
     pub entries: ::prost::alloc::vec::Vec<RedelegationEntry>,
 }
+impl ::prost::Name for Redelegation {
+    const NAME: &'static str = "Redelegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Redelegation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Redelegation".into()
+    }
+}
 /// Params defines the parameters for the staking module.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// unbonding_time is the time duration of unbonding.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub unbonding_time: ::core::option::Option<::prost_types::Duration>,
     /// max_validators is the maximum number of validators.
     #[prost(uint32, tag = "2")]
@@ -319,8 +548,20 @@ pub struct Params {
     #[prost(string, tag = "6")]
     pub min_commission_rate: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Params".into()
+    }
+}
 /// DelegationResponse is equivalent to Delegation except that it contains a
 /// balance in addition to shares which is more suitable for client responses.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DelegationResponse {
     #[prost(message, optional, tag = "1")]
@@ -328,9 +569,21 @@ pub struct DelegationResponse {
     #[prost(message, optional, tag = "2")]
     pub balance: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for DelegationResponse {
+    const NAME: &'static str = "DelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.DelegationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.DelegationResponse".into()
+    }
+}
 /// RedelegationEntryResponse is equivalent to a RedelegationEntry except that it
 /// contains a balance in addition to shares which is more suitable for client
 /// responses.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RedelegationEntryResponse {
     #[prost(message, optional, tag = "1")]
@@ -338,9 +591,21 @@ pub struct RedelegationEntryResponse {
     #[prost(string, tag = "4")]
     pub balance: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RedelegationEntryResponse {
+    const NAME: &'static str = "RedelegationEntryResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.RedelegationEntryResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.RedelegationEntryResponse".into()
+    }
+}
 /// RedelegationResponse is equivalent to a Redelegation except that its entries
 /// contain a balance in addition to shares which is more suitable for client
 /// responses.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RedelegationResponse {
     #[prost(message, optional, tag = "1")]
@@ -348,8 +613,20 @@ pub struct RedelegationResponse {
     #[prost(message, repeated, tag = "2")]
     pub entries: ::prost::alloc::vec::Vec<RedelegationEntryResponse>,
 }
+impl ::prost::Name for RedelegationResponse {
+    const NAME: &'static str = "RedelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.RedelegationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.RedelegationResponse".into()
+    }
+}
 /// Pool is used for tracking bonded and not-bonded token supply of the bond
 /// denomination.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Pool {
     #[prost(string, tag = "1")]
@@ -357,6 +634,16 @@ pub struct Pool {
     #[prost(string, tag = "2")]
     pub bonded_tokens: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Pool {
+    const NAME: &'static str = "Pool";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.Pool".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.Pool".into()
+    }
+}
 /// BondStatus is the status of a validator.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -394,7 +681,18 @@ impl BondStatus {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for BondStatus {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// GenesisState defines the staking module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     /// params defines all the paramaters of related to deposit.
@@ -403,6 +701,7 @@ pub struct GenesisState {
     /// last_total_power tracks the total amounts of bonded tokens recorded during
     /// the previous end block.
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub last_total_power: ::prost::alloc::vec::Vec<u8>,
     /// last_validator_powers is a special index that provides a historical list
     /// of the last-block's bonded validators.
@@ -423,7 +722,19 @@ pub struct GenesisState {
     #[prost(bool, tag = "8")]
     pub exported: bool,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.GenesisState".into()
+    }
+}
 /// LastValidatorPower required for validator set update logic.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LastValidatorPower {
     /// address is the address of the validator.
@@ -431,9 +742,22 @@ pub struct LastValidatorPower {
     pub address: ::prost::alloc::string::String,
     /// power defines the power of the validator.
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub power: i64,
 }
+impl ::prost::Name for LastValidatorPower {
+    const NAME: &'static str = "LastValidatorPower";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.LastValidatorPower".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.LastValidatorPower".into()
+    }
+}
 /// QueryValidatorsRequest is request type for Query/Validators RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorsRequest {
     /// status enables to query for validators matching a given status.
@@ -445,7 +769,19 @@ pub struct QueryValidatorsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryValidatorsRequest {
+    const NAME: &'static str = "QueryValidatorsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorsRequest".into()
+    }
+}
 /// QueryValidatorsResponse is response type for the Query/Validators RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorsResponse {
     /// validators contains all the queried validators.
@@ -457,22 +793,58 @@ pub struct QueryValidatorsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryValidatorsResponse {
+    const NAME: &'static str = "QueryValidatorsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorsResponse".into()
+    }
+}
 /// QueryValidatorRequest is response type for the Query/Validator RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorRequest {
     /// validator_addr defines the validator address to query for.
     #[prost(string, tag = "1")]
     pub validator_addr: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryValidatorRequest {
+    const NAME: &'static str = "QueryValidatorRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorRequest".into()
+    }
+}
 /// QueryValidatorResponse is response type for the Query/Validator RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorResponse {
     /// validator defines the validator info.
     #[prost(message, optional, tag = "1")]
     pub validator: ::core::option::Option<Validator>,
 }
+impl ::prost::Name for QueryValidatorResponse {
+    const NAME: &'static str = "QueryValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorResponse".into()
+    }
+}
 /// QueryValidatorDelegationsRequest is request type for the
 /// Query/ValidatorDelegations RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorDelegationsRequest {
     /// validator_addr defines the validator address to query for.
@@ -484,8 +856,20 @@ pub struct QueryValidatorDelegationsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryValidatorDelegationsRequest {
+    const NAME: &'static str = "QueryValidatorDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorDelegationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorDelegationsRequest".into()
+    }
+}
 /// QueryValidatorDelegationsResponse is response type for the
 /// Query/ValidatorDelegations RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorDelegationsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -496,8 +880,20 @@ pub struct QueryValidatorDelegationsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryValidatorDelegationsResponse {
+    const NAME: &'static str = "QueryValidatorDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorDelegationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorDelegationsResponse".into()
+    }
+}
 /// QueryValidatorUnbondingDelegationsRequest is required type for the
 /// Query/ValidatorUnbondingDelegations RPC method
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorUnbondingDelegationsRequest {
     /// validator_addr defines the validator address to query for.
@@ -509,8 +905,20 @@ pub struct QueryValidatorUnbondingDelegationsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryValidatorUnbondingDelegationsRequest {
+    const NAME: &'static str = "QueryValidatorUnbondingDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsRequest".into()
+    }
+}
 /// QueryValidatorUnbondingDelegationsResponse is response type for the
 /// Query/ValidatorUnbondingDelegations RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValidatorUnbondingDelegationsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -521,7 +929,19 @@ pub struct QueryValidatorUnbondingDelegationsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryValidatorUnbondingDelegationsResponse {
+    const NAME: &'static str = "QueryValidatorUnbondingDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryValidatorUnbondingDelegationsResponse".into()
+    }
+}
 /// QueryDelegationRequest is request type for the Query/Delegation RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -531,15 +951,39 @@ pub struct QueryDelegationRequest {
     #[prost(string, tag = "2")]
     pub validator_addr: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegationRequest {
+    const NAME: &'static str = "QueryDelegationRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegationRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegationRequest".into()
+    }
+}
 /// QueryDelegationResponse is response type for the Query/Delegation RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegationResponse {
     /// delegation_responses defines the delegation info of a delegation.
     #[prost(message, optional, tag = "1")]
     pub delegation_response: ::core::option::Option<DelegationResponse>,
 }
+impl ::prost::Name for QueryDelegationResponse {
+    const NAME: &'static str = "QueryDelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegationResponse".into()
+    }
+}
 /// QueryUnbondingDelegationRequest is request type for the
 /// Query/UnbondingDelegation RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryUnbondingDelegationRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -549,16 +993,40 @@ pub struct QueryUnbondingDelegationRequest {
     #[prost(string, tag = "2")]
     pub validator_addr: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryUnbondingDelegationRequest {
+    const NAME: &'static str = "QueryUnbondingDelegationRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryUnbondingDelegationRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryUnbondingDelegationRequest".into()
+    }
+}
 /// QueryDelegationResponse is response type for the Query/UnbondingDelegation
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryUnbondingDelegationResponse {
     /// unbond defines the unbonding information of a delegation.
     #[prost(message, optional, tag = "1")]
     pub unbond: ::core::option::Option<UnbondingDelegation>,
 }
+impl ::prost::Name for QueryUnbondingDelegationResponse {
+    const NAME: &'static str = "QueryUnbondingDelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryUnbondingDelegationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryUnbondingDelegationResponse".into()
+    }
+}
 /// QueryDelegatorDelegationsRequest is request type for the
 /// Query/DelegatorDelegations RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorDelegationsRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -570,8 +1038,20 @@ pub struct QueryDelegatorDelegationsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryDelegatorDelegationsRequest {
+    const NAME: &'static str = "QueryDelegatorDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorDelegationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorDelegationsRequest".into()
+    }
+}
 /// QueryDelegatorDelegationsResponse is response type for the
 /// Query/DelegatorDelegations RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorDelegationsResponse {
     /// delegation_responses defines all the delegations' info of a delegator.
@@ -583,8 +1063,20 @@ pub struct QueryDelegatorDelegationsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryDelegatorDelegationsResponse {
+    const NAME: &'static str = "QueryDelegatorDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorDelegationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorDelegationsResponse".into()
+    }
+}
 /// QueryDelegatorUnbondingDelegationsRequest is request type for the
 /// Query/DelegatorUnbondingDelegations RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorUnbondingDelegationsRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -596,8 +1088,20 @@ pub struct QueryDelegatorUnbondingDelegationsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryDelegatorUnbondingDelegationsRequest {
+    const NAME: &'static str = "QueryDelegatorUnbondingDelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsRequest".into()
+    }
+}
 /// QueryUnbondingDelegatorDelegationsResponse is response type for the
 /// Query/UnbondingDelegatorDelegations RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorUnbondingDelegationsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -608,8 +1112,20 @@ pub struct QueryDelegatorUnbondingDelegationsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryDelegatorUnbondingDelegationsResponse {
+    const NAME: &'static str = "QueryDelegatorUnbondingDelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorUnbondingDelegationsResponse".into()
+    }
+}
 /// QueryRedelegationsRequest is request type for the Query/Redelegations RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRedelegationsRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -627,8 +1143,20 @@ pub struct QueryRedelegationsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryRedelegationsRequest {
+    const NAME: &'static str = "QueryRedelegationsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryRedelegationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryRedelegationsRequest".into()
+    }
+}
 /// QueryRedelegationsResponse is response type for the Query/Redelegations RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryRedelegationsResponse {
     #[prost(message, repeated, tag = "1")]
@@ -639,8 +1167,20 @@ pub struct QueryRedelegationsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryRedelegationsResponse {
+    const NAME: &'static str = "QueryRedelegationsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryRedelegationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryRedelegationsResponse".into()
+    }
+}
 /// QueryDelegatorValidatorsRequest is request type for the
 /// Query/DelegatorValidators RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorsRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -652,8 +1192,20 @@ pub struct QueryDelegatorValidatorsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for QueryDelegatorValidatorsRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorValidatorsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorsRequest".into()
+    }
+}
 /// QueryDelegatorValidatorsResponse is response type for the
 /// Query/DelegatorValidators RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorsResponse {
     /// validators defines the validators' info of a delegator.
@@ -665,8 +1217,20 @@ pub struct QueryDelegatorValidatorsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for QueryDelegatorValidatorsResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorValidatorsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorsResponse".into()
+    }
+}
 /// QueryDelegatorValidatorRequest is request type for the
 /// Query/DelegatorValidator RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorRequest {
     /// delegator_addr defines the delegator address to query for.
@@ -676,50 +1240,147 @@ pub struct QueryDelegatorValidatorRequest {
     #[prost(string, tag = "2")]
     pub validator_addr: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegatorValidatorRequest {
+    const NAME: &'static str = "QueryDelegatorValidatorRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorValidatorRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorRequest".into()
+    }
+}
 /// QueryDelegatorValidatorResponse response type for the
 /// Query/DelegatorValidator RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegatorValidatorResponse {
     /// validator defines the validator info.
     #[prost(message, optional, tag = "1")]
     pub validator: ::core::option::Option<Validator>,
 }
+impl ::prost::Name for QueryDelegatorValidatorResponse {
+    const NAME: &'static str = "QueryDelegatorValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryDelegatorValidatorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryDelegatorValidatorResponse".into()
+    }
+}
 /// QueryHistoricalInfoRequest is request type for the Query/HistoricalInfo RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryHistoricalInfoRequest {
     /// height defines at which height to query the historical info.
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for QueryHistoricalInfoRequest {
+    const NAME: &'static str = "QueryHistoricalInfoRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryHistoricalInfoRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryHistoricalInfoRequest".into()
+    }
+}
 /// QueryHistoricalInfoResponse is response type for the Query/HistoricalInfo RPC
 /// method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryHistoricalInfoResponse {
     /// hist defines the historical info at the given height.
     #[prost(message, optional, tag = "1")]
     pub hist: ::core::option::Option<HistoricalInfo>,
 }
+impl ::prost::Name for QueryHistoricalInfoResponse {
+    const NAME: &'static str = "QueryHistoricalInfoResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryHistoricalInfoResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryHistoricalInfoResponse".into()
+    }
+}
 /// QueryPoolRequest is request type for the Query/Pool RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryPoolRequest {}
+impl ::prost::Name for QueryPoolRequest {
+    const NAME: &'static str = "QueryPoolRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryPoolRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryPoolRequest".into()
+    }
+}
 /// QueryPoolResponse is response type for the Query/Pool RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPoolResponse {
     /// pool defines the pool info.
     #[prost(message, optional, tag = "1")]
     pub pool: ::core::option::Option<Pool>,
 }
+impl ::prost::Name for QueryPoolResponse {
+    const NAME: &'static str = "QueryPoolResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryPoolResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryPoolResponse".into()
+    }
+}
 /// QueryParamsRequest is request type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryParamsRequest".into()
+    }
+}
 /// QueryParamsResponse is response type for the Query/Params RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     /// params holds all the parameters of this module.
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.QueryParamsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -1209,6 +1870,8 @@ pub mod query_client {
     }
 }
 /// MsgCreateValidator defines a SDK message for creating a new validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCreateValidator {
     #[prost(message, optional, tag = "1")]
@@ -1222,14 +1885,39 @@ pub struct MsgCreateValidator {
     #[prost(string, tag = "5")]
     pub validator_address: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub pubkey: ::core::option::Option<::prost_types::Any>,
     #[prost(message, optional, tag = "7")]
     pub value: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgCreateValidator {
+    const NAME: &'static str = "MsgCreateValidator";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgCreateValidator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgCreateValidator".into()
+    }
+}
 /// MsgCreateValidatorResponse defines the Msg/CreateValidator response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgCreateValidatorResponse {}
+impl ::prost::Name for MsgCreateValidatorResponse {
+    const NAME: &'static str = "MsgCreateValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgCreateValidatorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgCreateValidatorResponse".into()
+    }
+}
 /// MsgEditValidator defines a SDK message for editing an existing validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgEditValidator {
     #[prost(message, optional, tag = "1")]
@@ -1245,11 +1933,35 @@ pub struct MsgEditValidator {
     #[prost(string, tag = "4")]
     pub min_self_delegation: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgEditValidator {
+    const NAME: &'static str = "MsgEditValidator";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgEditValidator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgEditValidator".into()
+    }
+}
 /// MsgEditValidatorResponse defines the Msg/EditValidator response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgEditValidatorResponse {}
+impl ::prost::Name for MsgEditValidatorResponse {
+    const NAME: &'static str = "MsgEditValidatorResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgEditValidatorResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgEditValidatorResponse".into()
+    }
+}
 /// MsgDelegate defines a SDK message for performing a delegation of coins
 /// from a delegator to a validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgDelegate {
     #[prost(string, tag = "1")]
@@ -1259,11 +1971,35 @@ pub struct MsgDelegate {
     #[prost(message, optional, tag = "3")]
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgDelegate {
+    const NAME: &'static str = "MsgDelegate";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgDelegate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgDelegate".into()
+    }
+}
 /// MsgDelegateResponse defines the Msg/Delegate response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgDelegateResponse {}
+impl ::prost::Name for MsgDelegateResponse {
+    const NAME: &'static str = "MsgDelegateResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgDelegateResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgDelegateResponse".into()
+    }
+}
 /// MsgBeginRedelegate defines a SDK message for performing a redelegation
 /// of coins from a delegator and source validator to a destination validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgBeginRedelegate {
     #[prost(string, tag = "1")]
@@ -1275,14 +2011,39 @@ pub struct MsgBeginRedelegate {
     #[prost(message, optional, tag = "4")]
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgBeginRedelegate {
+    const NAME: &'static str = "MsgBeginRedelegate";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgBeginRedelegate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgBeginRedelegate".into()
+    }
+}
 /// MsgBeginRedelegateResponse defines the Msg/BeginRedelegate response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgBeginRedelegateResponse {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub completion_time: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for MsgBeginRedelegateResponse {
+    const NAME: &'static str = "MsgBeginRedelegateResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgBeginRedelegateResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgBeginRedelegateResponse".into()
+    }
+}
 /// MsgUndelegate defines a SDK message for performing an undelegation from a
 /// delegate and a validator.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgUndelegate {
     #[prost(string, tag = "1")]
@@ -1292,15 +2053,40 @@ pub struct MsgUndelegate {
     #[prost(message, optional, tag = "3")]
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgUndelegate {
+    const NAME: &'static str = "MsgUndelegate";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgUndelegate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgUndelegate".into()
+    }
+}
 /// MsgUndelegateResponse defines the Msg/Undelegate response type.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgUndelegateResponse {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub completion_time: ::core::option::Option<::prost_types::Timestamp>,
 }
+impl ::prost::Name for MsgUndelegateResponse {
+    const NAME: &'static str = "MsgUndelegateResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgUndelegateResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgUndelegateResponse".into()
+    }
+}
 /// MsgCancelUnbondingDelegation defines the SDK message for performing a cancel unbonding delegation for delegator
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCancelUnbondingDelegation {
     #[prost(string, tag = "1")]
@@ -1312,13 +2098,38 @@ pub struct MsgCancelUnbondingDelegation {
     pub amount: ::core::option::Option<super::super::base::v1beta1::Coin>,
     /// creation_height is the height which the unbonding took place.
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub creation_height: i64,
 }
+impl ::prost::Name for MsgCancelUnbondingDelegation {
+    const NAME: &'static str = "MsgCancelUnbondingDelegation";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgCancelUnbondingDelegation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegation".into()
+    }
+}
 /// MsgCancelUnbondingDelegationResponse
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgCancelUnbondingDelegationResponse {}
+impl ::prost::Name for MsgCancelUnbondingDelegationResponse {
+    const NAME: &'static str = "MsgCancelUnbondingDelegationResponse";
+    const PACKAGE: &'static str = "cosmos.staking.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.staking.v1beta1.MsgCancelUnbondingDelegationResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegationResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -1578,3 +2389,1416 @@ pub mod msg_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Validators queries all validators that match the given status.
+        async fn validators(
+            &self,
+            request: tonic::Request<super::QueryValidatorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorsResponse>,
+            tonic::Status,
+        >;
+        /// Validator queries validator info for given validator address.
+        async fn validator(
+            &self,
+            request: tonic::Request<super::QueryValidatorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorResponse>,
+            tonic::Status,
+        >;
+        /// ValidatorDelegations queries delegate info for given validator.
+        async fn validator_delegations(
+            &self,
+            request: tonic::Request<super::QueryValidatorDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorDelegationsResponse>,
+            tonic::Status,
+        >;
+        /// ValidatorUnbondingDelegations queries unbonding delegations of a validator.
+        async fn validator_unbonding_delegations(
+            &self,
+            request: tonic::Request<super::QueryValidatorUnbondingDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValidatorUnbondingDelegationsResponse>,
+            tonic::Status,
+        >;
+        /// Delegation queries delegate info for given validator delegator pair.
+        async fn delegation(
+            &self,
+            request: tonic::Request<super::QueryDelegationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegationResponse>,
+            tonic::Status,
+        >;
+        /// UnbondingDelegation queries unbonding info for given validator delegator
+        /// pair.
+        async fn unbonding_delegation(
+            &self,
+            request: tonic::Request<super::QueryUnbondingDelegationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryUnbondingDelegationResponse>,
+            tonic::Status,
+        >;
+        /// DelegatorDelegations queries all delegations of a given delegator address.
+        async fn delegator_delegations(
+            &self,
+            request: tonic::Request<super::QueryDelegatorDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegatorDelegationsResponse>,
+            tonic::Status,
+        >;
+        /// DelegatorUnbondingDelegations queries all unbonding delegations of a given
+        /// delegator address.
+        async fn delegator_unbonding_delegations(
+            &self,
+            request: tonic::Request<super::QueryDelegatorUnbondingDelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegatorUnbondingDelegationsResponse>,
+            tonic::Status,
+        >;
+        /// Redelegations queries redelegations of given address.
+        async fn redelegations(
+            &self,
+            request: tonic::Request<super::QueryRedelegationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryRedelegationsResponse>,
+            tonic::Status,
+        >;
+        /// DelegatorValidators queries all validators info for given delegator
+        /// address.
+        async fn delegator_validators(
+            &self,
+            request: tonic::Request<super::QueryDelegatorValidatorsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegatorValidatorsResponse>,
+            tonic::Status,
+        >;
+        /// DelegatorValidator queries validator info for given delegator validator
+        /// pair.
+        async fn delegator_validator(
+            &self,
+            request: tonic::Request<super::QueryDelegatorValidatorRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegatorValidatorResponse>,
+            tonic::Status,
+        >;
+        /// HistoricalInfo queries the historical info for given height.
+        async fn historical_info(
+            &self,
+            request: tonic::Request<super::QueryHistoricalInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryHistoricalInfoResponse>,
+            tonic::Status,
+        >;
+        /// Pool queries the pool info.
+        async fn pool(
+            &self,
+            request: tonic::Request<super::QueryPoolRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryPoolResponse>,
+            tonic::Status,
+        >;
+        /// Parameters queries the staking parameters.
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.staking.v1beta1.Query/Validators" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValidatorsRequest>
+                    for ValidatorsSvc<T> {
+                        type Response = super::QueryValidatorsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryValidatorsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validators(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/Validator" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValidatorRequest>
+                    for ValidatorSvc<T> {
+                        type Response = super::QueryValidatorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryValidatorRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/ValidatorDelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorDelegationsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryValidatorDelegationsRequest,
+                    > for ValidatorDelegationsSvc<T> {
+                        type Response = super::QueryValidatorDelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryValidatorDelegationsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator_delegations(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorDelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorUnbondingDelegationsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryValidatorUnbondingDelegationsRequest,
+                    > for ValidatorUnbondingDelegationsSvc<T> {
+                        type Response = super::QueryValidatorUnbondingDelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryValidatorUnbondingDelegationsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::validator_unbonding_delegations(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorUnbondingDelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/Delegation" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegationSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDelegationRequest>
+                    for DelegationSvc<T> {
+                        type Response = super::QueryDelegationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDelegationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegation(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/UnbondingDelegation" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnbondingDelegationSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryUnbondingDelegationRequest>
+                    for UnbondingDelegationSvc<T> {
+                        type Response = super::QueryUnbondingDelegationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryUnbondingDelegationRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::unbonding_delegation(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UnbondingDelegationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/DelegatorDelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegatorDelegationsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryDelegatorDelegationsRequest,
+                    > for DelegatorDelegationsSvc<T> {
+                        type Response = super::QueryDelegatorDelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegatorDelegationsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegator_delegations(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegatorDelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegatorUnbondingDelegationsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryDelegatorUnbondingDelegationsRequest,
+                    > for DelegatorUnbondingDelegationsSvc<T> {
+                        type Response = super::QueryDelegatorUnbondingDelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegatorUnbondingDelegationsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegator_unbonding_delegations(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegatorUnbondingDelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/Redelegations" => {
+                    #[allow(non_camel_case_types)]
+                    struct RedelegationsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryRedelegationsRequest>
+                    for RedelegationsSvc<T> {
+                        type Response = super::QueryRedelegationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRedelegationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::redelegations(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RedelegationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/DelegatorValidators" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegatorValidatorsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDelegatorValidatorsRequest>
+                    for DelegatorValidatorsSvc<T> {
+                        type Response = super::QueryDelegatorValidatorsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegatorValidatorsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegator_validators(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegatorValidatorsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/DelegatorValidator" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegatorValidatorSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDelegatorValidatorRequest>
+                    for DelegatorValidatorSvc<T> {
+                        type Response = super::QueryDelegatorValidatorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegatorValidatorRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::delegator_validator(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegatorValidatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/HistoricalInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct HistoricalInfoSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryHistoricalInfoRequest>
+                    for HistoricalInfoSvc<T> {
+                        type Response = super::QueryHistoricalInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryHistoricalInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::historical_info(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HistoricalInfoSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/Pool" => {
+                    #[allow(non_camel_case_types)]
+                    struct PoolSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryPoolRequest>
+                    for PoolSvc<T> {
+                        type Response = super::QueryPoolResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryPoolRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PoolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.staking.v1beta1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        /// CreateValidator defines a method for creating a new validator.
+        async fn create_validator(
+            &self,
+            request: tonic::Request<super::MsgCreateValidator>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgCreateValidatorResponse>,
+            tonic::Status,
+        >;
+        /// EditValidator defines a method for editing an existing validator.
+        async fn edit_validator(
+            &self,
+            request: tonic::Request<super::MsgEditValidator>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgEditValidatorResponse>,
+            tonic::Status,
+        >;
+        /// Delegate defines a method for performing a delegation of coins
+        /// from a delegator to a validator.
+        async fn delegate(
+            &self,
+            request: tonic::Request<super::MsgDelegate>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgDelegateResponse>,
+            tonic::Status,
+        >;
+        /// BeginRedelegate defines a method for performing a redelegation
+        /// of coins from a delegator and source validator to a destination validator.
+        async fn begin_redelegate(
+            &self,
+            request: tonic::Request<super::MsgBeginRedelegate>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgBeginRedelegateResponse>,
+            tonic::Status,
+        >;
+        /// Undelegate defines a method for performing an undelegation from a
+        /// delegate and a validator.
+        async fn undelegate(
+            &self,
+            request: tonic::Request<super::MsgUndelegate>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgUndelegateResponse>,
+            tonic::Status,
+        >;
+        /// CancelUnbondingDelegation defines a method for performing canceling the unbonding delegation
+        /// and delegate back to previous validator.
+        ///
+        /// Since: cosmos-sdk 0.46
+        async fn cancel_unbonding_delegation(
+            &self,
+            request: tonic::Request<super::MsgCancelUnbondingDelegation>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgCancelUnbondingDelegationResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the staking Msg service.
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.staking.v1beta1.Msg/CreateValidator" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateValidatorSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgCreateValidator>
+                    for CreateValidatorSvc<T> {
+                        type Response = super::MsgCreateValidatorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgCreateValidator>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::create_validator(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateValidatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Msg/EditValidator" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditValidatorSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgEditValidator>
+                    for EditValidatorSvc<T> {
+                        type Response = super::MsgEditValidatorResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgEditValidator>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::edit_validator(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditValidatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Msg/Delegate" => {
+                    #[allow(non_camel_case_types)]
+                    struct DelegateSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgDelegate>
+                    for DelegateSvc<T> {
+                        type Response = super::MsgDelegateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgDelegate>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::delegate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DelegateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Msg/BeginRedelegate" => {
+                    #[allow(non_camel_case_types)]
+                    struct BeginRedelegateSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgBeginRedelegate>
+                    for BeginRedelegateSvc<T> {
+                        type Response = super::MsgBeginRedelegateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgBeginRedelegate>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::begin_redelegate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BeginRedelegateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Msg/Undelegate" => {
+                    #[allow(non_camel_case_types)]
+                    struct UndelegateSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgUndelegate>
+                    for UndelegateSvc<T> {
+                        type Response = super::MsgUndelegateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgUndelegate>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::undelegate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UndelegateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.staking.v1beta1.Msg/CancelUnbondingDelegation" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelUnbondingDelegationSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgCancelUnbondingDelegation>
+                    for CancelUnbondingDelegationSvc<T> {
+                        type Response = super::MsgCancelUnbondingDelegationResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgCancelUnbondingDelegation>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::cancel_unbonding_delegation(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelUnbondingDelegationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.staking.v1beta1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<StakeAuthorization>();
+    registry.register::<stake_authorization::Validators>();
+    registry.register::<HistoricalInfo>();
+    registry.register::<CommissionRates>();
+    registry.register::<Commission>();
+    registry.register::<Description>();
+    registry.register::<Validator>();
+    registry.register::<ValAddresses>();
+    registry.register::<DvPair>();
+    registry.register::<DvPairs>();
+    registry.register::<DvvTriplet>();
+    registry.register::<DvvTriplets>();
+    registry.register::<Delegation>();
+    registry.register::<UnbondingDelegation>();
+    registry.register::<UnbondingDelegationEntry>();
+    registry.register::<RedelegationEntry>();
+    registry.register::<Redelegation>();
+    registry.register::<Params>();
+    registry.register::<DelegationResponse>();
+    registry.register::<RedelegationEntryResponse>();
+    registry.register::<RedelegationResponse>();
+    registry.register::<Pool>();
+    registry.register::<GenesisState>();
+    registry.register::<LastValidatorPower>();
+    registry.register::<QueryValidatorsRequest>();
+    registry.register::<QueryValidatorsResponse>();
+    registry.register::<QueryValidatorRequest>();
+    registry.register::<QueryValidatorResponse>();
+    registry.register::<QueryValidatorDelegationsRequest>();
+    registry.register::<QueryValidatorDelegationsResponse>();
+    registry.register::<QueryValidatorUnbondingDelegationsRequest>();
+    registry.register::<QueryValidatorUnbondingDelegationsResponse>();
+    registry.register::<QueryDelegationRequest>();
+    registry.register::<QueryDelegationResponse>();
+    registry.register::<QueryUnbondingDelegationRequest>();
+    registry.register::<QueryUnbondingDelegationResponse>();
+    registry.register::<QueryDelegatorDelegationsRequest>();
+    registry.register::<QueryDelegatorDelegationsResponse>();
+    registry.register::<QueryDelegatorUnbondingDelegationsRequest>();
+    registry.register::<QueryDelegatorUnbondingDelegationsResponse>();
+    registry.register::<QueryRedelegationsRequest>();
+    registry.register::<QueryRedelegationsResponse>();
+    registry.register::<QueryDelegatorValidatorsRequest>();
+    registry.register::<QueryDelegatorValidatorsResponse>();
+    registry.register::<QueryDelegatorValidatorRequest>();
+    registry.register::<QueryDelegatorValidatorResponse>();
+    registry.register::<QueryHistoricalInfoRequest>();
+    registry.register::<QueryHistoricalInfoResponse>();
+    registry.register::<QueryPoolRequest>();
+    registry.register::<QueryPoolResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<MsgCreateValidator>();
+    registry.register::<MsgCreateValidatorResponse>();
+    registry.register::<MsgEditValidator>();
+    registry.register::<MsgEditValidatorResponse>();
+    registry.register::<MsgDelegate>();
+    registry.register::<MsgDelegateResponse>();
+    registry.register::<MsgBeginRedelegate>();
+    registry.register::<MsgBeginRedelegateResponse>();
+    registry.register::<MsgUndelegate>();
+    registry.register::<MsgUndelegateResponse>();
+    registry.register::<MsgCancelUnbondingDelegation>();
+    registry.register::<MsgCancelUnbondingDelegationResponse>();
+}
@@ -1,19 +1,34 @@
 // This file is @generated by prost-build.
 /// SignatureDescriptors wraps multiple SignatureDescriptor's.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignatureDescriptors {
     /// signatures are the signature descriptors
     #[prost(message, repeated, tag = "1")]
     pub signatures: ::prost::alloc::vec::Vec<SignatureDescriptor>,
 }
+impl ::prost::Name for SignatureDescriptors {
+    const NAME: &'static str = "SignatureDescriptors";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.signing.v1beta1.SignatureDescriptors".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.signing.v1beta1.SignatureDescriptors".into()
+    }
+}
 /// SignatureDescriptor is a convenience type which represents the full data for
 /// a signature including the public key of the signer, signing modes and the
 /// signature itself. It is primarily used for coordinating signatures between
 /// clients.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignatureDescriptor {
     /// public_key is the public key of the signer
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     #[prost(message, optional, tag = "2")]
     pub data: ::core::option::Option<signature_descriptor::Data>,
@@ -21,30 +36,50 @@ pub struct SignatureDescriptor {
     /// number of committed transactions signed by a given address. It is used to prevent
     /// replay attacks.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
 }
 /// Nested message and enum types in `SignatureDescriptor`.
 pub mod signature_descriptor {
     /// Data represents signature data
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Data {
         /// sum is the oneof that specifies whether this represents single or multi-signature data
         #[prost(oneof = "data::Sum", tags = "1, 2")]
+        #[cfg_attr(feature = "serde", serde(flatten))]
         pub sum: ::core::option::Option<data::Sum>,
     }
     /// Nested message and enum types in `Data`.
     pub mod data {
         /// Single is the signature data for a single signer
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct Single {
             /// mode is the signing mode of the single signer
             #[prost(enumeration = "super::super::SignMode", tag = "1")]
+            #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<super::super::SignMode, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<super::super::SignMode, _>"))]
             pub mode: i32,
             /// signature is the raw signature bytes
             #[prost(bytes = "vec", tag = "2")]
+            #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
             pub signature: ::prost::alloc::vec::Vec<u8>,
         }
+        impl ::prost::Name for Single {
+            const NAME: &'static str = "Single";
+            const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+            fn full_name() -> ::prost::alloc::string::String {
+                "cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Single".into()
+            }
+            fn type_url() -> ::prost::alloc::string::String {
+                "/cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Single".into()
+            }
+        }
         /// Multi is the signature data for a multisig public key
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct Multi {
             /// bitarray specifies which keys within the multisig are signing
@@ -56,7 +91,19 @@ pub mod signature_descriptor {
             #[prost(message, repeated, tag = "2")]
             pub signatures: ::prost::alloc::vec::Vec<super::Data>,
         }
+        impl ::prost::Name for Multi {
+            const NAME: &'static str = "Multi";
+            const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+            fn full_name() -> ::prost::alloc::string::String {
+                "cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Multi".into()
+            }
+            fn type_url() -> ::prost::alloc::string::String {
+                "/cosmos.tx.signing.v1beta1.SignatureDescriptor.Data.Multi".into()
+            }
+        }
         /// sum is the oneof that specifies whether this represents single or multi-signature data
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
         #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum Sum {
             /// single represents a single signer
@@ -67,6 +114,26 @@ pub mod signature_descriptor {
             Multi(Multi),
         }
     }
+    impl ::prost::Name for Data {
+        const NAME: &'static str = "Data";
+        const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.tx.signing.v1beta1.SignatureDescriptor.Data".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.tx.signing.v1beta1.SignatureDescriptor.Data".into()
+        }
+    }
+}
+impl ::prost::Name for SignatureDescriptor {
+    const NAME: &'static str = "SignatureDescriptor";
+    const PACKAGE: &'static str = "cosmos.tx.signing.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.signing.v1beta1.SignatureDescriptor".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.signing.v1beta1.SignatureDescriptor".into()
+    }
 }
 /// SignMode represents a signing mode with its own security guarantees.
 ///
@@ -139,3 +206,21 @@ impl SignMode {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for SignMode {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<SignatureDescriptors>();
+    registry.register::<SignatureDescriptor>();
+    registry.register::<signature_descriptor::Data>();
+    registry.register::<signature_descriptor::data::Single>();
+    registry.register::<signature_descriptor::data::Multi>();
+}
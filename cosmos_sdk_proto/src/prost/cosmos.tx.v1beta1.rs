@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// Tx is the standard type used for broadcasting transactions.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Tx {
     /// body is the processable content of the transaction
@@ -13,39 +15,69 @@ pub struct Tx {
     /// AuthInfo's signer_infos to allow connecting signature meta information like
     /// public key and signing mode by position.
     #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for Tx {
+    const NAME: &'static str = "Tx";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.Tx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.Tx".into()
+    }
+}
 /// TxRaw is a variant of Tx that pins the signer's exact binary representation
 /// of body and auth_info. This is used for signing, broadcasting and
 /// verification. The binary `serialize(tx: TxRaw)` is stored in Tendermint and
 /// the hash `sha256(serialize(tx: TxRaw))` becomes the "txhash", commonly used
 /// as the transaction ID.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxRaw {
     /// body_bytes is a protobuf serialization of a TxBody that matches the
     /// representation in SignDoc.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub body_bytes: ::prost::alloc::vec::Vec<u8>,
     /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
     /// representation in SignDoc.
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub auth_info_bytes: ::prost::alloc::vec::Vec<u8>,
     /// signatures is a list of signatures that matches the length and order of
     /// AuthInfo's signer_infos to allow connecting signature meta information like
     /// public key and signing mode by position.
     #[prost(bytes = "vec", repeated, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub signatures: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for TxRaw {
+    const NAME: &'static str = "TxRaw";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.TxRaw".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.TxRaw".into()
+    }
+}
 /// SignDoc is the type used for generating sign bytes for SIGN_MODE_DIRECT.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignDoc {
     /// body_bytes is protobuf serialization of a TxBody that matches the
     /// representation in TxRaw.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub body_bytes: ::prost::alloc::vec::Vec<u8>,
     /// auth_info_bytes is a protobuf serialization of an AuthInfo that matches the
     /// representation in TxRaw.
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub auth_info_bytes: ::prost::alloc::vec::Vec<u8>,
     /// chain_id is the unique identifier of the chain this transaction targets.
     /// It prevents signed transactions from being used on another chain by an
@@ -54,20 +86,35 @@ pub struct SignDoc {
     pub chain_id: ::prost::alloc::string::String,
     /// account_number is the account number of the account in state
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub account_number: u64,
 }
+impl ::prost::Name for SignDoc {
+    const NAME: &'static str = "SignDoc";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.SignDoc".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.SignDoc".into()
+    }
+}
 /// SignDocDirectAux is the type used for generating sign bytes for
 /// SIGN_MODE_DIRECT_AUX.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignDocDirectAux {
     /// body_bytes is protobuf serialization of a TxBody that matches the
     /// representation in TxRaw.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub body_bytes: ::prost::alloc::vec::Vec<u8>,
     /// public_key is the public key of the signing account.
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     /// chain_id is the identifier of the chain this transaction targets.
     /// It prevents signed transactions from being used on another chain by an
@@ -76,9 +123,11 @@ pub struct SignDocDirectAux {
     pub chain_id: ::prost::alloc::string::String,
     /// account_number is the account number of the account in state.
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub account_number: u64,
     /// sequence is the sequence number of the signing account.
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     /// Tip is the optional tip used for transactions fees paid in another denom.
     /// It should be left empty if the signer is not the tipper for this
@@ -89,7 +138,19 @@ pub struct SignDocDirectAux {
     #[prost(message, optional, tag = "6")]
     pub tip: ::core::option::Option<Tip>,
 }
+impl ::prost::Name for SignDocDirectAux {
+    const NAME: &'static str = "SignDocDirectAux";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.SignDocDirectAux".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.SignDocDirectAux".into()
+    }
+}
 /// TxBody is the body of a transaction that all signers sign over.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxBody {
     /// messages is a list of messages to be executed. The required signers of
@@ -100,6 +161,7 @@ pub struct TxBody {
     /// is referred to as the primary signer and pays the fee for the whole
     /// transaction.
     #[prost(message, repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// memo is any arbitrary note/comment to be added to the transaction.
     /// WARNING: in clients, any publicly exposed text should not be called memo,
@@ -109,20 +171,35 @@ pub struct TxBody {
     /// timeout is the block height after which this transaction will not
     /// be processed by the chain
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timeout_height: u64,
     /// extension_options are arbitrary options that can be added by chains
     /// when the default options are not sufficient. If any of these are present
     /// and can't be handled, the transaction will be rejected
     #[prost(message, repeated, tag = "1023")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub extension_options: ::prost::alloc::vec::Vec<::prost_types::Any>,
     /// extension_options are arbitrary options that can be added by chains
     /// when the default options are not sufficient. If any of these are present
     /// and can't be handled, they will be ignored
     #[prost(message, repeated, tag = "2047")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub non_critical_extension_options: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for TxBody {
+    const NAME: &'static str = "TxBody";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.TxBody".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.TxBody".into()
+    }
+}
 /// AuthInfo describes the fee and signer modes that are used to sign a
 /// transaction.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthInfo {
     /// signer_infos defines the signing modes for the required signers. The number
@@ -146,14 +223,27 @@ pub struct AuthInfo {
     #[prost(message, optional, tag = "3")]
     pub tip: ::core::option::Option<Tip>,
 }
+impl ::prost::Name for AuthInfo {
+    const NAME: &'static str = "AuthInfo";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.AuthInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.AuthInfo".into()
+    }
+}
 /// SignerInfo describes the public key and signing mode of a single top-level
 /// signer.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignerInfo {
     /// public_key is the public key of the signer. It is optional for accounts
     /// that already exist in state. If unset, the verifier can use the required \
     /// signer address for this position and lookup the public key.
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     /// mode_info describes the signing mode of the signer and is a nested
     /// structure to support nested multisig pubkey's
@@ -163,14 +253,28 @@ pub struct SignerInfo {
     /// number of committed transactions signed by a given address. It is used to
     /// prevent replay attacks.
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
 }
+impl ::prost::Name for SignerInfo {
+    const NAME: &'static str = "SignerInfo";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.SignerInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.SignerInfo".into()
+    }
+}
 /// ModeInfo describes the signing mode of a single or nested multisig signer.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModeInfo {
     /// sum is the oneof that specifies whether this represents a single or nested
     /// multisig signer
     #[prost(oneof = "mode_info::Sum", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<mode_info::Sum>,
 }
 /// Nested message and enum types in `ModeInfo`.
@@ -178,13 +282,28 @@ pub mod mode_info {
     /// Single is the mode info for a single signer. It is structured as a message
     /// to allow for additional fields such as locale for SIGN_MODE_TEXTUAL in the
     /// future
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct Single {
         /// mode is the signing mode of the single signer
         #[prost(enumeration = "super::super::signing::v1beta1::SignMode", tag = "1")]
+        #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<super::super::signing::v1beta1::SignMode, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<super::super::signing::v1beta1::SignMode, _>"))]
         pub mode: i32,
     }
+    impl ::prost::Name for Single {
+        const NAME: &'static str = "Single";
+        const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.tx.v1beta1.ModeInfo.Single".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.tx.v1beta1.ModeInfo.Single".into()
+        }
+    }
     /// Multi is the mode info for a multisig public key
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Multi {
         /// bitarray specifies which keys within the multisig are signing
@@ -197,8 +316,20 @@ pub mod mode_info {
         #[prost(message, repeated, tag = "2")]
         pub mode_infos: ::prost::alloc::vec::Vec<super::ModeInfo>,
     }
+    impl ::prost::Name for Multi {
+        const NAME: &'static str = "Multi";
+        const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "cosmos.tx.v1beta1.ModeInfo.Multi".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/cosmos.tx.v1beta1.ModeInfo.Multi".into()
+        }
+    }
     /// sum is the oneof that specifies whether this represents a single or nested
     /// multisig signer
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         /// single represents a single signer
@@ -209,9 +340,21 @@ pub mod mode_info {
         Multi(Multi),
     }
 }
+impl ::prost::Name for ModeInfo {
+    const NAME: &'static str = "ModeInfo";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.ModeInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.ModeInfo".into()
+    }
+}
 /// Fee includes the amount of coins paid in fees and the maximum
 /// gas to be used by the transaction. The ratio yields an effective "gasprice",
 /// which must be above some miminum to be accepted into the mempool.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Fee {
     /// amount is the amount of coins to be paid as a fee
@@ -220,6 +363,7 @@ pub struct Fee {
     /// gas_limit is the maximum gas that can be used in transaction processing
     /// before an out of gas error occurs
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_limit: u64,
     /// if unset, the first signer is responsible for paying the fees. If set, the specified account must pay the fees.
     /// the payer must be a tx signer (and thus have signed this field in AuthInfo).
@@ -232,9 +376,21 @@ pub struct Fee {
     #[prost(string, tag = "4")]
     pub granter: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Fee {
+    const NAME: &'static str = "Fee";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.Fee".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.Fee".into()
+    }
+}
 /// Tip is the tip used for meta-transactions.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Tip {
     /// amount is the amount of the tip
@@ -244,12 +400,24 @@ pub struct Tip {
     #[prost(string, tag = "2")]
     pub tipper: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Tip {
+    const NAME: &'static str = "Tip";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.Tip".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.Tip".into()
+    }
+}
 /// AuxSignerData is the intermediary format that an auxiliary signer (e.g. a
 /// tipper) builds and sends to the fee payer (who will build and broadcast the
 /// actual tx). AuxSignerData is not a valid tx in itself, and will be rejected
 /// by the node if sent directly as-is.
 ///
 /// Since: cosmos-sdk 0.46
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuxSignerData {
     /// address is the bech32-encoded address of the auxiliary signer. If using
@@ -264,13 +432,27 @@ pub struct AuxSignerData {
     pub sign_doc: ::core::option::Option<SignDocDirectAux>,
     /// mode is the signing mode of the single signer.
     #[prost(enumeration = "super::signing::v1beta1::SignMode", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<super::signing::v1beta1::SignMode, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<super::signing::v1beta1::SignMode, _>"))]
     pub mode: i32,
     /// sig is the signature of the sign doc.
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub sig: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for AuxSignerData {
+    const NAME: &'static str = "AuxSignerData";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.AuxSignerData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.AuxSignerData".into()
+    }
+}
 /// GetTxsEventRequest is the request type for the Service.TxsByEvents
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxsEventRequest {
     /// events is the list of transaction event type.
@@ -284,17 +466,32 @@ pub struct GetTxsEventRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
     #[prost(enumeration = "OrderBy", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<OrderBy, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<OrderBy, _>"))]
     pub order_by: i32,
     /// page is the page number to query, starts at 1. If not provided, will default to first page.
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub page: u64,
     /// limit is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub limit: u64,
 }
+impl ::prost::Name for GetTxsEventRequest {
+    const NAME: &'static str = "GetTxsEventRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.GetTxsEventRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.GetTxsEventRequest".into()
+    }
+}
 /// GetTxsEventResponse is the response type for the Service.TxsByEvents
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxsEventResponse {
     /// txs is the list of queried transactions.
@@ -314,20 +511,47 @@ pub struct GetTxsEventResponse {
     >,
     /// total is total number of results available
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub total: u64,
 }
+impl ::prost::Name for GetTxsEventResponse {
+    const NAME: &'static str = "GetTxsEventResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.GetTxsEventResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.GetTxsEventResponse".into()
+    }
+}
 /// BroadcastTxRequest is the request type for the Service.BroadcastTxRequest
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastTxRequest {
     /// tx_bytes is the raw transaction.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub tx_bytes: ::prost::alloc::vec::Vec<u8>,
     #[prost(enumeration = "BroadcastMode", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<BroadcastMode, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<BroadcastMode, _>"))]
     pub mode: i32,
 }
+impl ::prost::Name for BroadcastTxRequest {
+    const NAME: &'static str = "BroadcastTxRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.BroadcastTxRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.BroadcastTxRequest".into()
+    }
+}
 /// BroadcastTxResponse is the response type for the
 /// Service.BroadcastTx method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastTxResponse {
     /// tx_response is the queried TxResponses.
@@ -336,8 +560,20 @@ pub struct BroadcastTxResponse {
         super::super::base::abci::v1beta1::TxResponse,
     >,
 }
+impl ::prost::Name for BroadcastTxResponse {
+    const NAME: &'static str = "BroadcastTxResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.BroadcastTxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.BroadcastTxResponse".into()
+    }
+}
 /// SimulateRequest is the request type for the Service.Simulate
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulateRequest {
     /// tx is the transaction to simulate.
@@ -349,10 +585,23 @@ pub struct SimulateRequest {
     ///
     /// Since: cosmos-sdk 0.43
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub tx_bytes: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for SimulateRequest {
+    const NAME: &'static str = "SimulateRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.SimulateRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.SimulateRequest".into()
+    }
+}
 /// SimulateResponse is the response type for the
 /// Service.SimulateRPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulateResponse {
     /// gas_info is the information about gas used in the simulation.
@@ -362,15 +611,39 @@ pub struct SimulateResponse {
     #[prost(message, optional, tag = "2")]
     pub result: ::core::option::Option<super::super::base::abci::v1beta1::Result>,
 }
+impl ::prost::Name for SimulateResponse {
+    const NAME: &'static str = "SimulateResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.SimulateResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.SimulateResponse".into()
+    }
+}
 /// GetTxRequest is the request type for the Service.GetTx
 /// RPC method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxRequest {
     /// hash is the tx hash to query, encoded as a hex string.
     #[prost(string, tag = "1")]
     pub hash: ::prost::alloc::string::String,
 }
+impl ::prost::Name for GetTxRequest {
+    const NAME: &'static str = "GetTxRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.GetTxRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.GetTxRequest".into()
+    }
+}
 /// GetTxResponse is the response type for the Service.GetTx method.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetTxResponse {
     /// tx is the queried transaction.
@@ -382,14 +655,27 @@ pub struct GetTxResponse {
         super::super::base::abci::v1beta1::TxResponse,
     >,
 }
+impl ::prost::Name for GetTxResponse {
+    const NAME: &'static str = "GetTxResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.GetTxResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.GetTxResponse".into()
+    }
+}
 /// GetBlockWithTxsRequest is the request type for the Service.GetBlockWithTxs
 /// RPC method.
 ///
 /// Since: cosmos-sdk 0.45.2
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBlockWithTxsRequest {
     /// height is the height of the block to query.
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     /// pagination defines a pagination for the request.
     #[prost(message, optional, tag = "2")]
@@ -397,9 +683,21 @@ pub struct GetBlockWithTxsRequest {
         super::super::base::query::v1beta1::PageRequest,
     >,
 }
+impl ::prost::Name for GetBlockWithTxsRequest {
+    const NAME: &'static str = "GetBlockWithTxsRequest";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.GetBlockWithTxsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.GetBlockWithTxsRequest".into()
+    }
+}
 /// GetBlockWithTxsResponse is the response type for the Service.GetBlockWithTxs method.
 ///
 /// Since: cosmos-sdk 0.45.2
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBlockWithTxsResponse {
     /// txs are the transactions in the block.
@@ -417,6 +715,16 @@ pub struct GetBlockWithTxsResponse {
         super::super::base::query::v1beta1::PageResponse,
     >,
 }
+impl ::prost::Name for GetBlockWithTxsResponse {
+    const NAME: &'static str = "GetBlockWithTxsResponse";
+    const PACKAGE: &'static str = "cosmos.tx.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.tx.v1beta1.GetBlockWithTxsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.tx.v1beta1.GetBlockWithTxsResponse".into()
+    }
+}
 /// OrderBy defines the sorting order
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -450,6 +758,15 @@ impl OrderBy {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for OrderBy {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// BroadcastMode specifies the broadcast mode for the TxService.Broadcast RPC method.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -490,6 +807,17 @@ impl BroadcastMode {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for BroadcastMode {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod service_client {
     #![allow(
@@ -708,3 +1036,421 @@ pub mod service_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ServiceServer.
+    #[async_trait]
+    pub trait Service: std::marker::Send + std::marker::Sync + 'static {
+        /// Simulate simulates executing a transaction for estimating gas usage.
+        async fn simulate(
+            &self,
+            request: tonic::Request<super::SimulateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateResponse>,
+            tonic::Status,
+        >;
+        /// GetTx fetches a tx by hash.
+        async fn get_tx(
+            &self,
+            request: tonic::Request<super::GetTxRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTxResponse>, tonic::Status>;
+        /// BroadcastTx broadcast transaction.
+        async fn broadcast_tx(
+            &self,
+            request: tonic::Request<super::BroadcastTxRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BroadcastTxResponse>,
+            tonic::Status,
+        >;
+        /// GetTxsEvent fetches txs by event.
+        async fn get_txs_event(
+            &self,
+            request: tonic::Request<super::GetTxsEventRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTxsEventResponse>,
+            tonic::Status,
+        >;
+        /// GetBlockWithTxs fetches a block with decoded txs.
+        ///
+        /// Since: cosmos-sdk 0.45.2
+        async fn get_block_with_txs(
+            &self,
+            request: tonic::Request<super::GetBlockWithTxsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetBlockWithTxsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Service defines a gRPC service for interacting with transactions.
+    #[derive(Debug)]
+    pub struct ServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ServiceServer<T>
+    where
+        T: Service,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/cosmos.tx.v1beta1.Service/Simulate" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateSvc<T: Service>(pub Arc<T>);
+                    impl<T: Service> tonic::server::UnaryService<super::SimulateRequest>
+                    for SimulateSvc<T> {
+                        type Response = super::SimulateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::simulate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SimulateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.tx.v1beta1.Service/GetTx" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTxSvc<T: Service>(pub Arc<T>);
+                    impl<T: Service> tonic::server::UnaryService<super::GetTxRequest>
+                    for GetTxSvc<T> {
+                        type Response = super::GetTxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTxRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_tx(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.tx.v1beta1.Service/BroadcastTx" => {
+                    #[allow(non_camel_case_types)]
+                    struct BroadcastTxSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::BroadcastTxRequest>
+                    for BroadcastTxSvc<T> {
+                        type Response = super::BroadcastTxResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BroadcastTxRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::broadcast_tx(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BroadcastTxSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.tx.v1beta1.Service/GetTxsEvent" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTxsEventSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetTxsEventRequest>
+                    for GetTxsEventSvc<T> {
+                        type Response = super::GetTxsEventResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTxsEventRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_txs_event(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTxsEventSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/cosmos.tx.v1beta1.Service/GetBlockWithTxs" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetBlockWithTxsSvc<T: Service>(pub Arc<T>);
+                    impl<
+                        T: Service,
+                    > tonic::server::UnaryService<super::GetBlockWithTxsRequest>
+                    for GetBlockWithTxsSvc<T> {
+                        type Response = super::GetBlockWithTxsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetBlockWithTxsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Service>::get_block_with_txs(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetBlockWithTxsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "cosmos.tx.v1beta1.Service";
+    impl<T> tonic::server::NamedService for ServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Tx>();
+    registry.register::<TxRaw>();
+    registry.register::<SignDoc>();
+    registry.register::<SignDocDirectAux>();
+    registry.register::<TxBody>();
+    registry.register::<AuthInfo>();
+    registry.register::<SignerInfo>();
+    registry.register::<ModeInfo>();
+    registry.register::<mode_info::Single>();
+    registry.register::<mode_info::Multi>();
+    registry.register::<Fee>();
+    registry.register::<Tip>();
+    registry.register::<AuxSignerData>();
+    registry.register::<GetTxsEventRequest>();
+    registry.register::<GetTxsEventResponse>();
+    registry.register::<BroadcastTxRequest>();
+    registry.register::<BroadcastTxResponse>();
+    registry.register::<SimulateRequest>();
+    registry.register::<SimulateResponse>();
+    registry.register::<GetTxRequest>();
+    registry.register::<GetTxResponse>();
+    registry.register::<GetBlockWithTxsRequest>();
+    registry.register::<GetBlockWithTxsResponse>();
+}
@@ -1,5 +1,7 @@
 // This file is @generated by prost-build.
 /// An InterchainAccount is defined as a BaseAccount & the address of the account owner on the controller chain
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterchainAccount {
     #[prost(message, optional, tag = "1")]
@@ -9,7 +11,19 @@ pub struct InterchainAccount {
     #[prost(string, tag = "2")]
     pub account_owner: ::prost::alloc::string::String,
 }
+impl ::prost::Name for InterchainAccount {
+    const NAME: &'static str = "InterchainAccount";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.InterchainAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.InterchainAccount".into()
+    }
+}
 /// GenesisState defines the interchain accounts genesis state
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
@@ -17,7 +31,19 @@ pub struct GenesisState {
     #[prost(message, optional, tag = "2")]
     pub host_genesis_state: ::core::option::Option<HostGenesisState>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.GenesisState".into()
+    }
+}
 /// ControllerGenesisState defines the interchain accounts controller genesis state
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ControllerGenesisState {
     #[prost(message, repeated, tag = "1")]
@@ -29,7 +55,19 @@ pub struct ControllerGenesisState {
     #[prost(message, optional, tag = "4")]
     pub params: ::core::option::Option<super::controller::v1::Params>,
 }
+impl ::prost::Name for ControllerGenesisState {
+    const NAME: &'static str = "ControllerGenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.ControllerGenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.ControllerGenesisState".into()
+    }
+}
 /// HostGenesisState defines the interchain accounts host genesis state
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HostGenesisState {
     #[prost(message, repeated, tag = "1")]
@@ -41,7 +79,19 @@ pub struct HostGenesisState {
     #[prost(message, optional, tag = "4")]
     pub params: ::core::option::Option<super::host::v1::Params>,
 }
+impl ::prost::Name for HostGenesisState {
+    const NAME: &'static str = "HostGenesisState";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.HostGenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.HostGenesisState".into()
+    }
+}
 /// ActiveChannel contains a connection ID, port ID and associated active channel ID
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ActiveChannel {
     #[prost(string, tag = "1")]
@@ -51,7 +101,19 @@ pub struct ActiveChannel {
     #[prost(string, tag = "3")]
     pub channel_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ActiveChannel {
+    const NAME: &'static str = "ActiveChannel";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.ActiveChannel".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.ActiveChannel".into()
+    }
+}
 /// RegisteredInterchainAccount contains a connection ID, port ID and associated interchain account address
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RegisteredInterchainAccount {
     #[prost(string, tag = "1")]
@@ -61,8 +123,20 @@ pub struct RegisteredInterchainAccount {
     #[prost(string, tag = "3")]
     pub account_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RegisteredInterchainAccount {
+    const NAME: &'static str = "RegisteredInterchainAccount";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.RegisteredInterchainAccount".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.RegisteredInterchainAccount".into()
+    }
+}
 /// Metadata defines a set of protocol specific data encoded into the ICS27 channel version bytestring
 /// See ICS004: <https://github.com/cosmos/ibc/tree/master/spec/core/ics-004-channel-and-packet-semantics#Versioning>
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Metadata {
     /// version defines the ICS27 protocol version
@@ -85,22 +159,59 @@ pub struct Metadata {
     #[prost(string, tag = "6")]
     pub tx_type: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Metadata {
+    const NAME: &'static str = "Metadata";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.Metadata".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.Metadata".into()
+    }
+}
 /// InterchainAccountPacketData is comprised of a raw transaction, type of transaction and optional memo field.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InterchainAccountPacketData {
     #[prost(enumeration = "Type", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<Type, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<Type, _>"))]
     pub r#type: i32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "3")]
     pub memo: ::prost::alloc::string::String,
 }
+impl ::prost::Name for InterchainAccountPacketData {
+    const NAME: &'static str = "InterchainAccountPacketData";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.InterchainAccountPacketData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.InterchainAccountPacketData".into()
+    }
+}
 /// CosmosTx contains a list of sdk.Msg's. It should be used when sending transactions to an SDK host chain.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CosmosTx {
     #[prost(message, repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_vec"))]
     pub messages: ::prost::alloc::vec::Vec<::prost_types::Any>,
 }
+impl ::prost::Name for CosmosTx {
+    const NAME: &'static str = "CosmosTx";
+    const PACKAGE: &'static str = "ibc.applications.interchain_accounts.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.applications.interchain_accounts.v1.CosmosTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.applications.interchain_accounts.v1.CosmosTx".into()
+    }
+}
 /// Type defines a classification of message issued from a controller chain to its associated interchain accounts
 /// host
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -131,3 +242,25 @@ impl Type {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for Type {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<InterchainAccount>();
+    registry.register::<GenesisState>();
+    registry.register::<ControllerGenesisState>();
+    registry.register::<HostGenesisState>();
+    registry.register::<ActiveChannel>();
+    registry.register::<RegisteredInterchainAccount>();
+    registry.register::<Metadata>();
+    registry.register::<InterchainAccountPacketData>();
+    registry.register::<CosmosTx>();
+}
@@ -1,6 +1,8 @@
 // This file is @generated by prost-build.
 /// ClientState defines a loopback (localhost) client. It requires (read-only)
 /// access to keys outside the client prefix.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientState {
     /// self chain ID
@@ -10,3 +12,18 @@ pub struct ClientState {
     #[prost(message, optional, tag = "2")]
     pub height: ::core::option::Option<super::super::super::core::client::v1::Height>,
 }
+impl ::prost::Name for ClientState {
+    const NAME: &'static str = "ClientState";
+    const PACKAGE: &'static str = "ibc.lightclients.localhost.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.localhost.v1.ClientState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.localhost.v1.ClientState".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<ClientState>();
+}
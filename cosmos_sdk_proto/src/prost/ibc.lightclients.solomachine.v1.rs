@@ -1,13 +1,17 @@
 // This file is @generated by prost-build.
 /// ClientState defines a solo machine client that tracks the current consensus
 /// state and if the client is frozen.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientState {
     /// latest sequence of the client state
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     /// frozen sequence of the solo machine
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub frozen_sequence: u64,
     #[prost(message, optional, tag = "3")]
     pub consensus_state: ::core::option::Option<ConsensusState>,
@@ -16,13 +20,26 @@ pub struct ClientState {
     #[prost(bool, tag = "4")]
     pub allow_update_after_proposal: bool,
 }
+impl ::prost::Name for ClientState {
+    const NAME: &'static str = "ClientState";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.ClientState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.ClientState".into()
+    }
+}
 /// ConsensusState defines a solo machine consensus state. The sequence of a
 /// consensus state is contained in the "height" key used in storing the
 /// consensus state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusState {
     /// public key of the solo machine
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub public_key: ::core::option::Option<::prost_types::Any>,
     /// diversifier allows the same public key to be re-used across different solo
     /// machine clients (potentially on different chains) without being considered
@@ -30,155 +47,363 @@ pub struct ConsensusState {
     #[prost(string, tag = "2")]
     pub diversifier: ::prost::alloc::string::String,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timestamp: u64,
 }
+impl ::prost::Name for ConsensusState {
+    const NAME: &'static str = "ConsensusState";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.ConsensusState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.ConsensusState".into()
+    }
+}
 /// Header defines a solo machine consensus header
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Header {
     /// sequence to update solo machine public key at
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timestamp: u64,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub signature: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub new_public_key: ::core::option::Option<::prost_types::Any>,
     #[prost(string, tag = "5")]
     pub new_diversifier: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.Header".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.Header".into()
+    }
+}
 /// Misbehaviour defines misbehaviour for a solo machine which consists
 /// of a sequence and two signatures over different messages at that sequence.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Misbehaviour {
     #[prost(string, tag = "1")]
     pub client_id: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     #[prost(message, optional, tag = "3")]
     pub signature_one: ::core::option::Option<SignatureAndData>,
     #[prost(message, optional, tag = "4")]
     pub signature_two: ::core::option::Option<SignatureAndData>,
 }
+impl ::prost::Name for Misbehaviour {
+    const NAME: &'static str = "Misbehaviour";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.Misbehaviour".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.Misbehaviour".into()
+    }
+}
 /// SignatureAndData contains a signature and the data signed over to create that
 /// signature.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignatureAndData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub signature: ::prost::alloc::vec::Vec<u8>,
     #[prost(enumeration = "DataType", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<DataType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<DataType, _>"))]
     pub data_type: i32,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timestamp: u64,
 }
+impl ::prost::Name for SignatureAndData {
+    const NAME: &'static str = "SignatureAndData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.SignatureAndData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.SignatureAndData".into()
+    }
+}
 /// TimestampedSignatureData contains the signature data and the timestamp of the
 /// signature.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TimestampedSignatureData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub signature_data: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timestamp: u64,
 }
+impl ::prost::Name for TimestampedSignatureData {
+    const NAME: &'static str = "TimestampedSignatureData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.TimestampedSignatureData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.TimestampedSignatureData".into()
+    }
+}
 /// SignBytes defines the signed bytes used for signature verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignBytes {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub sequence: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timestamp: u64,
     #[prost(string, tag = "3")]
     pub diversifier: ::prost::alloc::string::String,
     /// type of the data used
     #[prost(enumeration = "DataType", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<DataType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<DataType, _>"))]
     pub data_type: i32,
     /// marshaled data
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for SignBytes {
+    const NAME: &'static str = "SignBytes";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.SignBytes".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.SignBytes".into()
+    }
+}
 /// HeaderData returns the SignBytes data for update verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct HeaderData {
     /// header public key
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub new_pub_key: ::core::option::Option<::prost_types::Any>,
     /// header diversifier
     #[prost(string, tag = "2")]
     pub new_diversifier: ::prost::alloc::string::String,
 }
+impl ::prost::Name for HeaderData {
+    const NAME: &'static str = "HeaderData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.HeaderData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.HeaderData".into()
+    }
+}
 /// ClientStateData returns the SignBytes data for client state verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientStateData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub client_state: ::core::option::Option<::prost_types::Any>,
 }
+impl ::prost::Name for ClientStateData {
+    const NAME: &'static str = "ClientStateData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.ClientStateData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.ClientStateData".into()
+    }
+}
 /// ConsensusStateData returns the SignBytes data for consensus state
 /// verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusStateData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub consensus_state: ::core::option::Option<::prost_types::Any>,
 }
+impl ::prost::Name for ConsensusStateData {
+    const NAME: &'static str = "ConsensusStateData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.ConsensusStateData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.ConsensusStateData".into()
+    }
+}
 /// ConnectionStateData returns the SignBytes data for connection state
 /// verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConnectionStateData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub connection: ::core::option::Option<
         super::super::super::core::connection::v1::ConnectionEnd,
     >,
 }
+impl ::prost::Name for ConnectionStateData {
+    const NAME: &'static str = "ConnectionStateData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.ConnectionStateData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.ConnectionStateData".into()
+    }
+}
 /// ChannelStateData returns the SignBytes data for channel state
 /// verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChannelStateData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub channel: ::core::option::Option<super::super::super::core::channel::v1::Channel>,
 }
+impl ::prost::Name for ChannelStateData {
+    const NAME: &'static str = "ChannelStateData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.ChannelStateData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.ChannelStateData".into()
+    }
+}
 /// PacketCommitmentData returns the SignBytes data for packet commitment
 /// verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PacketCommitmentData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub commitment: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for PacketCommitmentData {
+    const NAME: &'static str = "PacketCommitmentData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.PacketCommitmentData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.PacketCommitmentData".into()
+    }
+}
 /// PacketAcknowledgementData returns the SignBytes data for acknowledgement
 /// verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PacketAcknowledgementData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub acknowledgement: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for PacketAcknowledgementData {
+    const NAME: &'static str = "PacketAcknowledgementData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.PacketAcknowledgementData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.PacketAcknowledgementData".into()
+    }
+}
 /// PacketReceiptAbsenceData returns the SignBytes data for
 /// packet receipt absence verification.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PacketReceiptAbsenceData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for PacketReceiptAbsenceData {
+    const NAME: &'static str = "PacketReceiptAbsenceData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.PacketReceiptAbsenceData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.PacketReceiptAbsenceData".into()
+    }
+}
 /// NextSequenceRecvData returns the SignBytes data for verification of the next
 /// sequence to be received.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NextSequenceRecvData {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub path: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub next_seq_recv: u64,
 }
+impl ::prost::Name for NextSequenceRecvData {
+    const NAME: &'static str = "NextSequenceRecvData";
+    const PACKAGE: &'static str = "ibc.lightclients.solomachine.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ibc.lightclients.solomachine.v1.NextSequenceRecvData".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ibc.lightclients.solomachine.v1.NextSequenceRecvData".into()
+    }
+}
 /// DataType defines the type of solo machine proof being created. This is done
 /// to preserve uniqueness of different data sign byte encodings.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -241,3 +466,32 @@ impl DataType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for DataType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<ClientState>();
+    registry.register::<ConsensusState>();
+    registry.register::<Header>();
+    registry.register::<Misbehaviour>();
+    registry.register::<SignatureAndData>();
+    registry.register::<TimestampedSignatureData>();
+    registry.register::<SignBytes>();
+    registry.register::<HeaderData>();
+    registry.register::<ClientStateData>();
+    registry.register::<ConsensusStateData>();
+    registry.register::<ConnectionStateData>();
+    registry.register::<ChannelStateData>();
+    registry.register::<PacketCommitmentData>();
+    registry.register::<PacketAcknowledgementData>();
+    registry.register::<PacketReceiptAbsenceData>();
+    registry.register::<NextSequenceRecvData>();
+}
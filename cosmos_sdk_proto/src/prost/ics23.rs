@@ -19,40 +19,72 @@
 /// With LengthOp this is tricker but not impossible. Which is why the "leafPrefixEqual" field
 /// in the ProofSpec is valuable to prevent this mutability. And why all trees should
 /// length-prefix the data before hashing it.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExistenceProof {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "3")]
     pub leaf: ::core::option::Option<LeafOp>,
     #[prost(message, repeated, tag = "4")]
     pub path: ::prost::alloc::vec::Vec<InnerOp>,
 }
+impl ::prost::Name for ExistenceProof {
+    const NAME: &'static str = "ExistenceProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.ExistenceProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.ExistenceProof".into()
+    }
+}
 ///
 /// NonExistenceProof takes a proof of two neighbors, one left of the desired key,
 /// one right of the desired key. If both proofs are valid AND they are neighbors,
 /// then there is no valid proof for the given key.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NonExistenceProof {
     /// TODO: remove this as unnecessary??? we prove a range
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub left: ::core::option::Option<ExistenceProof>,
     #[prost(message, optional, tag = "3")]
     pub right: ::core::option::Option<ExistenceProof>,
 }
+impl ::prost::Name for NonExistenceProof {
+    const NAME: &'static str = "NonExistenceProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.NonExistenceProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.NonExistenceProof".into()
+    }
+}
 ///
 /// CommitmentProof is either an ExistenceProof or a NonExistenceProof, or a Batch of such messages
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CommitmentProof {
     #[prost(oneof = "commitment_proof::Proof", tags = "1, 2, 3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub proof: ::core::option::Option<commitment_proof::Proof>,
 }
 /// Nested message and enum types in `CommitmentProof`.
 pub mod commitment_proof {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Proof {
         #[prost(message, tag = "1")]
@@ -65,6 +97,16 @@ pub mod commitment_proof {
         Compressed(super::CompressedBatchProof),
     }
 }
+impl ::prost::Name for CommitmentProof {
+    const NAME: &'static str = "CommitmentProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.CommitmentProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.CommitmentProof".into()
+    }
+}
 /// *
 /// LeafOp represents the raw key-value data we wish to prove, and
 /// must be flexible to represent the internal transformation from
@@ -80,21 +122,38 @@ pub mod commitment_proof {
 ///
 /// Then combine the bytes, and hash it
 /// output = hash(prefix || length(hkey) || hkey || length(hvalue) || hvalue)
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LeafOp {
     #[prost(enumeration = "HashOp", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<HashOp, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<HashOp, _>"))]
     pub hash: i32,
     #[prost(enumeration = "HashOp", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<HashOp, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<HashOp, _>"))]
     pub prehash_key: i32,
     #[prost(enumeration = "HashOp", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<HashOp, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<HashOp, _>"))]
     pub prehash_value: i32,
     #[prost(enumeration = "LengthOp", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<LengthOp, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<LengthOp, _>"))]
     pub length: i32,
     /// prefix is a fixed bytes that may optionally be included at the beginning to differentiate
     /// a leaf node from an inner node.
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub prefix: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for LeafOp {
+    const NAME: &'static str = "LeafOp";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.LeafOp".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.LeafOp".into()
+    }
+}
 /// *
 /// InnerOp represents a merkle-proof step that is not a leaf.
 /// It represents concatenating two children and hashing them to provide the next result.
@@ -111,15 +170,30 @@ pub struct LeafOp {
 /// Any special data, like prepending child with the length, or prepending the entire operation with
 /// some value to differentiate from leaf nodes, should be included in prefix and suffix.
 /// If either of prefix or suffix is empty, we just treat it as an empty string
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InnerOp {
     #[prost(enumeration = "HashOp", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<HashOp, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<HashOp, _>"))]
     pub hash: i32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub prefix: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub suffix: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for InnerOp {
+    const NAME: &'static str = "InnerOp";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.InnerOp".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.InnerOp".into()
+    }
+}
 /// *
 /// ProofSpec defines what the expected parameters are for a given proof type.
 /// This can be stored in the client and used to validate any incoming proofs.
@@ -131,6 +205,8 @@ pub struct InnerOp {
 /// generate a given hash (by interpretting the preimage differently).
 /// We need this for proper security, requires client knows a priori what
 /// tree format server uses. But not in code, rather a configuration object.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofSpec {
     /// any field in the ExistenceProof must be the same as in this spec.
@@ -146,6 +222,16 @@ pub struct ProofSpec {
     #[prost(int32, tag = "4")]
     pub min_depth: i32,
 }
+impl ::prost::Name for ProofSpec {
+    const NAME: &'static str = "ProofSpec";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.ProofSpec".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.ProofSpec".into()
+    }
+}
 ///
 /// InnerSpec contains all store-specific structure info to determine if two proofs from a
 /// given store are neighbors.
@@ -155,6 +241,8 @@ pub struct ProofSpec {
 /// isLeftMost(spec: InnerSpec, op: InnerOp)
 /// isRightMost(spec: InnerSpec, op: InnerOp)
 /// isLeftNeighbor(spec: InnerSpec, left: InnerOp, right: InnerOp)
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InnerSpec {
     /// Child order is the ordering of the children node, must count from 0
@@ -170,26 +258,55 @@ pub struct InnerSpec {
     pub max_prefix_length: i32,
     /// empty child is the prehash image that is used when one child is nil (eg. 20 bytes of 0)
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub empty_child: ::prost::alloc::vec::Vec<u8>,
     /// hash is the algorithm that must be used for each InnerOp
     #[prost(enumeration = "HashOp", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<HashOp, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<HashOp, _>"))]
     pub hash: i32,
 }
+impl ::prost::Name for InnerSpec {
+    const NAME: &'static str = "InnerSpec";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.InnerSpec".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.InnerSpec".into()
+    }
+}
 ///
 /// BatchProof is a group of multiple proof types than can be compressed
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BatchProof {
     #[prost(message, repeated, tag = "1")]
     pub entries: ::prost::alloc::vec::Vec<BatchEntry>,
 }
+impl ::prost::Name for BatchProof {
+    const NAME: &'static str = "BatchProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.BatchProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.BatchProof".into()
+    }
+}
 /// Use BatchEntry not CommitmentProof, to avoid recursion
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BatchEntry {
     #[prost(oneof = "batch_entry::Proof", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub proof: ::core::option::Option<batch_entry::Proof>,
 }
 /// Nested message and enum types in `BatchEntry`.
 pub mod batch_entry {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Proof {
         #[prost(message, tag = "1")]
@@ -198,6 +315,18 @@ pub mod batch_entry {
         Nonexist(super::NonExistenceProof),
     }
 }
+impl ::prost::Name for BatchEntry {
+    const NAME: &'static str = "BatchEntry";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.BatchEntry".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.BatchEntry".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompressedBatchProof {
     #[prost(message, repeated, tag = "1")]
@@ -205,14 +334,29 @@ pub struct CompressedBatchProof {
     #[prost(message, repeated, tag = "2")]
     pub lookup_inners: ::prost::alloc::vec::Vec<InnerOp>,
 }
+impl ::prost::Name for CompressedBatchProof {
+    const NAME: &'static str = "CompressedBatchProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.CompressedBatchProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.CompressedBatchProof".into()
+    }
+}
 /// Use BatchEntry not CommitmentProof, to avoid recursion
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompressedBatchEntry {
     #[prost(oneof = "compressed_batch_entry::Proof", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub proof: ::core::option::Option<compressed_batch_entry::Proof>,
 }
 /// Nested message and enum types in `CompressedBatchEntry`.
 pub mod compressed_batch_entry {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Proof {
         #[prost(message, tag = "1")]
@@ -221,11 +365,25 @@ pub mod compressed_batch_entry {
         Nonexist(super::CompressedNonExistenceProof),
     }
 }
+impl ::prost::Name for CompressedBatchEntry {
+    const NAME: &'static str = "CompressedBatchEntry";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.CompressedBatchEntry".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.CompressedBatchEntry".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompressedExistenceProof {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "3")]
     pub leaf: ::core::option::Option<LeafOp>,
@@ -233,16 +391,39 @@ pub struct CompressedExistenceProof {
     #[prost(int32, repeated, tag = "4")]
     pub path: ::prost::alloc::vec::Vec<i32>,
 }
+impl ::prost::Name for CompressedExistenceProof {
+    const NAME: &'static str = "CompressedExistenceProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.CompressedExistenceProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.CompressedExistenceProof".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CompressedNonExistenceProof {
     /// TODO: remove this as unnecessary??? we prove a range
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub left: ::core::option::Option<CompressedExistenceProof>,
     #[prost(message, optional, tag = "3")]
     pub right: ::core::option::Option<CompressedExistenceProof>,
 }
+impl ::prost::Name for CompressedNonExistenceProof {
+    const NAME: &'static str = "CompressedNonExistenceProof";
+    const PACKAGE: &'static str = "ics23";
+    fn full_name() -> ::prost::alloc::string::String {
+        "ics23.CompressedNonExistenceProof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/ics23.CompressedNonExistenceProof".into()
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum HashOp {
@@ -283,6 +464,15 @@ impl HashOp {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for HashOp {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// *
 /// LengthOp defines how to process the key and value of the LeafOp
 /// to include length information. After encoding the length with the given
@@ -344,3 +534,29 @@ impl LengthOp {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for LengthOp {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<ExistenceProof>();
+    registry.register::<NonExistenceProof>();
+    registry.register::<CommitmentProof>();
+    registry.register::<LeafOp>();
+    registry.register::<InnerOp>();
+    registry.register::<ProofSpec>();
+    registry.register::<InnerSpec>();
+    registry.register::<BatchProof>();
+    registry.register::<BatchEntry>();
+    registry.register::<CompressedBatchProof>();
+    registry.register::<CompressedBatchEntry>();
+    registry.register::<CompressedExistenceProof>();
+    registry.register::<CompressedNonExistenceProof>();
+}
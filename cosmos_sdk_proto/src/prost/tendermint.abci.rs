@@ -1,14 +1,20 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Request {
     #[prost(
         oneof = "request::Value",
         tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15"
     )]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub value: ::core::option::Option<request::Value>,
 }
 /// Nested message and enum types in `Request`.
 pub mod request {
+    #[allow(clippy::large_enum_variant)]
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Value {
         #[prost(message, tag = "1")]
@@ -43,23 +49,73 @@ pub mod request {
         ApplySnapshotChunk(super::RequestApplySnapshotChunk),
     }
 }
+impl ::prost::Name for Request {
+    const NAME: &'static str = "Request";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.Request".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.Request".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestEcho {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RequestEcho {
+    const NAME: &'static str = "RequestEcho";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestEcho".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestEcho".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestFlush {}
+impl ::prost::Name for RequestFlush {
+    const NAME: &'static str = "RequestFlush";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestFlush".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestFlush".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestInfo {
     #[prost(string, tag = "1")]
     pub version: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block_version: u64,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub p2p_version: u64,
 }
+impl ::prost::Name for RequestInfo {
+    const NAME: &'static str = "RequestInfo";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestInfo".into()
+    }
+}
 /// nondeterministic
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestSetOption {
     #[prost(string, tag = "1")]
@@ -67,9 +123,22 @@ pub struct RequestSetOption {
     #[prost(string, tag = "2")]
     pub value: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RequestSetOption {
+    const NAME: &'static str = "RequestSetOption";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestSetOption".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestSetOption".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestInitChain {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(string, tag = "2")]
     pub chain_id: ::prost::alloc::string::String,
@@ -78,24 +147,53 @@ pub struct RequestInitChain {
     #[prost(message, repeated, tag = "4")]
     pub validators: ::prost::alloc::vec::Vec<ValidatorUpdate>,
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub app_state_bytes: ::prost::alloc::vec::Vec<u8>,
     #[prost(int64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub initial_height: i64,
 }
+impl ::prost::Name for RequestInitChain {
+    const NAME: &'static str = "RequestInitChain";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestInitChain".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestInitChain".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestQuery {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "2")]
     pub path: ::prost::alloc::string::String,
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(bool, tag = "4")]
     pub prove: bool,
 }
+impl ::prost::Name for RequestQuery {
+    const NAME: &'static str = "RequestQuery";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestQuery".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestQuery".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestBeginBlock {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub header: ::core::option::Option<super::types::Header>,
@@ -104,29 +202,105 @@ pub struct RequestBeginBlock {
     #[prost(message, repeated, tag = "4")]
     pub byzantine_validators: ::prost::alloc::vec::Vec<Evidence>,
 }
+impl ::prost::Name for RequestBeginBlock {
+    const NAME: &'static str = "RequestBeginBlock";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestBeginBlock".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestBeginBlock".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestCheckTx {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub tx: ::prost::alloc::vec::Vec<u8>,
     #[prost(enumeration = "CheckTxType", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<CheckTxType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<CheckTxType, _>"))]
     pub r#type: i32,
 }
+impl ::prost::Name for RequestCheckTx {
+    const NAME: &'static str = "RequestCheckTx";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestCheckTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestCheckTx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestDeliverTx {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub tx: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for RequestDeliverTx {
+    const NAME: &'static str = "RequestDeliverTx";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestDeliverTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestDeliverTx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestEndBlock {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for RequestEndBlock {
+    const NAME: &'static str = "RequestEndBlock";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestEndBlock".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestEndBlock".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestCommit {}
+impl ::prost::Name for RequestCommit {
+    const NAME: &'static str = "RequestCommit";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestCommit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestCommit".into()
+    }
+}
 /// lists available snapshots
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestListSnapshots {}
+impl ::prost::Name for RequestListSnapshots {
+    const NAME: &'static str = "RequestListSnapshots";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestListSnapshots".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestListSnapshots".into()
+    }
+}
 /// offers a snapshot to the application
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestOfferSnapshot {
     /// snapshot offered by peers
@@ -134,38 +308,80 @@ pub struct RequestOfferSnapshot {
     pub snapshot: ::core::option::Option<Snapshot>,
     /// light client-verified app hash for snapshot height
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub app_hash: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for RequestOfferSnapshot {
+    const NAME: &'static str = "RequestOfferSnapshot";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestOfferSnapshot".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestOfferSnapshot".into()
+    }
+}
 /// loads a snapshot chunk
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestLoadSnapshotChunk {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(uint32, tag = "2")]
     pub format: u32,
     #[prost(uint32, tag = "3")]
     pub chunk: u32,
 }
+impl ::prost::Name for RequestLoadSnapshotChunk {
+    const NAME: &'static str = "RequestLoadSnapshotChunk";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestLoadSnapshotChunk".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestLoadSnapshotChunk".into()
+    }
+}
 /// Applies a snapshot chunk
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestApplySnapshotChunk {
     #[prost(uint32, tag = "1")]
     pub index: u32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub chunk: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "3")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RequestApplySnapshotChunk {
+    const NAME: &'static str = "RequestApplySnapshotChunk";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.RequestApplySnapshotChunk".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.RequestApplySnapshotChunk".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Response {
     #[prost(
         oneof = "response::Value",
         tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16"
     )]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub value: ::core::option::Option<response::Value>,
 }
 /// Nested message and enum types in `Response`.
 pub mod response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Value {
         #[prost(message, tag = "1")]
@@ -202,19 +418,67 @@ pub mod response {
         ApplySnapshotChunk(super::ResponseApplySnapshotChunk),
     }
 }
+impl ::prost::Name for Response {
+    const NAME: &'static str = "Response";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.Response".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.Response".into()
+    }
+}
 /// nondeterministic
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseException {
     #[prost(string, tag = "1")]
     pub error: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ResponseException {
+    const NAME: &'static str = "ResponseException";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseException".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseException".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseEcho {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ResponseEcho {
+    const NAME: &'static str = "ResponseEcho";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseEcho".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseEcho".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ResponseFlush {}
+impl ::prost::Name for ResponseFlush {
+    const NAME: &'static str = "ResponseFlush";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseFlush".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseFlush".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseInfo {
     #[prost(string, tag = "1")]
@@ -222,13 +486,28 @@ pub struct ResponseInfo {
     #[prost(string, tag = "2")]
     pub version: ::prost::alloc::string::String,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub app_version: u64,
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_block_height: i64,
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub last_block_app_hash: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for ResponseInfo {
+    const NAME: &'static str = "ResponseInfo";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseInfo".into()
+    }
+}
 /// nondeterministic
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseSetOption {
     #[prost(uint32, tag = "1")]
@@ -239,6 +518,18 @@ pub struct ResponseSetOption {
     #[prost(string, tag = "4")]
     pub info: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ResponseSetOption {
+    const NAME: &'static str = "ResponseSetOption";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseSetOption".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseSetOption".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseInitChain {
     #[prost(message, optional, tag = "1")]
@@ -246,8 +537,21 @@ pub struct ResponseInitChain {
     #[prost(message, repeated, tag = "2")]
     pub validators: ::prost::alloc::vec::Vec<ValidatorUpdate>,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub app_hash: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for ResponseInitChain {
+    const NAME: &'static str = "ResponseInitChain";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseInitChain".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseInitChain".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseQuery {
     #[prost(uint32, tag = "1")]
@@ -261,28 +565,57 @@ pub struct ResponseQuery {
     #[prost(string, tag = "4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: i64,
     #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "8")]
     pub proof_ops: ::core::option::Option<super::crypto::ProofOps>,
     #[prost(int64, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(string, tag = "10")]
     pub codespace: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ResponseQuery {
+    const NAME: &'static str = "ResponseQuery";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseQuery".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseQuery".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseBeginBlock {
     #[prost(message, repeated, tag = "1")]
     pub events: ::prost::alloc::vec::Vec<Event>,
 }
+impl ::prost::Name for ResponseBeginBlock {
+    const NAME: &'static str = "ResponseBeginBlock";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseBeginBlock".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseBeginBlock".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseCheckTx {
     #[prost(uint32, tag = "1")]
     pub code: u32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     /// nondeterministic
     #[prost(string, tag = "3")]
@@ -291,8 +624,10 @@ pub struct ResponseCheckTx {
     #[prost(string, tag = "4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_wanted: i64,
     #[prost(int64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: i64,
     #[prost(message, repeated, tag = "7")]
     pub events: ::prost::alloc::vec::Vec<Event>,
@@ -301,17 +636,31 @@ pub struct ResponseCheckTx {
     #[prost(string, tag = "9")]
     pub sender: ::prost::alloc::string::String,
     #[prost(int64, tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub priority: i64,
     /// mempool_error is set by CometBFT.
     /// ABCI applictions creating a ResponseCheckTX should not set mempool_error.
     #[prost(string, tag = "11")]
     pub mempool_error: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ResponseCheckTx {
+    const NAME: &'static str = "ResponseCheckTx";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseCheckTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseCheckTx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseDeliverTx {
     #[prost(uint32, tag = "1")]
     pub code: u32,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     /// nondeterministic
     #[prost(string, tag = "3")]
@@ -320,8 +669,10 @@ pub struct ResponseDeliverTx {
     #[prost(string, tag = "4")]
     pub info: ::prost::alloc::string::String,
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_wanted: i64,
     #[prost(int64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub gas_used: i64,
     /// nondeterministic
     #[prost(message, repeated, tag = "7")]
@@ -329,6 +680,18 @@ pub struct ResponseDeliverTx {
     #[prost(string, tag = "8")]
     pub codespace: ::prost::alloc::string::String,
 }
+impl ::prost::Name for ResponseDeliverTx {
+    const NAME: &'static str = "ResponseDeliverTx";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseDeliverTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseDeliverTx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseEndBlock {
     #[prost(message, repeated, tag = "1")]
@@ -338,22 +701,61 @@ pub struct ResponseEndBlock {
     #[prost(message, repeated, tag = "3")]
     pub events: ::prost::alloc::vec::Vec<Event>,
 }
+impl ::prost::Name for ResponseEndBlock {
+    const NAME: &'static str = "ResponseEndBlock";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseEndBlock".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseEndBlock".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseCommit {
     /// reserve 1
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub retain_height: i64,
 }
+impl ::prost::Name for ResponseCommit {
+    const NAME: &'static str = "ResponseCommit";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseCommit".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseCommit".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseListSnapshots {
     #[prost(message, repeated, tag = "1")]
     pub snapshots: ::prost::alloc::vec::Vec<Snapshot>,
 }
+impl ::prost::Name for ResponseListSnapshots {
+    const NAME: &'static str = "ResponseListSnapshots";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseListSnapshots".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseListSnapshots".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ResponseOfferSnapshot {
     #[prost(enumeration = "response_offer_snapshot::Result", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<response_offer_snapshot::Result, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<response_offer_snapshot::Result, _>"))]
     pub result: i32,
 }
 /// Nested message and enum types in `ResponseOfferSnapshot`.
@@ -412,15 +814,50 @@ pub mod response_offer_snapshot {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl cosmos_sdk_proto::proto_json::Enumeration for Result {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
+}
+impl ::prost::Name for ResponseOfferSnapshot {
+    const NAME: &'static str = "ResponseOfferSnapshot";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseOfferSnapshot".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseOfferSnapshot".into()
+    }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseLoadSnapshotChunk {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub chunk: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for ResponseLoadSnapshotChunk {
+    const NAME: &'static str = "ResponseLoadSnapshotChunk";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseLoadSnapshotChunk".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseLoadSnapshotChunk".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseApplySnapshotChunk {
     #[prost(enumeration = "response_apply_snapshot_chunk::Result", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<response_apply_snapshot_chunk::Result, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<response_apply_snapshot_chunk::Result, _>"))]
     pub result: i32,
     /// Chunks to refetch and reapply
     #[prost(uint32, repeated, tag = "2")]
@@ -485,9 +922,30 @@ pub mod response_apply_snapshot_chunk {
             }
         }
     }
+    #[cfg(feature = "serde")]
+    impl cosmos_sdk_proto::proto_json::Enumeration for Result {
+        fn proto_name(&self) -> &'static str {
+            self.as_str_name()
+        }
+        fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+            Self::from_str_name(value)
+        }
+    }
+}
+impl ::prost::Name for ResponseApplySnapshotChunk {
+    const NAME: &'static str = "ResponseApplySnapshotChunk";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ResponseApplySnapshotChunk".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ResponseApplySnapshotChunk".into()
+    }
 }
 /// ConsensusParams contains all consensus-relevant parameters
 /// that can be adjusted by the abci app
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusParams {
     #[prost(message, optional, tag = "1")]
@@ -499,16 +957,42 @@ pub struct ConsensusParams {
     #[prost(message, optional, tag = "4")]
     pub version: ::core::option::Option<super::types::VersionParams>,
 }
+impl ::prost::Name for ConsensusParams {
+    const NAME: &'static str = "ConsensusParams";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ConsensusParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ConsensusParams".into()
+    }
+}
 /// BlockParams contains limits on the block size.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct BlockParams {
     /// Note: must be greater than 0
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub max_bytes: i64,
     /// Note: must be greater or equal to -1
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub max_gas: i64,
 }
+impl ::prost::Name for BlockParams {
+    const NAME: &'static str = "BlockParams";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.BlockParams".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.BlockParams".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LastCommitInfo {
     #[prost(int32, tag = "1")]
@@ -516,9 +1000,21 @@ pub struct LastCommitInfo {
     #[prost(message, repeated, tag = "2")]
     pub votes: ::prost::alloc::vec::Vec<VoteInfo>,
 }
+impl ::prost::Name for LastCommitInfo {
+    const NAME: &'static str = "LastCommitInfo";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.LastCommitInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.LastCommitInfo".into()
+    }
+}
 /// Event allows application developers to attach additional information to
 /// ResponseBeginBlock, ResponseEndBlock, ResponseCheckTx and ResponseDeliverTx.
 /// Later, transactions may be queried using these events.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Event {
     #[prost(string, tag = "1")]
@@ -526,52 +1022,119 @@ pub struct Event {
     #[prost(message, repeated, tag = "2")]
     pub attributes: ::prost::alloc::vec::Vec<EventAttribute>,
 }
+impl ::prost::Name for Event {
+    const NAME: &'static str = "Event";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.Event".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.Event".into()
+    }
+}
 /// EventAttribute is a single key-value pair, associated with an event.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventAttribute {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub value: ::prost::alloc::vec::Vec<u8>,
     /// nondeterministic
     #[prost(bool, tag = "3")]
     pub index: bool,
 }
+impl ::prost::Name for EventAttribute {
+    const NAME: &'static str = "EventAttribute";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.EventAttribute".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.EventAttribute".into()
+    }
+}
 /// TxResult contains results of executing the transaction.
 ///
 /// One usage is indexing transaction results.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TxResult {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(uint32, tag = "2")]
     pub index: u32,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub tx: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "4")]
     pub result: ::core::option::Option<ResponseDeliverTx>,
 }
+impl ::prost::Name for TxResult {
+    const NAME: &'static str = "TxResult";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.TxResult".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.TxResult".into()
+    }
+}
 /// Validator
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Validator {
     /// The first 20 bytes of SHA256(public key)
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub address: ::prost::alloc::vec::Vec<u8>,
     /// PubKey pub_key = 2 \[(gogoproto.nullable)=false\];
     ///
     /// The voting power
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub power: i64,
 }
+impl ::prost::Name for Validator {
+    const NAME: &'static str = "Validator";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.Validator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.Validator".into()
+    }
+}
 /// ValidatorUpdate
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorUpdate {
     #[prost(message, optional, tag = "1")]
     pub pub_key: ::core::option::Option<super::crypto::PublicKey>,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub power: i64,
 }
+impl ::prost::Name for ValidatorUpdate {
+    const NAME: &'static str = "ValidatorUpdate";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.ValidatorUpdate".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.ValidatorUpdate".into()
+    }
+}
 /// VoteInfo
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VoteInfo {
     #[prost(message, optional, tag = "1")]
@@ -579,29 +1142,58 @@ pub struct VoteInfo {
     #[prost(bool, tag = "2")]
     pub signed_last_block: bool,
 }
+impl ::prost::Name for VoteInfo {
+    const NAME: &'static str = "VoteInfo";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.VoteInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.VoteInfo".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Evidence {
     #[prost(enumeration = "EvidenceType", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<EvidenceType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<EvidenceType, _>"))]
     pub r#type: i32,
     /// The offending validator
     #[prost(message, optional, tag = "2")]
     pub validator: ::core::option::Option<Validator>,
     /// The height when the offense occurred
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     /// The corresponding time where the offense occurred
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     /// Total voting power of the validator set in case the ABCI application does
     /// not store historical validators.
     /// <https://github.com/tendermint/tendermint/issues/4581>
     #[prost(int64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub total_voting_power: i64,
 }
+impl ::prost::Name for Evidence {
+    const NAME: &'static str = "Evidence";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.Evidence".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.Evidence".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Snapshot {
     /// The height at which the snapshot was taken
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     /// The application-specific snapshot format
     #[prost(uint32, tag = "2")]
@@ -611,11 +1203,23 @@ pub struct Snapshot {
     pub chunks: u32,
     /// Arbitrary snapshot hash, equal only if identical
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub hash: ::prost::alloc::vec::Vec<u8>,
     /// Arbitrary application metadata
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub metadata: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for Snapshot {
+    const NAME: &'static str = "Snapshot";
+    const PACKAGE: &'static str = "tendermint.abci";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.abci.Snapshot".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.abci.Snapshot".into()
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum CheckTxType {
@@ -642,6 +1246,15 @@ impl CheckTxType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for CheckTxType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum EvidenceType {
@@ -671,3 +1284,60 @@ impl EvidenceType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for EvidenceType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Request>();
+    registry.register::<RequestEcho>();
+    registry.register::<RequestFlush>();
+    registry.register::<RequestInfo>();
+    registry.register::<RequestSetOption>();
+    registry.register::<RequestInitChain>();
+    registry.register::<RequestQuery>();
+    registry.register::<RequestBeginBlock>();
+    registry.register::<RequestCheckTx>();
+    registry.register::<RequestDeliverTx>();
+    registry.register::<RequestEndBlock>();
+    registry.register::<RequestCommit>();
+    registry.register::<RequestListSnapshots>();
+    registry.register::<RequestOfferSnapshot>();
+    registry.register::<RequestLoadSnapshotChunk>();
+    registry.register::<RequestApplySnapshotChunk>();
+    registry.register::<Response>();
+    registry.register::<ResponseException>();
+    registry.register::<ResponseEcho>();
+    registry.register::<ResponseFlush>();
+    registry.register::<ResponseInfo>();
+    registry.register::<ResponseSetOption>();
+    registry.register::<ResponseInitChain>();
+    registry.register::<ResponseQuery>();
+    registry.register::<ResponseBeginBlock>();
+    registry.register::<ResponseCheckTx>();
+    registry.register::<ResponseDeliverTx>();
+    registry.register::<ResponseEndBlock>();
+    registry.register::<ResponseCommit>();
+    registry.register::<ResponseListSnapshots>();
+    registry.register::<ResponseOfferSnapshot>();
+    registry.register::<ResponseLoadSnapshotChunk>();
+    registry.register::<ResponseApplySnapshotChunk>();
+    registry.register::<ConsensusParams>();
+    registry.register::<BlockParams>();
+    registry.register::<LastCommitInfo>();
+    registry.register::<Event>();
+    registry.register::<EventAttribute>();
+    registry.register::<TxResult>();
+    registry.register::<Validator>();
+    registry.register::<ValidatorUpdate>();
+    registry.register::<VoteInfo>();
+    registry.register::<Evidence>();
+    registry.register::<Snapshot>();
+}
@@ -1,40 +1,109 @@
 // This file is @generated by prost-build.
 /// BlockRequest requests a block for a specific height
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct BlockRequest {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for BlockRequest {
+    const NAME: &'static str = "BlockRequest";
+    const PACKAGE: &'static str = "tendermint.blockchain";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.blockchain.BlockRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.blockchain.BlockRequest".into()
+    }
+}
 /// NoBlockResponse informs the node that the peer does not have block at the requested height
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct NoBlockResponse {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for NoBlockResponse {
+    const NAME: &'static str = "NoBlockResponse";
+    const PACKAGE: &'static str = "tendermint.blockchain";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.blockchain.NoBlockResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.blockchain.NoBlockResponse".into()
+    }
+}
 /// BlockResponse returns block to the requested
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BlockResponse {
     #[prost(message, optional, tag = "1")]
     pub block: ::core::option::Option<super::types::Block>,
 }
+impl ::prost::Name for BlockResponse {
+    const NAME: &'static str = "BlockResponse";
+    const PACKAGE: &'static str = "tendermint.blockchain";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.blockchain.BlockResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.blockchain.BlockResponse".into()
+    }
+}
 /// StatusRequest requests the status of a peer.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct StatusRequest {}
+impl ::prost::Name for StatusRequest {
+    const NAME: &'static str = "StatusRequest";
+    const PACKAGE: &'static str = "tendermint.blockchain";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.blockchain.StatusRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.blockchain.StatusRequest".into()
+    }
+}
 /// StatusResponse is a peer response to inform their status.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct StatusResponse {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub base: i64,
 }
+impl ::prost::Name for StatusResponse {
+    const NAME: &'static str = "StatusResponse";
+    const PACKAGE: &'static str = "tendermint.blockchain";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.blockchain.StatusResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.blockchain.StatusResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Message {
     #[prost(oneof = "message::Sum", tags = "1, 2, 3, 4, 5")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<message::Sum>,
 }
 /// Nested message and enum types in `Message`.
 pub mod message {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -49,3 +118,23 @@ pub mod message {
         StatusResponse(super::StatusResponse),
     }
 }
+impl ::prost::Name for Message {
+    const NAME: &'static str = "Message";
+    const PACKAGE: &'static str = "tendermint.blockchain";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.blockchain.Message".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.blockchain.Message".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<BlockRequest>();
+    registry.register::<NoBlockResponse>();
+    registry.register::<BlockResponse>();
+    registry.register::<StatusRequest>();
+    registry.register::<StatusResponse>();
+    registry.register::<Message>();
+}
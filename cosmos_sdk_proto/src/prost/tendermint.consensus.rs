@@ -1,25 +1,42 @@
 // This file is @generated by prost-build.
 /// NewRoundStep is sent for every step taken in the ConsensusState.
 /// For every height/round/step transition
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct NewRoundStep {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub round: i32,
     #[prost(uint32, tag = "3")]
     pub step: u32,
     #[prost(int64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub seconds_since_start_time: i64,
     #[prost(int32, tag = "5")]
     pub last_commit_round: i32,
 }
+impl ::prost::Name for NewRoundStep {
+    const NAME: &'static str = "NewRoundStep";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.NewRoundStep".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.NewRoundStep".into()
+    }
+}
 /// NewValidBlock is sent when a validator observes a valid block B in some round r,
 /// i.e., there is a Proposal for block B and 2/3+ prevotes for the block B in the round r.
 /// In case the block is also committed, then IsCommit flag is set to true.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NewValidBlock {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub round: i32,
@@ -30,83 +47,190 @@ pub struct NewValidBlock {
     #[prost(bool, tag = "5")]
     pub is_commit: bool,
 }
+impl ::prost::Name for NewValidBlock {
+    const NAME: &'static str = "NewValidBlock";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.NewValidBlock".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.NewValidBlock".into()
+    }
+}
 /// Proposal is sent when a new block is proposed.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Proposal {
     #[prost(message, optional, tag = "1")]
     pub proposal: ::core::option::Option<super::types::Proposal>,
 }
+impl ::prost::Name for Proposal {
+    const NAME: &'static str = "Proposal";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.Proposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.Proposal".into()
+    }
+}
 /// ProposalPOL is sent when a previous proposal is re-proposed.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProposalPol {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub proposal_pol_round: i32,
     #[prost(message, optional, tag = "3")]
     pub proposal_pol: ::core::option::Option<super::libs::bits::BitArray>,
 }
+impl ::prost::Name for ProposalPol {
+    const NAME: &'static str = "ProposalPOL";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.ProposalPOL".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.ProposalPOL".into()
+    }
+}
 /// BlockPart is sent when gossipping a piece of the proposed block.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BlockPart {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub round: i32,
     #[prost(message, optional, tag = "3")]
     pub part: ::core::option::Option<super::types::Part>,
 }
+impl ::prost::Name for BlockPart {
+    const NAME: &'static str = "BlockPart";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.BlockPart".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.BlockPart".into()
+    }
+}
 /// Vote is sent when voting for a proposal (or lack thereof).
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Vote {
     #[prost(message, optional, tag = "1")]
     pub vote: ::core::option::Option<super::types::Vote>,
 }
+impl ::prost::Name for Vote {
+    const NAME: &'static str = "Vote";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.Vote".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.Vote".into()
+    }
+}
 /// HasVote is sent to indicate that a particular vote has been received.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct HasVote {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub round: i32,
     #[prost(enumeration = "super::types::SignedMsgType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<super::types::SignedMsgType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<super::types::SignedMsgType, _>"))]
     pub r#type: i32,
     #[prost(int32, tag = "4")]
     pub index: i32,
 }
+impl ::prost::Name for HasVote {
+    const NAME: &'static str = "HasVote";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.HasVote".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.HasVote".into()
+    }
+}
 /// VoteSetMaj23 is sent to indicate that a given BlockID has seen +2/3 votes.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VoteSetMaj23 {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub round: i32,
     #[prost(enumeration = "super::types::SignedMsgType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<super::types::SignedMsgType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<super::types::SignedMsgType, _>"))]
     pub r#type: i32,
     #[prost(message, optional, tag = "4")]
     pub block_id: ::core::option::Option<super::types::BlockId>,
 }
+impl ::prost::Name for VoteSetMaj23 {
+    const NAME: &'static str = "VoteSetMaj23";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.VoteSetMaj23".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.VoteSetMaj23".into()
+    }
+}
 /// VoteSetBits is sent to communicate the bit-array of votes seen for the BlockID.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VoteSetBits {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "2")]
     pub round: i32,
     #[prost(enumeration = "super::types::SignedMsgType", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "cosmos_sdk_proto::proto_json::enumeration::serialize::<super::types::SignedMsgType, _>", deserialize_with = "cosmos_sdk_proto::proto_json::enumeration::deserialize::<super::types::SignedMsgType, _>"))]
     pub r#type: i32,
     #[prost(message, optional, tag = "4")]
     pub block_id: ::core::option::Option<super::types::BlockId>,
     #[prost(message, optional, tag = "5")]
     pub votes: ::core::option::Option<super::libs::bits::BitArray>,
 }
+impl ::prost::Name for VoteSetBits {
+    const NAME: &'static str = "VoteSetBits";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.VoteSetBits".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.VoteSetBits".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Message {
     #[prost(oneof = "message::Sum", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<message::Sum>,
 }
 /// Nested message and enum types in `Message`.
 pub mod message {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -129,7 +253,19 @@ pub mod message {
         VoteSetBits(super::VoteSetBits),
     }
 }
+impl ::prost::Name for Message {
+    const NAME: &'static str = "Message";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.Message".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.Message".into()
+    }
+}
 /// MsgInfo are msgs from the reactor which may update the state
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgInfo {
     #[prost(message, optional, tag = "1")]
@@ -137,32 +273,74 @@ pub struct MsgInfo {
     #[prost(string, tag = "2")]
     pub peer_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgInfo {
+    const NAME: &'static str = "MsgInfo";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.MsgInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.MsgInfo".into()
+    }
+}
 /// TimeoutInfo internally generated messages which may update the state
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct TimeoutInfo {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub duration: ::core::option::Option<::prost_types::Duration>,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
     #[prost(int32, tag = "3")]
     pub round: i32,
     #[prost(uint32, tag = "4")]
     pub step: u32,
 }
+impl ::prost::Name for TimeoutInfo {
+    const NAME: &'static str = "TimeoutInfo";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.TimeoutInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.TimeoutInfo".into()
+    }
+}
 /// EndHeight marks the end of the given height inside WAL.
 /// @internal used by scripts/wal2json util.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct EndHeight {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for EndHeight {
+    const NAME: &'static str = "EndHeight";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.EndHeight".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.EndHeight".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WalMessage {
     #[prost(oneof = "wal_message::Sum", tags = "1, 2, 3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<wal_message::Sum>,
 }
 /// Nested message and enum types in `WALMessage`.
 pub mod wal_message {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -175,11 +353,53 @@ pub mod wal_message {
         EndHeight(super::EndHeight),
     }
 }
+impl ::prost::Name for WalMessage {
+    const NAME: &'static str = "WALMessage";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.WALMessage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.WALMessage".into()
+    }
+}
 /// TimedWALMessage wraps WALMessage and adds Time for debugging purposes.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TimedWalMessage {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub time: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, optional, tag = "2")]
     pub msg: ::core::option::Option<WalMessage>,
 }
+impl ::prost::Name for TimedWalMessage {
+    const NAME: &'static str = "TimedWALMessage";
+    const PACKAGE: &'static str = "tendermint.consensus";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.consensus.TimedWALMessage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.consensus.TimedWALMessage".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<NewRoundStep>();
+    registry.register::<NewValidBlock>();
+    registry.register::<Proposal>();
+    registry.register::<ProposalPol>();
+    registry.register::<BlockPart>();
+    registry.register::<Vote>();
+    registry.register::<HasVote>();
+    registry.register::<VoteSetMaj23>();
+    registry.register::<VoteSetBits>();
+    registry.register::<Message>();
+    registry.register::<MsgInfo>();
+    registry.register::<TimeoutInfo>();
+    registry.register::<EndHeight>();
+    registry.register::<WalMessage>();
+    registry.register::<TimedWalMessage>();
+}
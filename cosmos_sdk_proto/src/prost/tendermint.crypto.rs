@@ -1,24 +1,55 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Proof {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub total: i64,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub index: i64,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub leaf_hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", repeated, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes_vec"))]
     pub aunts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+impl ::prost::Name for Proof {
+    const NAME: &'static str = "Proof";
+    const PACKAGE: &'static str = "tendermint.crypto";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.crypto.Proof".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.crypto.Proof".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValueOp {
     /// Encoded in ProofOp.Key.
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     /// To encode in ProofOp.Data
     #[prost(message, optional, tag = "2")]
     pub proof: ::core::option::Option<Proof>,
 }
+impl ::prost::Name for ValueOp {
+    const NAME: &'static str = "ValueOp";
+    const PACKAGE: &'static str = "tendermint.crypto";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.crypto.ValueOp".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.crypto.ValueOp".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DominoOp {
     #[prost(string, tag = "1")]
@@ -28,37 +59,100 @@ pub struct DominoOp {
     #[prost(string, tag = "3")]
     pub output: ::prost::alloc::string::String,
 }
+impl ::prost::Name for DominoOp {
+    const NAME: &'static str = "DominoOp";
+    const PACKAGE: &'static str = "tendermint.crypto";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.crypto.DominoOp".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.crypto.DominoOp".into()
+    }
+}
 /// ProofOp defines an operation used for calculating Merkle root
 /// The data could be arbitrary format, providing nessecary data
 /// for example neighbouring node hash
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofOp {
     #[prost(string, tag = "1")]
     pub r#type: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub key: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for ProofOp {
+    const NAME: &'static str = "ProofOp";
+    const PACKAGE: &'static str = "tendermint.crypto";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.crypto.ProofOp".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.crypto.ProofOp".into()
+    }
+}
 /// ProofOps is Merkle proof defined by the list of ProofOps
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProofOps {
     #[prost(message, repeated, tag = "1")]
     pub ops: ::prost::alloc::vec::Vec<ProofOp>,
 }
+impl ::prost::Name for ProofOps {
+    const NAME: &'static str = "ProofOps";
+    const PACKAGE: &'static str = "tendermint.crypto";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.crypto.ProofOps".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.crypto.ProofOps".into()
+    }
+}
 /// PublicKey defines the keys available for use with Validators
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PublicKey {
     #[prost(oneof = "public_key::Sum", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<public_key::Sum>,
 }
 /// Nested message and enum types in `PublicKey`.
 pub mod public_key {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(bytes, tag = "1")]
+        #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
         Ed25519(::prost::alloc::vec::Vec<u8>),
         #[prost(bytes, tag = "2")]
+        #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
         Secp256k1(::prost::alloc::vec::Vec<u8>),
     }
 }
+impl ::prost::Name for PublicKey {
+    const NAME: &'static str = "PublicKey";
+    const PACKAGE: &'static str = "tendermint.crypto";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.crypto.PublicKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.crypto.PublicKey".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Proof>();
+    registry.register::<ValueOp>();
+    registry.register::<DominoOp>();
+    registry.register::<ProofOp>();
+    registry.register::<ProofOps>();
+    registry.register::<PublicKey>();
+}
@@ -1,8 +1,27 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BitArray {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub bits: i64,
     #[prost(uint64, repeated, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64_vec"))]
     pub elems: ::prost::alloc::vec::Vec<u64>,
 }
+impl ::prost::Name for BitArray {
+    const NAME: &'static str = "BitArray";
+    const PACKAGE: &'static str = "tendermint.libs.bits";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.libs.bits.BitArray".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.libs.bits.BitArray".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<BitArray>();
+}
@@ -1,8 +1,34 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PacketPing {}
+impl ::prost::Name for PacketPing {
+    const NAME: &'static str = "PacketPing";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.PacketPing".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.PacketPing".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PacketPong {}
+impl ::prost::Name for PacketPong {
+    const NAME: &'static str = "PacketPong";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.PacketPong".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.PacketPong".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PacketMsg {
     #[prost(int32, tag = "1")]
@@ -10,15 +36,31 @@ pub struct PacketMsg {
     #[prost(bool, tag = "2")]
     pub eof: bool,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for PacketMsg {
+    const NAME: &'static str = "PacketMsg";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.PacketMsg".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.PacketMsg".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Packet {
     #[prost(oneof = "packet::Sum", tags = "1, 2, 3")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<packet::Sum>,
 }
 /// Nested message and enum types in `Packet`.
 pub mod packet {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -29,13 +71,38 @@ pub mod packet {
         PacketMsg(super::PacketMsg),
     }
 }
+impl ::prost::Name for Packet {
+    const NAME: &'static str = "Packet";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.Packet".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.Packet".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthSigMessage {
     #[prost(message, optional, tag = "1")]
     pub pub_key: ::core::option::Option<super::crypto::PublicKey>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub sig: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for AuthSigMessage {
+    const NAME: &'static str = "AuthSigMessage";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.AuthSigMessage".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.AuthSigMessage".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NetAddress {
     #[prost(string, tag = "1")]
@@ -45,15 +112,42 @@ pub struct NetAddress {
     #[prost(uint32, tag = "3")]
     pub port: u32,
 }
+impl ::prost::Name for NetAddress {
+    const NAME: &'static str = "NetAddress";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.NetAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.NetAddress".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ProtocolVersion {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub p2p: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block: u64,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub app: u64,
 }
+impl ::prost::Name for ProtocolVersion {
+    const NAME: &'static str = "ProtocolVersion";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.ProtocolVersion".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.ProtocolVersion".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DefaultNodeInfo {
     #[prost(message, optional, tag = "1")]
@@ -67,12 +161,25 @@ pub struct DefaultNodeInfo {
     #[prost(string, tag = "5")]
     pub version: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub channels: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "7")]
     pub moniker: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "8")]
     pub other: ::core::option::Option<DefaultNodeInfoOther>,
 }
+impl ::prost::Name for DefaultNodeInfo {
+    const NAME: &'static str = "DefaultNodeInfo";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.DefaultNodeInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.DefaultNodeInfo".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DefaultNodeInfoOther {
     #[prost(string, tag = "1")]
@@ -80,20 +187,59 @@ pub struct DefaultNodeInfoOther {
     #[prost(string, tag = "2")]
     pub rpc_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for DefaultNodeInfoOther {
+    const NAME: &'static str = "DefaultNodeInfoOther";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.DefaultNodeInfoOther".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.DefaultNodeInfoOther".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PexRequest {}
+impl ::prost::Name for PexRequest {
+    const NAME: &'static str = "PexRequest";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.PexRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.PexRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PexAddrs {
     #[prost(message, repeated, tag = "1")]
     pub addrs: ::prost::alloc::vec::Vec<NetAddress>,
 }
+impl ::prost::Name for PexAddrs {
+    const NAME: &'static str = "PexAddrs";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.PexAddrs".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.PexAddrs".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Message {
     #[prost(oneof = "message::Sum", tags = "1, 2")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<message::Sum>,
 }
 /// Nested message and enum types in `Message`.
 pub mod message {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -102,3 +248,29 @@ pub mod message {
         PexAddrs(super::PexAddrs),
     }
 }
+impl ::prost::Name for Message {
+    const NAME: &'static str = "Message";
+    const PACKAGE: &'static str = "tendermint.p2p";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.p2p.Message".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.p2p.Message".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<PacketPing>();
+    registry.register::<PacketPong>();
+    registry.register::<PacketMsg>();
+    registry.register::<Packet>();
+    registry.register::<AuthSigMessage>();
+    registry.register::<NetAddress>();
+    registry.register::<ProtocolVersion>();
+    registry.register::<DefaultNodeInfo>();
+    registry.register::<DefaultNodeInfoOther>();
+    registry.register::<PexRequest>();
+    registry.register::<PexAddrs>();
+    registry.register::<Message>();
+}
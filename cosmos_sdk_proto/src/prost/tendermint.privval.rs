@@ -1,4 +1,6 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RemoteSignerError {
     #[prost(int32, tag = "1")]
@@ -6,13 +8,37 @@ pub struct RemoteSignerError {
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
 }
+impl ::prost::Name for RemoteSignerError {
+    const NAME: &'static str = "RemoteSignerError";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.RemoteSignerError".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.RemoteSignerError".into()
+    }
+}
 /// PubKeyRequest requests the consensus public key from the remote signer.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PubKeyRequest {
     #[prost(string, tag = "1")]
     pub chain_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for PubKeyRequest {
+    const NAME: &'static str = "PubKeyRequest";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.PubKeyRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.PubKeyRequest".into()
+    }
+}
 /// PubKeyResponse is a response message containing the public key.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PubKeyResponse {
     #[prost(message, optional, tag = "1")]
@@ -20,7 +46,19 @@ pub struct PubKeyResponse {
     #[prost(message, optional, tag = "2")]
     pub error: ::core::option::Option<RemoteSignerError>,
 }
+impl ::prost::Name for PubKeyResponse {
+    const NAME: &'static str = "PubKeyResponse";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.PubKeyResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.PubKeyResponse".into()
+    }
+}
 /// SignVoteRequest is a request to sign a vote
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignVoteRequest {
     #[prost(message, optional, tag = "1")]
@@ -28,7 +66,19 @@ pub struct SignVoteRequest {
     #[prost(string, tag = "2")]
     pub chain_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for SignVoteRequest {
+    const NAME: &'static str = "SignVoteRequest";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.SignVoteRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.SignVoteRequest".into()
+    }
+}
 /// SignedVoteResponse is a response containing a signed vote or an error
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignedVoteResponse {
     #[prost(message, optional, tag = "1")]
@@ -36,7 +86,19 @@ pub struct SignedVoteResponse {
     #[prost(message, optional, tag = "2")]
     pub error: ::core::option::Option<RemoteSignerError>,
 }
+impl ::prost::Name for SignedVoteResponse {
+    const NAME: &'static str = "SignedVoteResponse";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.SignedVoteResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.SignedVoteResponse".into()
+    }
+}
 /// SignProposalRequest is a request to sign a proposal
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignProposalRequest {
     #[prost(message, optional, tag = "1")]
@@ -44,7 +106,19 @@ pub struct SignProposalRequest {
     #[prost(string, tag = "2")]
     pub chain_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for SignProposalRequest {
+    const NAME: &'static str = "SignProposalRequest";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.SignProposalRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.SignProposalRequest".into()
+    }
+}
 /// SignedProposalResponse is response containing a signed proposal or an error
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignedProposalResponse {
     #[prost(message, optional, tag = "1")]
@@ -52,19 +126,58 @@ pub struct SignedProposalResponse {
     #[prost(message, optional, tag = "2")]
     pub error: ::core::option::Option<RemoteSignerError>,
 }
+impl ::prost::Name for SignedProposalResponse {
+    const NAME: &'static str = "SignedProposalResponse";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.SignedProposalResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.SignedProposalResponse".into()
+    }
+}
 /// PingRequest is a request to confirm that the connection is alive.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PingRequest {}
+impl ::prost::Name for PingRequest {
+    const NAME: &'static str = "PingRequest";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.PingRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.PingRequest".into()
+    }
+}
 /// PingResponse is a response to confirm that the connection is alive.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct PingResponse {}
+impl ::prost::Name for PingResponse {
+    const NAME: &'static str = "PingResponse";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.PingResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.PingResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Message {
     #[prost(oneof = "message::Sum", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<message::Sum>,
 }
 /// Nested message and enum types in `Message`.
 pub mod message {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -85,6 +198,16 @@ pub mod message {
         PingResponse(super::PingResponse),
     }
 }
+impl ::prost::Name for Message {
+    const NAME: &'static str = "Message";
+    const PACKAGE: &'static str = "tendermint.privval";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.privval.Message".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.privval.Message".into()
+    }
+}
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum Errors {
@@ -123,3 +246,26 @@ impl Errors {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for Errors {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<RemoteSignerError>();
+    registry.register::<PubKeyRequest>();
+    registry.register::<PubKeyResponse>();
+    registry.register::<SignVoteRequest>();
+    registry.register::<SignedVoteResponse>();
+    registry.register::<SignProposalRequest>();
+    registry.register::<SignedProposalResponse>();
+    registry.register::<PingRequest>();
+    registry.register::<PingResponse>();
+    registry.register::<Message>();
+}
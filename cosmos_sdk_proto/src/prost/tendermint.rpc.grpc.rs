@@ -1,13 +1,52 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct RequestPing {}
+impl ::prost::Name for RequestPing {
+    const NAME: &'static str = "RequestPing";
+    const PACKAGE: &'static str = "tendermint.rpc.grpc";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.rpc.grpc.RequestPing".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.rpc.grpc.RequestPing".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RequestBroadcastTx {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub tx: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for RequestBroadcastTx {
+    const NAME: &'static str = "RequestBroadcastTx";
+    const PACKAGE: &'static str = "tendermint.rpc.grpc";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.rpc.grpc.RequestBroadcastTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.rpc.grpc.RequestBroadcastTx".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ResponsePing {}
+impl ::prost::Name for ResponsePing {
+    const NAME: &'static str = "ResponsePing";
+    const PACKAGE: &'static str = "tendermint.rpc.grpc";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.rpc.grpc.ResponsePing".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.rpc.grpc.ResponsePing".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseBroadcastTx {
     #[prost(message, optional, tag = "1")]
@@ -15,3 +54,21 @@ pub struct ResponseBroadcastTx {
     #[prost(message, optional, tag = "2")]
     pub deliver_tx: ::core::option::Option<super::super::abci::ResponseDeliverTx>,
 }
+impl ::prost::Name for ResponseBroadcastTx {
+    const NAME: &'static str = "ResponseBroadcastTx";
+    const PACKAGE: &'static str = "tendermint.rpc.grpc";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.rpc.grpc.ResponseBroadcastTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.rpc.grpc.ResponseBroadcastTx".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<RequestPing>();
+    registry.register::<RequestBroadcastTx>();
+    registry.register::<ResponsePing>();
+    registry.register::<ResponseBroadcastTx>();
+}
@@ -2,6 +2,8 @@
 /// ABCIResponses retains the responses
 /// of the various ABCI calls during block processing.
 /// It is persisted to disk for each height before calling Commit.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciResponses {
     #[prost(message, repeated, tag = "1")]
@@ -11,29 +13,80 @@ pub struct AbciResponses {
     #[prost(message, optional, tag = "3")]
     pub begin_block: ::core::option::Option<super::abci::ResponseBeginBlock>,
 }
+impl ::prost::Name for AbciResponses {
+    const NAME: &'static str = "ABCIResponses";
+    const PACKAGE: &'static str = "tendermint.state";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.state.ABCIResponses".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.state.ABCIResponses".into()
+    }
+}
 /// ValidatorsInfo represents the latest validator set, or the last height it changed
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorsInfo {
     #[prost(message, optional, tag = "1")]
     pub validator_set: ::core::option::Option<super::types::ValidatorSet>,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_height_changed: i64,
 }
+impl ::prost::Name for ValidatorsInfo {
+    const NAME: &'static str = "ValidatorsInfo";
+    const PACKAGE: &'static str = "tendermint.state";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.state.ValidatorsInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.state.ValidatorsInfo".into()
+    }
+}
 /// ConsensusParamsInfo represents the latest consensus params, or the last height it changed
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusParamsInfo {
     #[prost(message, optional, tag = "1")]
     pub consensus_params: ::core::option::Option<super::types::ConsensusParams>,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_height_changed: i64,
 }
+impl ::prost::Name for ConsensusParamsInfo {
+    const NAME: &'static str = "ConsensusParamsInfo";
+    const PACKAGE: &'static str = "tendermint.state";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.state.ConsensusParamsInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.state.ConsensusParamsInfo".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AbciResponsesInfo {
     #[prost(message, optional, tag = "1")]
     pub abci_responses: ::core::option::Option<AbciResponses>,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for AbciResponsesInfo {
+    const NAME: &'static str = "ABCIResponsesInfo";
+    const PACKAGE: &'static str = "tendermint.state";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.state.ABCIResponsesInfo".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.state.ABCIResponsesInfo".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Version {
     #[prost(message, optional, tag = "1")]
@@ -41,6 +94,18 @@ pub struct Version {
     #[prost(string, tag = "2")]
     pub software: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Version {
+    const NAME: &'static str = "Version";
+    const PACKAGE: &'static str = "tendermint.state";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.state.Version".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.state.Version".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct State {
     #[prost(message, optional, tag = "1")]
@@ -49,13 +114,16 @@ pub struct State {
     #[prost(string, tag = "2")]
     pub chain_id: ::prost::alloc::string::String,
     #[prost(int64, tag = "14")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub initial_height: i64,
     /// LastBlockHeight=0 at genesis (ie. block(H=0) does not exist)
     #[prost(int64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_block_height: i64,
     #[prost(message, optional, tag = "4")]
     pub last_block_id: ::core::option::Option<super::types::BlockId>,
     #[prost(message, optional, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::string_opt"))]
     pub last_block_time: ::core::option::Option<::prost_types::Timestamp>,
     /// LastValidators is used to validate block.LastCommit.
     /// Validators are persisted to the database separately every time they change,
@@ -70,17 +138,41 @@ pub struct State {
     #[prost(message, optional, tag = "8")]
     pub last_validators: ::core::option::Option<super::types::ValidatorSet>,
     #[prost(int64, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_height_validators_changed: i64,
     /// Consensus parameters used for validating blocks.
     /// Changes returned by EndBlock and updated after Commit.
     #[prost(message, optional, tag = "10")]
     pub consensus_params: ::core::option::Option<super::types::ConsensusParams>,
     #[prost(int64, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_height_consensus_params_changed: i64,
     /// Merkle root of the results from executing prev block
     #[prost(bytes = "vec", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub last_results_hash: ::prost::alloc::vec::Vec<u8>,
     /// the latest AppHash we've received from calling abci.Commit()
     #[prost(bytes = "vec", tag = "13")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub app_hash: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for State {
+    const NAME: &'static str = "State";
+    const PACKAGE: &'static str = "tendermint.state";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.state.State".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.state.State".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<AbciResponses>();
+    registry.register::<ValidatorsInfo>();
+    registry.register::<ConsensusParamsInfo>();
+    registry.register::<AbciResponsesInfo>();
+    registry.register::<Version>();
+    registry.register::<State>();
+}
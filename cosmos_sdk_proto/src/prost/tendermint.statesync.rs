@@ -1,11 +1,16 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Message {
     #[prost(oneof = "message::Sum", tags = "1, 2, 3, 4")]
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub sum: ::core::option::Option<message::Sum>,
 }
 /// Nested message and enum types in `Message`.
 pub mod message {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Sum {
         #[prost(message, tag = "1")]
@@ -18,40 +23,113 @@ pub mod message {
         ChunkResponse(super::ChunkResponse),
     }
 }
+impl ::prost::Name for Message {
+    const NAME: &'static str = "Message";
+    const PACKAGE: &'static str = "tendermint.statesync";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.statesync.Message".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.statesync.Message".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SnapshotsRequest {}
+impl ::prost::Name for SnapshotsRequest {
+    const NAME: &'static str = "SnapshotsRequest";
+    const PACKAGE: &'static str = "tendermint.statesync";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.statesync.SnapshotsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.statesync.SnapshotsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SnapshotsResponse {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(uint32, tag = "2")]
     pub format: u32,
     #[prost(uint32, tag = "3")]
     pub chunks: u32,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub metadata: ::prost::alloc::vec::Vec<u8>,
 }
+impl ::prost::Name for SnapshotsResponse {
+    const NAME: &'static str = "SnapshotsResponse";
+    const PACKAGE: &'static str = "tendermint.statesync";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.statesync.SnapshotsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.statesync.SnapshotsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ChunkRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(uint32, tag = "2")]
     pub format: u32,
     #[prost(uint32, tag = "3")]
     pub index: u32,
 }
+impl ::prost::Name for ChunkRequest {
+    const NAME: &'static str = "ChunkRequest";
+    const PACKAGE: &'static str = "tendermint.statesync";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.statesync.ChunkRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.statesync.ChunkRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChunkResponse {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(uint32, tag = "2")]
     pub format: u32,
     #[prost(uint32, tag = "3")]
     pub index: u32,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub chunk: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag = "5")]
     pub missing: bool,
 }
+impl ::prost::Name for ChunkResponse {
+    const NAME: &'static str = "ChunkResponse";
+    const PACKAGE: &'static str = "tendermint.statesync";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.statesync.ChunkResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.statesync.ChunkResponse".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Message>();
+    registry.register::<SnapshotsRequest>();
+    registry.register::<SnapshotsResponse>();
+    registry.register::<ChunkRequest>();
+    registry.register::<ChunkResponse>();
+}
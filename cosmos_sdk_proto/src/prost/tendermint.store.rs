@@ -1,8 +1,27 @@
 // This file is @generated by prost-build.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct BlockStoreState {
     #[prost(int64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub base: i64,
     #[prost(int64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: i64,
 }
+impl ::prost::Name for BlockStoreState {
+    const NAME: &'static str = "BlockStoreState";
+    const PACKAGE: &'static str = "tendermint.store";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.store.BlockStoreState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.store.BlockStoreState".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<BlockStoreState>();
+}
@@ -2,20 +2,53 @@
 /// App includes the protocol and software version for the application.
 /// This information is included in ResponseInfo. The App.Protocol can be
 /// updated in ResponseEndBlock.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct App {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub protocol: u64,
     #[prost(string, tag = "2")]
     pub software: ::prost::alloc::string::String,
 }
+impl ::prost::Name for App {
+    const NAME: &'static str = "App";
+    const PACKAGE: &'static str = "tendermint.version";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.version.App".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.version.App".into()
+    }
+}
 /// Consensus captures the consensus rules for processing a block in the blockchain,
 /// including all blockchain data structures and the rules of the application's
 /// state transition machine.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct Consensus {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub app: u64,
 }
+impl ::prost::Name for Consensus {
+    const NAME: &'static str = "Consensus";
+    const PACKAGE: &'static str = "tendermint.version";
+    fn full_name() -> ::prost::alloc::string::String {
+        "tendermint.version.Consensus".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/tendermint.version.Consensus".into()
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<App>();
+    registry.register::<Consensus>();
+}
@@ -0,0 +1,841 @@
+//! Helpers implementing the proto3 JSON mapping for the `serde` feature. proto_build annotates the generated code
+//! with `#[serde(with = "...")]` attributes pointing at these modules for every field which serde would not
+//! otherwise encode according to the mapping:
+//!
+//! * 64 bit integers are encoded as decimal strings, and may be decoded from either strings or numbers
+//! * bytes are encoded as standard base64, and may be decoded from standard or url safe base64
+//! * enums are encoded by their protobuf names (e.g. "VOTE_OPTION_YES"), and may be decoded from names or numbers
+//! * Timestamp and Duration are encoded as RFC 3339 and "1.5s" strings respectively
+//! * Any is encoded as an object with an "@type" field, see [AnyRegistry] for how the embedded message is encoded
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display},
+    hash::Hash,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use base64::{
+    engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+    Engine,
+};
+use serde::{
+    de::{self, DeserializeOwned, Visitor},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::{Map, Value};
+
+/// Implemented for every generated protobuf enum, allowing enum fields (which prost stores as i32) to be
+/// encoded by name
+pub trait Enumeration: Sized + Into<i32> + TryFrom<i32> {
+    /// The name of this value in the protobuf definition
+    fn proto_name(&self) -> &'static str;
+    /// Looks up a value by its name in the protobuf definition
+    fn from_proto_name(value: &str) -> Option<Self>;
+}
+
+/// The JSON encoding of a single value of type T
+trait Mapping<T> {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error>;
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error>;
+}
+
+/// Serializes a borrowed value with the mapping M, used to encode collections
+struct Ser<'a, M, T>(&'a T, PhantomData<M>);
+
+impl<M: Mapping<T>, T> Serialize for Ser<'_, M, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        M::serialize(self.0, serializer)
+    }
+}
+
+/// Deserializes a value with the mapping M, used to decode collections
+struct De<M, T>(T, PhantomData<M>);
+
+impl<'de, M: Mapping<T>, T> Deserialize<'de> for De<M, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        M::deserialize(deserializer).map(|value| De(value, PhantomData))
+    }
+}
+
+fn serialize_vec<M: Mapping<T>, T, S: Serializer>(
+    values: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(values.iter().map(|v| Ser::<M, T>(v, PhantomData)))
+}
+
+fn deserialize_vec<'de, M: Mapping<T>, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    let values = Vec::<De<M, T>>::deserialize(deserializer)?;
+    Ok(values.into_iter().map(|v| v.0).collect())
+}
+
+fn serialize_opt<M: Mapping<T>, T, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => serializer.serialize_some(&Ser::<M, T>(v, PhantomData)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_opt<'de, M: Mapping<T>, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    let value = Option::<De<M, T>>::deserialize(deserializer)?;
+    Ok(value.map(|v| v.0))
+}
+
+fn serialize_map<M: Mapping<T>, K: Serialize, T, S: Serializer>(
+    values: &HashMap<K, T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(values.iter().map(|(k, v)| (k, Ser::<M, T>(v, PhantomData))))
+}
+
+fn deserialize_map<'de, M: Mapping<T>, K: Deserialize<'de> + Eq + Hash, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<K, T>, D::Error> {
+    let values = HashMap::<K, De<M, T>>::deserialize(deserializer)?;
+    Ok(values.into_iter().map(|(k, v)| (k, v.0)).collect())
+}
+
+/// Declares the scalar, repeated and optional `with` modules for a mapping of the type $t
+macro_rules! with_modules {
+    ($mapping:ty, $scalar:ident, $vec:ident, $opt:ident, [$($generics:tt)*] $t:ty) => {
+        pub mod $scalar {
+            use super::*;
+
+            pub fn serialize<$($generics)* S: Serializer>(
+                value: &$t,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                <$mapping as Mapping<$t>>::serialize(value, serializer)
+            }
+
+            pub fn deserialize<'de, $($generics)* D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<$t, D::Error> {
+                <$mapping as Mapping<$t>>::deserialize(deserializer)
+            }
+        }
+
+        pub mod $vec {
+            use super::*;
+
+            pub fn serialize<$($generics)* S: Serializer>(
+                values: &[$t],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize_vec::<$mapping, $t, S>(values, serializer)
+            }
+
+            pub fn deserialize<'de, $($generics)* D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Vec<$t>, D::Error> {
+                deserialize_vec::<$mapping, $t, D>(deserializer)
+            }
+        }
+
+        pub mod $opt {
+            use super::*;
+
+            pub fn serialize<$($generics)* S: Serializer>(
+                value: &Option<$t>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize_opt::<$mapping, $t, S>(value, serializer)
+            }
+
+            pub fn deserialize<'de, $($generics)* D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<$t>, D::Error> {
+                deserialize_opt::<$mapping, $t, D>(deserializer)
+            }
+        }
+    };
+}
+
+/// 64 bit integers, encoded as strings
+struct Int64;
+
+/// Bound for the integer types handled by the int64 modules
+pub trait Int64Value: Display + FromStr + TryFrom<i64> + TryFrom<u64> {}
+impl Int64Value for i64 {}
+impl Int64Value for u64 {}
+
+impl<T: Int64Value> Mapping<T> for Int64 {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        struct Int64Visitor<T>(PhantomData<T>);
+
+        impl<T: Int64Value> Visitor<'_> for Int64Visitor<T> {
+            type Value = T;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a 64 bit integer or a string containing one")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+                T::try_from(v).map_err(|_| E::custom(format!("integer {v} out of range")))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+                T::try_from(v).map_err(|_| E::custom(format!("integer {v} out of range")))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+                v.parse()
+                    .map_err(|_| E::custom(format!("invalid 64 bit integer {v:?}")))
+            }
+        }
+
+        deserializer.deserialize_any(Int64Visitor(PhantomData))
+    }
+}
+
+with_modules!(Int64, int64, int64_vec, int64_opt, [T: Int64Value,] T);
+
+/// Map fields with 64 bit integer values, the keys are encoded by serde as usual
+pub mod int64_map {
+    use super::*;
+
+    pub fn serialize<K: Serialize, T: Int64Value, S: Serializer>(
+        values: &HashMap<K, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_map::<Int64, K, T, S>(values, serializer)
+    }
+
+    pub fn deserialize<
+        'de,
+        K: Deserialize<'de> + Eq + Hash,
+        T: Int64Value,
+        D: Deserializer<'de>,
+    >(
+        deserializer: D,
+    ) -> Result<HashMap<K, T>, D::Error> {
+        deserialize_map::<Int64, K, T, D>(deserializer)
+    }
+}
+
+/// Bytes, encoded as base64 strings
+struct Base64;
+
+impl Mapping<Vec<u8>> for Base64 {
+    fn serialize<S: Serializer>(value: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(value))
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        decode_base64(&encoded).map_err(de::Error::custom)
+    }
+}
+
+fn decode_base64(encoded: &str) -> Result<Vec<u8>, String> {
+    [STANDARD, URL_SAFE, STANDARD_NO_PAD, URL_SAFE_NO_PAD]
+        .iter()
+        .find_map(|engine| engine.decode(encoded).ok())
+        .ok_or_else(|| format!("invalid base64 {encoded:?}"))
+}
+
+with_modules!(Base64, bytes, bytes_vec, bytes_opt, [] Vec<u8>);
+
+/// Well known types with a string encoding (Timestamp and Duration), which prost-types provides Display and
+/// FromStr implementations of
+struct DisplayFromStr;
+
+impl<T: StringValue> Mapping<T> for DisplayFromStr {
+    fn serialize<S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        encoded.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Bound for the types handled by the string modules
+pub trait StringValue: Display + FromStr<Err = Self::ParseError> {
+    type ParseError: Display;
+}
+impl StringValue for prost_types::Timestamp {
+    type ParseError = prost_types::TimestampError;
+}
+impl StringValue for prost_types::Duration {
+    type ParseError = prost_types::DurationError;
+}
+
+with_modules!(DisplayFromStr, string, string_vec, string_opt, [T: StringValue,] T);
+
+/// Enums, encoded by name
+struct EnumName<E>(PhantomData<E>);
+
+impl<E: Enumeration> Mapping<i32> for EnumName<E> {
+    fn serialize<S: Serializer>(value: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+        // unknown values have no name, so they are encoded as numbers
+        match E::try_from(*value) {
+            Ok(e) => serializer.serialize_str(e.proto_name()),
+            Err(_) => serializer.serialize_i32(*value),
+        }
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+        struct EnumVisitor<E>(PhantomData<E>);
+
+        impl<E: Enumeration> Visitor<'_> for EnumVisitor<E> {
+            type Value = i32;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an enum name or number")
+            }
+
+            fn visit_i64<Er: de::Error>(self, v: i64) -> Result<i32, Er> {
+                i32::try_from(v).map_err(|_| Er::custom(format!("enum value {v} out of range")))
+            }
+
+            fn visit_u64<Er: de::Error>(self, v: u64) -> Result<i32, Er> {
+                i32::try_from(v).map_err(|_| Er::custom(format!("enum value {v} out of range")))
+            }
+
+            fn visit_str<Er: de::Error>(self, v: &str) -> Result<i32, Er> {
+                E::from_proto_name(v)
+                    .map(Into::into)
+                    .ok_or_else(|| Er::custom(format!("unknown enum value {v:?}")))
+            }
+        }
+
+        deserializer.deserialize_any(EnumVisitor::<E>(PhantomData))
+    }
+}
+
+/// Enum fields, which must name the enum type: `serialize_with = "enumeration::serialize::<VoteOption, _>"`
+pub mod enumeration {
+    use super::*;
+
+    pub fn serialize<E: Enumeration, S: Serializer>(
+        value: &i32,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        <EnumName<E> as Mapping<i32>>::serialize(value, serializer)
+    }
+
+    pub fn deserialize<'de, E: Enumeration, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<i32, D::Error> {
+        <EnumName<E> as Mapping<i32>>::deserialize(deserializer)
+    }
+}
+
+/// Repeated enum fields
+pub mod enumeration_vec {
+    use super::*;
+
+    pub fn serialize<E: Enumeration, S: Serializer>(
+        values: &[i32],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_vec::<EnumName<E>, i32, S>(values, serializer)
+    }
+
+    pub fn deserialize<'de, E: Enumeration, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<i32>, D::Error> {
+        deserialize_vec::<EnumName<E>, i32, D>(deserializer)
+    }
+}
+
+/// Optional enum fields
+pub mod enumeration_opt {
+    use super::*;
+
+    pub fn serialize<E: Enumeration, S: Serializer>(
+        value: &Option<i32>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_opt::<EnumName<E>, i32, S>(value, serializer)
+    }
+
+    pub fn deserialize<'de, E: Enumeration, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<i32>, D::Error> {
+        deserialize_opt::<EnumName<E>, i32, D>(deserializer)
+    }
+}
+
+type ToJson = fn(&[u8]) -> Result<Value, String>;
+type FromJson = fn(Value) -> Result<Vec<u8>, String>;
+
+/// Well known types with a custom JSON encoding, which an Any nests under "value" rather than embedding their fields
+/// next to "@type", even where that encoding is an object
+const WELL_KNOWN_TYPE_URLS: &[&str] = &[
+    "/google.protobuf.Any",
+    "/google.protobuf.Timestamp",
+    "/google.protobuf.Duration",
+    "/google.protobuf.BoolValue",
+    "/google.protobuf.Int32Value",
+    "/google.protobuf.Int64Value",
+    "/google.protobuf.UInt32Value",
+    "/google.protobuf.UInt64Value",
+    "/google.protobuf.FloatValue",
+    "/google.protobuf.DoubleValue",
+    "/google.protobuf.StringValue",
+    "/google.protobuf.BytesValue",
+    "/google.protobuf.Empty",
+    "/google.protobuf.FieldMask",
+    "/google.protobuf.Struct",
+    "/google.protobuf.ListValue",
+    "/google.protobuf.Value",
+];
+
+/// The message types which may be embedded in an Any, and their JSON codecs. The proto3 JSON encoding of an Any
+/// embeds the fields of the contained message next to "@type", which requires knowing the message type. Any
+/// containing a registered type is encoded that way, for example
+/// `{"@type": "/cosmos.bank.v1beta1.MsgSend", "fromAddress": ...}`. Any containing a type which was not registered is
+/// encoded with the message bytes in base64 instead: `{"@type": "/cosmos.bank.v1beta1.MsgSend", "value": "Ci1..."}`.
+///
+/// Every generated module has a `register_json_types` function adding its messages to a registry. Each crate builds
+/// its registry once, on the first use of its [any_json_modules](crate::any_json_modules), from a fixed list of
+/// these functions, so the encoding of an Any never depends on what was registered at runtime. The Any fields of
+/// this crate's messages use [register_sdk_types], the crates built on this one add their own messages to those,
+/// e.g. `gravity_proto::register_json_types`. An Any in one of this crate's messages, such as the messages of an
+/// authz MsgExec, therefore only embeds the fields of this crate's messages.
+#[derive(Default)]
+pub struct AnyRegistry {
+    codecs: BTreeMap<String, (ToJson, FromJson)>,
+}
+
+impl AnyRegistry {
+    /// Registers a message type for use in Any
+    pub fn register<M>(&mut self)
+    where
+        M: prost::Name + prost::Message + Default + Serialize + DeserializeOwned,
+    {
+        self.codecs.insert(
+            M::type_url(),
+            (message_to_json::<M>, message_from_json::<M>),
+        );
+    }
+
+    fn register_string<T: prost::Message + Default + StringValue>(&mut self, type_url: &str) {
+        self.codecs.insert(
+            type_url.to_string(),
+            (string_to_json::<T>, string_from_json::<T>),
+        );
+    }
+
+    fn get(&self, type_url: &str) -> Option<(ToJson, FromJson)> {
+        self.codecs.get(type_url).copied()
+    }
+}
+
+/// Registers the well known types with a string encoding and the messages of every generated module in this crate
+pub fn register_sdk_types(registry: &mut AnyRegistry) {
+    registry.register_string::<prost_types::Timestamp>("/google.protobuf.Timestamp");
+    registry.register_string::<prost_types::Duration>("/google.protobuf.Duration");
+
+    #[cfg(feature = "bech32ibc")]
+    crate::bech32ibc::bech32ibc::v1::register_json_types(registry);
+    crate::cosmos::auth::v1beta1::register_json_types(registry);
+    crate::cosmos::authz::v1beta1::register_json_types(registry);
+    crate::cosmos::bank::v1beta1::register_json_types(registry);
+    crate::cosmos::base::abci::v1beta1::register_json_types(registry);
+    crate::cosmos::base::kv::v1beta1::register_json_types(registry);
+    crate::cosmos::base::query::v1beta1::register_json_types(registry);
+    crate::cosmos::base::reflection::v1beta1::register_json_types(registry);
+    crate::cosmos::base::snapshots::v1beta1::register_json_types(registry);
+    crate::cosmos::base::store::v1beta1::register_json_types(registry);
+    crate::cosmos::base::v1beta1::register_json_types(registry);
+    crate::cosmos::base::tendermint::v1beta1::register_json_types(registry);
+    crate::cosmos::crisis::v1beta1::register_json_types(registry);
+    crate::cosmos::crypto::multisig::register_json_types(registry);
+    crate::cosmos::crypto::multisig::v1beta1::register_json_types(registry);
+    crate::cosmos::crypto::ed25519::register_json_types(registry);
+    crate::cosmos::crypto::secp256k1::register_json_types(registry);
+    crate::cosmos::distribution::v1beta1::register_json_types(registry);
+    crate::cosmos::evidence::v1beta1::register_json_types(registry);
+    crate::cosmos::feegrant::v1beta1::register_json_types(registry);
+    crate::cosmos::genutil::v1beta1::register_json_types(registry);
+    crate::cosmos::gov::v1::register_json_types(registry);
+    crate::cosmos::gov::v1beta1::register_json_types(registry);
+    crate::cosmos::mint::v1beta1::register_json_types(registry);
+    crate::cosmos::params::v1beta1::register_json_types(registry);
+    crate::cosmos::slashing::v1beta1::register_json_types(registry);
+    crate::cosmos::staking::v1beta1::register_json_types(registry);
+    crate::cosmos::tx::signing::v1beta1::register_json_types(registry);
+    crate::cosmos::tx::v1beta1::register_json_types(registry);
+    crate::cosmos::upgrade::v1beta1::register_json_types(registry);
+    crate::cosmos::vesting::v1beta1::register_json_types(registry);
+    crate::ibc::applications::transfer::v1::register_json_types(registry);
+    crate::ibc::applications::transfer::v2::register_json_types(registry);
+    crate::ibc::applications::interchain_accounts::v1::register_json_types(registry);
+    crate::ibc::applications::interchain_accounts::controller::v1::register_json_types(registry);
+    crate::ibc::applications::interchain_accounts::host::v1::register_json_types(registry);
+    crate::ibc::core::channel::v1::register_json_types(registry);
+    crate::ibc::core::client::v1::register_json_types(registry);
+    crate::ibc::core::commitment::v1::register_json_types(registry);
+    crate::ibc::core::connection::v1::register_json_types(registry);
+    crate::ibc::core::types::v1::register_json_types(registry);
+    crate::ibc::lightclients::localhost::v1::register_json_types(registry);
+    crate::ibc::lightclients::solomachine::v1::register_json_types(registry);
+    crate::ibc::lightclients::solomachine::v2::register_json_types(registry);
+    crate::ibc::lightclients::tendermint::v1::register_json_types(registry);
+    crate::ics23::register_json_types(registry);
+    crate::tendermint::abci::register_json_types(registry);
+    crate::tendermint::libs::bits::register_json_types(registry);
+    crate::tendermint::consensus::register_json_types(registry);
+    crate::tendermint::crypto::register_json_types(registry);
+    crate::tendermint::mempool::register_json_types(registry);
+    crate::tendermint::p2p::register_json_types(registry);
+    crate::tendermint::privval::register_json_types(registry);
+    crate::tendermint::state::register_json_types(registry);
+    crate::tendermint::statesync::register_json_types(registry);
+    crate::tendermint::types::register_json_types(registry);
+    crate::tendermint::version::register_json_types(registry);
+}
+
+fn message_to_json<M: prost::Message + Default + Serialize>(bytes: &[u8]) -> Result<Value, String> {
+    let message = M::decode(bytes).map_err(|e| e.to_string())?;
+    serde_json::to_value(&message).map_err(|e| e.to_string())
+}
+
+fn message_from_json<M: prost::Message + DeserializeOwned>(
+    value: Value,
+) -> Result<Vec<u8>, String> {
+    let message: M = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    Ok(message.encode_to_vec())
+}
+
+fn string_to_json<T: prost::Message + Default + StringValue>(
+    bytes: &[u8],
+) -> Result<Value, String> {
+    let value = T::decode(bytes).map_err(|e| e.to_string())?;
+    Ok(Value::String(value.to_string()))
+}
+
+fn string_from_json<T: prost::Message + StringValue>(value: Value) -> Result<Vec<u8>, String> {
+    match value {
+        Value::String(encoded) => {
+            let value: T = encoded.parse().map_err(|e: T::ParseError| e.to_string())?;
+            Ok(value.encode_to_vec())
+        }
+        other => Err(format!("expected a string, found {other}")),
+    }
+}
+
+fn is_well_known(type_url: &str) -> bool {
+    WELL_KNOWN_TYPE_URLS.contains(&type_url)
+}
+
+/// The registry an Any is encoded with, implemented by the AnyTypes declared by
+/// [any_json_modules](crate::any_json_modules)
+pub trait RegisteredTypes {
+    /// The registry, built on first use
+    fn registry() -> &'static AnyRegistry;
+}
+
+/// Any, encoded with "@type" using the registry of R
+pub struct AnyJson<R>(PhantomData<R>);
+
+impl<R: RegisteredTypes> Mapping<prost_types::Any> for AnyJson<R> {
+    fn serialize<S: Serializer>(
+        value: &prost_types::Any,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut object = match R::registry().get(&value.type_url) {
+            Some((to_json, _)) => match to_json(&value.value).map_err(ser::Error::custom)? {
+                json if is_well_known(&value.type_url) => {
+                    Map::from_iter([("value".to_string(), json)])
+                }
+                Value::Object(fields) => fields,
+                other => {
+                    return Err(ser::Error::custom(format!(
+                        "{} is not encoded as a JSON object: {other}",
+                        value.type_url
+                    )))
+                }
+            },
+            None => Map::from_iter([(
+                "value".to_string(),
+                Value::String(STANDARD.encode(&value.value)),
+            )]),
+        };
+        object.insert("@type".to_string(), Value::String(value.type_url.clone()));
+        object.serialize(serializer)
+    }
+
+    fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<prost_types::Any, D::Error> {
+        let mut object = Map::deserialize(deserializer)?;
+        let type_url = match object.remove("@type") {
+            Some(Value::String(type_url)) => type_url,
+            _ => return Err(de::Error::missing_field("@type")),
+        };
+        let value = match R::registry().get(&type_url) {
+            // well known types are always nested under "value", whatever the shape of their encoding
+            Some((_, from_json)) if is_well_known(&type_url) => match object.remove("value") {
+                Some(value) if object.is_empty() => from_json(value),
+                _ => Err(format!(
+                    "well known type {type_url} must be encoded as a single \"value\""
+                )),
+            },
+            Some((_, from_json)) => from_json(Value::Object(object)),
+            None => match object.remove("value") {
+                Some(Value::String(encoded)) => decode_base64(&encoded),
+                _ => Err(format!(
+                    "unregistered type {type_url} must be encoded as base64 \"value\""
+                )),
+            },
+        }
+        .map_err(de::Error::custom)?;
+        Ok(prost_types::Any { type_url, value })
+    }
+}
+
+// The functions the modules declared by any_json_modules forward to, Mapping itself is private
+impl<R: RegisteredTypes> AnyJson<R> {
+    pub fn serialize<S: Serializer>(
+        value: &prost_types::Any,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        <Self as Mapping<prost_types::Any>>::serialize(value, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<prost_types::Any, D::Error> {
+        <Self as Mapping<prost_types::Any>>::deserialize(deserializer)
+    }
+
+    pub fn serialize_vec<S: Serializer>(
+        values: &[prost_types::Any],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_vec::<Self, prost_types::Any, S>(values, serializer)
+    }
+
+    pub fn deserialize_vec<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<prost_types::Any>, D::Error> {
+        deserialize_vec::<Self, prost_types::Any, D>(deserializer)
+    }
+
+    pub fn serialize_opt<S: Serializer>(
+        value: &Option<prost_types::Any>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_opt::<Self, prost_types::Any, S>(value, serializer)
+    }
+
+    pub fn deserialize_opt<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<prost_types::Any>, D::Error> {
+        deserialize_opt::<Self, prost_types::Any, D>(deserializer)
+    }
+}
+
+/// Declares the `any`, `any_vec` and `any_opt` modules the generated code encodes Any fields with, along with the
+/// `AnyTypes` holding their registry. The registry is built on first use by the given function, which adds
+/// every type an Any may contain to an [AnyRegistry]. proto_build points the Any fields of every crate at
+/// `crate::proto_json`, so each crate declares these modules there
+#[macro_export]
+macro_rules! any_json_modules {
+    ($register:path) => {
+        /// The message types an Any in this crate's messages may contain
+        pub struct AnyTypes;
+
+        impl $crate::proto_json::RegisteredTypes for AnyTypes {
+            fn registry() -> &'static $crate::proto_json::AnyRegistry {
+                static REGISTRY: ::std::sync::OnceLock<$crate::proto_json::AnyRegistry> =
+                    ::std::sync::OnceLock::new();
+                REGISTRY.get_or_init(|| {
+                    let mut registry = $crate::proto_json::AnyRegistry::default();
+                    $register(&mut registry);
+                    registry
+                })
+            }
+        }
+
+        pub mod any {
+            type Json = $crate::proto_json::AnyJson<super::AnyTypes>;
+
+            pub fn serialize<S: ::serde::Serializer>(
+                value: &::prost_types::Any,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Json::serialize(value, serializer)
+            }
+
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<::prost_types::Any, D::Error> {
+                Json::deserialize(deserializer)
+            }
+        }
+
+        pub mod any_vec {
+            type Json = $crate::proto_json::AnyJson<super::AnyTypes>;
+
+            pub fn serialize<S: ::serde::Serializer>(
+                values: &[::prost_types::Any],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Json::serialize_vec(values, serializer)
+            }
+
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Vec<::prost_types::Any>, D::Error> {
+                Json::deserialize_vec(deserializer)
+            }
+        }
+
+        pub mod any_opt {
+            type Json = $crate::proto_json::AnyJson<super::AnyTypes>;
+
+            pub fn serialize<S: ::serde::Serializer>(
+                value: &Option<::prost_types::Any>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Json::serialize_opt(value, serializer)
+            }
+
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<::prost_types::Any>, D::Error> {
+                Json::deserialize_opt(deserializer)
+            }
+        }
+    };
+}
+
+crate::any_json_modules!(register_sdk_types);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::{
+        bank::v1beta1::MsgSend, base::v1beta1::Coin, params::v1beta1::ParamChange,
+    };
+    use prost::{Message, Name};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize)]
+    struct AnyField(#[serde(with = "any")] prost_types::Any);
+
+    fn round_trip(any: prost_types::Any, expected: Value) {
+        assert_eq!(
+            serde_json::to_value(AnyField(any.clone())).unwrap(),
+            expected
+        );
+        assert_eq!(serde_json::from_value::<AnyField>(expected).unwrap().0, any);
+    }
+
+    #[test]
+    fn any_of_sdk_message() {
+        let msg = MsgSend {
+            from_address: "cosmos1from".to_string(),
+            to_address: "cosmos1to".to_string(),
+            amount: vec![Coin {
+                denom: "uatom".to_string(),
+                amount: "1".to_string(),
+            }],
+        };
+        round_trip(
+            prost_types::Any::from_msg(&msg).unwrap(),
+            json!({
+                "@type": "/cosmos.bank.v1beta1.MsgSend",
+                "fromAddress": "cosmos1from",
+                "toAddress": "cosmos1to",
+                "amount": [{"denom": "uatom", "amount": "1"}],
+            }),
+        );
+    }
+
+    #[test]
+    fn any_of_message_with_value_field() {
+        let msg = ParamChange {
+            value: "10".to_string(),
+            ..Default::default()
+        };
+        let any = prost_types::Any::from_msg(&msg).unwrap();
+        let decoded = serde_json::from_value::<AnyField>(json!({
+            "@type": ParamChange::type_url(),
+            "value": "10",
+        }))
+        .unwrap();
+        assert_eq!(decoded.0, any);
+    }
+
+    #[test]
+    fn any_of_well_known_type() {
+        let timestamp = prost_types::Timestamp {
+            seconds: 1_700_000_000,
+            nanos: 0,
+        };
+        round_trip(
+            prost_types::Any {
+                type_url: "/google.protobuf.Timestamp".to_string(),
+                value: timestamp.encode_to_vec(),
+            },
+            json!({"@type": "/google.protobuf.Timestamp", "value": "2023-11-14T22:13:20Z"}),
+        );
+        let duration = prost_types::Duration {
+            seconds: 1,
+            nanos: 500_000_000,
+        };
+        round_trip(
+            prost_types::Any {
+                type_url: "/google.protobuf.Duration".to_string(),
+                value: duration.encode_to_vec(),
+            },
+            json!({"@type": "/google.protobuf.Duration", "value": "1.500s"}),
+        );
+        // the encoding of a well known type is never embedded next to "@type"
+        assert!(serde_json::from_value::<AnyField>(json!({
+            "@type": "/google.protobuf.Duration",
+            "value": "1s",
+            "seconds": "1",
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn any_of_unregistered_type() {
+        round_trip(
+            prost_types::Any {
+                type_url: "/unknown.Msg".to_string(),
+                value: vec![1, 2, 3],
+            },
+            json!({"@type": "/unknown.Msg", "value": "AQID"}),
+        );
+    }
+
+    #[test]
+    fn int64_map_values() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Balances(#[serde(with = "int64_map")] HashMap<String, u64>);
+
+        let balances = Balances(HashMap::from([("uatom".to_string(), u64::MAX)]));
+        let encoded = json!({"uatom": "18446744073709551615"});
+        assert_eq!(serde_json::to_value(&balances).unwrap(), encoded);
+        assert_eq!(
+            serde_json::from_value::<Balances>(encoded).unwrap(),
+            balances
+        );
+        assert_eq!(
+            serde_json::from_value::<Balances>(json!({"uatom": 5})).unwrap(),
+            Balances(HashMap::from([("uatom".to_string(), 5)]))
+        );
+    }
+}
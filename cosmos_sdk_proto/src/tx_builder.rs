@@ -0,0 +1,325 @@
+//! Assembles and signs cosmos.tx.v1beta1 transactions using SIGN_MODE_DIRECT. The builder collects the TxBody and
+//! AuthInfo contents, produces the SignDoc bytes each signer must sign, and encodes the final TxRaw. Signing itself is
+//! delegated to a [Signer] so that this crate does not depend on any particular key type.
+//!
+//! A transaction with a single signer is added its messages and fee and then passed the key with [TxBuilder::sign],
+//! which returns the TxRaw to broadcast. Transactions with several signers add one [SignerInfo] per signer with [TxBuilder::signer_info], have each signer
+//! sign the bytes returned by [TxBuilder::sign_doc_bytes] with their own account number, then call [TxBuilder::build]
+//! with the signatures in the same order as the signer infos.
+
+use crate::cosmos::base::v1beta1::Coin;
+use crate::cosmos::tx::signing::v1beta1::SignMode;
+use crate::cosmos::tx::v1beta1::{
+    mode_info, AuthInfo, Fee, ModeInfo, SignDoc, SignerInfo, TxBody, TxRaw,
+};
+use prost::Message;
+use prost_types::Any;
+use std::fmt;
+
+/// An error assembling a transaction, `E` is the error of the [Signer] for [TxBuilder::sign]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxBuilderError<E = std::convert::Infallible> {
+    /// The number of signatures differs from the number of signer infos
+    SignatureCount {
+        signer_infos: usize,
+        signatures: usize,
+    },
+    /// Signer infos were added before calling [TxBuilder::sign], which only produces the signature of its own signer,
+    /// transactions with several signers must use [TxBuilder::build]
+    OtherSigners(usize),
+    /// The signer failed to sign
+    Signer(E),
+}
+
+impl<E: fmt::Display> fmt::Display for TxBuilderError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TxBuilderError::SignatureCount {
+                signer_infos,
+                signatures,
+            } => write!(
+                f,
+                "{signatures} signatures were given for {signer_infos} signer infos"
+            ),
+            TxBuilderError::OtherSigners(count) => write!(
+                f,
+                "{count} other signer infos were already added, use build() with every signature"
+            ),
+            TxBuilderError::Signer(e) => write!(f, "Failed to sign: {e}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for TxBuilderError<E> {}
+
+/// A key capable of signing transactions, implemented by the user for whatever key type or hardware they use
+pub trait Signer {
+    type Error;
+
+    /// The public key of the signer wrapped in an Any, for example a cosmos.crypto.secp256k1.PubKey or an
+    /// ethermint.crypto.v1.ethsecp256k1.PubKey
+    fn public_key(&self) -> Any;
+
+    /// Signs the encoded SignDoc, hashing it as required by the key type, and returns the signature bytes expected by
+    /// the chain (for secp256k1 the 64 byte r || s encoding)
+    fn sign(&self, sign_doc_bytes: &[u8]) -> Result<Vec<u8>, Self::Error>;
+}
+
+/// Builds a SIGN_MODE_DIRECT transaction
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TxBuilder {
+    body: TxBody,
+    fee: Fee,
+    signer_infos: Vec<SignerInfo>,
+}
+
+impl TxBuilder {
+    pub fn new() -> Self {
+        TxBuilder::default()
+    }
+
+    /// Adds a message to the transaction, messages are executed in the order they are added
+    pub fn message(mut self, message: Any) -> Self {
+        self.body.messages.push(message);
+        self
+    }
+
+    /// Adds several messages to the transaction
+    pub fn messages(mut self, messages: impl IntoIterator<Item = Any>) -> Self {
+        self.body.messages.extend(messages);
+        self
+    }
+
+    pub fn memo(mut self, memo: String) -> Self {
+        self.body.memo = memo;
+        self
+    }
+
+    /// Sets the block height after which the transaction will not be included, 0 disables the timeout
+    pub fn timeout_height(mut self, timeout_height: u64) -> Self {
+        self.body.timeout_height = timeout_height;
+        self
+    }
+
+    /// Adds an extension option, which the chain must understand or it will reject the transaction
+    pub fn extension_option(mut self, option: Any) -> Self {
+        self.body.extension_options.push(option);
+        self
+    }
+
+    /// Adds an extension option which the chain may ignore
+    pub fn non_critical_extension_option(mut self, option: Any) -> Self {
+        self.body.non_critical_extension_options.push(option);
+        self
+    }
+
+    /// Sets the fee paid and the gas limit of the transaction
+    pub fn fee(mut self, amount: Vec<Coin>, gas_limit: u64) -> Self {
+        self.fee.amount = amount;
+        self.fee.gas_limit = gas_limit;
+        self
+    }
+
+    /// Sets the address which pays the fee, it must also sign the transaction
+    pub fn fee_payer(mut self, payer: String) -> Self {
+        self.fee.payer = payer;
+        self
+    }
+
+    /// Sets the address of a feegrant granter which pays the fee
+    pub fn fee_granter(mut self, granter: String) -> Self {
+        self.fee.granter = granter;
+        self
+    }
+
+    /// Adds a SIGN_MODE_DIRECT signer, signers must be added in the order their signatures will be provided.
+    /// The public key may be omitted if the account's public key is already known on chain
+    pub fn signer_info(mut self, public_key: Option<Any>, sequence: u64) -> Self {
+        self.signer_infos.push(SignerInfo {
+            public_key,
+            mode_info: Some(ModeInfo {
+                sum: Some(mode_info::Sum::Single(mode_info::Single {
+                    mode: SignMode::Direct as i32,
+                })),
+            }),
+            sequence,
+        });
+        self
+    }
+
+    pub fn tx_body(&self) -> TxBody {
+        self.body.clone()
+    }
+
+    pub fn auth_info(&self) -> AuthInfo {
+        AuthInfo {
+            signer_infos: self.signer_infos.clone(),
+            fee: Some(self.fee.clone()),
+            tip: None,
+        }
+    }
+
+    /// Returns the SignDoc for the signer with the given account number
+    pub fn sign_doc(&self, chain_id: &str, account_number: u64) -> SignDoc {
+        SignDoc {
+            body_bytes: self.body.encode_to_vec(),
+            auth_info_bytes: self.auth_info().encode_to_vec(),
+            chain_id: chain_id.to_string(),
+            account_number,
+        }
+    }
+
+    /// Returns the bytes the signer with the given account number must sign
+    pub fn sign_doc_bytes(&self, chain_id: &str, account_number: u64) -> Vec<u8> {
+        self.sign_doc(chain_id, account_number).encode_to_vec()
+    }
+
+    /// Adds the signer and signs the transaction, which must have no other signers. Transactions with several signers
+    /// are signed with [TxBuilder::build] instead
+    pub fn sign<S: Signer>(
+        self,
+        signer: &S,
+        chain_id: &str,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<TxRaw, TxBuilderError<S::Error>> {
+        if !self.signer_infos.is_empty() {
+            return Err(TxBuilderError::OtherSigners(self.signer_infos.len()));
+        }
+        let builder = self.signer_info(Some(signer.public_key()), sequence);
+        let sign_doc = builder.sign_doc(chain_id, account_number);
+        let signature = signer
+            .sign(&sign_doc.encode_to_vec())
+            .map_err(TxBuilderError::Signer)?;
+        Ok(TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![signature],
+        })
+    }
+
+    /// Returns the TxRaw with the given signatures, which must be in the same order as the signer infos
+    pub fn build(&self, signatures: Vec<Vec<u8>>) -> Result<TxRaw, TxBuilderError> {
+        if signatures.len() != self.signer_infos.len() {
+            return Err(TxBuilderError::SignatureCount {
+                signer_infos: self.signer_infos.len(),
+                signatures: signatures.len(),
+            });
+        }
+        Ok(TxRaw {
+            body_bytes: self.body.encode_to_vec(),
+            auth_info_bytes: self.auth_info().encode_to_vec(),
+            signatures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cosmos::bank::v1beta1::MsgSend;
+    use crate::cosmos::crypto::secp256k1;
+    use k256::ecdsa::{signature::Signer as _, Signature, SigningKey};
+
+    struct Secp256k1Key(SigningKey);
+
+    impl Signer for Secp256k1Key {
+        type Error = k256::ecdsa::Error;
+
+        fn public_key(&self) -> Any {
+            let key = self.0.verifying_key().to_encoded_point(true);
+            Any::from_msg(&secp256k1::PubKey {
+                key: key.as_bytes().to_vec(),
+            })
+            .unwrap()
+        }
+
+        // RFC 6979 signatures of the SHA-256 hash, so the signature bytes are deterministic
+        fn sign(&self, sign_doc_bytes: &[u8]) -> Result<Vec<u8>, Self::Error> {
+            let signature: Signature = self.0.try_sign(sign_doc_bytes)?;
+            Ok(signature.to_bytes().to_vec())
+        }
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn key() -> Secp256k1Key {
+        Secp256k1Key(SigningKey::from_bytes(&[1u8; 32].into()).unwrap())
+    }
+
+    fn builder() -> TxBuilder {
+        let msg = MsgSend {
+            from_address: "cosmos1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3pahzj0".to_string(),
+            to_address: "cosmos1xqcnyve5x5mrwwpexqcnyve5x5mrwwpeg5thmd".to_string(),
+            amount: vec![Coin {
+                denom: "uatom".to_string(),
+                amount: "1000".to_string(),
+            }],
+        };
+        TxBuilder::new()
+            .message(Any::from_msg(&msg).unwrap())
+            .memo("memo".to_string())
+            .fee(
+                vec![Coin {
+                    denom: "uatom".to_string(),
+                    amount: "500".to_string(),
+                }],
+                200_000,
+            )
+    }
+
+    // The protobuf encodings and the RFC 6979 signature were produced independently of prost and k256
+    const BODY: &str = "0a8d010a1c2f636f736d6f732e62616e6b2e763162657461312e4d736753656e64126d0a2d636f736d6f73317a7967337a7967337a7967337a7967337a7967337a7967337a7967337a7967337061687a6a30122d636f736d6f73317871636e7976653578356d72777770657871636e7976653578356d7277777065673574686d641a0d0a057561746f6d12043130303012046d656d6f";
+    const AUTH_INFO: &str = "0a500a460a1f2f636f736d6f732e63727970746f2e736563703235366b312e5075624b657912230a21031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f12040a020801180312120a0c0a057561746f6d120335303010c09a0c";
+    const SIGNATURE: &str = "ad9c583f352f2dc2163cf0ebd396f3c30f0c0f46239d8d529c8501dc142d07344a8be1625de01372ba850b8104d95bbb95d0c87f847fefd7cfe9d6f7ab551a63";
+
+    #[test]
+    fn sign_doc_and_tx_raw_bytes() {
+        let with_signer = builder().signer_info(Some(key().public_key()), 3);
+        assert_eq!(
+            hex(&with_signer.sign_doc_bytes("cosmoshub-4", 7)),
+            format!("0a9601{BODY}1266{AUTH_INFO}1a0b636f736d6f736875622d342007")
+        );
+
+        let tx_raw = builder().sign(&key(), "cosmoshub-4", 7, 3).unwrap();
+        assert_eq!(
+            hex(&tx_raw.encode_to_vec()),
+            format!("0a9601{BODY}1266{AUTH_INFO}1a40{SIGNATURE}")
+        );
+        // build() with the same signature produces the same transaction
+        let signature = tx_raw.signatures[0].clone();
+        assert_eq!(with_signer.build(vec![signature]), Ok(tx_raw));
+    }
+
+    #[test]
+    fn signature_count_must_match_signer_infos() {
+        let two_signers = builder()
+            .signer_info(Some(key().public_key()), 3)
+            .signer_info(None, 0);
+        assert_eq!(
+            two_signers.build(vec![vec![1; 64]]),
+            Err(TxBuilderError::SignatureCount {
+                signer_infos: 2,
+                signatures: 1,
+            })
+        );
+        assert_eq!(
+            builder().build(vec![vec![1; 64]]),
+            Err(TxBuilderError::SignatureCount {
+                signer_infos: 0,
+                signatures: 1,
+            })
+        );
+        assert!(two_signers.build(vec![vec![1; 64], vec![2; 64]]).is_ok());
+    }
+
+    #[test]
+    fn sign_rejects_other_signers() {
+        let result = builder()
+            .signer_info(None, 0)
+            .sign(&key(), "cosmoshub-4", 7, 3);
+        assert!(matches!(result, Err(TxBuilderError::OtherSigners(1))));
+    }
+}
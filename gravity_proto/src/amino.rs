@@ -0,0 +1,329 @@
+//! Legacy Amino JSON encoding of the Gravity messages, see cosmos_sdk_proto::amino for how to produce sign bytes.
+//! Call [register_amino_types] before encoding Cosmos SDK messages which may contain Gravity messages in an Any,
+//! like authz MsgExec.
+
+use crate::gravity::*;
+use cosmos_sdk_proto::amino::{register, AminoError, AminoJson, AminoMsg, AminoObject};
+use serde_json::Value;
+
+macro_rules! amino_msg {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            impl AminoMsg for $ty {
+                fn amino_type(&self) -> &'static str {
+                    $name
+                }
+            }
+        )*
+
+        /// Registers the Gravity types which may appear inside an Any with cosmos_sdk_proto::amino
+        pub fn register_amino_types() {
+            $(register::<$ty>();)*
+        }
+    };
+}
+
+amino_msg!(
+    MsgSetOrchestratorAddress => "gravity/MsgSetOrchestratorAddress",
+    MsgValsetConfirm => "gravity/MsgValsetConfirm",
+    MsgSendToEth => "gravity/MsgSendToEth",
+    MsgRequestBatch => "gravity/MsgRequestBatch",
+    MsgConfirmBatch => "gravity/MsgConfirmBatch",
+    MsgConfirmLogicCall => "gravity/MsgConfirmLogicCall",
+    MsgSendToCosmosClaim => "gravity/MsgSendToCosmosClaim",
+    MsgBatchSendToEthClaim => "gravity/MsgBatchSendToEthClaim",
+    MsgErc20DeployedClaim => "gravity/MsgERC20DeployedClaim",
+    MsgLogicCallExecutedClaim => "gravity/MsgLogicCallExecutedClaim",
+    MsgValsetUpdatedClaim => "gravity/MsgValsetUpdatedClaim",
+    MsgCancelSendToEth => "gravity/MsgCancelSendToEth",
+    MsgSubmitBadSignatureEvidence => "gravity/MsgSubmitBadSignatureEvidence",
+    MsgExecuteIbcAutoForwards => "gravity/MsgExecuteIbcAutoForwards",
+    Valset => "gravity/Valset",
+    OutgoingTxBatch => "gravity/OutgoingTxBatch",
+);
+
+impl AminoJson for Erc20Token {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("contract", &self.contract)
+            .string("amount", &self.amount)
+            .finish())
+    }
+}
+
+impl AminoJson for BridgeValidator {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("power", self.power)
+            .string("ethereum_address", &self.ethereum_address)
+            .finish())
+    }
+}
+
+impl AminoJson for Valset {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("nonce", self.nonce)
+            .messages("members", &self.members)?
+            .uint64("height", self.height)
+            .string("reward_amount", &self.reward_amount)
+            .string("reward_token", &self.reward_token)
+            .finish())
+    }
+}
+
+impl AminoJson for OutgoingTransferTx {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("id", self.id)
+            .string("sender", &self.sender)
+            .string("dest_address", &self.dest_address)
+            .message("erc20_token", &self.erc20_token)?
+            .message("erc20_fee", &self.erc20_fee)?
+            .finish())
+    }
+}
+
+impl AminoJson for OutgoingTxBatch {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("batch_nonce", self.batch_nonce)
+            .uint64("batch_timeout", self.batch_timeout)
+            .messages("transactions", &self.transactions)?
+            .string("token_contract", &self.token_contract)
+            .uint64("cosmos_block_created", self.cosmos_block_created)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgSetOrchestratorAddress {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("validator", &self.validator)
+            .string("orchestrator", &self.orchestrator)
+            .string("eth_address", &self.eth_address)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgValsetConfirm {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("nonce", self.nonce)
+            .string("orchestrator", &self.orchestrator)
+            .string("eth_address", &self.eth_address)
+            .string("signature", &self.signature)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgSendToEth {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("sender", &self.sender)
+            .string("eth_dest", &self.eth_dest)
+            .message("amount", &self.amount)?
+            .message("bridge_fee", &self.bridge_fee)?
+            .message("chain_fee", &self.chain_fee)?
+            .finish())
+    }
+}
+
+impl AminoJson for MsgRequestBatch {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("sender", &self.sender)
+            .string("denom", &self.denom)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgConfirmBatch {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("nonce", self.nonce)
+            .string("token_contract", &self.token_contract)
+            .string("eth_signer", &self.eth_signer)
+            .string("orchestrator", &self.orchestrator)
+            .string("signature", &self.signature)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgConfirmLogicCall {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .string("invalidation_id", &self.invalidation_id)
+            .uint64("invalidation_nonce", self.invalidation_nonce)
+            .string("eth_signer", &self.eth_signer)
+            .string("orchestrator", &self.orchestrator)
+            .string("signature", &self.signature)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgSendToCosmosClaim {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("event_nonce", self.event_nonce)
+            .uint64("eth_block_height", self.eth_block_height)
+            .string("token_contract", &self.token_contract)
+            .string("amount", &self.amount)
+            .string("ethereum_sender", &self.ethereum_sender)
+            .string("cosmos_receiver", &self.cosmos_receiver)
+            .string("orchestrator", &self.orchestrator)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgBatchSendToEthClaim {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("event_nonce", self.event_nonce)
+            .uint64("eth_block_height", self.eth_block_height)
+            .uint64("batch_nonce", self.batch_nonce)
+            .string("token_contract", &self.token_contract)
+            .string("orchestrator", &self.orchestrator)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgErc20DeployedClaim {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("event_nonce", self.event_nonce)
+            .uint64("eth_block_height", self.eth_block_height)
+            .string("cosmos_denom", &self.cosmos_denom)
+            .string("token_contract", &self.token_contract)
+            .string("name", &self.name)
+            .string("symbol", &self.symbol)
+            .uint64("decimals", self.decimals)
+            .string("orchestrator", &self.orchestrator)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgLogicCallExecutedClaim {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("event_nonce", self.event_nonce)
+            .uint64("eth_block_height", self.eth_block_height)
+            .bytes("invalidation_id", &self.invalidation_id)
+            .uint64("invalidation_nonce", self.invalidation_nonce)
+            .string("orchestrator", &self.orchestrator)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgValsetUpdatedClaim {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("event_nonce", self.event_nonce)
+            .uint64("valset_nonce", self.valset_nonce)
+            .uint64("eth_block_height", self.eth_block_height)
+            .messages("members", &self.members)?
+            .string("reward_amount", &self.reward_amount)
+            .string("reward_token", &self.reward_token)
+            .string("orchestrator", &self.orchestrator)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgCancelSendToEth {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("transaction_id", self.transaction_id)
+            .string("sender", &self.sender)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgSubmitBadSignatureEvidence {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .any("subject", &self.subject)?
+            .string("signature", &self.signature)
+            .string("sender", &self.sender)
+            .finish())
+    }
+}
+
+impl AminoJson for MsgExecuteIbcAutoForwards {
+    fn amino_json(&self) -> Result<Value, AminoError> {
+        Ok(AminoObject::new()
+            .uint64("forwards_to_clear", self.forwards_to_clear)
+            .string("executor", &self.executor)
+            .finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::amino::StdSignDoc;
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExec;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::tx::v1beta1::Fee;
+    use prost_types::Any;
+
+    const SENDER: &str = "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8";
+    const DENOM: &str = "gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA";
+
+    fn coin(amount: &str) -> Option<Coin> {
+        Some(Coin {
+            amount: amount.to_string(),
+            denom: DENOM.to_string(),
+        })
+    }
+
+    fn send_to_eth() -> MsgSendToEth {
+        MsgSendToEth {
+            sender: SENDER.to_string(),
+            eth_dest: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".to_string(),
+            amount: coin("1000"),
+            bridge_fee: coin("10"),
+            chain_fee: coin("1"),
+        }
+    }
+
+    fn sign_bytes(msg: &dyn AminoMsg) -> String {
+        let fee = Fee {
+            amount: vec![],
+            gas_limit: 200000,
+            payer: String::new(),
+            granter: String::new(),
+        };
+        let doc =
+            StdSignDoc::new("gravity-bridge-3".into(), 12, 3, fee, String::new(), &[msg]).unwrap();
+        String::from_utf8(doc.sign_bytes()).unwrap()
+    }
+
+    #[test]
+    fn msg_send_to_eth() {
+        assert_eq!(
+            sign_bytes(&send_to_eth()),
+            r#"{"account_number":"12","chain_id":"gravity-bridge-3","fee":{"amount":[],"gas":"200000"},"memo":"","msgs":[{"type":"gravity/MsgSendToEth","value":{"amount":{"amount":"1000","denom":"gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA"},"bridge_fee":{"amount":"10","denom":"gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA"},"chain_fee":{"amount":"1","denom":"gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA"},"eth_dest":"0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F","sender":"gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8"}}],"sequence":"3"}"#
+        );
+    }
+
+    #[test]
+    fn registered_messages_inside_msg_exec() {
+        register_amino_types();
+        let cancel = MsgCancelSendToEth {
+            transaction_id: 7,
+            sender: SENDER.to_string(),
+        };
+        let exec = MsgExec {
+            grantee: SENDER.to_string(),
+            msgs: vec![
+                Any::from_msg(&send_to_eth()).unwrap(),
+                Any::from_msg(&cancel).unwrap(),
+            ],
+        };
+        let expected = format!(
+            r#"{{"account_number":"12","chain_id":"gravity-bridge-3","fee":{{"amount":[],"gas":"200000"}},"memo":"","msgs":[{{"type":"cosmos-sdk/MsgExec","value":{{"grantee":"{SENDER}","msgs":[{},{{"type":"gravity/MsgCancelSendToEth","value":{{"sender":"{SENDER}","transaction_id":"7"}}}}]}}}}],"sequence":"3"}}"#,
+            r#"{"type":"gravity/MsgSendToEth","value":{"amount":{"amount":"1000","denom":"gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA"},"bridge_fee":{"amount":"10","denom":"gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA"},"chain_fee":{"amount":"1","denom":"gravity0xD7600ae27C99988A6CCB5C7B68DD8C3C1a2D7fcA"},"eth_dest":"0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F","sender":"gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8"}}"#
+        );
+        assert_eq!(sign_bytes(&exec), expected);
+    }
+}
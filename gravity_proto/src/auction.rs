@@ -0,0 +1,367 @@
+// The checks the auction module applies to a MsgBid, so that a bidder can tell whether a bid will be accepted and win
+// before broadcasting it. A bid must pay at least min_bid_fee and its amount must be strictly greater than the
+// auction's current highest bid, the previous highest bidder is refunded when they are outbid. Auctions are closed and
+// the winning bids paid out in the EndBlocker of the period's end block.
+
+/// An error the auction module would return for a MsgBid
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BidError {
+    /// The module is disabled and rejects every bid
+    Disabled,
+    /// The bidder is not a valid bech32 address
+    InvalidBidder(String),
+    /// The bid has a zero amount
+    ZeroAmount,
+    /// The bid fee is less than the min_bid_fee param
+    FeeTooLow { fee: u64, min_bid_fee: u64 },
+    /// The bid is for a different auction
+    WrongAuction { expected: u64, found: u64 },
+    /// The auctioned token is one of the non_auctionable_tokens
+    NonAuctionableToken(String),
+    /// The bid does not beat the current highest bid
+    BidTooLow { amount: u64, highest_bid: u64 },
+    /// The auction period has ended
+    PeriodEnded { end_block_height: u64 },
+}
+
+impl std::fmt::Display for BidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BidError::Disabled => write!(f, "the auction module is disabled"),
+            BidError::InvalidBidder(b) => write!(f, "invalid bidder {b}"),
+            BidError::ZeroAmount => write!(f, "bid amount must be greater than zero"),
+            BidError::FeeTooLow { fee, min_bid_fee } => {
+                write!(
+                    f,
+                    "bid fee {fee} is less than the min bid fee {min_bid_fee}"
+                )
+            }
+            BidError::WrongAuction { expected, found } => {
+                write!(f, "bid is for auction {found}, expected {expected}")
+            }
+            BidError::NonAuctionableToken(denom) => write!(f, "{denom} can not be auctioned"),
+            BidError::BidTooLow {
+                amount,
+                highest_bid,
+            } => write!(
+                f,
+                "bid {amount} does not exceed the highest bid {highest_bid}"
+            ),
+            BidError::PeriodEnded { end_block_height } => {
+                write!(f, "the auction period ended at block {end_block_height}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BidError {}
+
+impl Params {
+    /// If the pool's balance of the denom may be auctioned
+    pub fn is_auctionable(&self, denom: &str) -> bool {
+        !self.non_auctionable_tokens.iter().any(|t| t == denom)
+    }
+
+    /// The period the module starts at the given height, which lasts auction_length blocks
+    pub fn new_period(&self, start_block_height: u64) -> AuctionPeriod {
+        AuctionPeriod {
+            start_block_height,
+            end_block_height: start_block_height.saturating_add(self.auction_length),
+        }
+    }
+}
+
+impl AuctionPeriod {
+    /// The number of blocks the period spans
+    pub fn length(&self) -> u64 {
+        self.end_block_height
+            .saturating_sub(self.start_block_height)
+    }
+
+    /// If a bid included in a block at the given height is accepted, bids are accepted up to and including the end
+    /// block since the auctions are only closed in its EndBlocker
+    pub fn is_active(&self, height: u64) -> bool {
+        (self.start_block_height..=self.end_block_height).contains(&height)
+    }
+
+    /// The number of blocks after the given height in which a bid can still be included
+    pub fn blocks_remaining(&self, height: u64) -> u64 {
+        self.end_block_height.saturating_sub(height)
+    }
+
+    /// The approximate time until the period ends, given the chain's average block time
+    pub fn time_remaining(
+        &self,
+        height: u64,
+        block_time: std::time::Duration,
+    ) -> std::time::Duration {
+        block_time.saturating_mul(self.blocks_remaining(height).min(u32::MAX as u64) as u32)
+    }
+}
+
+impl MsgBid {
+    /// Performs the stateless checks of the module's ValidateBasic and the params checks of its Bid handler. The
+    /// bidder is only checked to be valid bech32, its prefix is left to the caller
+    pub fn validate(&self, params: &Params) -> Result<(), BidError> {
+        if !params.enabled {
+            return Err(BidError::Disabled);
+        }
+        // bech32::decode would also accept a bech32m checksum, which sdk.AccAddressFromBech32 does not
+        match bech32::primitives::decode::CheckedHrpstring::new::<bech32::Bech32>(&self.bidder) {
+            Ok(bidder) if (1..=255).contains(&bidder.byte_iter().len()) => {}
+            _ => return Err(BidError::InvalidBidder(self.bidder.clone())),
+        }
+        if self.amount == 0 {
+            return Err(BidError::ZeroAmount);
+        }
+        if self.bid_fee < params.min_bid_fee {
+            return Err(BidError::FeeTooLow {
+                fee: self.bid_fee,
+                min_bid_fee: params.min_bid_fee,
+            });
+        }
+        Ok(())
+    }
+
+    /// The native tokens this bid takes from the bidder if it is accepted, the amount is refunded if it is outbid
+    pub fn total_cost(&self) -> u64 {
+        self.amount.saturating_add(self.bid_fee)
+    }
+
+    /// The Bid this message places once accepted
+    pub fn to_bid(&self) -> Bid {
+        Bid {
+            bid_amount: self.amount,
+            bidder_address: self.bidder.clone(),
+        }
+    }
+}
+
+impl Auction {
+    /// Checks the bid would be accepted as this auction's new highest bid, in a block at the given height of the
+    /// active period
+    pub fn check_bid(
+        &self,
+        bid: &MsgBid,
+        params: &Params,
+        period: &AuctionPeriod,
+        height: u64,
+    ) -> Result<(), BidError> {
+        bid.validate(params)?;
+        if bid.auction_id != self.id {
+            return Err(BidError::WrongAuction {
+                expected: self.id,
+                found: bid.auction_id,
+            });
+        }
+        if let Some(amount) = &self.amount {
+            if !params.is_auctionable(&amount.denom) {
+                return Err(BidError::NonAuctionableToken(amount.denom.clone()));
+            }
+        }
+        if !period.is_active(height) {
+            return Err(BidError::PeriodEnded {
+                end_block_height: period.end_block_height,
+            });
+        }
+        if let Some(highest) = &self.highest_bid {
+            if bid.amount <= highest.bid_amount {
+                return Err(BidError::BidTooLow {
+                    amount: bid.amount,
+                    highest_bid: highest.bid_amount,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The smallest bid amount which would beat the current highest bid
+    pub fn min_winning_amount(&self) -> u64 {
+        self.highest_bid
+            .as_ref()
+            .map_or(1, |b| b.bid_amount.saturating_add(1))
+    }
+
+    /// Applies the bids in the order they would be executed, returning the highest bid once all of them are processed
+    /// along with the error of each rejected bid. Bids are only compared against the highest bid at the time they
+    /// execute, so a bid which was valid when signed can still be outbid before it lands
+    pub fn resolve_bids<'a>(
+        &self,
+        bids: impl IntoIterator<Item = &'a MsgBid>,
+        params: &Params,
+        period: &AuctionPeriod,
+        height: u64,
+    ) -> (Option<Bid>, Vec<(&'a MsgBid, BidError)>) {
+        let mut auction = self.clone();
+        let mut rejected = Vec::new();
+        for bid in bids {
+            match auction.check_bid(bid, params, period, height) {
+                Ok(()) => auction.highest_bid = Some(bid.to_bid()),
+                Err(e) => rejected.push((bid, e)),
+            }
+        }
+        (auction.highest_bid, rejected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+
+    const BIDDER: &str = "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8";
+
+    fn params() -> Params {
+        Params {
+            auction_length: 100,
+            min_bid_fee: 10,
+            non_auctionable_tokens: vec!["ugraviton".to_string()],
+            burn_winning_bids: false,
+            enabled: true,
+        }
+    }
+
+    fn bid(amount: u64, bid_fee: u64) -> MsgBid {
+        MsgBid {
+            auction_id: 1,
+            bidder: BIDDER.to_string(),
+            amount,
+            bid_fee,
+        }
+    }
+
+    fn auction(denom: &str, highest_bid: Option<u64>) -> Auction {
+        Auction {
+            id: 1,
+            amount: Some(Coin {
+                denom: denom.to_string(),
+                amount: "1000".to_string(),
+            }),
+            highest_bid: highest_bid.map(|bid_amount| Bid {
+                bid_amount,
+                bidder_address: BIDDER.to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn period_timing() {
+        let period = params().new_period(1000);
+        assert_eq!(period.end_block_height, 1100);
+        assert_eq!(period.length(), 100);
+        assert!(!period.is_active(999));
+        assert!(period.is_active(1000));
+        assert!(period.is_active(1100));
+        assert!(!period.is_active(1101));
+        assert_eq!(period.blocks_remaining(1040), 60);
+        assert_eq!(period.blocks_remaining(1200), 0);
+        assert_eq!(
+            period.time_remaining(1040, std::time::Duration::from_secs(6)),
+            std::time::Duration::from_secs(360)
+        );
+    }
+
+    #[test]
+    fn bid_validation() {
+        let params = params();
+        assert_eq!(bid(5, 10).validate(&params), Ok(()));
+        assert_eq!(bid(0, 10).validate(&params), Err(BidError::ZeroAmount));
+        assert_eq!(
+            bid(5, 9).validate(&params),
+            Err(BidError::FeeTooLow {
+                fee: 9,
+                min_bid_fee: 10
+            })
+        );
+        let mut invalid = bid(5, 10);
+        invalid.bidder = "gravity1invalid".to_string();
+        assert_eq!(
+            invalid.validate(&params),
+            Err(BidError::InvalidBidder("gravity1invalid".to_string()))
+        );
+        let disabled = Params {
+            enabled: false,
+            ..params
+        };
+        assert_eq!(bid(5, 10).validate(&disabled), Err(BidError::Disabled));
+    }
+
+    #[test]
+    fn bid_fee_and_cost() {
+        let msg = bid(500, 10);
+        assert_eq!(msg.total_cost(), 510);
+        assert_eq!(bid(u64::MAX, 10).total_cost(), u64::MAX);
+        assert_eq!(
+            msg.to_bid(),
+            Bid {
+                bid_amount: 500,
+                bidder_address: BIDDER.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn check_bid() {
+        let params = params();
+        let period = params.new_period(1000);
+        let open = auction("ibc/ATOM", None);
+        assert_eq!(open.min_winning_amount(), 1);
+        assert_eq!(open.check_bid(&bid(1, 10), &params, &period, 1050), Ok(()));
+        assert_eq!(
+            open.check_bid(&bid(1, 10), &params, &period, 1101),
+            Err(BidError::PeriodEnded {
+                end_block_height: 1100
+            })
+        );
+        let mut other = bid(1, 10);
+        other.auction_id = 2;
+        assert_eq!(
+            open.check_bid(&other, &params, &period, 1050),
+            Err(BidError::WrongAuction {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert_eq!(
+            auction("ugraviton", None).check_bid(&bid(1, 10), &params, &period, 1050),
+            Err(BidError::NonAuctionableToken("ugraviton".to_string()))
+        );
+
+        let bid_on = auction("ibc/ATOM", Some(100));
+        assert_eq!(bid_on.min_winning_amount(), 101);
+        assert_eq!(
+            bid_on.check_bid(&bid(100, 10), &params, &period, 1050),
+            Err(BidError::BidTooLow {
+                amount: 100,
+                highest_bid: 100
+            })
+        );
+        assert_eq!(
+            bid_on.check_bid(&bid(101, 10), &params, &period, 1050),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn resolve_bids() {
+        let params = params();
+        let period = params.new_period(1000);
+        let bids = [bid(100, 10), bid(90, 10), bid(150, 5), bid(200, 10)];
+        let (highest, rejected) =
+            auction("ibc/ATOM", None).resolve_bids(&bids, &params, &period, 1050);
+        assert_eq!(highest.map(|b| b.bid_amount), Some(200));
+        assert_eq!(
+            rejected.into_iter().map(|(_, e)| e).collect::<Vec<_>>(),
+            vec![
+                BidError::BidTooLow {
+                    amount: 90,
+                    highest_bid: 100
+                },
+                BidError::FeeTooLow {
+                    fee: 5,
+                    min_bid_fee: 10
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,364 @@
+// The checkpoints signed by the orchestrators, these are the keccak256 hash of the Solidity abi.encode() of the
+// arguments Gravity.sol checks signatures against in updateValset (makeCheckpoint), submitBatch and submitLogicCall.
+// The gravity_id in the module Params is included in each checkpoint so that signatures can not be replayed against
+// a different bridge.
+
+/// An error computing a checkpoint
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckpointError {
+    /// The gravity id is longer than the 32 bytes of the bytes32 it is stored in
+    GravityIdTooLong(String),
+    /// The invalidation id of a logic call is longer than the 32 bytes of the bytes32 it is stored in
+    InvalidationIdTooLong(Vec<u8>),
+    /// The named field is not a valid Ethereum address
+    InvalidEthAddress {
+        field: &'static str,
+        error: crate::ethereum::EthAddressError,
+    },
+    /// The named field is not a valid uint256
+    InvalidAmount {
+        field: &'static str,
+        error: crate::ethereum::Uint256Error,
+    },
+    /// A transaction in a batch has no erc20_token or erc20_fee
+    MissingToken { tx_id: u64 },
+}
+
+impl std::fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckpointError::GravityIdTooLong(id) => {
+                write!(f, "gravity id {id:?} does not fit in 32 bytes")
+            }
+            CheckpointError::InvalidationIdTooLong(id) => {
+                write!(
+                    f,
+                    "invalidation id of {} bytes does not fit in 32 bytes",
+                    id.len()
+                )
+            }
+            CheckpointError::InvalidEthAddress { field, error } => {
+                write!(f, "invalid {field}: {error}")
+            }
+            CheckpointError::InvalidAmount { field, error } => {
+                write!(f, "invalid {field}: {error}")
+            }
+            CheckpointError::MissingToken { tx_id } => {
+                write!(f, "transaction {tx_id} is missing its token or fee")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CheckpointError {}
+
+/// A Solidity ABI value, only the types used by the checkpoints are supported
+enum AbiToken {
+    /// Any static type, already encoded as a single word
+    Word([u8; 32]),
+    /// A dynamic array of static types
+    Array(Vec<[u8; 32]>),
+    /// Dynamic bytes
+    Bytes(Vec<u8>),
+}
+
+/// abi.encode() of the given values
+fn abi_encode(tokens: &[AbiToken]) -> Vec<u8> {
+    let mut head = Vec::new();
+    let mut tail = Vec::new();
+    for token in tokens {
+        let offset = tokens.len() * 32 + tail.len();
+        match token {
+            AbiToken::Word(word) => head.extend_from_slice(word),
+            AbiToken::Array(words) => {
+                head.extend_from_slice(&uint_word(offset as u64));
+                tail.extend_from_slice(&uint_word(words.len() as u64));
+                for word in words {
+                    tail.extend_from_slice(word);
+                }
+            }
+            AbiToken::Bytes(bytes) => {
+                head.extend_from_slice(&uint_word(offset as u64));
+                tail.extend_from_slice(&uint_word(bytes.len() as u64));
+                tail.extend_from_slice(bytes);
+                tail.resize(tail.len() + (32 - bytes.len() % 32) % 32, 0);
+            }
+        }
+    }
+    head.extend(tail);
+    head
+}
+
+fn uint_word(value: u64) -> [u8; 32] {
+    crate::ethereum::Uint256::from(value).to_be_bytes()
+}
+
+/// Left aligns a string or bytes into a bytes32, as Solidity stores string literals like "checkpoint"
+fn bytes32(bytes: &[u8]) -> Option<[u8; 32]> {
+    let mut word = [0u8; 32];
+    word.get_mut(..bytes.len())?.copy_from_slice(bytes);
+    Some(word)
+}
+
+fn gravity_id_word(gravity_id: &str) -> Result<[u8; 32], CheckpointError> {
+    bytes32(gravity_id.as_bytes())
+        .ok_or_else(|| CheckpointError::GravityIdTooLong(gravity_id.to_string()))
+}
+
+fn address_word(field: &'static str, address: &str) -> Result<[u8; 32], CheckpointError> {
+    let address = crate::ethereum::parse_address(address)
+        .map_err(|error| CheckpointError::InvalidEthAddress { field, error })?;
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(&address);
+    Ok(word)
+}
+
+fn amount_word(field: &'static str, amount: &str) -> Result<[u8; 32], CheckpointError> {
+    amount
+        .parse::<crate::ethereum::Uint256>()
+        .map(|a| a.to_be_bytes())
+        .map_err(|error| CheckpointError::InvalidAmount { field, error })
+}
+
+fn checkpoint_hash(tokens: &[AbiToken]) -> [u8; 32] {
+    crate::ethereum::keccak256(&abi_encode(tokens))
+}
+
+impl Valset {
+    /// The checkpoint of this valset, computed as Gravity.sol's makeCheckpoint. Validators sign this to approve
+    /// the valset and the contract stores the checkpoint of its current valset
+    pub fn get_checkpoint(&self, gravity_id: &str) -> Result<[u8; 32], CheckpointError> {
+        let validators = self
+            .members
+            .iter()
+            .map(|m| address_word("member", &m.ethereum_address))
+            .collect::<Result<Vec<_>, _>>()?;
+        let powers = self.members.iter().map(|m| uint_word(m.power)).collect();
+        // an unset reward is encoded as a zero amount of the zero address, as the Go module does
+        let reward_amount = match self.reward_amount.as_str() {
+            "" => [0u8; 32],
+            amount => amount_word("reward amount", amount)?,
+        };
+        let reward_token = match self.reward_token.as_str() {
+            "" => [0u8; 32],
+            token => address_word("reward token", token)?,
+        };
+        Ok(checkpoint_hash(&[
+            AbiToken::Word(gravity_id_word(gravity_id)?),
+            AbiToken::Word(bytes32(b"checkpoint").unwrap()),
+            AbiToken::Word(uint_word(self.nonce)),
+            AbiToken::Array(validators),
+            AbiToken::Array(powers),
+            AbiToken::Word(reward_amount),
+            AbiToken::Word(reward_token),
+        ]))
+    }
+}
+
+impl OutgoingTxBatch {
+    /// The checkpoint of this batch, computed as Gravity.sol's submitBatch. Validators sign this to approve the batch
+    pub fn get_checkpoint(&self, gravity_id: &str) -> Result<[u8; 32], CheckpointError> {
+        let mut amounts = Vec::with_capacity(self.transactions.len());
+        let mut destinations = Vec::with_capacity(self.transactions.len());
+        let mut fees = Vec::with_capacity(self.transactions.len());
+        for tx in &self.transactions {
+            let (token, fee) = match (&tx.erc20_token, &tx.erc20_fee) {
+                (Some(token), Some(fee)) => (token, fee),
+                _ => return Err(CheckpointError::MissingToken { tx_id: tx.id }),
+            };
+            amounts.push(amount_word("amount", &token.amount)?);
+            destinations.push(address_word("destination", &tx.dest_address)?);
+            fees.push(amount_word("fee", &fee.amount)?);
+        }
+        Ok(checkpoint_hash(&[
+            AbiToken::Word(gravity_id_word(gravity_id)?),
+            AbiToken::Word(bytes32(b"transactionBatch").unwrap()),
+            AbiToken::Array(amounts),
+            AbiToken::Array(destinations),
+            AbiToken::Array(fees),
+            AbiToken::Word(uint_word(self.batch_nonce)),
+            AbiToken::Word(address_word("token contract", &self.token_contract)?),
+            AbiToken::Word(uint_word(self.batch_timeout)),
+        ]))
+    }
+}
+
+impl OutgoingLogicCall {
+    /// The checkpoint of this logic call, computed as Gravity.sol's submitLogicCall. Validators sign this to approve
+    /// the logic call
+    pub fn get_checkpoint(&self, gravity_id: &str) -> Result<[u8; 32], CheckpointError> {
+        let amounts = |tokens: &[Erc20Token], field| {
+            tokens
+                .iter()
+                .map(|t| amount_word(field, &t.amount))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let contracts = |tokens: &[Erc20Token], field| {
+            tokens
+                .iter()
+                .map(|t| address_word(field, &t.contract))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let invalidation_id = bytes32(&self.invalidation_id)
+            .ok_or_else(|| CheckpointError::InvalidationIdTooLong(self.invalidation_id.clone()))?;
+        Ok(checkpoint_hash(&[
+            AbiToken::Word(gravity_id_word(gravity_id)?),
+            AbiToken::Word(bytes32(b"logicCall").unwrap()),
+            AbiToken::Array(amounts(&self.transfers, "transfer amount")?),
+            AbiToken::Array(contracts(&self.transfers, "transfer token contract")?),
+            AbiToken::Array(amounts(&self.fees, "fee amount")?),
+            AbiToken::Array(contracts(&self.fees, "fee token contract")?),
+            AbiToken::Word(address_word(
+                "logic contract address",
+                &self.logic_contract_address,
+            )?),
+            AbiToken::Bytes(self.payload.clone()),
+            AbiToken::Word(uint_word(self.timeout)),
+            AbiToken::Word(invalidation_id),
+            AbiToken::Word(uint_word(self.invalidation_nonce)),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod checkpoint_tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    fn erc20(contract: &str, amount: &str) -> Erc20Token {
+        Erc20Token {
+            contract: contract.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    /// TestValsetCheckpointGold1 of the gravity module, the hash computed by Gravity.sol's makeCheckpoint
+    #[test]
+    fn valset_checkpoint_gold() {
+        let valset = Valset {
+            nonce: 0,
+            members: vec![BridgeValidator {
+                power: 6667,
+                ethereum_address: "0xc783df8a850f42e7F7e57013759C285caa701eB6".to_string(),
+            }],
+            height: 0,
+            reward_amount: "0".to_string(),
+            reward_token: "0x0000000000000000000000000000000000000000".to_string(),
+        };
+        assert_eq!(
+            hex(&valset.get_checkpoint("foo").unwrap()),
+            "89731c26bab12cf0cb5363ef9abab6f9bd5496cf758a2309311c7946d54bca85"
+        );
+    }
+
+    /// TestOutgoingTxBatchCheckpointGold1 of the gravity module, the hash computed by Gravity.sol's submitBatch
+    #[test]
+    fn batch_checkpoint_gold() {
+        let token = "0x835973768750b3ED2D5c3EF5AdcD5eDb44d12aD4";
+        let batch = OutgoingTxBatch {
+            batch_nonce: 1,
+            batch_timeout: 2111,
+            transactions: vec![OutgoingTransferTx {
+                id: 1,
+                sender: "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8".to_string(),
+                dest_address: "0x9FC9C2DfBA3b6cF204C37a5F690619772b926e39".to_string(),
+                erc20_token: Some(erc20(token, "1")),
+                erc20_fee: Some(erc20(token, "1")),
+            }],
+            token_contract: token.to_string(),
+            cosmos_block_created: 0,
+        };
+        assert_eq!(
+            hex(&batch.get_checkpoint("foo").unwrap()),
+            "a3a7ee0a363b8ad2514e7ee8f110d7449c0d88f3b0913c28c1751e6e0079a9b2"
+        );
+    }
+
+    /// The inputs of TestOutgoingLogicCallCheckpointGold1 of the gravity module. The checkpoint hashes the abi.encode()
+    /// of submitLogicCall's arguments, written out word by word from the ABI specification, and the digest is pinned
+    /// so that the encoding and the hashing are both checked
+    #[test]
+    fn logic_call_checkpoint_encoding() {
+        let token = "0xC26eFfa98B8A2632141562Ae7E34953Cfe5B1888";
+        let mut payload = b"testingPayload".to_vec();
+        payload.resize(32, 0);
+        let mut invalidation_id = b"invalidationId".to_vec();
+        invalidation_id.resize(32, 0);
+        let call = OutgoingLogicCall {
+            transfers: vec![erc20(token, "1")],
+            fees: vec![erc20(token, "1")],
+            logic_contract_address: "0x17c1736CcF692F653c433d7aa2aB45148C016F68".to_string(),
+            payload,
+            timeout: 4766922941000,
+            invalidation_id,
+            invalidation_nonce: 1,
+            cosmos_block_created: 0,
+        };
+        let words = [
+            // gravity id
+            "666f6f0000000000000000000000000000000000000000000000000000000000",
+            // "logicCall"
+            "6c6f67696343616c6c0000000000000000000000000000000000000000000000",
+            // offsets of transferAmounts, transferTokenContracts, feeAmounts and feeTokenContracts
+            "0000000000000000000000000000000000000000000000000000000000000160",
+            "00000000000000000000000000000000000000000000000000000000000001a0",
+            "00000000000000000000000000000000000000000000000000000000000001e0",
+            "0000000000000000000000000000000000000000000000000000000000000220",
+            // logicContractAddress
+            "00000000000000000000000017c1736ccf692f653c433d7aa2ab45148c016f68",
+            // offset of payload
+            "0000000000000000000000000000000000000000000000000000000000000260",
+            // timeOut
+            "00000000000000000000000000000000000000000000000000000455e2bfa248",
+            // invalidationId
+            "696e76616c69646174696f6e4964000000000000000000000000000000000000",
+            // invalidationNonce
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            // transferAmounts
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            // transferTokenContracts
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "000000000000000000000000c26effa98b8a2632141562ae7e34953cfe5b1888",
+            // feeAmounts
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            // feeTokenContracts
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "000000000000000000000000c26effa98b8a2632141562ae7e34953cfe5b1888",
+            // payload
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "74657374696e675061796c6f6164000000000000000000000000000000000000",
+        ];
+        let checkpoint = call.get_checkpoint("foo").unwrap();
+        let encoded = crate::ethereum::decode_hex(&words.concat()).unwrap();
+        assert_eq!(checkpoint, crate::ethereum::keccak256(&encoded));
+        assert_eq!(
+            hex(&checkpoint),
+            "fdcd8198a8bd1635e05531a6f495848dad617254b79b048bd398fef4fd3cbfff"
+        );
+    }
+
+    #[test]
+    fn checkpoint_errors() {
+        let valset = Valset::default();
+        assert_eq!(
+            valset.get_checkpoint(&"a".repeat(33)),
+            Err(CheckpointError::GravityIdTooLong("a".repeat(33)))
+        );
+        let batch = OutgoingTxBatch {
+            transactions: vec![OutgoingTransferTx {
+                id: 7,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            batch.get_checkpoint("foo"),
+            Err(CheckpointError::MissingToken { tx_id: 7 })
+        );
+    }
+}
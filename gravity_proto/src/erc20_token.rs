@@ -0,0 +1,284 @@
+// Checked arithmetic over the decimal string amounts of Erc20Token and the batch types. Amounts are sdk.Int values
+// sent to Ethereum as uint256, so they are parsed into a Uint256 and any negative, malformed or overflowing value is an
+// error rather than being silently truncated.
+
+/// An error in Erc20Token arithmetic
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TokenError {
+    /// An amount is not a valid uint256
+    InvalidAmount(crate::ethereum::Uint256Error),
+    /// The result does not fit in a uint256
+    Overflow,
+    /// The result would be negative
+    Underflow,
+    /// Amounts of two different token contracts were combined
+    MixedContracts { expected: String, found: String },
+    /// A transaction in a batch has no erc20_token or erc20_fee
+    MissingToken { tx_id: u64 },
+}
+
+impl std::fmt::Display for TokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenError::InvalidAmount(e) => write!(f, "invalid amount: {e}"),
+            TokenError::Overflow => write!(f, "token amount overflows a uint256"),
+            TokenError::Underflow => write!(f, "token amount would be negative"),
+            TokenError::MixedContracts { expected, found } => {
+                write!(f, "expected token {expected}, found {found}")
+            }
+            TokenError::MissingToken { tx_id } => {
+                write!(f, "transaction {tx_id} is missing its token or fee")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenError {}
+
+impl From<crate::ethereum::Uint256Error> for TokenError {
+    fn from(e: crate::ethereum::Uint256Error) -> Self {
+        TokenError::InvalidAmount(e)
+    }
+}
+
+fn same_contract(a: &str, b: &str) -> bool {
+    match (
+        crate::ethereum::parse_address(a),
+        crate::ethereum::parse_address(b),
+    ) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Wei in one ether, the unit the price of a token is given per
+const WEI_PER_ETH: u64 = 1_000_000_000_000_000_000;
+
+/// If the fees exceed the gas cost in wei priced at the given number of the token's base units per ether, compared as
+/// fees * 10^18 > gas_cost * price so that no precision is lost
+fn fees_cover_gas(
+    fees: crate::ethereum::Uint256,
+    gas_cost: crate::ethereum::Uint256,
+    price: crate::ethereum::Uint256,
+) -> Result<bool, TokenError> {
+    let fees = fees
+        .checked_mul(WEI_PER_ETH.into())
+        .ok_or(TokenError::Overflow)?;
+    let cost = gas_cost.checked_mul(price).ok_or(TokenError::Overflow)?;
+    Ok(fees > cost)
+}
+
+fn sum_amounts<'a>(
+    amounts: impl IntoIterator<Item = &'a str>,
+) -> Result<crate::ethereum::Uint256, TokenError> {
+    amounts
+        .into_iter()
+        .try_fold(crate::ethereum::Uint256::ZERO, |acc, amount| {
+            acc.checked_add(amount.parse()?).ok_or(TokenError::Overflow)
+        })
+}
+
+impl Erc20Token {
+    pub fn new(contract: String, amount: crate::ethereum::Uint256) -> Self {
+        Erc20Token {
+            contract,
+            amount: amount.to_string(),
+        }
+    }
+
+    /// The amount as a uint256
+    pub fn parse_amount(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        Ok(self.amount.parse()?)
+    }
+
+    fn check_contract(&self, other: &Erc20Token) -> Result<(), TokenError> {
+        if !same_contract(&self.contract, &other.contract) {
+            return Err(TokenError::MixedContracts {
+                expected: self.contract.clone(),
+                found: other.contract.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds the amounts of two tokens of the same contract
+    pub fn checked_add(&self, other: &Erc20Token) -> Result<Erc20Token, TokenError> {
+        self.check_contract(other)?;
+        let amount = self
+            .parse_amount()?
+            .checked_add(other.parse_amount()?)
+            .ok_or(TokenError::Overflow)?;
+        Ok(Erc20Token::new(self.contract.clone(), amount))
+    }
+
+    /// Subtracts the amount of another token of the same contract
+    pub fn checked_sub(&self, other: &Erc20Token) -> Result<Erc20Token, TokenError> {
+        self.check_contract(other)?;
+        let amount = self
+            .parse_amount()?
+            .checked_sub(other.parse_amount()?)
+            .ok_or(TokenError::Underflow)?;
+        Ok(Erc20Token::new(self.contract.clone(), amount))
+    }
+}
+
+impl OutgoingTransferTx {
+    fn token_and_fee(&self) -> Result<(&Erc20Token, &Erc20Token), TokenError> {
+        match (&self.erc20_token, &self.erc20_fee) {
+            (Some(token), Some(fee)) => Ok((token, fee)),
+            _ => Err(TokenError::MissingToken { tx_id: self.id }),
+        }
+    }
+
+    /// The amount sent plus the fee, the total deducted from the sender
+    pub fn total(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        let (token, fee) = self.token_and_fee()?;
+        token.check_contract(fee)?;
+        token
+            .parse_amount()?
+            .checked_add(fee.parse_amount()?)
+            .ok_or(TokenError::Overflow)
+    }
+}
+
+impl OutgoingTxBatch {
+    /// The checked transactions of the batch, every token and fee must be of the batch's token contract
+    fn tokens_and_fees(&self) -> Result<Vec<(&Erc20Token, &Erc20Token)>, TokenError> {
+        self.transactions
+            .iter()
+            .map(|tx| {
+                let (token, fee) = tx.token_and_fee()?;
+                for t in [token, fee] {
+                    if !same_contract(&self.token_contract, &t.contract) {
+                        return Err(TokenError::MixedContracts {
+                            expected: self.token_contract.clone(),
+                            found: t.contract.clone(),
+                        });
+                    }
+                }
+                Ok((token, fee))
+            })
+            .collect()
+    }
+
+    /// The summed amount sent by the transactions of the batch, not including fees
+    pub fn total_amount(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        let txs = self.tokens_and_fees()?;
+        sum_amounts(txs.iter().map(|(token, _)| token.amount.as_str()))
+    }
+
+    /// The summed fee of the transactions of the batch, the reward for relaying it
+    pub fn total_fee(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        let txs = self.tokens_and_fees()?;
+        sum_amounts(txs.iter().map(|(_, fee)| fee.amount.as_str()))
+    }
+
+    /// The average fee per transaction rounded down, zero for an empty batch
+    pub fn fee_per_tx(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        Ok(self
+            .total_fee()?
+            .checked_div_u64(self.transactions.len() as u64)
+            .unwrap_or_default())
+    }
+
+    /// If the total fee is greater than the cost of relaying the batch, gas_cost is the gas used times the gas price
+    /// in wei and price the number of the batch token's base units one ether is worth
+    pub fn is_profitable(
+        &self,
+        gas_cost: crate::ethereum::Uint256,
+        price: crate::ethereum::Uint256,
+    ) -> Result<bool, TokenError> {
+        fees_cover_gas(self.total_fee()?, gas_cost, price)
+    }
+}
+
+impl BatchFees {
+    /// The summed fees of the transactions which would go in a batch of this token
+    pub fn parse_total_fees(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        Ok(self.total_fees.parse()?)
+    }
+
+    /// The average fee per transaction rounded down, zero when there are no transactions
+    pub fn fee_per_tx(&self) -> Result<crate::ethereum::Uint256, TokenError> {
+        Ok(self
+            .parse_total_fees()?
+            .checked_div_u64(self.tx_count)
+            .unwrap_or_default())
+    }
+
+    /// If a batch of these transactions would pay more than the cost of relaying it, gas_cost is the gas used times
+    /// the gas price in wei and price the number of the token's base units one ether is worth
+    pub fn is_profitable(
+        &self,
+        gas_cost: crate::ethereum::Uint256,
+        price: crate::ethereum::Uint256,
+    ) -> Result<bool, TokenError> {
+        fees_cover_gas(self.parse_total_fees()?, gas_cost, price)
+    }
+}
+
+#[cfg(test)]
+mod erc20_token_tests {
+    use super::*;
+    use crate::ethereum::Uint256;
+
+    const TOKEN: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+
+    fn batch_fees(total_fees: &str, tx_count: u64) -> BatchFees {
+        BatchFees {
+            token: TOKEN.to_string(),
+            total_fees: total_fees.to_string(),
+            tx_count,
+        }
+    }
+
+    #[test]
+    fn fees_must_exceed_gas_cost() {
+        // 2 * 10^18 wei of gas at 1500 base units per ether costs exactly 3000 base units
+        let gas_cost: Uint256 = "2000000000000000000".parse().unwrap();
+        let price = Uint256::from(1500);
+        assert_eq!(fees_cover_gas(2999u64.into(), gas_cost, price), Ok(false));
+        assert_eq!(fees_cover_gas(3000u64.into(), gas_cost, price), Ok(false));
+        assert_eq!(fees_cover_gas(3001u64.into(), gas_cost, price), Ok(true));
+        assert_eq!(
+            batch_fees("3000", 2).is_profitable(gas_cost, price),
+            Ok(false)
+        );
+        assert_eq!(
+            batch_fees("3001", 2).is_profitable(gas_cost, price),
+            Ok(true)
+        );
+        assert_eq!(
+            fees_cover_gas(Uint256::MAX, gas_cost, price),
+            Err(TokenError::Overflow)
+        );
+    }
+
+    #[test]
+    fn token_arithmetic() {
+        let token = |amount: &str| Erc20Token {
+            contract: TOKEN.to_string(),
+            amount: amount.to_string(),
+        };
+        // the contract is compared case insensitively
+        let lower = Erc20Token {
+            contract: TOKEN.to_lowercase(),
+            amount: "5".to_string(),
+        };
+        assert_eq!(token("7").checked_add(&lower), Ok(token("12")));
+        assert_eq!(token("7").checked_sub(&lower), Ok(token("2")));
+        assert_eq!(token("4").checked_sub(&lower), Err(TokenError::Underflow));
+        assert_eq!(
+            Erc20Token::new(TOKEN.to_string(), Uint256::MAX).checked_add(&token("1")),
+            Err(TokenError::Overflow)
+        );
+        assert_eq!(
+            token("07").checked_add(&token("1")),
+            Err(TokenError::InvalidAmount(
+                crate::ethereum::Uint256Error::Invalid("07".to_string())
+            ))
+        );
+        assert_eq!(batch_fees("7", 2).fee_per_tx(), Ok(3u64.into()));
+        assert_eq!(batch_fees("7", 0).fee_per_tx(), Ok(Uint256::ZERO));
+    }
+}
@@ -0,0 +1,349 @@
+//! Ethereum address handling shared by the Gravity helpers. Addresses are carried as strings in the Gravity messages,
+//! the Go module parses them and always writes them back out in their EIP-55 checksummed form, so anything hashed or
+//! compared by the module must be normalized the same way.
+
+use sha3::{Digest, Keccak256};
+use std::fmt;
+
+/// The length of an Ethereum address in bytes
+pub const ADDRESS_LEN: usize = 20;
+
+/// An error parsing an Ethereum address
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EthAddressError {
+    /// The address is not 0x followed by 40 hex characters
+    InvalidFormat(String),
+    /// The address mixes upper and lower case but does not match its EIP-55 checksum
+    InvalidChecksum(String),
+}
+
+impl fmt::Display for EthAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EthAddressError::InvalidFormat(a) => write!(f, "invalid Ethereum address {a:?}"),
+            EthAddressError::InvalidChecksum(a) => {
+                write!(
+                    f,
+                    "Ethereum address {a:?} does not match its EIP-55 checksum"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EthAddressError {}
+
+/// Returns the keccak256 hash of the input
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Parses a 0x prefixed hex Ethereum address. Like the Go module this accepts any casing, use
+/// [parse_checksummed_address] to also verify the EIP-55 checksum of mixed case addresses
+pub fn parse_address(address: &str) -> Result<[u8; ADDRESS_LEN], EthAddressError> {
+    let invalid = || EthAddressError::InvalidFormat(address.to_string());
+    let hex = address.strip_prefix("0x").ok_or_else(invalid)?;
+    if hex.len() != ADDRESS_LEN * 2 || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let mut bytes = [0u8; ADDRESS_LEN];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+/// Parses a 0x prefixed hex Ethereum address, rejecting mixed case addresses which do not match their EIP-55 checksum.
+/// All lower case and all upper case addresses carry no checksum and are accepted
+pub fn parse_checksummed_address(address: &str) -> Result<[u8; ADDRESS_LEN], EthAddressError> {
+    let bytes = parse_address(address)?;
+    let hex = &address[2..];
+    let has_lower = hex.bytes().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex.bytes().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper && checksum_address(&bytes) != address {
+        return Err(EthAddressError::InvalidChecksum(address.to_string()));
+    }
+    Ok(bytes)
+}
+
+/// Formats the address with its EIP-55 checksum, the form produced by go-ethereum's Address.Hex()
+pub fn checksum_address(address: &[u8; ADDRESS_LEN]) -> String {
+    let lower: String = address.iter().map(|b| format!("{b:02x}")).collect();
+    let hash = keccak256(lower.as_bytes());
+    let mut out = String::with_capacity(2 + ADDRESS_LEN * 2);
+    out.push_str("0x");
+    for (i, c) in lower.chars().enumerate() {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+        out.push(if nibble >= 8 {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        });
+    }
+    out
+}
+
+/// Normalizes an address to its EIP-55 checksummed form, as the Go module does when it stores an address
+pub fn normalize_address(address: &str) -> Result<String, EthAddressError> {
+    Ok(checksum_address(&parse_address(address)?))
+}
+
+/// An error parsing a Uint256
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Uint256Error {
+    /// The string is not a decimal integer without leading zeros
+    Invalid(String),
+    /// The string is a negative integer
+    Negative(String),
+    /// The value does not fit in 256 bits
+    Overflow,
+}
+
+impl fmt::Display for Uint256Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Uint256Error::Invalid(s) => write!(f, "{s:?} is not a decimal integer"),
+            Uint256Error::Negative(s) => write!(f, "{s:?} is negative"),
+            Uint256Error::Overflow => write!(f, "integer overflows 256 bits"),
+        }
+    }
+}
+
+impl std::error::Error for Uint256Error {}
+
+/// An unsigned 256 bit integer, the Solidity uint256 which the decimal string amounts of the Gravity messages
+/// (sdk.Int on the Go side) are converted to before they are sent to Ethereum
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Uint256([u64; 4]);
+
+impl Uint256 {
+    pub const ZERO: Uint256 = Uint256([0; 4]);
+    pub const MAX: Uint256 = Uint256([u64::MAX; 4]);
+
+    /// The value as a 32 byte big endian word, the Solidity ABI encoding of a uint256
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, limb) in self.0.iter().rev().enumerate() {
+            out[i * 8..i * 8 + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        out
+    }
+
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().rev().enumerate() {
+            *limb = u64::from_be_bytes(bytes[i * 8..i * 8 + 8].try_into().unwrap());
+        }
+        Uint256(limbs)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Uint256::ZERO
+    }
+
+    pub fn checked_add(&self, other: Uint256) -> Option<Self> {
+        let mut out = [0u64; 4];
+        let mut carry = false;
+        for (i, o) in out.iter_mut().enumerate() {
+            let (sum, c1) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            *o = sum;
+            carry = c1 || c2;
+        }
+        (!carry).then_some(Uint256(out))
+    }
+
+    pub fn checked_sub(&self, other: Uint256) -> Option<Self> {
+        let mut out = [0u64; 4];
+        let mut borrow = false;
+        for (i, o) in out.iter_mut().enumerate() {
+            let (diff, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *o = diff;
+            borrow = b1 || b2;
+        }
+        (!borrow).then_some(Uint256(out))
+    }
+
+    pub fn checked_mul(&self, other: Uint256) -> Option<Self> {
+        let mut out = [0u64; 4];
+        for (i, a) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, b) in other.0.iter().enumerate() {
+                let product = (*a as u128) * (*b as u128) + carry;
+                if i + j >= 4 {
+                    if product != 0 {
+                        return None;
+                    }
+                    continue;
+                }
+                let v = out[i + j] as u128 + (product as u64) as u128;
+                out[i + j] = v as u64;
+                carry = (product >> 64) + (v >> 64);
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(Uint256(out))
+    }
+
+    /// Divides by a u64, rounding down, returns None when dividing by zero
+    pub fn checked_div_u64(&self, div: u64) -> Option<Self> {
+        (div != 0).then(|| self.div_rem_u64(div).0)
+    }
+
+    /// Multiplies by a small value and adds another, used for decimal parsing and formatting
+    fn checked_mul_add_u64(&self, mul: u64, add: u64) -> Option<Self> {
+        let mut out = [0u64; 4];
+        let mut carry = add as u128;
+        for (o, limb) in out.iter_mut().zip(self.0.iter()) {
+            let v = (*limb as u128) * (mul as u128) + carry;
+            *o = v as u64;
+            carry = v >> 64;
+        }
+        (carry == 0).then_some(Uint256(out))
+    }
+
+    /// Divides by a small value returning the quotient and remainder
+    fn div_rem_u64(&self, div: u64) -> (Self, u64) {
+        let mut out = [0u64; 4];
+        let mut rem = 0u128;
+        for (o, limb) in out.iter_mut().zip(self.0.iter()).rev() {
+            let v = (rem << 64) | *limb as u128;
+            *o = (v / div as u128) as u64;
+            rem = v % div as u128;
+        }
+        (Uint256(out), rem as u64)
+    }
+}
+
+impl From<u64> for Uint256 {
+    fn from(value: u64) -> Self {
+        Uint256([value, 0, 0, 0])
+    }
+}
+
+impl std::str::FromStr for Uint256 {
+    type Err = Uint256Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(digits) = s.strip_prefix('-') {
+            if !digits.is_empty() && digits.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(Uint256Error::Negative(s.to_string()));
+            }
+        }
+        // leading zeros are rejected along with hex, which would otherwise be read as octal or hex by big.Int
+        if s.is_empty()
+            || !s.bytes().all(|c| c.is_ascii_digit())
+            || (s.len() > 1 && s.starts_with('0'))
+        {
+            return Err(Uint256Error::Invalid(s.to_string()));
+        }
+        s.bytes().try_fold(Uint256::ZERO, |acc, c| {
+            acc.checked_mul_add_u64(10, (c - b'0') as u64)
+                .ok_or(Uint256Error::Overflow)
+        })
+    }
+}
+
+impl fmt::Display for Uint256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        let mut digits = Vec::new();
+        let mut value = *self;
+        while !value.is_zero() {
+            let (quotient, digit) = value.div_rem_u64(10);
+            digits.push(b'0' + digit as u8);
+            value = quotient;
+        }
+        digits.reverse();
+        f.write_str(std::str::from_utf8(&digits).unwrap())
+    }
+}
+
+impl Ord for Uint256 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for Uint256 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Decodes a hex string with or without a 0x prefix, as used for the signatures and ids in the Gravity messages
+pub fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+    #[test]
+    fn decimal_round_trip() {
+        for s in [
+            "0",
+            "1",
+            "18446744073709551616",
+            "1000000000000000000000000",
+            MAX,
+        ] {
+            assert_eq!(s.parse::<Uint256>().unwrap().to_string(), s);
+        }
+        assert_eq!(MAX.parse::<Uint256>(), Ok(Uint256::MAX));
+        assert_eq!(Uint256::MAX.to_be_bytes(), [0xff; 32]);
+        let value: Uint256 = "18446744073709551616".parse().unwrap();
+        let mut bytes = [0u8; 32];
+        bytes[23] = 1;
+        assert_eq!(value.to_be_bytes(), bytes);
+        assert_eq!(Uint256::from_be_bytes(bytes), value);
+    }
+
+    #[test]
+    fn overflow() {
+        let above_max =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(above_max.parse::<Uint256>(), Err(Uint256Error::Overflow));
+        assert_eq!(
+            format!("{MAX}0").parse::<Uint256>(),
+            Err(Uint256Error::Overflow)
+        );
+        assert_eq!(Uint256::MAX.checked_add(1u64.into()), None);
+        assert_eq!(Uint256::ZERO.checked_sub(1u64.into()), None);
+        assert_eq!(Uint256::MAX.checked_mul(2u64.into()), None);
+        assert_eq!(Uint256::MAX.checked_mul(1u64.into()), Some(Uint256::MAX));
+        assert_eq!(Uint256::MAX.checked_sub(Uint256::MAX), Some(Uint256::ZERO));
+        assert_eq!(Uint256::MAX.checked_div_u64(0), None);
+    }
+
+    #[test]
+    fn rejects_non_decimal_strings() {
+        for s in ["", "007", "00", "0x10", "ff", "1e18", " 1", "1.5", "+1"] {
+            assert_eq!(
+                s.parse::<Uint256>(),
+                Err(Uint256Error::Invalid(s.to_string())),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            "-1".parse::<Uint256>(),
+            Err(Uint256Error::Negative("-1".to_string()))
+        );
+    }
+}
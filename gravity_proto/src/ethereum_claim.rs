@@ -22,17 +22,120 @@ pub trait EthereumClaim {
     /// Which type of claim this is
     fn get_type(&self) -> ClaimType;
 
-    /*
-    TODO: Consider implementing ClaimHash, although it should be queryable via a cosmos node
-    fn validate_basic(&self) -> error;
+    /// Performs the stateless checks of the Go module's ValidateBasic so that a bad claim is rejected before it is
+    /// broadcast. The orchestrator is only checked to be valid bech32, its prefix is left to the caller
+    fn validate_basic(&self) -> Result<(), ClaimError>;
 
     /// The claim hash of this claim. This is used to store these claims and also used to check if two different
     /// validators claims agree. Therefore it's extremely important that this include all elements of the claim
     /// with the exception of the orchestrator who sent it in, which will be used as a different part of the index
-    fn claim_hash(&self) -> Result<Vec<u8>, error>;
-     */
+    fn claim_hash(&self) -> Result<Vec<u8>, ClaimError>;
 }
 
+/// An error returned while hashing or validating an EthereumClaim
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClaimError {
+    /// The event nonce is zero, nonces issued by the Gravity contract start at one
+    ZeroEventNonce,
+    /// The batch nonce of a MsgBatchSendToEthClaim is zero
+    ZeroBatchNonce,
+    /// The named field is not a valid Ethereum address
+    InvalidEthAddress {
+        field: &'static str,
+        error: crate::ethereum::EthAddressError,
+    },
+    /// The orchestrator is not a valid bech32 address
+    InvalidOrchestrator(String),
+    /// The named field is not a non-negative 256 bit integer
+    InvalidAmount { field: &'static str, amount: String },
+    /// A MsgValsetUpdatedClaim has no members
+    NoMembers,
+    /// A member of a MsgValsetUpdatedClaim has an invalid Ethereum address
+    InvalidMember(crate::ethereum::EthAddressError),
+    /// A member of a MsgValsetUpdatedClaim has no power, given by its Ethereum address
+    ZeroPowerMember(String),
+}
+
+impl std::fmt::Display for ClaimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClaimError::ZeroEventNonce => write!(f, "nonce == 0"),
+            ClaimError::ZeroBatchNonce => write!(f, "batch_nonce == 0"),
+            ClaimError::InvalidEthAddress { field, error } => write!(f, "invalid {field}: {error}"),
+            ClaimError::InvalidOrchestrator(o) => write!(f, "invalid orchestrator address {o:?}"),
+            ClaimError::InvalidAmount { field, amount } => write!(f, "invalid {field} {amount:?}"),
+            ClaimError::NoMembers => write!(f, "valset has no members"),
+            ClaimError::InvalidMember(e) => write!(f, "invalid members: {e}"),
+            ClaimError::ZeroPowerMember(a) => write!(f, "member {a} has zero power"),
+        }
+    }
+}
+
+impl std::error::Error for ClaimError {}
+
+fn validate_event_nonce(event_nonce: u64) -> Result<(), ClaimError> {
+    if event_nonce == 0 {
+        return Err(ClaimError::ZeroEventNonce);
+    }
+    Ok(())
+}
+
+fn validate_eth_address(field: &'static str, address: &str) -> Result<(), ClaimError> {
+    crate::ethereum::parse_checksummed_address(address)
+        .map(|_| ())
+        .map_err(|error| ClaimError::InvalidEthAddress { field, error })
+}
+
+fn validate_orchestrator(orchestrator: &str) -> Result<(), ClaimError> {
+    // bech32::decode would also accept a bech32m checksum, which sdk.AccAddressFromBech32 does not
+    match bech32::primitives::decode::CheckedHrpstring::new::<bech32::Bech32>(orchestrator) {
+        Ok(address) if (1..=255).contains(&address.byte_iter().len()) => Ok(()),
+        _ => Err(ClaimError::InvalidOrchestrator(orchestrator.to_string())),
+    }
+}
+
+/// Checks the amount parses as an sdk.Int, which is limited to 256 bits, and is not negative
+fn validate_amount(field: &'static str, amount: &str) -> Result<(), ClaimError> {
+    const MAX_UINT256: &str =
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    let digits = amount.trim_start_matches('0');
+    let valid = !amount.is_empty()
+        && amount.bytes().all(|c| c.is_ascii_digit())
+        && (digits.len() < MAX_UINT256.len()
+            || (digits.len() == MAX_UINT256.len() && digits <= MAX_UINT256));
+    if !valid {
+        return Err(ClaimError::InvalidAmount {
+            field,
+            amount: amount.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// The amount as the module formats it with sdk.Int's String(), without leading zeros
+fn canonical_amount(field: &'static str, amount: &str) -> Result<String, ClaimError> {
+    // Uint256 rejects leading zeros, the claim's amount only has to be a valid sdk.Int
+    let digits = match amount.trim_start_matches('0') {
+        "" if !amount.is_empty() => "0",
+        digits => digits,
+    };
+    digits
+        .parse::<crate::ethereum::Uint256>()
+        .map(|a| a.to_string())
+        .map_err(|_| ClaimError::InvalidAmount {
+            field,
+            amount: amount.to_string(),
+        })
+}
+
+/// Claim hashes are the tendermint hash (sha256) of a '/' separated path of the claim's fields, formatted with Go's fmt
+fn claim_path_hash(path: &[u8]) -> Vec<u8> {
+    use sha2::Digest;
+    sha2::Sha256::digest(path).to_vec()
+}
+
+// Kept as ToString rather than Display to leave the public API of ClaimType unchanged
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for ClaimType {
     fn to_string(&self) -> String {
         match self {
@@ -62,6 +165,29 @@ impl EthereumClaim for MsgSendToCosmosClaim {
     fn get_type(&self) -> ClaimType {
         ClaimType::SendToCosmos
     }
+
+    fn validate_basic(&self) -> Result<(), ClaimError> {
+        validate_eth_address("eth sender", &self.ethereum_sender)?;
+        validate_eth_address("erc20 token", &self.token_contract)?;
+        validate_orchestrator(&self.orchestrator)?;
+        validate_amount("amount", &self.amount)?;
+        // the destination address is intentionally not validated, the module sends the tokens to the community pool
+        // when it is invalid
+        validate_event_nonce(self.event_nonce)
+    }
+
+    fn claim_hash(&self) -> Result<Vec<u8>, ClaimError> {
+        let path = format!(
+            "{}/{}/{}/{}/{}/{}",
+            self.event_nonce,
+            self.eth_block_height,
+            self.token_contract,
+            canonical_amount("amount", &self.amount)?,
+            self.ethereum_sender,
+            self.cosmos_receiver
+        );
+        Ok(claim_path_hash(path.as_bytes()))
+    }
 }
 impl EthereumClaim for MsgBatchSendToEthClaim {
     fn get_event_nonce(&self) -> u64 {
@@ -79,6 +205,23 @@ impl EthereumClaim for MsgBatchSendToEthClaim {
     fn get_type(&self) -> ClaimType {
         ClaimType::BatchSendToEth
     }
+
+    fn validate_basic(&self) -> Result<(), ClaimError> {
+        validate_event_nonce(self.event_nonce)?;
+        if self.batch_nonce == 0 {
+            return Err(ClaimError::ZeroBatchNonce);
+        }
+        validate_eth_address("erc20 token", &self.token_contract)?;
+        validate_orchestrator(&self.orchestrator)
+    }
+
+    fn claim_hash(&self) -> Result<Vec<u8>, ClaimError> {
+        let path = format!(
+            "{}/{}/{}/{}",
+            self.event_nonce, self.eth_block_height, self.batch_nonce, self.token_contract
+        );
+        Ok(claim_path_hash(path.as_bytes()))
+    }
 }
 impl EthereumClaim for MsgErc20DeployedClaim {
     fn get_event_nonce(&self) -> u64 {
@@ -96,6 +239,26 @@ impl EthereumClaim for MsgErc20DeployedClaim {
     fn get_type(&self) -> ClaimType {
         ClaimType::Erc20Deployed
     }
+
+    fn validate_basic(&self) -> Result<(), ClaimError> {
+        validate_eth_address("erc20 token", &self.token_contract)?;
+        validate_orchestrator(&self.orchestrator)?;
+        validate_event_nonce(self.event_nonce)
+    }
+
+    fn claim_hash(&self) -> Result<Vec<u8>, ClaimError> {
+        let path = format!(
+            "{}/{}/{}/{}/{}/{}/{}",
+            self.event_nonce,
+            self.eth_block_height,
+            self.cosmos_denom,
+            self.token_contract,
+            self.name,
+            self.symbol,
+            self.decimals
+        );
+        Ok(claim_path_hash(path.as_bytes()))
+    }
 }
 impl EthereumClaim for MsgLogicCallExecutedClaim {
     fn get_event_nonce(&self) -> u64 {
@@ -113,6 +276,19 @@ impl EthereumClaim for MsgLogicCallExecutedClaim {
     fn get_type(&self) -> ClaimType {
         ClaimType::LogicCallExecuted
     }
+
+    fn validate_basic(&self) -> Result<(), ClaimError> {
+        validate_orchestrator(&self.orchestrator)?;
+        validate_event_nonce(self.event_nonce)
+    }
+
+    fn claim_hash(&self) -> Result<Vec<u8>, ClaimError> {
+        // the invalidation id is formatted with %s, which writes the raw bytes
+        let mut path = format!("{}/{}/", self.event_nonce, self.eth_block_height).into_bytes();
+        path.extend_from_slice(&self.invalidation_id);
+        path.extend_from_slice(format!("/{}", self.invalidation_nonce).as_bytes());
+        Ok(claim_path_hash(&path))
+    }
 }
 impl EthereumClaim for MsgValsetUpdatedClaim {
     fn get_event_nonce(&self) -> u64 {
@@ -130,4 +306,295 @@ impl EthereumClaim for MsgValsetUpdatedClaim {
     fn get_type(&self) -> ClaimType {
         ClaimType::ValsetUpdated
     }
+
+    fn validate_basic(&self) -> Result<(), ClaimError> {
+        validate_event_nonce(self.event_nonce)?;
+        validate_orchestrator(&self.orchestrator)?;
+        if self.members.is_empty() {
+            return Err(ClaimError::NoMembers);
+        }
+        // the checks of BridgeValidator.ValidateBasic
+        for member in &self.members {
+            if member.power == 0 {
+                return Err(ClaimError::ZeroPowerMember(member.ethereum_address.clone()));
+            }
+            crate::ethereum::parse_checksummed_address(&member.ethereum_address)
+                .map_err(ClaimError::InvalidMember)?;
+        }
+        validate_amount("reward amount", &self.reward_amount)?;
+        validate_eth_address("reward token", &self.reward_token)
+    }
+
+    fn claim_hash(&self) -> Result<Vec<u8>, ClaimError> {
+        // The module converts the members to checksummed addresses and sorts them before formatting them with %x, which
+        // writes the slice of structs as "[{power address} ...]" with the power and the address string hex encoded
+        let mut members = self
+            .members
+            .iter()
+            .map(|m| {
+                crate::ethereum::normalize_address(&m.ethereum_address)
+                    .map(|address| (m.power, address))
+                    .map_err(ClaimError::InvalidMember)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // by descending power, ties are broken by the checksummed address
+        members.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        let members = members
+            .iter()
+            .map(|(power, address)| {
+                let address: String = address.bytes().map(|b| format!("{b:02x}")).collect();
+                format!("{{{power:x} {address}}}")
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let path = format!(
+            "{}/{}/{}/[{}]/{}/{}",
+            self.event_nonce,
+            self.valset_nonce,
+            self.eth_block_height,
+            members,
+            canonical_amount("reward amount", &self.reward_amount)?,
+            self.reward_token
+        );
+        Ok(claim_path_hash(path.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod claim_hash_tests {
+    use super::*;
+
+    // The expected hashes are SHA256 of the path each claim's ClaimHash formats in the gravity module, given above each
+    // case
+    const TOKEN: &str = "0x429881672B9AE42b8EbA0E26cD9C73711b891Ca5";
+    const ORCHESTRATOR: &str = "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8";
+
+    fn hash(claim: &dyn EthereumClaim) -> String {
+        claim
+            .claim_hash()
+            .unwrap()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    #[test]
+    fn send_to_cosmos_claim_hash() {
+        // "1/2/0x4298...1Ca5/7/0xE590...D1D3/gravity1zyg3...96h8", the amount formatted without its leading zeros
+        let claim = MsgSendToCosmosClaim {
+            event_nonce: 1,
+            eth_block_height: 2,
+            token_contract: TOKEN.to_string(),
+            amount: "007".to_string(),
+            ethereum_sender: "0xE5904695748fe4A84b40b3fc79De2277660BD1D3".to_string(),
+            cosmos_receiver: ORCHESTRATOR.to_string(),
+            orchestrator: ORCHESTRATOR.to_string(),
+        };
+        assert_eq!(
+            hash(&claim),
+            "61df685e58df6c9f217576cdec99e06605082ba84482871d2b574e2d6e9569cd"
+        );
+    }
+
+    #[test]
+    fn batch_send_to_eth_claim_hash() {
+        // "3/4/5/0x4298...1Ca5"
+        let claim = MsgBatchSendToEthClaim {
+            event_nonce: 3,
+            eth_block_height: 4,
+            batch_nonce: 5,
+            token_contract: TOKEN.to_string(),
+            orchestrator: ORCHESTRATOR.to_string(),
+        };
+        assert_eq!(
+            hash(&claim),
+            "1aeec03afb54269e2b0ed8f38527ef51607573b85d11937c539d3f0e5f336a66"
+        );
+    }
+
+    #[test]
+    fn erc20_deployed_claim_hash() {
+        // "6/7/ugraviton/0x4298...1Ca5/Graviton/GRAV/6"
+        let claim = MsgErc20DeployedClaim {
+            event_nonce: 6,
+            eth_block_height: 7,
+            cosmos_denom: "ugraviton".to_string(),
+            token_contract: TOKEN.to_string(),
+            name: "Graviton".to_string(),
+            symbol: "GRAV".to_string(),
+            decimals: 6,
+            orchestrator: ORCHESTRATOR.to_string(),
+        };
+        assert_eq!(
+            hash(&claim),
+            "44f7c991ff772485084e90cfb524199019e870a6f4f0632dbec4f46ddeed5559"
+        );
+    }
+
+    #[test]
+    fn logic_call_executed_claim_hash() {
+        // "8/9/invalidationId/10"
+        let claim = MsgLogicCallExecutedClaim {
+            event_nonce: 8,
+            eth_block_height: 9,
+            invalidation_id: b"invalidationId".to_vec(),
+            invalidation_nonce: 10,
+            orchestrator: ORCHESTRATOR.to_string(),
+        };
+        assert_eq!(
+            hash(&claim),
+            "11e33c6ed0e3fef196a72e34ed8024f67f72f6508f3cfe2667a8df59573b0bde"
+        );
+    }
+
+    #[test]
+    fn valset_updated_claim_hash() {
+        // "11/12/13/[{bb8 3078...4236} {64 3078...4433} {64 3078...30d4}]/10/0x4298...1Ca5", the members sorted by
+        // descending power then address, with the lower case address normalized
+        let member = |power, address: &str| BridgeValidator {
+            power,
+            ethereum_address: address.to_string(),
+        };
+        let claim = MsgValsetUpdatedClaim {
+            event_nonce: 11,
+            valset_nonce: 12,
+            eth_block_height: 13,
+            members: vec![
+                member(100, "0xead9c93b79ae7c1591b1fb5323bd777e86e150d4"),
+                member(3000, "0xc783df8a850f42e7F7e57013759C285caa701eB6"),
+                member(100, "0xE5904695748fe4A84b40b3fc79De2277660BD1D3"),
+            ],
+            reward_amount: "0010".to_string(),
+            reward_token: TOKEN.to_string(),
+            orchestrator: ORCHESTRATOR.to_string(),
+        };
+        assert_eq!(
+            hash(&claim),
+            "68f6e97880cc6e2c9650a67f398ce2f9b308685c8682a25add2d15c33d59edba"
+        );
+    }
+}
+
+#[cfg(test)]
+mod claim_validation_tests {
+    use super::*;
+
+    const TOKEN: &str = "0x429881672B9AE42b8EbA0E26cD9C73711b891Ca5";
+    const SENDER: &str = "0xE5904695748fe4A84b40b3fc79De2277660BD1D3";
+    const ORCHESTRATOR: &str = "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8";
+    // the same address with a bech32m checksum
+    const BECH32M_ORCHESTRATOR: &str = "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3s34kj9";
+
+    fn send_to_cosmos() -> MsgSendToCosmosClaim {
+        MsgSendToCosmosClaim {
+            event_nonce: 1,
+            eth_block_height: 2,
+            token_contract: TOKEN.to_string(),
+            amount: "7".to_string(),
+            ethereum_sender: SENDER.to_string(),
+            cosmos_receiver: "not an address".to_string(),
+            orchestrator: ORCHESTRATOR.to_string(),
+        }
+    }
+
+    fn valset_updated(members: Vec<BridgeValidator>) -> MsgValsetUpdatedClaim {
+        MsgValsetUpdatedClaim {
+            event_nonce: 11,
+            valset_nonce: 12,
+            eth_block_height: 13,
+            members,
+            reward_amount: "0".to_string(),
+            reward_token: TOKEN.to_string(),
+            orchestrator: ORCHESTRATOR.to_string(),
+        }
+    }
+
+    fn member(power: u64, address: &str) -> BridgeValidator {
+        BridgeValidator {
+            power,
+            ethereum_address: address.to_string(),
+        }
+    }
+
+    #[test]
+    fn send_to_cosmos_validation() {
+        assert_eq!(send_to_cosmos().validate_basic(), Ok(()));
+        let claim = MsgSendToCosmosClaim {
+            event_nonce: 0,
+            ..send_to_cosmos()
+        };
+        assert_eq!(claim.validate_basic(), Err(ClaimError::ZeroEventNonce));
+        let claim = MsgSendToCosmosClaim {
+            amount: "-1".to_string(),
+            ..send_to_cosmos()
+        };
+        assert_eq!(
+            claim.validate_basic(),
+            Err(ClaimError::InvalidAmount {
+                field: "amount",
+                amount: "-1".to_string()
+            })
+        );
+        let claim = MsgSendToCosmosClaim {
+            // mixed case with a bad checksum
+            ethereum_sender: "0xe5904695748fe4A84b40b3fc79De2277660BD1D3".to_string(),
+            ..send_to_cosmos()
+        };
+        assert!(matches!(
+            claim.validate_basic(),
+            Err(ClaimError::InvalidEthAddress {
+                field: "eth sender",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn orchestrator_must_be_bech32() {
+        for orchestrator in [BECH32M_ORCHESTRATOR, "gravity1invalid", "", SENDER] {
+            let claim = MsgSendToCosmosClaim {
+                orchestrator: orchestrator.to_string(),
+                ..send_to_cosmos()
+            };
+            assert_eq!(
+                claim.validate_basic(),
+                Err(ClaimError::InvalidOrchestrator(orchestrator.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn batch_send_to_eth_validation() {
+        let claim = MsgBatchSendToEthClaim {
+            event_nonce: 3,
+            eth_block_height: 4,
+            batch_nonce: 5,
+            token_contract: TOKEN.to_string(),
+            orchestrator: ORCHESTRATOR.to_string(),
+        };
+        assert_eq!(claim.validate_basic(), Ok(()));
+        let claim = MsgBatchSendToEthClaim {
+            batch_nonce: 0,
+            ..claim
+        };
+        assert_eq!(claim.validate_basic(), Err(ClaimError::ZeroBatchNonce));
+    }
+
+    #[test]
+    fn valset_updated_validation() {
+        let valid = valset_updated(vec![member(3000, SENDER), member(100, TOKEN)]);
+        assert_eq!(valid.validate_basic(), Ok(()));
+        assert_eq!(
+            valset_updated(vec![]).validate_basic(),
+            Err(ClaimError::NoMembers)
+        );
+        assert_eq!(
+            valset_updated(vec![member(3000, SENDER), member(0, TOKEN)]).validate_basic(),
+            Err(ClaimError::ZeroPowerMember(TOKEN.to_string()))
+        );
+        assert!(matches!(
+            valset_updated(vec![member(3000, "0x1234")]).validate_basic(),
+            Err(ClaimError::InvalidMember(_))
+        ));
+    }
 }
@@ -0,0 +1,279 @@
+//! Typed decoding of the events emitted by the Gravity module, see cosmos_sdk_proto::abci_event. The auction module
+//! only emits untyped events so it has no event messages to decode into.
+
+use crate::gravity::*;
+use cosmos_sdk_proto::abci_event::{decode_event, EventAttributes, EventError};
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::typed_event;
+use prost::Name;
+
+macro_rules! gravity_events {
+    ($($variant:ident($ty:ident { $($field:ident),* $(,)? })),* $(,)?) => {
+        typed_event!($($ty { $($field),* }),*);
+
+        /// Any of the typed events emitted by the Gravity module. The untyped events the module emits alongside
+        /// them, such as the "message" event naming the module and the outgoing tx id, carry no typed message and
+        /// are not mapped
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum GravityEvent {
+            $($variant($ty),)*
+        }
+
+        impl GravityEvent {
+            /// Decodes the event if it is one of the Gravity module's typed events, otherwise returns None
+            pub fn decode(event: &EventAttributes) -> Result<Option<Self>, EventError> {
+                $(
+                    if event.r#type == $ty::full_name() {
+                        return decode_event::<$ty>(event).map(|e| Some(GravityEvent::$variant(e)));
+                    }
+                )*
+                Ok(None)
+            }
+        }
+    };
+}
+
+gravity_events!(
+    Observation(EventObservation {
+        attestation_type,
+        bridge_contract,
+        bridge_chain_id,
+        attestation_id,
+        nonce
+    }),
+    InvalidSendToCosmosReceiver(EventInvalidSendToCosmosReceiver {
+        amount,
+        nonce,
+        token,
+        sender
+    }),
+    SendToCosmos(EventSendToCosmos {
+        amount,
+        nonce,
+        token
+    }),
+    SendToCosmosLocal(EventSendToCosmosLocal {
+        nonce,
+        receiver,
+        token,
+        amount
+    }),
+    SendToCosmosPendingIbcAutoForward(EventSendToCosmosPendingIbcAutoForward {
+        nonce,
+        receiver,
+        token,
+        amount,
+        channel
+    }),
+    SendToCosmosExecutedIbcAutoForward(EventSendToCosmosExecutedIbcAutoForward {
+        nonce,
+        receiver,
+        token,
+        amount,
+        channel,
+        timeout_time,
+        timeout_height
+    }),
+    OutgoingBatchCanceled(EventOutgoingBatchCanceled {
+        bridge_contract,
+        bridge_chain_id,
+        batch_id,
+        nonce
+    }),
+    OutgoingBatch(EventOutgoingBatch {
+        bridge_contract,
+        bridge_chain_id,
+        batch_id,
+        nonce
+    }),
+    SetOperatorAddress(EventSetOperatorAddress { message, address }),
+    ValsetConfirmKey(EventValsetConfirmKey { message, key }),
+    BatchCreated(EventBatchCreated {
+        message,
+        batch_nonce
+    }),
+    BatchConfirmKey(EventBatchConfirmKey {
+        message,
+        batch_confirm_key
+    }),
+    BatchSendToEthClaim(EventBatchSendToEthClaim { nonce }),
+    Claim(EventClaim {
+        message,
+        claim_hash,
+        attestation_id
+    }),
+    BadSignatureEvidence(EventBadSignatureEvidence {
+        message,
+        bad_eth_signature,
+        bad_eth_signature_subject
+    }),
+    Erc20DeployedClaim(EventErc20DeployedClaim { token, nonce }),
+    ValsetUpdatedClaim(EventValsetUpdatedClaim { nonce }),
+    MultisigUpdateRequest(EventMultisigUpdateRequest {
+        bridge_contract,
+        bridge_chain_id,
+        multisig_id,
+        nonce
+    }),
+    OutgoingLogicCallCanceled(EventOutgoingLogicCallCanceled {
+        logic_call_invalidation_id,
+        logic_call_invalidation_nonce
+    }),
+    SignatureSlashing(EventSignatureSlashing { r#type, address }),
+    OutgoingTxId(EventOutgoingTxId { message, tx_id }),
+    SendToEthFeeCollected(EventSendToEthFeeCollected {
+        sender,
+        send_amount,
+        fee_amount
+    }),
+    WithdrawalReceived(EventWithdrawalReceived {
+        bridge_contract,
+        bridge_chain_id,
+        outgoing_tx_id,
+        nonce
+    }),
+    WithdrawCanceled(EventWithdrawCanceled {
+        sender,
+        tx_id,
+        bridge_contract,
+        bridge_chain_id
+    }),
+);
+
+impl GravityEvent {
+    /// Decodes every Gravity event emitted by a transaction, skipping the events of other modules
+    pub fn from_tx_response(tx_response: &TxResponse) -> Result<Vec<Self>, EventError> {
+        let mut events = Vec::new();
+        for event in cosmos_sdk_proto::abci_event::tx_response_events(tx_response) {
+            events.extend(GravityEvent::decode(&event)?);
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::abci::v1beta1::{AbciMessageLog, Attribute, StringEvent};
+    use cosmos_sdk_proto::tendermint::abci::{Event, EventAttribute};
+
+    fn event(r#type: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            r#type: r#type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| EventAttribute {
+                    key: k.as_bytes().to_vec(),
+                    value: v.as_bytes().to_vec(),
+                    index: true,
+                })
+                .collect(),
+        }
+    }
+
+    fn send_to_cosmos_local() -> EventSendToCosmosLocal {
+        EventSendToCosmosLocal {
+            nonce: "7".to_string(),
+            receiver: "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8".to_string(),
+            token: "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F".to_string(),
+            amount: "1000".to_string(),
+        }
+    }
+
+    #[test]
+    fn typed_event_with_json_attributes() {
+        let event = event(
+            "gravity.v1.EventSendToCosmosLocal",
+            &[
+                ("nonce", "\"7\""),
+                (
+                    "receiver",
+                    "\"gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8\"",
+                ),
+                ("token", "\"0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F\""),
+                ("amount", "\"1000\""),
+            ],
+        );
+        assert_eq!(
+            GravityEvent::decode(&EventAttributes::from(&event)),
+            Ok(Some(
+                GravityEvent::SendToCosmosLocal(send_to_cosmos_local())
+            ))
+        );
+    }
+
+    #[test]
+    fn legacy_plain_string_event() {
+        let legacy = event(
+            "gravity.v1.EventSendToCosmosLocal",
+            &[
+                ("nonce", "7"),
+                ("receiver", "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8"),
+                ("token", "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+                ("amount", "1000"),
+            ],
+        );
+        assert_eq!(
+            GravityEvent::decode(&EventAttributes::from(&legacy)),
+            Ok(Some(
+                GravityEvent::SendToCosmosLocal(send_to_cosmos_local())
+            ))
+        );
+
+        // untyped events are not mapped
+        let untyped = event("message", &[("module", "gravity"), ("outgoing_tx_id", "3")]);
+        assert_eq!(
+            GravityEvent::decode(&EventAttributes::from(&untyped)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn from_tx_response_falls_back_to_logs() {
+        let string_event = |r#type: &str, attributes: &[(&str, &str)]| StringEvent {
+            r#type: r#type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| Attribute {
+                    key: k.to_string(),
+                    value: v.to_string(),
+                })
+                .collect(),
+        };
+        let tx_response = TxResponse {
+            logs: vec![AbciMessageLog {
+                msg_index: 0,
+                log: String::new(),
+                events: vec![
+                    string_event("message", &[("module", "gravity")]),
+                    string_event(
+                        "gravity.v1.EventBatchSendToEthClaim",
+                        &[("nonce", "\"12\"")],
+                    ),
+                    string_event(
+                        "gravity.v1.EventClaim",
+                        &[
+                            ("message", "\"/gravity.v1.MsgBatchSendToEthClaim\""),
+                            ("claim_hash", "\"AQID\""),
+                            ("attestation_id", "\"BAUG\""),
+                        ],
+                    ),
+                ],
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            GravityEvent::from_tx_response(&tx_response),
+            Ok(vec![
+                GravityEvent::BatchSendToEthClaim(EventBatchSendToEthClaim {
+                    nonce: "12".to_string()
+                }),
+                GravityEvent::Claim(EventClaim {
+                    message: "/gravity.v1.MsgBatchSendToEthClaim".to_string(),
+                    claim_hash: "AQID".to_string(),
+                    attestation_id: "BAUG".to_string(),
+                }),
+            ])
+        );
+    }
+}
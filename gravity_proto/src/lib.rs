@@ -8,9 +8,24 @@ pub use cosmos_sdk_proto;
 pub mod gravity {
     include!("prost/gravity.v1.rs");
     include!("ethereum_claim.rs");
+    include!("checkpoint.rs");
+    include!("valset.rs");
+    include!("erc20_token.rs");
 }
+/// Legacy Amino JSON encoding of the Gravity messages
+#[cfg(feature = "amino")]
+pub mod amino;
+/// Ethereum address parsing and checksumming
+pub mod ethereum;
+/// Typed decoding of the Gravity module's ABCI events
+#[cfg(feature = "events")]
+pub mod events;
+/// Verification of the orchestrator signatures in valset, batch and logic call confirms
+#[cfg(feature = "signatures")]
+pub mod signatures;
 pub mod auction {
     include!("prost/auction.v1.rs");
+    include!("auction.rs");
 }
 /// note to future readers I have hand copied the RegisteredInterchainAccount file from ibc.applications.interchain_accounts.v1.rs which is compiled
 /// but not checked in into gaia.icaauth.v1.rs
@@ -28,3 +43,62 @@ pub mod gravity_test {
         }
     }
 }
+
+/// Registers every Gravity message along with those of cosmos_sdk_proto, the types an Any in the messages of this
+/// crate is encoded with the fields of rather than its bytes
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    cosmos_sdk_proto::proto_json::register_sdk_types(registry);
+    gravity::register_json_types(registry);
+    auction::register_json_types(registry);
+    gravity_test::gaia::globalfee::v1beta1::register_json_types(registry);
+    gravity_test::gaia::icaauth::v1::register_json_types(registry);
+}
+
+/// The Any encoding of this crate's messages, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub mod proto_json {
+    cosmos_sdk_proto::any_json_modules!(crate::register_json_types);
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::gravity::{Attestation, MsgBatchSendToEthClaim};
+    use serde_json::json;
+
+    #[test]
+    fn any_of_gravity_message() {
+        let claim = MsgBatchSendToEthClaim {
+            event_nonce: 3,
+            eth_block_height: 4,
+            batch_nonce: 5,
+            token_contract: "0x429881672B9AE42b8EbA0E26cD9C73711b891Ca5".to_string(),
+            orchestrator: "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8".to_string(),
+        };
+        let attestation = Attestation {
+            observed: true,
+            votes: vec![],
+            height: 10,
+            claim: Some(prost_types::Any::from_msg(&claim).unwrap()),
+        };
+        // the claim's fields are embedded without registering anything first
+        let expected = json!({
+            "observed": true,
+            "votes": [],
+            "height": "10",
+            "claim": {
+                "@type": "/gravity.v1.MsgBatchSendToEthClaim",
+                "eventNonce": "3",
+                "ethBlockHeight": "4",
+                "batchNonce": "5",
+                "tokenContract": "0x429881672B9AE42b8EbA0E26cD9C73711b891Ca5",
+                "orchestrator": "gravity1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg39d96h8",
+            },
+        });
+        assert_eq!(serde_json::to_value(&attestation).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<Attestation>(expected).unwrap(),
+            attestation
+        );
+    }
+}
@@ -2,21 +2,38 @@
 /// AuctionPeriod represents a period of auctions.
 /// An AuctionPeriod applies to as many auctionable tokens exist in the auction pool
 /// Note: see params for a list of non-auctionable tokens
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct AuctionPeriod {
     /// Block height at which the AuctionPeriod starts.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub start_block_height: u64,
     /// Block height at which the AuctionPeriod end.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub end_block_height: u64,
 }
+impl ::prost::Name for AuctionPeriod {
+    const NAME: &'static str = "AuctionPeriod";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.AuctionPeriod".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.AuctionPeriod".into()
+    }
+}
 /// Auction represents a single auction.
 /// An Auction has a unique identifier relative to its Auction Period Id , an amount being auctioned, a status, and a highest bid.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Auction {
     /// Unique identifier for the Auction.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub id: u64,
     /// Amount being auctioned.
     #[prost(message, optional, tag = "2")]
@@ -25,33 +42,73 @@ pub struct Auction {
     #[prost(message, optional, tag = "3")]
     pub highest_bid: ::core::option::Option<Bid>,
 }
+impl ::prost::Name for Auction {
+    const NAME: &'static str = "Auction";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.Auction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.Auction".into()
+    }
+}
 /// Bid represents a bid on an Auction.
 /// A Bid includes the identifier of the Auction, the amount of the bid, and the address of the bidder.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bid {
     /// Amount of the bid.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub bid_amount: u64,
     /// Address of the bidder.
     #[prost(string, tag = "2")]
     pub bidder_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Bid {
+    const NAME: &'static str = "Bid";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.Bid".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.Bid".into()
+    }
+}
 /// AuctionId enables easy storage and retrieval of the most recently used AuctionId
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct AuctionId {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub id: u64,
 }
+impl ::prost::Name for AuctionId {
+    const NAME: &'static str = "AuctionId";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.AuctionId".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.AuctionId".into()
+    }
+}
 /// Params defines the parameters for the GravityBridge auction module.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     /// AuctionLength is the number of blocks that the AuctionPeriod will be active
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub auction_length: u64,
     /// MinBidFee defines the required minimum fee that must be paid by bidders for their bid to be considered by the module.
     /// This fee is paid out to the auction pool. This fee is separate from the standard Cosmos Tx spam protection fee.
     /// This fee will not be charged unless a bid is successful.
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub min_bid_fee: u64,
     /// NonAuctionableTokens is a list of token denomss which should never be auctioned from the auction pool
     #[prost(string, repeated, tag = "3")]
@@ -64,7 +121,19 @@ pub struct Params {
     #[prost(bool, tag = "5")]
     pub enabled: bool,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.Params".into()
+    }
+}
 /// GenesisState defines the auction module's genesis state.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
@@ -74,29 +143,68 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "3")]
     pub active_auctions: ::prost::alloc::vec::Vec<Auction>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.GenesisState".into()
+    }
+}
 /// MsgBid is a message type for placing a bid on an auction with given `auction_id`
 /// `bidder` is the signer of the Msg
 /// `amount` is the native token amount locked by the auction module if the bid is accepted as the highest bid
 /// `bid_fee` is the native token amount sent to the auction pool, and should be at least equal to the min bid fee param
 ///
 /// Additionally, all bids must meet or exceed `min_bid_amount`
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgBid {
     /// ID of the auction to bid on
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub auction_id: u64,
     /// Address of the bidder
     #[prost(string, tag = "2")]
     pub bidder: ::prost::alloc::string::String,
     /// Amount of the bid
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub amount: u64,
     /// Fee amount
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub bid_fee: u64,
 }
+impl ::prost::Name for MsgBid {
+    const NAME: &'static str = "MsgBid";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.MsgBid".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.MsgBid".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgBidResponse {}
+impl ::prost::Name for MsgBidResponse {
+    const NAME: &'static str = "MsgBidResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.MsgBidResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.MsgBidResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -209,59 +317,218 @@ pub mod msg_client {
         }
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryParamsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryParamsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryAuctionPeriodRequest {}
+impl ::prost::Name for QueryAuctionPeriodRequest {
+    const NAME: &'static str = "QueryAuctionPeriodRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionPeriodRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionPeriodRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryAuctionPeriodResponse {
     #[prost(message, optional, tag = "1")]
     pub auction_period: ::core::option::Option<AuctionPeriod>,
 }
+impl ::prost::Name for QueryAuctionPeriodResponse {
+    const NAME: &'static str = "QueryAuctionPeriodResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionPeriodResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionPeriodResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryAuctionsRequest {}
+impl ::prost::Name for QueryAuctionsRequest {
+    const NAME: &'static str = "QueryAuctionsRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAuctionsResponse {
     #[prost(message, repeated, tag = "1")]
     pub auctions: ::prost::alloc::vec::Vec<Auction>,
 }
+impl ::prost::Name for QueryAuctionsResponse {
+    const NAME: &'static str = "QueryAuctionsResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryAuctionByIdRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub auction_id: u64,
 }
+impl ::prost::Name for QueryAuctionByIdRequest {
+    const NAME: &'static str = "QueryAuctionByIdRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionByIdRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionByIdRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAuctionByIdResponse {
     #[prost(message, optional, tag = "1")]
     pub auction: ::core::option::Option<Auction>,
 }
+impl ::prost::Name for QueryAuctionByIdResponse {
+    const NAME: &'static str = "QueryAuctionByIdResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionByIdResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionByIdResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAuctionByDenomRequest {
     #[prost(string, tag = "1")]
     pub auction_denom: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryAuctionByDenomRequest {
+    const NAME: &'static str = "QueryAuctionByDenomRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionByDenomRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionByDenomRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAuctionByDenomResponse {
     #[prost(message, optional, tag = "1")]
     pub auction: ::core::option::Option<Auction>,
 }
+impl ::prost::Name for QueryAuctionByDenomResponse {
+    const NAME: &'static str = "QueryAuctionByDenomResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionByDenomResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionByDenomResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllAuctionsByBidderRequest {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryAllAuctionsByBidderRequest {
+    const NAME: &'static str = "QueryAllAuctionsByBidderRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAllAuctionsByBidderRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAllAuctionsByBidderRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAllAuctionsByBidderResponse {
     #[prost(message, repeated, tag = "1")]
     pub auctions: ::prost::alloc::vec::Vec<Auction>,
 }
+impl ::prost::Name for QueryAllAuctionsByBidderResponse {
+    const NAME: &'static str = "QueryAllAuctionsByBidderResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAllAuctionsByBidderResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAllAuctionsByBidderResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryAuctionPoolRequest {}
+impl ::prost::Name for QueryAuctionPoolRequest {
+    const NAME: &'static str = "QueryAuctionPoolRequest";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionPoolRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionPoolRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAuctionPoolResponse {
     #[prost(string, tag = "1")]
@@ -269,6 +536,18 @@ pub struct QueryAuctionPoolResponse {
     #[prost(message, repeated, tag = "2")]
     pub balances: ::prost::alloc::vec::Vec<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
 }
+impl ::prost::Name for QueryAuctionPoolResponse {
+    const NAME: &'static str = "QueryAuctionPoolResponse";
+    const PACKAGE: &'static str = "auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "auction.v1.QueryAuctionPoolResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/auction.v1.QueryAuctionPoolResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -535,3 +814,710 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        async fn bid(
+            &self,
+            request: tonic::Request<super::MsgBid>,
+        ) -> std::result::Result<tonic::Response<super::MsgBidResponse>, tonic::Status>;
+    }
+    /// Msg defines the state transitions possible within auction
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/auction.v1.Msg/Bid" => {
+                    #[allow(non_camel_case_types)]
+                    struct BidSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgBid>
+                    for BidSvc<T> {
+                        type Response = super::MsgBidResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgBid>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::bid(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BidSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "auction.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Params returns the current module parameters (decided by governance)
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        /// AuctionPeriod returns the current active auction period, or a future one if no period is active
+        async fn auction_period(
+            &self,
+            request: tonic::Request<super::QueryAuctionPeriodRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAuctionPeriodResponse>,
+            tonic::Status,
+        >;
+        /// Auctions returns all current active auctions
+        async fn auctions(
+            &self,
+            request: tonic::Request<super::QueryAuctionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAuctionsResponse>,
+            tonic::Status,
+        >;
+        /// AuctionById returns an open auction given by its `id`
+        async fn auction_by_id(
+            &self,
+            request: tonic::Request<super::QueryAuctionByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAuctionByIdResponse>,
+            tonic::Status,
+        >;
+        /// AuctionByDenom returns an open auction given by its `denom`
+        async fn auction_by_denom(
+            &self,
+            request: tonic::Request<super::QueryAuctionByDenomRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAuctionByDenomResponse>,
+            tonic::Status,
+        >;
+        /// AllAuctionsByBidder returns all open auctions with the given highest bidder address
+        async fn all_auctions_by_bidder(
+            &self,
+            request: tonic::Request<super::QueryAllAuctionsByBidderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAllAuctionsByBidderResponse>,
+            tonic::Status,
+        >;
+        /// AuctionPool returns the auction pool account address and the tokens which will be up for auction next,
+        /// (but does not return any amounts from auctions with no bidder)
+        async fn auction_pool(
+            &self,
+            request: tonic::Request<super::QueryAuctionPoolRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAuctionPoolResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service.
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/auction.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auction.v1.Query/AuctionPeriod" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuctionPeriodSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAuctionPeriodRequest>
+                    for AuctionPeriodSvc<T> {
+                        type Response = super::QueryAuctionPeriodResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAuctionPeriodRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::auction_period(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AuctionPeriodSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auction.v1.Query/Auctions" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuctionsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAuctionsRequest>
+                    for AuctionsSvc<T> {
+                        type Response = super::QueryAuctionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAuctionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::auctions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AuctionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auction.v1.Query/AuctionById" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuctionByIdSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAuctionByIdRequest>
+                    for AuctionByIdSvc<T> {
+                        type Response = super::QueryAuctionByIdResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAuctionByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::auction_by_id(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AuctionByIdSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auction.v1.Query/AuctionByDenom" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuctionByDenomSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAuctionByDenomRequest>
+                    for AuctionByDenomSvc<T> {
+                        type Response = super::QueryAuctionByDenomResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAuctionByDenomRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::auction_by_denom(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AuctionByDenomSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auction.v1.Query/AllAuctionsByBidder" => {
+                    #[allow(non_camel_case_types)]
+                    struct AllAuctionsByBidderSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAllAuctionsByBidderRequest>
+                    for AllAuctionsByBidderSvc<T> {
+                        type Response = super::QueryAllAuctionsByBidderResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryAllAuctionsByBidderRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::all_auctions_by_bidder(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AllAuctionsByBidderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/auction.v1.Query/AuctionPool" => {
+                    #[allow(non_camel_case_types)]
+                    struct AuctionPoolSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAuctionPoolRequest>
+                    for AuctionPoolSvc<T> {
+                        type Response = super::QueryAuctionPoolResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAuctionPoolRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::auction_pool(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AuctionPoolSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "auction.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<AuctionPeriod>();
+    registry.register::<Auction>();
+    registry.register::<Bid>();
+    registry.register::<AuctionId>();
+    registry.register::<Params>();
+    registry.register::<GenesisState>();
+    registry.register::<MsgBid>();
+    registry.register::<MsgBidResponse>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryAuctionPeriodRequest>();
+    registry.register::<QueryAuctionPeriodResponse>();
+    registry.register::<QueryAuctionsRequest>();
+    registry.register::<QueryAuctionsResponse>();
+    registry.register::<QueryAuctionByIdRequest>();
+    registry.register::<QueryAuctionByIdResponse>();
+    registry.register::<QueryAuctionByDenomRequest>();
+    registry.register::<QueryAuctionByDenomResponse>();
+    registry.register::<QueryAllAuctionsByBidderRequest>();
+    registry.register::<QueryAllAuctionsByBidderResponse>();
+    registry.register::<QueryAuctionPoolRequest>();
+    registry.register::<QueryAuctionPoolResponse>();
+}
@@ -14,6 +14,8 @@
 /// the key in which the attestation is stored is keyed on the exact details of the claim
 /// but there is no reason to store those exact details becuause the next message sender
 /// will kindly provide you with them.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Attestation {
     #[prost(bool, tag = "1")]
@@ -21,15 +23,29 @@ pub struct Attestation {
     #[prost(string, repeated, tag = "2")]
     pub votes: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(message, optional, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub claim: ::core::option::Option<::prost_types::Any>,
 }
+impl ::prost::Name for Attestation {
+    const NAME: &'static str = "Attestation";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.Attestation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.Attestation".into()
+    }
+}
 /// ERC20Token unique identifier for an Ethereum ERC20 token.
 /// CONTRACT:
 /// The contract address on ETH of the token, this could be a Cosmos
 /// originated token, if so it will be the ERC20 address of the representation
 /// (note: developers should look up the token symbol using the address on ETH to display for UI)
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Erc20Token {
     #[prost(string, tag = "1")]
@@ -37,6 +53,18 @@ pub struct Erc20Token {
     #[prost(string, tag = "2")]
     pub amount: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Erc20Token {
+    const NAME: &'static str = "ERC20Token";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.ERC20Token".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.ERC20Token".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventObservation {
     #[prost(string, tag = "1")]
@@ -50,6 +78,18 @@ pub struct EventObservation {
     #[prost(string, tag = "5")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventObservation {
+    const NAME: &'static str = "EventObservation";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventObservation".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventObservation".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventInvalidSendToCosmosReceiver {
     #[prost(string, tag = "1")]
@@ -61,6 +101,18 @@ pub struct EventInvalidSendToCosmosReceiver {
     #[prost(string, tag = "4")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventInvalidSendToCosmosReceiver {
+    const NAME: &'static str = "EventInvalidSendToCosmosReceiver";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventInvalidSendToCosmosReceiver".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventInvalidSendToCosmosReceiver".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSendToCosmos {
     #[prost(string, tag = "1")]
@@ -70,6 +122,18 @@ pub struct EventSendToCosmos {
     #[prost(string, tag = "3")]
     pub token: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSendToCosmos {
+    const NAME: &'static str = "EventSendToCosmos";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSendToCosmos".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSendToCosmos".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSendToCosmosLocal {
     #[prost(string, tag = "1")]
@@ -81,6 +145,18 @@ pub struct EventSendToCosmosLocal {
     #[prost(string, tag = "4")]
     pub amount: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSendToCosmosLocal {
+    const NAME: &'static str = "EventSendToCosmosLocal";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSendToCosmosLocal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSendToCosmosLocal".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSendToCosmosPendingIbcAutoForward {
     #[prost(string, tag = "1")]
@@ -94,6 +170,18 @@ pub struct EventSendToCosmosPendingIbcAutoForward {
     #[prost(string, tag = "5")]
     pub channel: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSendToCosmosPendingIbcAutoForward {
+    const NAME: &'static str = "EventSendToCosmosPendingIbcAutoForward";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSendToCosmosPendingIbcAutoForward".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSendToCosmosPendingIbcAutoForward".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSendToCosmosExecutedIbcAutoForward {
     #[prost(string, tag = "1")]
@@ -111,6 +199,16 @@ pub struct EventSendToCosmosExecutedIbcAutoForward {
     #[prost(string, tag = "7")]
     pub timeout_height: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSendToCosmosExecutedIbcAutoForward {
+    const NAME: &'static str = "EventSendToCosmosExecutedIbcAutoForward";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSendToCosmosExecutedIbcAutoForward".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSendToCosmosExecutedIbcAutoForward".into()
+    }
+}
 /// ClaimType is the cosmos type of an event from the counterpart chain that can
 /// be handled
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -157,24 +255,51 @@ impl ClaimType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for ClaimType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// OutgoingTxBatch represents a batch of transactions going from gravity to ETH
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OutgoingTxBatch {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub batch_nonce: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub batch_timeout: u64,
     #[prost(message, repeated, tag = "3")]
     pub transactions: ::prost::alloc::vec::Vec<OutgoingTransferTx>,
     #[prost(string, tag = "4")]
     pub token_contract: ::prost::alloc::string::String,
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub cosmos_block_created: u64,
 }
+impl ::prost::Name for OutgoingTxBatch {
+    const NAME: &'static str = "OutgoingTxBatch";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.OutgoingTxBatch".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.OutgoingTxBatch".into()
+    }
+}
 /// OutgoingTransferTx represents an individual send from gravity to ETH
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OutgoingTransferTx {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub id: u64,
     #[prost(string, tag = "2")]
     pub sender: ::prost::alloc::string::String,
@@ -185,7 +310,19 @@ pub struct OutgoingTransferTx {
     #[prost(message, optional, tag = "5")]
     pub erc20_fee: ::core::option::Option<Erc20Token>,
 }
+impl ::prost::Name for OutgoingTransferTx {
+    const NAME: &'static str = "OutgoingTransferTx";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.OutgoingTransferTx".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.OutgoingTransferTx".into()
+    }
+}
 /// OutgoingLogicCall represents an individual logic call from gravity to ETH
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OutgoingLogicCall {
     #[prost(message, repeated, tag = "1")]
@@ -195,16 +332,33 @@ pub struct OutgoingLogicCall {
     #[prost(string, tag = "3")]
     pub logic_contract_address: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub payload: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub timeout: u64,
     #[prost(bytes = "vec", tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub invalidation_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub invalidation_nonce: u64,
     #[prost(uint64, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub cosmos_block_created: u64,
 }
+impl ::prost::Name for OutgoingLogicCall {
+    const NAME: &'static str = "OutgoingLogicCall";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.OutgoingLogicCall".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.OutgoingLogicCall".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventOutgoingBatchCanceled {
     #[prost(string, tag = "1")]
@@ -216,6 +370,18 @@ pub struct EventOutgoingBatchCanceled {
     #[prost(string, tag = "4")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventOutgoingBatchCanceled {
+    const NAME: &'static str = "EventOutgoingBatchCanceled";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventOutgoingBatchCanceled".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventOutgoingBatchCanceled".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventOutgoingBatch {
     #[prost(string, tag = "1")]
@@ -227,6 +393,16 @@ pub struct EventOutgoingBatch {
     #[prost(string, tag = "4")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventOutgoingBatch {
+    const NAME: &'static str = "EventOutgoingBatch";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventOutgoingBatch".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventOutgoingBatch".into()
+    }
+}
 /// SignType defines messages that have been signed by an orchestrator
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -268,24 +444,50 @@ impl SignType {
         }
     }
 }
+#[cfg(feature = "serde")]
+impl cosmos_sdk_proto::proto_json::Enumeration for SignType {
+    fn proto_name(&self) -> &'static str {
+        self.as_str_name()
+    }
+    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {
+        Self::from_str_name(value)
+    }
+}
 /// BridgeValidator represents a validator's ETH address and its power
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BridgeValidator {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub power: u64,
     #[prost(string, tag = "2")]
     pub ethereum_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for BridgeValidator {
+    const NAME: &'static str = "BridgeValidator";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.BridgeValidator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.BridgeValidator".into()
+    }
+}
 /// Valset is the Ethereum Bridge Multsig Set, each gravity validator also
 /// maintains an ETH key to sign messages, these are used to check signatures on
 /// ETH because of the significant gas savings
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Valset {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     #[prost(message, repeated, tag = "2")]
     pub members: ::prost::alloc::vec::Vec<BridgeValidator>,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     #[prost(string, tag = "4")]
     pub reward_amount: ::prost::alloc::string::String,
@@ -293,20 +495,46 @@ pub struct Valset {
     #[prost(string, tag = "5")]
     pub reward_token: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Valset {
+    const NAME: &'static str = "Valset";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.Valset".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.Valset".into()
+    }
+}
 /// LastObservedEthereumBlockHeight stores the last observed
 /// Ethereum block height along with the Cosmos block height that
 /// it was observed at. These two numbers can be used to project
 /// outward and always produce batches with timeouts in the future
 /// even if no Ethereum block height has been relayed for a long time
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct LastObservedEthereumBlockHeight {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub cosmos_block_height: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub ethereum_block_height: u64,
 }
+impl ::prost::Name for LastObservedEthereumBlockHeight {
+    const NAME: &'static str = "LastObservedEthereumBlockHeight";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.LastObservedEthereumBlockHeight".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.LastObservedEthereumBlockHeight".into()
+    }
+}
 /// This records the relationship between an ERC20 token and the denom
 /// of the corresponding Cosmos originated asset
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Erc20ToDenom {
     #[prost(string, tag = "1")]
@@ -314,12 +542,24 @@ pub struct Erc20ToDenom {
     #[prost(string, tag = "2")]
     pub denom: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Erc20ToDenom {
+    const NAME: &'static str = "ERC20ToDenom";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.ERC20ToDenom".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.ERC20ToDenom".into()
+    }
+}
 /// UnhaltBridgeProposal defines a custom governance proposal useful for restoring
 /// the bridge after a oracle disagreement. Once this proposal is passed bridge state will roll back events
 /// to the nonce provided in target_nonce if and only if those events have not yet been observed (executed on the Cosmos chain). This allows for easy
 /// handling of cases where for example an Ethereum hardfork has occured and more than 1/3 of the vlaidtor set
 /// disagrees with the rest. Normally this would require a chain halt, manual genesis editing and restar to resolve
 /// with this feature a governance proposal can be used instead
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UnhaltBridgeProposal {
     #[prost(string, tag = "1")]
@@ -327,12 +567,25 @@ pub struct UnhaltBridgeProposal {
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub target_nonce: u64,
 }
+impl ::prost::Name for UnhaltBridgeProposal {
+    const NAME: &'static str = "UnhaltBridgeProposal";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.UnhaltBridgeProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.UnhaltBridgeProposal".into()
+    }
+}
 /// AirdropProposal defines a custom governance proposal type that allows an airdrop to occur in a decentralized
 /// fashion. A list of destination addresses and an amount per airdrop recipient is provided. The funds for this
 /// airdrop are removed from the Community Pool, if the community pool does not have sufficient funding to perform
 /// the airdrop to all provided recipients nothing will occur
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AirdropProposal {
     #[prost(string, tag = "1")]
@@ -342,10 +595,22 @@ pub struct AirdropProposal {
     #[prost(string, tag = "3")]
     pub denom: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub recipients: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, repeated, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64_vec"))]
     pub amounts: ::prost::alloc::vec::Vec<u64>,
 }
+impl ::prost::Name for AirdropProposal {
+    const NAME: &'static str = "AirdropProposal";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.AirdropProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.AirdropProposal".into()
+    }
+}
 /// IBCMetadataProposal defines a custom governance proposal type that allows governance to set the
 /// metadata for an IBC token, this will allow Gravity to deploy an ERC20 representing this token on
 /// Ethereum
@@ -355,6 +620,8 @@ pub struct AirdropProposal {
 /// Display: the token display name (only used on Cosmos to decide ERC20 Decimals)
 /// Deicmals: the decimals for the display unit
 /// ibc_denom is the denom of the token in question on this chain
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IbcMetadataProposal {
     #[prost(string, tag = "1")]
@@ -366,8 +633,20 @@ pub struct IbcMetadataProposal {
     #[prost(string, tag = "4")]
     pub ibc_denom: ::prost::alloc::string::String,
 }
+impl ::prost::Name for IbcMetadataProposal {
+    const NAME: &'static str = "IBCMetadataProposal";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.IBCMetadataProposal".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.IBCMetadataProposal".into()
+    }
+}
 /// PendingIbcAutoForward represents a SendToCosmos transaction with a foreign CosmosReceiver which will be added to the
 /// PendingIbcAutoForward queue in attestation_handler and sent over IBC on some submission of a MsgExecuteIbcAutoForwards
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PendingIbcAutoForward {
     /// the destination address. sdk.AccAddress does not preserve foreign prefixes
@@ -381,8 +660,19 @@ pub struct PendingIbcAutoForward {
     pub ibc_channel: ::prost::alloc::string::String,
     /// the EventNonce from the MsgSendToCosmosClaim, used for ordering the queue
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
 }
+impl ::prost::Name for PendingIbcAutoForward {
+    const NAME: &'static str = "PendingIbcAutoForward";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.PendingIbcAutoForward".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.PendingIbcAutoForward".into()
+    }
+}
 /// MsgSetOrchestratorAddress
 /// this message allows validators to delegate their voting responsibilities
 /// to a given key. This key is then used as an optional authentication method
@@ -396,6 +686,8 @@ pub struct PendingIbcAutoForward {
 /// ETH_ADDRESS
 /// This is a hex encoded 0x Ethereum public key that will be used by this validator
 /// on Ethereum
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSetOrchestratorAddress {
     #[prost(string, tag = "1")]
@@ -405,8 +697,30 @@ pub struct MsgSetOrchestratorAddress {
     #[prost(string, tag = "3")]
     pub eth_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgSetOrchestratorAddress {
+    const NAME: &'static str = "MsgSetOrchestratorAddress";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSetOrchestratorAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSetOrchestratorAddress".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSetOrchestratorAddressResponse {}
+impl ::prost::Name for MsgSetOrchestratorAddressResponse {
+    const NAME: &'static str = "MsgSetOrchestratorAddressResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSetOrchestratorAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSetOrchestratorAddressResponse".into()
+    }
+}
 /// MsgValsetConfirm
 /// this is the message sent by the validators when they wish to submit their
 /// signatures over the validator set at a given block height. A validator must
@@ -422,9 +736,12 @@ pub struct MsgSetOrchestratorAddressResponse {}
 /// signatures it is then possible for anyone to view these signatures in the
 /// chain store and submit them to Ethereum to update the validator set
 /// -------------
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgValsetConfirm {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     #[prost(string, tag = "2")]
     pub orchestrator: ::prost::alloc::string::String,
@@ -433,8 +750,30 @@ pub struct MsgValsetConfirm {
     #[prost(string, tag = "4")]
     pub signature: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgValsetConfirm {
+    const NAME: &'static str = "MsgValsetConfirm";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgValsetConfirm".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgValsetConfirm".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgValsetConfirmResponse {}
+impl ::prost::Name for MsgValsetConfirmResponse {
+    const NAME: &'static str = "MsgValsetConfirmResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgValsetConfirmResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgValsetConfirmResponse".into()
+    }
+}
 /// MsgSendToEth
 /// This is the message that a user calls when they want to bridge an asset
 /// it will later be removed when it is included in a batch and successfully
@@ -451,6 +790,8 @@ pub struct MsgValsetConfirmResponse {}
 /// the fee paid to the chain for handling the request, which must be a
 /// certain percentage of the AMOUNT, as determined by governance.
 /// This Msg will be rejected if CHAIN_FEE is insufficient.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSendToEth {
     #[prost(string, tag = "1")]
@@ -464,8 +805,30 @@ pub struct MsgSendToEth {
     #[prost(message, optional, tag = "5")]
     pub chain_fee: ::core::option::Option<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
 }
+impl ::prost::Name for MsgSendToEth {
+    const NAME: &'static str = "MsgSendToEth";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSendToEth".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSendToEth".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSendToEthResponse {}
+impl ::prost::Name for MsgSendToEthResponse {
+    const NAME: &'static str = "MsgSendToEthResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSendToEthResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSendToEthResponse".into()
+    }
+}
 /// MsgRequestBatch
 /// this is a message anyone can send that requests a batch of transactions to
 /// send across the bridge be created for whatever block height this message is
@@ -475,6 +838,8 @@ pub struct MsgSendToEthResponse {}
 /// batch, sign it, submit the signatures with a MsgConfirmBatch before a relayer
 /// can finally submit the batch
 /// -------------
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgRequestBatch {
     #[prost(string, tag = "1")]
@@ -482,8 +847,30 @@ pub struct MsgRequestBatch {
     #[prost(string, tag = "2")]
     pub denom: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgRequestBatch {
+    const NAME: &'static str = "MsgRequestBatch";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgRequestBatch".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgRequestBatch".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgRequestBatchResponse {}
+impl ::prost::Name for MsgRequestBatchResponse {
+    const NAME: &'static str = "MsgRequestBatchResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgRequestBatchResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgRequestBatchResponse".into()
+    }
+}
 /// MsgConfirmBatch
 /// When validators observe a MsgRequestBatch they form a batch by ordering
 /// transactions currently in the txqueue in order of highest to lowest fee,
@@ -492,9 +879,12 @@ pub struct MsgRequestBatchResponse {}
 /// (TODO determine this without nondeterminism) This message includes the batch
 /// as well as an Ethereum signature over this batch by the validator
 /// -------------
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgConfirmBatch {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     #[prost(string, tag = "2")]
     pub token_contract: ::prost::alloc::string::String,
@@ -505,8 +895,30 @@ pub struct MsgConfirmBatch {
     #[prost(string, tag = "5")]
     pub signature: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgConfirmBatch {
+    const NAME: &'static str = "MsgConfirmBatch";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgConfirmBatch".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgConfirmBatch".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgConfirmBatchResponse {}
+impl ::prost::Name for MsgConfirmBatchResponse {
+    const NAME: &'static str = "MsgConfirmBatchResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgConfirmBatchResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgConfirmBatchResponse".into()
+    }
+}
 /// MsgConfirmLogicCall
 /// When validators observe a MsgRequestBatch they form a batch by ordering
 /// transactions currently in the txqueue in order of highest to lowest fee,
@@ -515,11 +927,14 @@ pub struct MsgConfirmBatchResponse {}
 /// (TODO determine this without nondeterminism) This message includes the batch
 /// as well as an Ethereum signature over this batch by the validator
 /// -------------
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgConfirmLogicCall {
     #[prost(string, tag = "1")]
     pub invalidation_id: ::prost::alloc::string::String,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub invalidation_nonce: u64,
     #[prost(string, tag = "3")]
     pub eth_signer: ::prost::alloc::string::String,
@@ -528,18 +943,44 @@ pub struct MsgConfirmLogicCall {
     #[prost(string, tag = "5")]
     pub signature: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgConfirmLogicCall {
+    const NAME: &'static str = "MsgConfirmLogicCall";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgConfirmLogicCall".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgConfirmLogicCall".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgConfirmLogicCallResponse {}
+impl ::prost::Name for MsgConfirmLogicCallResponse {
+    const NAME: &'static str = "MsgConfirmLogicCallResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgConfirmLogicCallResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgConfirmLogicCallResponse".into()
+    }
+}
 /// MsgSendToCosmosClaim
 /// When more than 66% of the active validator set has
 /// claimed to have seen the deposit enter the ethereum blockchain coins are
 /// issued to the Cosmos address in question
 /// -------------
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSendToCosmosClaim {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub eth_block_height: u64,
     #[prost(string, tag = "3")]
     pub token_contract: ::prost::alloc::string::String,
@@ -552,48 +993,126 @@ pub struct MsgSendToCosmosClaim {
     #[prost(string, tag = "7")]
     pub orchestrator: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgSendToCosmosClaim {
+    const NAME: &'static str = "MsgSendToCosmosClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSendToCosmosClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSendToCosmosClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSendToCosmosClaimResponse {}
+impl ::prost::Name for MsgSendToCosmosClaimResponse {
+    const NAME: &'static str = "MsgSendToCosmosClaimResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSendToCosmosClaimResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSendToCosmosClaimResponse".into()
+    }
+}
 /// MsgExecuteIbcAutoForwards
 /// Prompts the forwarding of Pending IBC Auto-Forwards in the queue
 /// The Pending forwards will be executed in order of their original SendToCosmos.EventNonce
 /// The funds in the queue will be sent to a local gravity-prefixed address if IBC transfer is not possible
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgExecuteIbcAutoForwards {
     /// How many queued forwards to clear, be careful about gas limits
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub forwards_to_clear: u64,
     /// This message's sender
     #[prost(string, tag = "2")]
     pub executor: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgExecuteIbcAutoForwards {
+    const NAME: &'static str = "MsgExecuteIbcAutoForwards";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgExecuteIbcAutoForwards".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgExecuteIbcAutoForwards".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgExecuteIbcAutoForwardsResponse {}
+impl ::prost::Name for MsgExecuteIbcAutoForwardsResponse {
+    const NAME: &'static str = "MsgExecuteIbcAutoForwardsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgExecuteIbcAutoForwardsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgExecuteIbcAutoForwardsResponse".into()
+    }
+}
 /// BatchSendToEthClaim claims that a batch of send to eth
 /// operations on the bridge contract was executed.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgBatchSendToEthClaim {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub eth_block_height: u64,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub batch_nonce: u64,
     #[prost(string, tag = "4")]
     pub token_contract: ::prost::alloc::string::String,
     #[prost(string, tag = "5")]
     pub orchestrator: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgBatchSendToEthClaim {
+    const NAME: &'static str = "MsgBatchSendToEthClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgBatchSendToEthClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgBatchSendToEthClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgBatchSendToEthClaimResponse {}
+impl ::prost::Name for MsgBatchSendToEthClaimResponse {
+    const NAME: &'static str = "MsgBatchSendToEthClaimResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgBatchSendToEthClaimResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgBatchSendToEthClaimResponse".into()
+    }
+}
 /// ERC20DeployedClaim allows the Cosmos module
 /// to learn about an ERC20 that someone deployed
 /// to represent a Cosmos asset
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgErc20DeployedClaim {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub eth_block_height: u64,
     #[prost(string, tag = "3")]
     pub cosmos_denom: ::prost::alloc::string::String,
@@ -604,38 +1123,94 @@ pub struct MsgErc20DeployedClaim {
     #[prost(string, tag = "6")]
     pub symbol: ::prost::alloc::string::String,
     #[prost(uint64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub decimals: u64,
     #[prost(string, tag = "8")]
     pub orchestrator: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgErc20DeployedClaim {
+    const NAME: &'static str = "MsgERC20DeployedClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgERC20DeployedClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgERC20DeployedClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgErc20DeployedClaimResponse {}
+impl ::prost::Name for MsgErc20DeployedClaimResponse {
+    const NAME: &'static str = "MsgERC20DeployedClaimResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgERC20DeployedClaimResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgERC20DeployedClaimResponse".into()
+    }
+}
 /// This informs the Cosmos module that a logic
 /// call has been executed
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgLogicCallExecutedClaim {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub eth_block_height: u64,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub invalidation_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub invalidation_nonce: u64,
     #[prost(string, tag = "5")]
     pub orchestrator: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgLogicCallExecutedClaim {
+    const NAME: &'static str = "MsgLogicCallExecutedClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgLogicCallExecutedClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgLogicCallExecutedClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgLogicCallExecutedClaimResponse {}
+impl ::prost::Name for MsgLogicCallExecutedClaimResponse {
+    const NAME: &'static str = "MsgLogicCallExecutedClaimResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgLogicCallExecutedClaimResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgLogicCallExecutedClaimResponse".into()
+    }
+}
 /// This informs the Cosmos module that a validator
 /// set has been updated.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgValsetUpdatedClaim {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub valset_nonce: u64,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub eth_block_height: u64,
     #[prost(message, repeated, tag = "4")]
     pub members: ::prost::alloc::vec::Vec<BridgeValidator>,
@@ -646,35 +1221,109 @@ pub struct MsgValsetUpdatedClaim {
     #[prost(string, tag = "7")]
     pub orchestrator: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgValsetUpdatedClaim {
+    const NAME: &'static str = "MsgValsetUpdatedClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgValsetUpdatedClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgValsetUpdatedClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgValsetUpdatedClaimResponse {}
+impl ::prost::Name for MsgValsetUpdatedClaimResponse {
+    const NAME: &'static str = "MsgValsetUpdatedClaimResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgValsetUpdatedClaimResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgValsetUpdatedClaimResponse".into()
+    }
+}
 /// This call allows the sender (and only the sender)
 /// to cancel a given MsgSendToEth and recieve a refund
 /// of the tokens
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgCancelSendToEth {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub transaction_id: u64,
     #[prost(string, tag = "2")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgCancelSendToEth {
+    const NAME: &'static str = "MsgCancelSendToEth";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgCancelSendToEth".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgCancelSendToEth".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgCancelSendToEthResponse {}
+impl ::prost::Name for MsgCancelSendToEthResponse {
+    const NAME: &'static str = "MsgCancelSendToEthResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgCancelSendToEthResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgCancelSendToEthResponse".into()
+    }
+}
 /// This call allows anyone to submit evidence that a
 /// validator has signed a valset, batch, or logic call that never
 /// existed on the Cosmos chain.
 /// Subject contains the batch, valset, or logic call.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MsgSubmitBadSignatureEvidence {
     #[prost(message, optional, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::proto_json::any_opt"))]
     pub subject: ::core::option::Option<::prost_types::Any>,
     #[prost(string, tag = "2")]
     pub signature: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub sender: ::prost::alloc::string::String,
 }
+impl ::prost::Name for MsgSubmitBadSignatureEvidence {
+    const NAME: &'static str = "MsgSubmitBadSignatureEvidence";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSubmitBadSignatureEvidence".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSubmitBadSignatureEvidence".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct MsgSubmitBadSignatureEvidenceResponse {}
+impl ::prost::Name for MsgSubmitBadSignatureEvidenceResponse {
+    const NAME: &'static str = "MsgSubmitBadSignatureEvidenceResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.MsgSubmitBadSignatureEvidenceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.MsgSubmitBadSignatureEvidenceResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSetOperatorAddress {
     #[prost(string, tag = "1")]
@@ -682,6 +1331,18 @@ pub struct EventSetOperatorAddress {
     #[prost(string, tag = "2")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSetOperatorAddress {
+    const NAME: &'static str = "EventSetOperatorAddress";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSetOperatorAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSetOperatorAddress".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventValsetConfirmKey {
     #[prost(string, tag = "1")]
@@ -689,6 +1350,18 @@ pub struct EventValsetConfirmKey {
     #[prost(string, tag = "2")]
     pub key: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventValsetConfirmKey {
+    const NAME: &'static str = "EventValsetConfirmKey";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventValsetConfirmKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventValsetConfirmKey".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBatchCreated {
     #[prost(string, tag = "1")]
@@ -696,6 +1369,18 @@ pub struct EventBatchCreated {
     #[prost(string, tag = "2")]
     pub batch_nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventBatchCreated {
+    const NAME: &'static str = "EventBatchCreated";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventBatchCreated".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventBatchCreated".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBatchConfirmKey {
     #[prost(string, tag = "1")]
@@ -703,11 +1388,35 @@ pub struct EventBatchConfirmKey {
     #[prost(string, tag = "2")]
     pub batch_confirm_key: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventBatchConfirmKey {
+    const NAME: &'static str = "EventBatchConfirmKey";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventBatchConfirmKey".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventBatchConfirmKey".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBatchSendToEthClaim {
     #[prost(string, tag = "1")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventBatchSendToEthClaim {
+    const NAME: &'static str = "EventBatchSendToEthClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventBatchSendToEthClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventBatchSendToEthClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventClaim {
     #[prost(string, tag = "1")]
@@ -717,6 +1426,18 @@ pub struct EventClaim {
     #[prost(string, tag = "3")]
     pub attestation_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventClaim {
+    const NAME: &'static str = "EventClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBadSignatureEvidence {
     #[prost(string, tag = "1")]
@@ -726,6 +1447,18 @@ pub struct EventBadSignatureEvidence {
     #[prost(string, tag = "3")]
     pub bad_eth_signature_subject: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventBadSignatureEvidence {
+    const NAME: &'static str = "EventBadSignatureEvidence";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventBadSignatureEvidence".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventBadSignatureEvidence".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventErc20DeployedClaim {
     #[prost(string, tag = "1")]
@@ -733,11 +1466,35 @@ pub struct EventErc20DeployedClaim {
     #[prost(string, tag = "2")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventErc20DeployedClaim {
+    const NAME: &'static str = "EventERC20DeployedClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventERC20DeployedClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventERC20DeployedClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventValsetUpdatedClaim {
     #[prost(string, tag = "1")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventValsetUpdatedClaim {
+    const NAME: &'static str = "EventValsetUpdatedClaim";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventValsetUpdatedClaim".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventValsetUpdatedClaim".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventMultisigUpdateRequest {
     #[prost(string, tag = "1")]
@@ -749,6 +1506,18 @@ pub struct EventMultisigUpdateRequest {
     #[prost(string, tag = "4")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventMultisigUpdateRequest {
+    const NAME: &'static str = "EventMultisigUpdateRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventMultisigUpdateRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventMultisigUpdateRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventOutgoingLogicCallCanceled {
     #[prost(string, tag = "1")]
@@ -756,6 +1525,18 @@ pub struct EventOutgoingLogicCallCanceled {
     #[prost(string, tag = "2")]
     pub logic_call_invalidation_nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventOutgoingLogicCallCanceled {
+    const NAME: &'static str = "EventOutgoingLogicCallCanceled";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventOutgoingLogicCallCanceled".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventOutgoingLogicCallCanceled".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSignatureSlashing {
     #[prost(string, tag = "1")]
@@ -763,6 +1544,18 @@ pub struct EventSignatureSlashing {
     #[prost(string, tag = "2")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSignatureSlashing {
+    const NAME: &'static str = "EventSignatureSlashing";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSignatureSlashing".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSignatureSlashing".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventOutgoingTxId {
     #[prost(string, tag = "1")]
@@ -770,6 +1563,18 @@ pub struct EventOutgoingTxId {
     #[prost(string, tag = "2")]
     pub tx_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventOutgoingTxId {
+    const NAME: &'static str = "EventOutgoingTxId";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventOutgoingTxId".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventOutgoingTxId".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSendToEthFeeCollected {
     #[prost(string, tag = "1")]
@@ -779,6 +1584,18 @@ pub struct EventSendToEthFeeCollected {
     #[prost(string, tag = "3")]
     pub fee_amount: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventSendToEthFeeCollected {
+    const NAME: &'static str = "EventSendToEthFeeCollected";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventSendToEthFeeCollected".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventSendToEthFeeCollected".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod msg_client {
     #![allow(
@@ -1246,6 +2063,8 @@ pub mod msg_client {
 ///
 /// Specifies what fraction of the SendToEth `chain_fee` amount should go to the auction pool.
 /// e.g. "0.5" gives a 50% auction pool / staker split while "0.9" would cause 90% of the fee to go to the pool
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Params {
     #[prost(string, tag = "1")]
@@ -1255,28 +2074,40 @@ pub struct Params {
     #[prost(string, tag = "4")]
     pub bridge_ethereum_address: ::prost::alloc::string::String,
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub bridge_chain_id: u64,
     #[prost(uint64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub signed_valsets_window: u64,
     #[prost(uint64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub signed_batches_window: u64,
     #[prost(uint64, tag = "8")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub signed_logic_calls_window: u64,
     #[prost(uint64, tag = "9")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub target_batch_timeout: u64,
     #[prost(uint64, tag = "10")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub average_block_time: u64,
     #[prost(uint64, tag = "11")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub average_ethereum_block_time: u64,
     #[prost(bytes = "vec", tag = "12")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub slash_fraction_valset: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "13")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub slash_fraction_batch: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "14")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub slash_fraction_logic_call: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "15")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub unbond_slashing_valsets_window: u64,
     #[prost(bytes = "vec", tag = "16")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub slash_fraction_bad_eth_signature: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "17")]
     pub valset_reward: ::core::option::Option<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>,
@@ -1287,11 +2118,24 @@ pub struct Params {
     #[prost(string, repeated, tag = "19")]
     pub ethereum_blacklist: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
     #[prost(uint64, tag = "20")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub min_chain_fee_basis_points: u64,
     #[prost(string, tag = "21")]
     pub chain_fee_auction_pool_fraction: ::prost::alloc::string::String,
 }
+impl ::prost::Name for Params {
+    const NAME: &'static str = "Params";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.Params".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.Params".into()
+    }
+}
 /// GenesisState struct, containing all persistant data required by the Gravity module
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisState {
     #[prost(message, optional, tag = "1")]
@@ -1321,41 +2165,85 @@ pub struct GenesisState {
     #[prost(message, repeated, tag = "13")]
     pub pending_ibc_auto_forwards: ::prost::alloc::vec::Vec<PendingIbcAutoForward>,
 }
+impl ::prost::Name for GenesisState {
+    const NAME: &'static str = "GenesisState";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.GenesisState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.GenesisState".into()
+    }
+}
 /// GravityCounters contains the many noces and counters required to maintain the bridge state in the genesis
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GravityNonces {
     /// the nonce of the last generated validator set
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub latest_valset_nonce: u64,
     /// the last observed Gravity.sol contract event nonce
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_observed_nonce: u64,
     /// the last valset nonce we have slashed, to prevent double slashing
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_slashed_valset_nonce: u64,
     /// the last batch Cosmos chain block that batch slashing has completed for
     /// there is an individual batch nonce for each token type so this removes
     /// the need to store them all
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_slashed_batch_block: u64,
     /// the last cosmos block that logic call slashing has completed for
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_slashed_logic_call_block: u64,
     /// the last transaction id from the Gravity TX pool, this prevents ID
     /// duplication during chain upgrades
     #[prost(uint64, tag = "6")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_tx_pool_id: u64,
     /// the last batch id from the Gravity batch pool, this prevents ID duplication
     /// during chain upgrades
     #[prost(uint64, tag = "7")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub last_batch_id: u64,
 }
+impl ::prost::Name for GravityNonces {
+    const NAME: &'static str = "GravityNonces";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.GravityNonces".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.GravityNonces".into()
+    }
+}
 /// IDSet represents a set of IDs
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IdSet {
     #[prost(uint64, repeated, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64_vec"))]
     pub ids: ::prost::alloc::vec::Vec<u64>,
 }
+impl ::prost::Name for IdSet {
+    const NAME: &'static str = "IDSet";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.IDSet".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.IDSet".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BatchFees {
     #[prost(string, tag = "1")]
@@ -1363,8 +2251,21 @@ pub struct BatchFees {
     #[prost(string, tag = "2")]
     pub total_fees: ::prost::alloc::string::String,
     #[prost(uint64, tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub tx_count: u64,
 }
+impl ::prost::Name for BatchFees {
+    const NAME: &'static str = "BatchFees";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.BatchFees".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.BatchFees".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventWithdrawalReceived {
     #[prost(string, tag = "1")]
@@ -1376,6 +2277,18 @@ pub struct EventWithdrawalReceived {
     #[prost(string, tag = "4")]
     pub nonce: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventWithdrawalReceived {
+    const NAME: &'static str = "EventWithdrawalReceived";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventWithdrawalReceived".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventWithdrawalReceived".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventWithdrawCanceled {
     #[prost(string, tag = "1")]
@@ -1387,161 +2300,577 @@ pub struct EventWithdrawCanceled {
     #[prost(string, tag = "4")]
     pub bridge_chain_id: ::prost::alloc::string::String,
 }
+impl ::prost::Name for EventWithdrawCanceled {
+    const NAME: &'static str = "EventWithdrawCanceled";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.EventWithdrawCanceled".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.EventWithdrawCanceled".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryParamsRequest {}
+impl ::prost::Name for QueryParamsRequest {
+    const NAME: &'static str = "QueryParamsRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryParamsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryParamsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryParamsResponse {
     #[prost(message, optional, tag = "1")]
     pub params: ::core::option::Option<Params>,
 }
+impl ::prost::Name for QueryParamsResponse {
+    const NAME: &'static str = "QueryParamsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryParamsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryParamsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryCurrentValsetRequest {}
+impl ::prost::Name for QueryCurrentValsetRequest {
+    const NAME: &'static str = "QueryCurrentValsetRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryCurrentValsetRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryCurrentValsetRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryCurrentValsetResponse {
     #[prost(message, optional, tag = "1")]
     pub valset: ::core::option::Option<Valset>,
 }
+impl ::prost::Name for QueryCurrentValsetResponse {
+    const NAME: &'static str = "QueryCurrentValsetResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryCurrentValsetResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryCurrentValsetResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryValsetRequestRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
 }
+impl ::prost::Name for QueryValsetRequestRequest {
+    const NAME: &'static str = "QueryValsetRequestRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryValsetRequestRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryValsetRequestRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValsetRequestResponse {
     #[prost(message, optional, tag = "1")]
     pub valset: ::core::option::Option<Valset>,
 }
+impl ::prost::Name for QueryValsetRequestResponse {
+    const NAME: &'static str = "QueryValsetRequestResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryValsetRequestResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryValsetRequestResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValsetConfirmRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     #[prost(string, tag = "2")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryValsetConfirmRequest {
+    const NAME: &'static str = "QueryValsetConfirmRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryValsetConfirmRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryValsetConfirmRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValsetConfirmResponse {
     #[prost(message, optional, tag = "1")]
     pub confirm: ::core::option::Option<MsgValsetConfirm>,
 }
+impl ::prost::Name for QueryValsetConfirmResponse {
+    const NAME: &'static str = "QueryValsetConfirmResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryValsetConfirmResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryValsetConfirmResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryValsetConfirmsByNonceRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
 }
+impl ::prost::Name for QueryValsetConfirmsByNonceRequest {
+    const NAME: &'static str = "QueryValsetConfirmsByNonceRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryValsetConfirmsByNonceRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryValsetConfirmsByNonceRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryValsetConfirmsByNonceResponse {
     #[prost(message, repeated, tag = "1")]
     pub confirms: ::prost::alloc::vec::Vec<MsgValsetConfirm>,
 }
+impl ::prost::Name for QueryValsetConfirmsByNonceResponse {
+    const NAME: &'static str = "QueryValsetConfirmsByNonceResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryValsetConfirmsByNonceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryValsetConfirmsByNonceResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLastValsetRequestsRequest {}
+impl ::prost::Name for QueryLastValsetRequestsRequest {
+    const NAME: &'static str = "QueryLastValsetRequestsRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastValsetRequestsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastValsetRequestsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastValsetRequestsResponse {
     #[prost(message, repeated, tag = "1")]
     pub valsets: ::prost::alloc::vec::Vec<Valset>,
 }
+impl ::prost::Name for QueryLastValsetRequestsResponse {
+    const NAME: &'static str = "QueryLastValsetRequestsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastValsetRequestsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastValsetRequestsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastPendingValsetRequestByAddrRequest {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryLastPendingValsetRequestByAddrRequest {
+    const NAME: &'static str = "QueryLastPendingValsetRequestByAddrRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastPendingValsetRequestByAddrRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastPendingValsetRequestByAddrRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastPendingValsetRequestByAddrResponse {
     #[prost(message, repeated, tag = "1")]
     pub valsets: ::prost::alloc::vec::Vec<Valset>,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+impl ::prost::Name for QueryLastPendingValsetRequestByAddrResponse {
+    const NAME: &'static str = "QueryLastPendingValsetRequestByAddrResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastPendingValsetRequestByAddrResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastPendingValsetRequestByAddrResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryBatchFeeRequest {}
+impl ::prost::Name for QueryBatchFeeRequest {
+    const NAME: &'static str = "QueryBatchFeeRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryBatchFeeRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryBatchFeeRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBatchFeeResponse {
     #[prost(message, repeated, tag = "1")]
     pub batch_fees: ::prost::alloc::vec::Vec<BatchFees>,
 }
+impl ::prost::Name for QueryBatchFeeResponse {
+    const NAME: &'static str = "QueryBatchFeeResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryBatchFeeResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryBatchFeeResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastPendingBatchRequestByAddrRequest {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryLastPendingBatchRequestByAddrRequest {
+    const NAME: &'static str = "QueryLastPendingBatchRequestByAddrRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastPendingBatchRequestByAddrRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastPendingBatchRequestByAddrRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastPendingBatchRequestByAddrResponse {
     #[prost(message, repeated, tag = "1")]
     pub batch: ::prost::alloc::vec::Vec<OutgoingTxBatch>,
 }
+impl ::prost::Name for QueryLastPendingBatchRequestByAddrResponse {
+    const NAME: &'static str = "QueryLastPendingBatchRequestByAddrResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastPendingBatchRequestByAddrResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastPendingBatchRequestByAddrResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastPendingLogicCallByAddrRequest {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryLastPendingLogicCallByAddrRequest {
+    const NAME: &'static str = "QueryLastPendingLogicCallByAddrRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastPendingLogicCallByAddrRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastPendingLogicCallByAddrRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastPendingLogicCallByAddrResponse {
     #[prost(message, repeated, tag = "1")]
     pub call: ::prost::alloc::vec::Vec<OutgoingLogicCall>,
 }
+impl ::prost::Name for QueryLastPendingLogicCallByAddrResponse {
+    const NAME: &'static str = "QueryLastPendingLogicCallByAddrResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastPendingLogicCallByAddrResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastPendingLogicCallByAddrResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryOutgoingTxBatchesRequest {}
+impl ::prost::Name for QueryOutgoingTxBatchesRequest {
+    const NAME: &'static str = "QueryOutgoingTxBatchesRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryOutgoingTxBatchesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryOutgoingTxBatchesRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryOutgoingTxBatchesResponse {
     #[prost(message, repeated, tag = "1")]
     pub batches: ::prost::alloc::vec::Vec<OutgoingTxBatch>,
 }
+impl ::prost::Name for QueryOutgoingTxBatchesResponse {
+    const NAME: &'static str = "QueryOutgoingTxBatchesResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryOutgoingTxBatchesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryOutgoingTxBatchesResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryOutgoingLogicCallsRequest {}
+impl ::prost::Name for QueryOutgoingLogicCallsRequest {
+    const NAME: &'static str = "QueryOutgoingLogicCallsRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryOutgoingLogicCallsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryOutgoingLogicCallsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryOutgoingLogicCallsResponse {
     #[prost(message, repeated, tag = "1")]
     pub calls: ::prost::alloc::vec::Vec<OutgoingLogicCall>,
 }
+impl ::prost::Name for QueryOutgoingLogicCallsResponse {
+    const NAME: &'static str = "QueryOutgoingLogicCallsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryOutgoingLogicCallsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryOutgoingLogicCallsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBatchRequestByNonceRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     #[prost(string, tag = "2")]
     pub contract_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryBatchRequestByNonceRequest {
+    const NAME: &'static str = "QueryBatchRequestByNonceRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryBatchRequestByNonceRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryBatchRequestByNonceRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBatchRequestByNonceResponse {
     #[prost(message, optional, tag = "1")]
     pub batch: ::core::option::Option<OutgoingTxBatch>,
 }
+impl ::prost::Name for QueryBatchRequestByNonceResponse {
+    const NAME: &'static str = "QueryBatchRequestByNonceResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryBatchRequestByNonceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryBatchRequestByNonceResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBatchConfirmsRequest {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     #[prost(string, tag = "2")]
     pub contract_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryBatchConfirmsRequest {
+    const NAME: &'static str = "QueryBatchConfirmsRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryBatchConfirmsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryBatchConfirmsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryBatchConfirmsResponse {
     #[prost(message, repeated, tag = "1")]
     pub confirms: ::prost::alloc::vec::Vec<MsgConfirmBatch>,
 }
+impl ::prost::Name for QueryBatchConfirmsResponse {
+    const NAME: &'static str = "QueryBatchConfirmsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryBatchConfirmsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryBatchConfirmsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLogicConfirmsRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::bytes"))]
     pub invalidation_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub invalidation_nonce: u64,
 }
+impl ::prost::Name for QueryLogicConfirmsRequest {
+    const NAME: &'static str = "QueryLogicConfirmsRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLogicConfirmsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLogicConfirmsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLogicConfirmsResponse {
     #[prost(message, repeated, tag = "1")]
     pub confirms: ::prost::alloc::vec::Vec<MsgConfirmLogicCall>,
 }
+impl ::prost::Name for QueryLogicConfirmsResponse {
+    const NAME: &'static str = "QueryLogicConfirmsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLogicConfirmsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLogicConfirmsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryLastEventNonceByAddrRequest {
     #[prost(string, tag = "1")]
     pub address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryLastEventNonceByAddrRequest {
+    const NAME: &'static str = "QueryLastEventNonceByAddrRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastEventNonceByAddrRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastEventNonceByAddrRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLastEventNonceByAddrResponse {
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub event_nonce: u64,
 }
+impl ::prost::Name for QueryLastEventNonceByAddrResponse {
+    const NAME: &'static str = "QueryLastEventNonceByAddrResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastEventNonceByAddrResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastEventNonceByAddrResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryErc20ToDenomRequest {
     #[prost(string, tag = "1")]
     pub erc20: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryErc20ToDenomRequest {
+    const NAME: &'static str = "QueryERC20ToDenomRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryERC20ToDenomRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryERC20ToDenomRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryErc20ToDenomResponse {
     #[prost(string, tag = "1")]
@@ -1549,11 +2878,35 @@ pub struct QueryErc20ToDenomResponse {
     #[prost(bool, tag = "2")]
     pub cosmos_originated: bool,
 }
+impl ::prost::Name for QueryErc20ToDenomResponse {
+    const NAME: &'static str = "QueryERC20ToDenomResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryERC20ToDenomResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryERC20ToDenomResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomToErc20Request {
     #[prost(string, tag = "1")]
     pub denom: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDenomToErc20Request {
+    const NAME: &'static str = "QueryDenomToERC20Request";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDenomToERC20Request".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDenomToERC20Request".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDenomToErc20Response {
     #[prost(string, tag = "1")]
@@ -1561,10 +2914,22 @@ pub struct QueryDenomToErc20Response {
     #[prost(bool, tag = "2")]
     pub cosmos_originated: bool,
 }
+impl ::prost::Name for QueryDenomToErc20Response {
+    const NAME: &'static str = "QueryDenomToERC20Response";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDenomToERC20Response".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDenomToERC20Response".into()
+    }
+}
 /// QueryLastObservedEthBlockRequest defines the request for getting the height of the
 /// last applied Ethereum Event on the bridge. This is expected to lag the actual
 /// Ethereum block height significantly due to 1. Ethereum Finality and
 ///   2. Consensus mirroring the state on Ethereum
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLastObservedEthBlockRequest {
     /// indicates whether to search for store data using the old Gravity v1 key "LastObservedEthereumBlockHeightKey"
@@ -1572,17 +2937,42 @@ pub struct QueryLastObservedEthBlockRequest {
     #[prost(bool, tag = "1")]
     pub use_v1_key: bool,
 }
+impl ::prost::Name for QueryLastObservedEthBlockRequest {
+    const NAME: &'static str = "QueryLastObservedEthBlockRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastObservedEthBlockRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastObservedEthBlockRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLastObservedEthBlockResponse {
     /// a response of 0 indicates that no Ethereum events have been observed, and thus
     /// the bridge is inactive
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub block: u64,
 }
+impl ::prost::Name for QueryLastObservedEthBlockResponse {
+    const NAME: &'static str = "QueryLastObservedEthBlockResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastObservedEthBlockResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastObservedEthBlockResponse".into()
+    }
+}
 /// QueryLastObservedEthNonceRequest defines the request for getting the event nonce
 /// of the last applied Ethereum Event on the bridge.
 /// Note that this is likely to lag the last executed event a little
 /// due to 1. Ethereum Finality and 2. Consensus mirroring the Ethereum state
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLastObservedEthNonceRequest {
     /// indicates whether to search for store data using the old Gravity v1 key "LastObservedEventNonceKey"
@@ -1590,13 +2980,36 @@ pub struct QueryLastObservedEthNonceRequest {
     #[prost(bool, tag = "1")]
     pub use_v1_key: bool,
 }
+impl ::prost::Name for QueryLastObservedEthNonceRequest {
+    const NAME: &'static str = "QueryLastObservedEthNonceRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastObservedEthNonceRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastObservedEthNonceRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryLastObservedEthNonceResponse {
     /// a response of 0 indicates that no Ethereum events have been observed, and thus
     /// the bridge is inactive
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
 }
+impl ::prost::Name for QueryLastObservedEthNonceResponse {
+    const NAME: &'static str = "QueryLastObservedEthNonceResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryLastObservedEthNonceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryLastObservedEthNonceResponse".into()
+    }
+}
 /// QueryAttestationsRequest defines the request structure for getting recent
 /// attestations with optional query parameters. By default, a limited set of
 /// recent attestations will be returned, defined by 'limit'. These attestations
@@ -1604,10 +3017,13 @@ pub struct QueryLastObservedEthNonceResponse {
 /// Filtering criteria may also be provided, including nonce, claim type, and
 /// height. Note, that an attestation will be returned if it matches ANY of the
 /// filter query parameters provided.
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAttestationsRequest {
     /// limit defines how many attestations to limit in the response.
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub limit: u64,
     /// order_by provides ordering of atteststions by nonce in the response. Either
     /// 'asc' or 'desc' can be provided. If no value is provided, it defaults to
@@ -1619,25 +3035,63 @@ pub struct QueryAttestationsRequest {
     pub claim_type: ::prost::alloc::string::String,
     /// nonce allows filtering attestations by Ethereum claim nonce.
     #[prost(uint64, tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub nonce: u64,
     /// height allows filtering attestations by Ethereum claim height.
     #[prost(uint64, tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub height: u64,
     /// indicates whether to search for store data using the old Gravity v1 key "OracleAttestationKey"
     /// Note that queries before the Mercury upgrade at height 1282013 must set this to true
     #[prost(bool, tag = "6")]
     pub use_v1_key: bool,
 }
+impl ::prost::Name for QueryAttestationsRequest {
+    const NAME: &'static str = "QueryAttestationsRequest";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryAttestationsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryAttestationsRequest".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryAttestationsResponse {
     #[prost(message, repeated, tag = "1")]
     pub attestations: ::prost::alloc::vec::Vec<Attestation>,
 }
+impl ::prost::Name for QueryAttestationsResponse {
+    const NAME: &'static str = "QueryAttestationsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryAttestationsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryAttestationsResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegateKeysByValidatorAddress {
     #[prost(string, tag = "1")]
     pub validator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegateKeysByValidatorAddress {
+    const NAME: &'static str = "QueryDelegateKeysByValidatorAddress";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDelegateKeysByValidatorAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDelegateKeysByValidatorAddress".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegateKeysByValidatorAddressResponse {
     #[prost(string, tag = "1")]
@@ -1645,11 +3099,35 @@ pub struct QueryDelegateKeysByValidatorAddressResponse {
     #[prost(string, tag = "2")]
     pub orchestrator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegateKeysByValidatorAddressResponse {
+    const NAME: &'static str = "QueryDelegateKeysByValidatorAddressResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDelegateKeysByValidatorAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDelegateKeysByValidatorAddressResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegateKeysByEthAddress {
     #[prost(string, tag = "1")]
     pub eth_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegateKeysByEthAddress {
+    const NAME: &'static str = "QueryDelegateKeysByEthAddress";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDelegateKeysByEthAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDelegateKeysByEthAddress".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegateKeysByEthAddressResponse {
     #[prost(string, tag = "1")]
@@ -1657,11 +3135,35 @@ pub struct QueryDelegateKeysByEthAddressResponse {
     #[prost(string, tag = "2")]
     pub orchestrator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegateKeysByEthAddressResponse {
+    const NAME: &'static str = "QueryDelegateKeysByEthAddressResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDelegateKeysByEthAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDelegateKeysByEthAddressResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegateKeysByOrchestratorAddress {
     #[prost(string, tag = "1")]
     pub orchestrator_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegateKeysByOrchestratorAddress {
+    const NAME: &'static str = "QueryDelegateKeysByOrchestratorAddress";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDelegateKeysByOrchestratorAddress".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDelegateKeysByOrchestratorAddress".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryDelegateKeysByOrchestratorAddressResponse {
     #[prost(string, tag = "1")]
@@ -1669,11 +3171,35 @@ pub struct QueryDelegateKeysByOrchestratorAddressResponse {
     #[prost(string, tag = "2")]
     pub eth_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryDelegateKeysByOrchestratorAddressResponse {
+    const NAME: &'static str = "QueryDelegateKeysByOrchestratorAddressResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryDelegateKeysByOrchestratorAddressResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryDelegateKeysByOrchestratorAddressResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPendingSendToEth {
     #[prost(string, tag = "1")]
     pub sender_address: ::prost::alloc::string::String,
 }
+impl ::prost::Name for QueryPendingSendToEth {
+    const NAME: &'static str = "QueryPendingSendToEth";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryPendingSendToEth".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryPendingSendToEth".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPendingSendToEthResponse {
     #[prost(message, repeated, tag = "1")]
@@ -1681,17 +3207,54 @@ pub struct QueryPendingSendToEthResponse {
     #[prost(message, repeated, tag = "2")]
     pub unbatched_transfers: ::prost::alloc::vec::Vec<OutgoingTransferTx>,
 }
+impl ::prost::Name for QueryPendingSendToEthResponse {
+    const NAME: &'static str = "QueryPendingSendToEthResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryPendingSendToEthResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryPendingSendToEthResponse".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct QueryPendingIbcAutoForwards {
     /// limit defines the number of pending forwards to return, in order of their SendToCosmos.EventNonce
     #[prost(uint64, tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "cosmos_sdk_proto::proto_json::int64"))]
     pub limit: u64,
 }
+impl ::prost::Name for QueryPendingIbcAutoForwards {
+    const NAME: &'static str = "QueryPendingIbcAutoForwards";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryPendingIbcAutoForwards".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryPendingIbcAutoForwards".into()
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase", default))]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct QueryPendingIbcAutoForwardsResponse {
     #[prost(message, repeated, tag = "1")]
     pub pending_ibc_auto_forwards: ::prost::alloc::vec::Vec<PendingIbcAutoForward>,
 }
+impl ::prost::Name for QueryPendingIbcAutoForwardsResponse {
+    const NAME: &'static str = "QueryPendingIbcAutoForwardsResponse";
+    const PACKAGE: &'static str = "gravity.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "gravity.v1.QueryPendingIbcAutoForwardsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/gravity.v1.QueryPendingIbcAutoForwardsResponse".into()
+    }
+}
+#[cfg(feature = "grpc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc")))]
 /// Generated client implementations.
 pub mod query_client {
     #![allow(
@@ -2428,3 +3991,2512 @@ pub mod query_client {
         }
     }
 }
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod msg_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MsgServer.
+    #[async_trait]
+    pub trait Msg: std::marker::Send + std::marker::Sync + 'static {
+        async fn valset_confirm(
+            &self,
+            request: tonic::Request<super::MsgValsetConfirm>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgValsetConfirmResponse>,
+            tonic::Status,
+        >;
+        async fn send_to_eth(
+            &self,
+            request: tonic::Request<super::MsgSendToEth>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSendToEthResponse>,
+            tonic::Status,
+        >;
+        async fn request_batch(
+            &self,
+            request: tonic::Request<super::MsgRequestBatch>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgRequestBatchResponse>,
+            tonic::Status,
+        >;
+        async fn confirm_batch(
+            &self,
+            request: tonic::Request<super::MsgConfirmBatch>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgConfirmBatchResponse>,
+            tonic::Status,
+        >;
+        async fn confirm_logic_call(
+            &self,
+            request: tonic::Request<super::MsgConfirmLogicCall>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgConfirmLogicCallResponse>,
+            tonic::Status,
+        >;
+        async fn send_to_cosmos_claim(
+            &self,
+            request: tonic::Request<super::MsgSendToCosmosClaim>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSendToCosmosClaimResponse>,
+            tonic::Status,
+        >;
+        async fn execute_ibc_auto_forwards(
+            &self,
+            request: tonic::Request<super::MsgExecuteIbcAutoForwards>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgExecuteIbcAutoForwardsResponse>,
+            tonic::Status,
+        >;
+        async fn batch_send_to_eth_claim(
+            &self,
+            request: tonic::Request<super::MsgBatchSendToEthClaim>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgBatchSendToEthClaimResponse>,
+            tonic::Status,
+        >;
+        async fn valset_update_claim(
+            &self,
+            request: tonic::Request<super::MsgValsetUpdatedClaim>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgValsetUpdatedClaimResponse>,
+            tonic::Status,
+        >;
+        async fn erc20_deployed_claim(
+            &self,
+            request: tonic::Request<super::MsgErc20DeployedClaim>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgErc20DeployedClaimResponse>,
+            tonic::Status,
+        >;
+        async fn logic_call_executed_claim(
+            &self,
+            request: tonic::Request<super::MsgLogicCallExecutedClaim>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgLogicCallExecutedClaimResponse>,
+            tonic::Status,
+        >;
+        async fn set_orchestrator_address(
+            &self,
+            request: tonic::Request<super::MsgSetOrchestratorAddress>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSetOrchestratorAddressResponse>,
+            tonic::Status,
+        >;
+        async fn cancel_send_to_eth(
+            &self,
+            request: tonic::Request<super::MsgCancelSendToEth>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgCancelSendToEthResponse>,
+            tonic::Status,
+        >;
+        async fn submit_bad_signature_evidence(
+            &self,
+            request: tonic::Request<super::MsgSubmitBadSignatureEvidence>,
+        ) -> std::result::Result<
+            tonic::Response<super::MsgSubmitBadSignatureEvidenceResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Msg defines the state transitions possible within gravity
+    #[derive(Debug)]
+    pub struct MsgServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> MsgServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MsgServer<T>
+    where
+        T: Msg,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/gravity.v1.Msg/ValsetConfirm" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValsetConfirmSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgValsetConfirm>
+                    for ValsetConfirmSvc<T> {
+                        type Response = super::MsgValsetConfirmResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgValsetConfirm>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::valset_confirm(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValsetConfirmSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/SendToEth" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendToEthSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgSendToEth>
+                    for SendToEthSvc<T> {
+                        type Response = super::MsgSendToEthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSendToEth>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::send_to_eth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendToEthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/RequestBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct RequestBatchSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgRequestBatch>
+                    for RequestBatchSvc<T> {
+                        type Response = super::MsgRequestBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgRequestBatch>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::request_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RequestBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/ConfirmBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConfirmBatchSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgConfirmBatch>
+                    for ConfirmBatchSvc<T> {
+                        type Response = super::MsgConfirmBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgConfirmBatch>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::confirm_batch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ConfirmBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/ConfirmLogicCall" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConfirmLogicCallSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgConfirmLogicCall>
+                    for ConfirmLogicCallSvc<T> {
+                        type Response = super::MsgConfirmLogicCallResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgConfirmLogicCall>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::confirm_logic_call(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ConfirmLogicCallSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/SendToCosmosClaim" => {
+                    #[allow(non_camel_case_types)]
+                    struct SendToCosmosClaimSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgSendToCosmosClaim>
+                    for SendToCosmosClaimSvc<T> {
+                        type Response = super::MsgSendToCosmosClaimResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSendToCosmosClaim>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::send_to_cosmos_claim(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SendToCosmosClaimSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/ExecuteIbcAutoForwards" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecuteIbcAutoForwardsSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgExecuteIbcAutoForwards>
+                    for ExecuteIbcAutoForwardsSvc<T> {
+                        type Response = super::MsgExecuteIbcAutoForwardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgExecuteIbcAutoForwards>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::execute_ibc_auto_forwards(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExecuteIbcAutoForwardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/BatchSendToEthClaim" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchSendToEthClaimSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgBatchSendToEthClaim>
+                    for BatchSendToEthClaimSvc<T> {
+                        type Response = super::MsgBatchSendToEthClaimResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgBatchSendToEthClaim>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::batch_send_to_eth_claim(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BatchSendToEthClaimSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/ValsetUpdateClaim" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValsetUpdateClaimSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgValsetUpdatedClaim>
+                    for ValsetUpdateClaimSvc<T> {
+                        type Response = super::MsgValsetUpdatedClaimResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgValsetUpdatedClaim>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::valset_update_claim(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValsetUpdateClaimSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/ERC20DeployedClaim" => {
+                    #[allow(non_camel_case_types)]
+                    struct ERC20DeployedClaimSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgErc20DeployedClaim>
+                    for ERC20DeployedClaimSvc<T> {
+                        type Response = super::MsgErc20DeployedClaimResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgErc20DeployedClaim>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::erc20_deployed_claim(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ERC20DeployedClaimSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/LogicCallExecutedClaim" => {
+                    #[allow(non_camel_case_types)]
+                    struct LogicCallExecutedClaimSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgLogicCallExecutedClaim>
+                    for LogicCallExecutedClaimSvc<T> {
+                        type Response = super::MsgLogicCallExecutedClaimResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgLogicCallExecutedClaim>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::logic_call_executed_claim(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LogicCallExecutedClaimSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/SetOrchestratorAddress" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetOrchestratorAddressSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgSetOrchestratorAddress>
+                    for SetOrchestratorAddressSvc<T> {
+                        type Response = super::MsgSetOrchestratorAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSetOrchestratorAddress>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::set_orchestrator_address(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetOrchestratorAddressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/CancelSendToEth" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelSendToEthSvc<T: Msg>(pub Arc<T>);
+                    impl<T: Msg> tonic::server::UnaryService<super::MsgCancelSendToEth>
+                    for CancelSendToEthSvc<T> {
+                        type Response = super::MsgCancelSendToEthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgCancelSendToEth>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::cancel_send_to_eth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelSendToEthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Msg/SubmitBadSignatureEvidence" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitBadSignatureEvidenceSvc<T: Msg>(pub Arc<T>);
+                    impl<
+                        T: Msg,
+                    > tonic::server::UnaryService<super::MsgSubmitBadSignatureEvidence>
+                    for SubmitBadSignatureEvidenceSvc<T> {
+                        type Response = super::MsgSubmitBadSignatureEvidenceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MsgSubmitBadSignatureEvidence>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Msg>::submit_bad_signature_evidence(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubmitBadSignatureEvidenceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for MsgServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "gravity.v1.Msg";
+    impl<T> tonic::server::NamedService for MsgServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[cfg(feature = "grpc-server")]
+#[cfg_attr(docsrs, doc(cfg(feature = "grpc-server")))]
+/// Generated server implementations.
+pub mod query_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with QueryServer.
+    #[async_trait]
+    pub trait Query: std::marker::Send + std::marker::Sync + 'static {
+        /// Deployments queries deployments
+        async fn params(
+            &self,
+            request: tonic::Request<super::QueryParamsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryParamsResponse>,
+            tonic::Status,
+        >;
+        async fn current_valset(
+            &self,
+            request: tonic::Request<super::QueryCurrentValsetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryCurrentValsetResponse>,
+            tonic::Status,
+        >;
+        async fn valset_request(
+            &self,
+            request: tonic::Request<super::QueryValsetRequestRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValsetRequestResponse>,
+            tonic::Status,
+        >;
+        async fn valset_confirm(
+            &self,
+            request: tonic::Request<super::QueryValsetConfirmRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValsetConfirmResponse>,
+            tonic::Status,
+        >;
+        async fn valset_confirms_by_nonce(
+            &self,
+            request: tonic::Request<super::QueryValsetConfirmsByNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryValsetConfirmsByNonceResponse>,
+            tonic::Status,
+        >;
+        async fn last_valset_requests(
+            &self,
+            request: tonic::Request<super::QueryLastValsetRequestsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastValsetRequestsResponse>,
+            tonic::Status,
+        >;
+        async fn last_pending_valset_request_by_addr(
+            &self,
+            request: tonic::Request<super::QueryLastPendingValsetRequestByAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastPendingValsetRequestByAddrResponse>,
+            tonic::Status,
+        >;
+        async fn last_pending_batch_request_by_addr(
+            &self,
+            request: tonic::Request<super::QueryLastPendingBatchRequestByAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastPendingBatchRequestByAddrResponse>,
+            tonic::Status,
+        >;
+        async fn last_pending_logic_call_by_addr(
+            &self,
+            request: tonic::Request<super::QueryLastPendingLogicCallByAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastPendingLogicCallByAddrResponse>,
+            tonic::Status,
+        >;
+        async fn last_event_nonce_by_addr(
+            &self,
+            request: tonic::Request<super::QueryLastEventNonceByAddrRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastEventNonceByAddrResponse>,
+            tonic::Status,
+        >;
+        async fn batch_fees(
+            &self,
+            request: tonic::Request<super::QueryBatchFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryBatchFeeResponse>,
+            tonic::Status,
+        >;
+        async fn outgoing_tx_batches(
+            &self,
+            request: tonic::Request<super::QueryOutgoingTxBatchesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryOutgoingTxBatchesResponse>,
+            tonic::Status,
+        >;
+        async fn outgoing_logic_calls(
+            &self,
+            request: tonic::Request<super::QueryOutgoingLogicCallsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryOutgoingLogicCallsResponse>,
+            tonic::Status,
+        >;
+        async fn batch_request_by_nonce(
+            &self,
+            request: tonic::Request<super::QueryBatchRequestByNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryBatchRequestByNonceResponse>,
+            tonic::Status,
+        >;
+        async fn batch_confirms(
+            &self,
+            request: tonic::Request<super::QueryBatchConfirmsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryBatchConfirmsResponse>,
+            tonic::Status,
+        >;
+        async fn logic_confirms(
+            &self,
+            request: tonic::Request<super::QueryLogicConfirmsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLogicConfirmsResponse>,
+            tonic::Status,
+        >;
+        async fn erc20_to_denom(
+            &self,
+            request: tonic::Request<super::QueryErc20ToDenomRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryErc20ToDenomResponse>,
+            tonic::Status,
+        >;
+        async fn denom_to_erc20(
+            &self,
+            request: tonic::Request<super::QueryDenomToErc20Request>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDenomToErc20Response>,
+            tonic::Status,
+        >;
+        async fn get_last_observed_eth_block(
+            &self,
+            request: tonic::Request<super::QueryLastObservedEthBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastObservedEthBlockResponse>,
+            tonic::Status,
+        >;
+        async fn get_last_observed_eth_nonce(
+            &self,
+            request: tonic::Request<super::QueryLastObservedEthNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryLastObservedEthNonceResponse>,
+            tonic::Status,
+        >;
+        async fn get_attestations(
+            &self,
+            request: tonic::Request<super::QueryAttestationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryAttestationsResponse>,
+            tonic::Status,
+        >;
+        async fn get_delegate_key_by_validator(
+            &self,
+            request: tonic::Request<super::QueryDelegateKeysByValidatorAddress>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegateKeysByValidatorAddressResponse>,
+            tonic::Status,
+        >;
+        async fn get_delegate_key_by_eth(
+            &self,
+            request: tonic::Request<super::QueryDelegateKeysByEthAddress>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegateKeysByEthAddressResponse>,
+            tonic::Status,
+        >;
+        async fn get_delegate_key_by_orchestrator(
+            &self,
+            request: tonic::Request<super::QueryDelegateKeysByOrchestratorAddress>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryDelegateKeysByOrchestratorAddressResponse>,
+            tonic::Status,
+        >;
+        async fn get_pending_send_to_eth(
+            &self,
+            request: tonic::Request<super::QueryPendingSendToEth>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryPendingSendToEthResponse>,
+            tonic::Status,
+        >;
+        async fn get_pending_ibc_auto_forwards(
+            &self,
+            request: tonic::Request<super::QueryPendingIbcAutoForwards>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueryPendingIbcAutoForwardsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// Query defines the gRPC querier service
+    #[derive(Debug)]
+    pub struct QueryServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> QueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for QueryServer<T>
+    where
+        T: Query,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/gravity.v1.Query/Params" => {
+                    #[allow(non_camel_case_types)]
+                    struct ParamsSvc<T: Query>(pub Arc<T>);
+                    impl<T: Query> tonic::server::UnaryService<super::QueryParamsRequest>
+                    for ParamsSvc<T> {
+                        type Response = super::QueryParamsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryParamsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::params(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ParamsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/CurrentValset" => {
+                    #[allow(non_camel_case_types)]
+                    struct CurrentValsetSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryCurrentValsetRequest>
+                    for CurrentValsetSvc<T> {
+                        type Response = super::QueryCurrentValsetResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryCurrentValsetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::current_valset(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CurrentValsetSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/ValsetRequest" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValsetRequestSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValsetRequestRequest>
+                    for ValsetRequestSvc<T> {
+                        type Response = super::QueryValsetRequestResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryValsetRequestRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::valset_request(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValsetRequestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/ValsetConfirm" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValsetConfirmSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryValsetConfirmRequest>
+                    for ValsetConfirmSvc<T> {
+                        type Response = super::QueryValsetConfirmResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryValsetConfirmRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::valset_confirm(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValsetConfirmSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/ValsetConfirmsByNonce" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValsetConfirmsByNonceSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryValsetConfirmsByNonceRequest,
+                    > for ValsetConfirmsByNonceSvc<T> {
+                        type Response = super::QueryValsetConfirmsByNonceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryValsetConfirmsByNonceRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::valset_confirms_by_nonce(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValsetConfirmsByNonceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/LastValsetRequests" => {
+                    #[allow(non_camel_case_types)]
+                    struct LastValsetRequestsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryLastValsetRequestsRequest>
+                    for LastValsetRequestsSvc<T> {
+                        type Response = super::QueryLastValsetRequestsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastValsetRequestsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::last_valset_requests(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LastValsetRequestsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/LastPendingValsetRequestByAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct LastPendingValsetRequestByAddrSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryLastPendingValsetRequestByAddrRequest,
+                    > for LastPendingValsetRequestByAddrSvc<T> {
+                        type Response = super::QueryLastPendingValsetRequestByAddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastPendingValsetRequestByAddrRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::last_pending_valset_request_by_addr(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LastPendingValsetRequestByAddrSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/LastPendingBatchRequestByAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct LastPendingBatchRequestByAddrSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryLastPendingBatchRequestByAddrRequest,
+                    > for LastPendingBatchRequestByAddrSvc<T> {
+                        type Response = super::QueryLastPendingBatchRequestByAddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastPendingBatchRequestByAddrRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::last_pending_batch_request_by_addr(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LastPendingBatchRequestByAddrSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/LastPendingLogicCallByAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct LastPendingLogicCallByAddrSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryLastPendingLogicCallByAddrRequest,
+                    > for LastPendingLogicCallByAddrSvc<T> {
+                        type Response = super::QueryLastPendingLogicCallByAddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastPendingLogicCallByAddrRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::last_pending_logic_call_by_addr(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LastPendingLogicCallByAddrSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/LastEventNonceByAddr" => {
+                    #[allow(non_camel_case_types)]
+                    struct LastEventNonceByAddrSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryLastEventNonceByAddrRequest,
+                    > for LastEventNonceByAddrSvc<T> {
+                        type Response = super::QueryLastEventNonceByAddrResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastEventNonceByAddrRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::last_event_nonce_by_addr(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LastEventNonceByAddrSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/BatchFees" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchFeesSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryBatchFeeRequest>
+                    for BatchFeesSvc<T> {
+                        type Response = super::QueryBatchFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBatchFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::batch_fees(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BatchFeesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/OutgoingTxBatches" => {
+                    #[allow(non_camel_case_types)]
+                    struct OutgoingTxBatchesSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryOutgoingTxBatchesRequest>
+                    for OutgoingTxBatchesSvc<T> {
+                        type Response = super::QueryOutgoingTxBatchesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryOutgoingTxBatchesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::outgoing_tx_batches(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = OutgoingTxBatchesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/OutgoingLogicCalls" => {
+                    #[allow(non_camel_case_types)]
+                    struct OutgoingLogicCallsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryOutgoingLogicCallsRequest>
+                    for OutgoingLogicCallsSvc<T> {
+                        type Response = super::QueryOutgoingLogicCallsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryOutgoingLogicCallsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::outgoing_logic_calls(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = OutgoingLogicCallsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/BatchRequestByNonce" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchRequestByNonceSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryBatchRequestByNonceRequest>
+                    for BatchRequestByNonceSvc<T> {
+                        type Response = super::QueryBatchRequestByNonceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryBatchRequestByNonceRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::batch_request_by_nonce(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BatchRequestByNonceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/BatchConfirms" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchConfirmsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryBatchConfirmsRequest>
+                    for BatchConfirmsSvc<T> {
+                        type Response = super::QueryBatchConfirmsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryBatchConfirmsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::batch_confirms(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BatchConfirmsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/LogicConfirms" => {
+                    #[allow(non_camel_case_types)]
+                    struct LogicConfirmsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryLogicConfirmsRequest>
+                    for LogicConfirmsSvc<T> {
+                        type Response = super::QueryLogicConfirmsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryLogicConfirmsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::logic_confirms(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LogicConfirmsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/ERC20ToDenom" => {
+                    #[allow(non_camel_case_types)]
+                    struct ERC20ToDenomSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryErc20ToDenomRequest>
+                    for ERC20ToDenomSvc<T> {
+                        type Response = super::QueryErc20ToDenomResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryErc20ToDenomRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::erc20_to_denom(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ERC20ToDenomSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/DenomToERC20" => {
+                    #[allow(non_camel_case_types)]
+                    struct DenomToERC20Svc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDenomToErc20Request>
+                    for DenomToERC20Svc<T> {
+                        type Response = super::QueryDenomToErc20Response;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDenomToErc20Request>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::denom_to_erc20(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DenomToERC20Svc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetLastObservedEthBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLastObservedEthBlockSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryLastObservedEthBlockRequest,
+                    > for GetLastObservedEthBlockSvc<T> {
+                        type Response = super::QueryLastObservedEthBlockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastObservedEthBlockRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_last_observed_eth_block(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLastObservedEthBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetLastObservedEthNonce" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetLastObservedEthNonceSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryLastObservedEthNonceRequest,
+                    > for GetLastObservedEthNonceSvc<T> {
+                        type Response = super::QueryLastObservedEthNonceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryLastObservedEthNonceRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_last_observed_eth_nonce(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetLastObservedEthNonceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetAttestations" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAttestationsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryAttestationsRequest>
+                    for GetAttestationsSvc<T> {
+                        type Response = super::QueryAttestationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryAttestationsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_attestations(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAttestationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetDelegateKeyByValidator" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDelegateKeyByValidatorSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryDelegateKeysByValidatorAddress,
+                    > for GetDelegateKeyByValidatorSvc<T> {
+                        type Response = super::QueryDelegateKeysByValidatorAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegateKeysByValidatorAddress,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_delegate_key_by_validator(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetDelegateKeyByValidatorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetDelegateKeyByEth" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDelegateKeyByEthSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryDelegateKeysByEthAddress>
+                    for GetDelegateKeyByEthSvc<T> {
+                        type Response = super::QueryDelegateKeysByEthAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryDelegateKeysByEthAddress>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_delegate_key_by_eth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetDelegateKeyByEthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetDelegateKeyByOrchestrator" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDelegateKeyByOrchestratorSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<
+                        super::QueryDelegateKeysByOrchestratorAddress,
+                    > for GetDelegateKeyByOrchestratorSvc<T> {
+                        type Response = super::QueryDelegateKeysByOrchestratorAddressResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::QueryDelegateKeysByOrchestratorAddress,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_delegate_key_by_orchestrator(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetDelegateKeyByOrchestratorSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetPendingSendToEth" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPendingSendToEthSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryPendingSendToEth>
+                    for GetPendingSendToEthSvc<T> {
+                        type Response = super::QueryPendingSendToEthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryPendingSendToEth>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_pending_send_to_eth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetPendingSendToEthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/gravity.v1.Query/GetPendingIbcAutoForwards" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPendingIbcAutoForwardsSvc<T: Query>(pub Arc<T>);
+                    impl<
+                        T: Query,
+                    > tonic::server::UnaryService<super::QueryPendingIbcAutoForwards>
+                    for GetPendingIbcAutoForwardsSvc<T> {
+                        type Response = super::QueryPendingIbcAutoForwardsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryPendingIbcAutoForwards>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Query>::get_pending_ibc_auto_forwards(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetPendingIbcAutoForwardsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for QueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "gravity.v1.Query";
+    impl<T> tonic::server::NamedService for QueryServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry
+#[cfg(feature = "serde")]
+pub fn register_json_types(registry: &mut cosmos_sdk_proto::proto_json::AnyRegistry) {
+    registry.register::<Attestation>();
+    registry.register::<Erc20Token>();
+    registry.register::<EventObservation>();
+    registry.register::<EventInvalidSendToCosmosReceiver>();
+    registry.register::<EventSendToCosmos>();
+    registry.register::<EventSendToCosmosLocal>();
+    registry.register::<EventSendToCosmosPendingIbcAutoForward>();
+    registry.register::<EventSendToCosmosExecutedIbcAutoForward>();
+    registry.register::<OutgoingTxBatch>();
+    registry.register::<OutgoingTransferTx>();
+    registry.register::<OutgoingLogicCall>();
+    registry.register::<EventOutgoingBatchCanceled>();
+    registry.register::<EventOutgoingBatch>();
+    registry.register::<BridgeValidator>();
+    registry.register::<Valset>();
+    registry.register::<LastObservedEthereumBlockHeight>();
+    registry.register::<Erc20ToDenom>();
+    registry.register::<UnhaltBridgeProposal>();
+    registry.register::<AirdropProposal>();
+    registry.register::<IbcMetadataProposal>();
+    registry.register::<PendingIbcAutoForward>();
+    registry.register::<MsgSetOrchestratorAddress>();
+    registry.register::<MsgSetOrchestratorAddressResponse>();
+    registry.register::<MsgValsetConfirm>();
+    registry.register::<MsgValsetConfirmResponse>();
+    registry.register::<MsgSendToEth>();
+    registry.register::<MsgSendToEthResponse>();
+    registry.register::<MsgRequestBatch>();
+    registry.register::<MsgRequestBatchResponse>();
+    registry.register::<MsgConfirmBatch>();
+    registry.register::<MsgConfirmBatchResponse>();
+    registry.register::<MsgConfirmLogicCall>();
+    registry.register::<MsgConfirmLogicCallResponse>();
+    registry.register::<MsgSendToCosmosClaim>();
+    registry.register::<MsgSendToCosmosClaimResponse>();
+    registry.register::<MsgExecuteIbcAutoForwards>();
+    registry.register::<MsgExecuteIbcAutoForwardsResponse>();
+    registry.register::<MsgBatchSendToEthClaim>();
+    registry.register::<MsgBatchSendToEthClaimResponse>();
+    registry.register::<MsgErc20DeployedClaim>();
+    registry.register::<MsgErc20DeployedClaimResponse>();
+    registry.register::<MsgLogicCallExecutedClaim>();
+    registry.register::<MsgLogicCallExecutedClaimResponse>();
+    registry.register::<MsgValsetUpdatedClaim>();
+    registry.register::<MsgValsetUpdatedClaimResponse>();
+    registry.register::<MsgCancelSendToEth>();
+    registry.register::<MsgCancelSendToEthResponse>();
+    registry.register::<MsgSubmitBadSignatureEvidence>();
+    registry.register::<MsgSubmitBadSignatureEvidenceResponse>();
+    registry.register::<EventSetOperatorAddress>();
+    registry.register::<EventValsetConfirmKey>();
+    registry.register::<EventBatchCreated>();
+    registry.register::<EventBatchConfirmKey>();
+    registry.register::<EventBatchSendToEthClaim>();
+    registry.register::<EventClaim>();
+    registry.register::<EventBadSignatureEvidence>();
+    registry.register::<EventErc20DeployedClaim>();
+    registry.register::<EventValsetUpdatedClaim>();
+    registry.register::<EventMultisigUpdateRequest>();
+    registry.register::<EventOutgoingLogicCallCanceled>();
+    registry.register::<EventSignatureSlashing>();
+    registry.register::<EventOutgoingTxId>();
+    registry.register::<EventSendToEthFeeCollected>();
+    registry.register::<Params>();
+    registry.register::<GenesisState>();
+    registry.register::<GravityNonces>();
+    registry.register::<IdSet>();
+    registry.register::<BatchFees>();
+    registry.register::<EventWithdrawalReceived>();
+    registry.register::<EventWithdrawCanceled>();
+    registry.register::<QueryParamsRequest>();
+    registry.register::<QueryParamsResponse>();
+    registry.register::<QueryCurrentValsetRequest>();
+    registry.register::<QueryCurrentValsetResponse>();
+    registry.register::<QueryValsetRequestRequest>();
+    registry.register::<QueryValsetRequestResponse>();
+    registry.register::<QueryValsetConfirmRequest>();
+    registry.register::<QueryValsetConfirmResponse>();
+    registry.register::<QueryValsetConfirmsByNonceRequest>();
+    registry.register::<QueryValsetConfirmsByNonceResponse>();
+    registry.register::<QueryLastValsetRequestsRequest>();
+    registry.register::<QueryLastValsetRequestsResponse>();
+    registry.register::<QueryLastPendingValsetRequestByAddrRequest>();
+    registry.register::<QueryLastPendingValsetRequestByAddrResponse>();
+    registry.register::<QueryBatchFeeRequest>();
+    registry.register::<QueryBatchFeeResponse>();
+    registry.register::<QueryLastPendingBatchRequestByAddrRequest>();
+    registry.register::<QueryLastPendingBatchRequestByAddrResponse>();
+    registry.register::<QueryLastPendingLogicCallByAddrRequest>();
+    registry.register::<QueryLastPendingLogicCallByAddrResponse>();
+    registry.register::<QueryOutgoingTxBatchesRequest>();
+    registry.register::<QueryOutgoingTxBatchesResponse>();
+    registry.register::<QueryOutgoingLogicCallsRequest>();
+    registry.register::<QueryOutgoingLogicCallsResponse>();
+    registry.register::<QueryBatchRequestByNonceRequest>();
+    registry.register::<QueryBatchRequestByNonceResponse>();
+    registry.register::<QueryBatchConfirmsRequest>();
+    registry.register::<QueryBatchConfirmsResponse>();
+    registry.register::<QueryLogicConfirmsRequest>();
+    registry.register::<QueryLogicConfirmsResponse>();
+    registry.register::<QueryLastEventNonceByAddrRequest>();
+    registry.register::<QueryLastEventNonceByAddrResponse>();
+    registry.register::<QueryErc20ToDenomRequest>();
+    registry.register::<QueryErc20ToDenomResponse>();
+    registry.register::<QueryDenomToErc20Request>();
+    registry.register::<QueryDenomToErc20Response>();
+    registry.register::<QueryLastObservedEthBlockRequest>();
+    registry.register::<QueryLastObservedEthBlockResponse>();
+    registry.register::<QueryLastObservedEthNonceRequest>();
+    registry.register::<QueryLastObservedEthNonceResponse>();
+    registry.register::<QueryAttestationsRequest>();
+    registry.register::<QueryAttestationsResponse>();
+    registry.register::<QueryDelegateKeysByValidatorAddress>();
+    registry.register::<QueryDelegateKeysByValidatorAddressResponse>();
+    registry.register::<QueryDelegateKeysByEthAddress>();
+    registry.register::<QueryDelegateKeysByEthAddressResponse>();
+    registry.register::<QueryDelegateKeysByOrchestratorAddress>();
+    registry.register::<QueryDelegateKeysByOrchestratorAddressResponse>();
+    registry.register::<QueryPendingSendToEth>();
+    registry.register::<QueryPendingSendToEthResponse>();
+    registry.register::<QueryPendingIbcAutoForwards>();
+    registry.register::<QueryPendingIbcAutoForwardsResponse>();
+}
@@ -0,0 +1,384 @@
+//! Verification of the Ethereum signatures orchestrators submit in MsgValsetConfirm, MsgConfirmBatch and
+//! MsgConfirmLogicCall. Orchestrators personal-sign (EIP-191) the checkpoint of the valset, batch or logic call, a
+//! relayer should only submit to Ethereum once the valid signatures of the current valset's members have more than
+//! Gravity.sol's constant_powerThreshold of its power.
+
+use crate::ethereum::{decode_hex, keccak256, parse_address, EthAddressError, ADDRESS_LEN};
+use crate::gravity::{
+    BridgeValidator, CheckpointError, MsgConfirmBatch, MsgConfirmLogicCall, MsgValsetConfirm,
+    OutgoingLogicCall, OutgoingTxBatch, Valset, CONSTANT_POWER_THRESHOLD, TOTAL_POWER,
+};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use std::fmt;
+
+/// The prefix go-ethereum and Gravity.sol add to a 32 byte hash before signing it
+const SIGNATURE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+/// An error verifying a confirm signature
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signature is not hex encoded
+    InvalidHex(String),
+    /// The signature is not 65 bytes of r || s || v
+    InvalidLength(usize),
+    /// No public key could be recovered from the signature
+    RecoveryFailed,
+    /// The signature was produced by a different address than the one which claims to have signed
+    Mismatch { expected: String, recovered: String },
+    /// The eth_signer of the confirm is not a valid Ethereum address
+    InvalidSigner(EthAddressError),
+    /// The eth_signer of the confirm is not a member of the valset
+    UnknownSigner(String),
+    /// The member already has a valid confirm
+    DuplicateSigner(String),
+    /// The confirm is for a different valset, batch or logic call
+    WrongTarget,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::InvalidHex(s) => write!(f, "signature {s:?} is not hex"),
+            SignatureError::InvalidLength(l) => write!(f, "signature is {l} bytes, expected 65"),
+            SignatureError::RecoveryFailed => {
+                write!(f, "could not recover a key from the signature")
+            }
+            SignatureError::Mismatch {
+                expected,
+                recovered,
+            } => write!(f, "signature is from {recovered}, expected {expected}"),
+            SignatureError::InvalidSigner(e) => write!(f, "invalid eth signer: {e}"),
+            SignatureError::UnknownSigner(s) => write!(f, "{s} is not a member of the valset"),
+            SignatureError::DuplicateSigner(s) => write!(f, "{s} has already signed"),
+            SignatureError::WrongTarget => write!(f, "confirm is for a different checkpoint"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+/// The hash which is actually signed for a checkpoint, keccak256("\x19Ethereum Signed Message:\n32" || checkpoint)
+pub fn ethereum_message_hash(checkpoint: &[u8; 32]) -> [u8; 32] {
+    let mut message = SIGNATURE_PREFIX.to_vec();
+    message.extend_from_slice(checkpoint);
+    keccak256(&message)
+}
+
+/// Recovers the address which signed the checkpoint. The signature is the 65 byte r || s || v, v may be either
+/// 27/28 as Ethereum presents it or 0/1. High-S signatures are accepted as they are by go-ethereum's SigToPub and
+/// ecrecover
+pub fn recover_signer(
+    checkpoint: &[u8; 32],
+    signature: &[u8],
+) -> Result<[u8; ADDRESS_LEN], SignatureError> {
+    if signature.len() != 65 {
+        return Err(SignatureError::InvalidLength(signature.len()));
+    }
+    let v = match signature[64] {
+        27 | 28 => signature[64] - 27,
+        v => v,
+    };
+    let recovery_id = RecoveryId::from_byte(v).ok_or(SignatureError::RecoveryFailed)?;
+    let signature =
+        Signature::from_slice(&signature[..64]).map_err(|_| SignatureError::RecoveryFailed)?;
+    // k256 only recovers from low-S signatures, negating s negates the point R so the y parity flips with it
+    let (signature, recovery_id) = match signature.normalize_s() {
+        Some(normalized) => (
+            normalized,
+            RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+        ),
+        None => (signature, recovery_id),
+    };
+    let key = VerifyingKey::recover_from_prehash(
+        &ethereum_message_hash(checkpoint),
+        &signature,
+        recovery_id,
+    )
+    .map_err(|_| SignatureError::RecoveryFailed)?;
+    // the address is the last 20 bytes of the hash of the uncompressed key without its 0x04 prefix
+    let hash = keccak256(&key.to_encoded_point(false).as_bytes()[1..]);
+    let mut address = [0u8; ADDRESS_LEN];
+    address.copy_from_slice(&hash[12..]);
+    Ok(address)
+}
+
+/// Verifies the hex encoded signature of the checkpoint was produced by the given address
+pub fn verify_signature(
+    checkpoint: &[u8; 32],
+    signature: &str,
+    address: &str,
+) -> Result<(), SignatureError> {
+    let expected = parse_address(address).map_err(SignatureError::InvalidSigner)?;
+    let signature =
+        decode_hex(signature).ok_or_else(|| SignatureError::InvalidHex(signature.to_string()))?;
+    let recovered = recover_signer(checkpoint, &signature)?;
+    if recovered != expected {
+        return Err(SignatureError::Mismatch {
+            expected: crate::ethereum::checksum_address(&expected),
+            recovered: crate::ethereum::checksum_address(&recovered),
+        });
+    }
+    Ok(())
+}
+
+/// The signatures found for a checkpoint
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfirmReport {
+    /// The indexes of the valset members with a valid signature
+    pub signers: Vec<usize>,
+    /// The eth_signer of each confirm which was not counted and why
+    pub rejected: Vec<(String, SignatureError)>,
+    /// The summed power of the signers
+    pub signed_power: u64,
+    /// The summed power of every member of the valset
+    pub total_power: u64,
+}
+
+impl ConfirmReport {
+    /// The power which must be exceeded, Gravity.sol's constant_powerThreshold scaled to the valset's total power
+    /// in case its powers are not normalized
+    pub fn power_threshold(&self) -> u64 {
+        (self.total_power as u128 * CONSTANT_POWER_THRESHOLD as u128 / TOTAL_POWER as u128) as u64
+    }
+
+    /// If the signers have enough power for Gravity.sol to accept the signatures
+    pub fn threshold_met(&self) -> bool {
+        self.signed_power > self.power_threshold()
+    }
+}
+
+/// A confirm message which carries an orchestrator's signature of a checkpoint
+pub trait Confirm {
+    /// The Ethereum address of the orchestrator which signed
+    fn eth_signer(&self) -> &str;
+
+    /// The hex encoded signature
+    fn signature(&self) -> &str;
+}
+
+impl Confirm for MsgValsetConfirm {
+    fn eth_signer(&self) -> &str {
+        &self.eth_address
+    }
+
+    fn signature(&self) -> &str {
+        &self.signature
+    }
+}
+
+impl Confirm for MsgConfirmBatch {
+    fn eth_signer(&self) -> &str {
+        &self.eth_signer
+    }
+
+    fn signature(&self) -> &str {
+        &self.signature
+    }
+}
+
+impl Confirm for MsgConfirmLogicCall {
+    fn eth_signer(&self) -> &str {
+        &self.eth_signer
+    }
+
+    fn signature(&self) -> &str {
+        &self.signature
+    }
+}
+
+/// Checks each confirm's signature of the checkpoint against the members of the valset which must sign it.
+/// Confirms for a different target should be filtered out first, see the check_* functions
+pub fn check_confirms<'a, C: Confirm + 'a>(
+    members: &[BridgeValidator],
+    checkpoint: &[u8; 32],
+    confirms: impl IntoIterator<Item = &'a C>,
+) -> ConfirmReport {
+    let mut report = ConfirmReport {
+        total_power: members.iter().fold(0, |acc, m| acc.saturating_add(m.power)),
+        ..Default::default()
+    };
+    for confirm in confirms {
+        let signer = confirm.eth_signer().to_string();
+        match check_confirm(members, checkpoint, confirm, &report.signers) {
+            Ok(index) => {
+                report.signers.push(index);
+                report.signed_power = report.signed_power.saturating_add(members[index].power);
+            }
+            Err(e) => report.rejected.push((signer, e)),
+        }
+    }
+    report
+}
+
+/// Returns the index of the member which produced the confirm
+fn check_confirm(
+    members: &[BridgeValidator],
+    checkpoint: &[u8; 32],
+    confirm: &impl Confirm,
+    signers: &[usize],
+) -> Result<usize, SignatureError> {
+    let signer = parse_address(confirm.eth_signer()).map_err(SignatureError::InvalidSigner)?;
+    let index = members
+        .iter()
+        .position(|m| parse_address(&m.ethereum_address).ok() == Some(signer))
+        .ok_or_else(|| SignatureError::UnknownSigner(confirm.eth_signer().to_string()))?;
+    if signers.contains(&index) {
+        return Err(SignatureError::DuplicateSigner(
+            confirm.eth_signer().to_string(),
+        ));
+    }
+    verify_signature(checkpoint, confirm.signature(), confirm.eth_signer())?;
+    Ok(index)
+}
+
+/// Checks the confirms of a new valset, which are signed by the members of that same valset
+pub fn check_valset_confirms(
+    valset: &Valset,
+    gravity_id: &str,
+    confirms: &[MsgValsetConfirm],
+) -> Result<ConfirmReport, CheckpointError> {
+    let checkpoint = valset.get_checkpoint(gravity_id)?;
+    let (matching, other): (Vec<_>, Vec<_>) =
+        confirms.iter().partition(|c| c.nonce == valset.nonce);
+    Ok(with_wrong_targets(
+        check_confirms(&valset.members, &checkpoint, matching),
+        other,
+    ))
+}
+
+/// Checks the confirms of a batch, which are signed by the members of the current valset
+pub fn check_batch_confirms(
+    current_valset: &Valset,
+    batch: &OutgoingTxBatch,
+    gravity_id: &str,
+    confirms: &[MsgConfirmBatch],
+) -> Result<ConfirmReport, CheckpointError> {
+    let checkpoint = batch.get_checkpoint(gravity_id)?;
+    let (matching, other): (Vec<_>, Vec<_>) = confirms.iter().partition(|c| {
+        c.nonce == batch.batch_nonce
+            && parse_address(&c.token_contract).ok() == parse_address(&batch.token_contract).ok()
+    });
+    Ok(with_wrong_targets(
+        check_confirms(&current_valset.members, &checkpoint, matching),
+        other,
+    ))
+}
+
+/// Checks the confirms of a logic call, which are signed by the members of the current valset
+pub fn check_logic_call_confirms(
+    current_valset: &Valset,
+    logic_call: &OutgoingLogicCall,
+    gravity_id: &str,
+    confirms: &[MsgConfirmLogicCall],
+) -> Result<ConfirmReport, CheckpointError> {
+    let checkpoint = logic_call.get_checkpoint(gravity_id)?;
+    let (matching, other): (Vec<_>, Vec<_>) = confirms.iter().partition(|c| {
+        c.invalidation_nonce == logic_call.invalidation_nonce
+            && decode_hex(&c.invalidation_id).as_ref() == Some(&logic_call.invalidation_id)
+    });
+    Ok(with_wrong_targets(
+        check_confirms(&current_valset.members, &checkpoint, matching),
+        other,
+    ))
+}
+
+fn with_wrong_targets<C: Confirm>(mut report: ConfirmReport, other: Vec<&C>) -> ConfirmReport {
+    report.rejected.extend(
+        other
+            .into_iter()
+            .map(|c| (c.eth_signer().to_string(), SignatureError::WrongTarget)),
+    );
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+
+    /// The private key of the EIP-155 example transaction
+    const KEY: [u8; 32] = [0x46; 32];
+    const ADDRESS: &str = "0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F";
+    const CHECKPOINT: [u8; 32] = [0x11; 32];
+
+    /// Signs the checkpoint as an orchestrator does, returning r || s || v with v as 27/28
+    fn sign(checkpoint: &[u8; 32]) -> Vec<u8> {
+        let key = SigningKey::from_bytes(&KEY.into()).unwrap();
+        let (signature, recovery_id) = key
+            .sign_prehash_recoverable(&ethereum_message_hash(checkpoint))
+            .unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(27 + recovery_id.to_byte());
+        bytes
+    }
+
+    /// The same signature with s replaced by n - s and the y parity flipped, which is equally valid
+    fn to_high_s(signature: &[u8]) -> Vec<u8> {
+        let low = Signature::from_slice(&signature[..64]).unwrap();
+        let (r, s) = low.split_scalars();
+        let high = Signature::from_scalars(r.to_bytes(), (-*s).to_bytes()).unwrap();
+        assert!(high.normalize_s().is_some());
+        let mut bytes = high.to_bytes().to_vec();
+        bytes.push(27 + ((signature[64] - 27) ^ 1));
+        bytes
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn recovers_from_low_and_high_s_signatures() {
+        let low = sign(&CHECKPOINT);
+        let high = to_high_s(&low);
+        assert_ne!(low, high);
+        let expected = parse_address(ADDRESS).unwrap();
+        assert_eq!(recover_signer(&CHECKPOINT, &low), Ok(expected));
+        assert_eq!(recover_signer(&CHECKPOINT, &high), Ok(expected));
+
+        // v may also be given as 0/1
+        let mut zero_based = high.clone();
+        zero_based[64] -= 27;
+        assert_eq!(recover_signer(&CHECKPOINT, &zero_based), Ok(expected));
+
+        assert_eq!(verify_signature(&CHECKPOINT, &hex(&low), ADDRESS), Ok(()));
+        let prefixed = format!("0x{}", hex(&high));
+        assert_eq!(verify_signature(&CHECKPOINT, &prefixed, ADDRESS), Ok(()));
+    }
+
+    #[test]
+    fn rejects_wrong_signer() {
+        let signature = hex(&sign(&CHECKPOINT));
+        let other = "0xaAaAaAaaAaAaAaaAaAAAAAAAAaaaAaAaAaaAaaAa";
+        assert_eq!(
+            verify_signature(&CHECKPOINT, &signature, other),
+            Err(SignatureError::Mismatch {
+                expected: other.to_string(),
+                recovered: ADDRESS.to_string(),
+            })
+        );
+        // a signature of a different checkpoint recovers some other address
+        assert!(matches!(
+            verify_signature(&[0x22; 32], &signature, ADDRESS),
+            Err(SignatureError::Mismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_v_and_length() {
+        let mut signature = sign(&CHECKPOINT);
+        signature[64] = 29;
+        assert_eq!(
+            recover_signer(&CHECKPOINT, &signature),
+            Err(SignatureError::RecoveryFailed)
+        );
+        assert_eq!(
+            recover_signer(&CHECKPOINT, &signature[..64]),
+            Err(SignatureError::InvalidLength(64))
+        );
+        assert_eq!(
+            verify_signature(&CHECKPOINT, "0xzz", ADDRESS),
+            Err(SignatureError::InvalidHex("0xzz".to_string()))
+        );
+    }
+}
@@ -0,0 +1,183 @@
+/// The power the module scales a valset to when it normalizes the members' staking powers (2^32-1), normalized powers
+/// are each member's share of it
+pub const TOTAL_POWER: u64 = u32::MAX as u64;
+/// Gravity.sol's constant_powerThreshold, the signed power must be strictly greater than this (2/3 of TOTAL_POWER)
+pub const CONSTANT_POWER_THRESHOLD: u64 = 2_863_311_530;
+/// The module creates a new valset once the power of the current validators differs from the latest valset by more
+/// than this fraction of the total power
+pub const SIGNIFICANT_POWER_DIFF: f64 = 0.05;
+
+/// Members are identified by their checksummed address, so that differently cased copies of an address match
+fn member_key(address: &str) -> String {
+    crate::ethereum::normalize_address(address).unwrap_or_else(|_| address.to_string())
+}
+
+impl Valset {
+    /// The summed power of the members
+    pub fn total_power(&self) -> u64 {
+        self.members
+            .iter()
+            .fold(0, |acc, m| acc.saturating_add(m.power))
+    }
+
+    /// Converts the members' staking powers into bridge powers the way the module does when it creates a valset:
+    /// each power becomes power * TOTAL_POWER / total power, rounded down
+    pub fn normalize_powers(&mut self) {
+        let total = self.members.iter().map(|m| m.power as u128).sum::<u128>();
+        if total == 0 {
+            return;
+        }
+        for member in self.members.iter_mut() {
+            member.power = (member.power as u128 * TOTAL_POWER as u128 / total) as u64;
+        }
+    }
+
+    /// Sorts the members in the module's canonical order, by descending power with ties broken by the checksummed
+    /// Ethereum address
+    pub fn sort_members(&mut self) {
+        self.members.sort_by(|a, b| {
+            b.power
+                .cmp(&a.power)
+                .then_with(|| member_key(&a.ethereum_address).cmp(&member_key(&b.ethereum_address)))
+        });
+    }
+
+    /// The fraction of the total bridge power which moved between this valset and another, matching the module's
+    /// PowerDiff. Both valsets should be normalized, members missing from either valset count as having no power
+    pub fn power_diff(&self, other: &Valset) -> f64 {
+        let mut powers: std::collections::HashMap<String, i128> = std::collections::HashMap::new();
+        for member in &self.members {
+            *powers
+                .entry(member_key(&member.ethereum_address))
+                .or_default() = member.power as i128;
+        }
+        for member in &other.members {
+            *powers
+                .entry(member_key(&member.ethereum_address))
+                .or_default() -= member.power as i128;
+        }
+        let delta: f64 = powers.values().map(|v| v.unsigned_abs() as f64).sum();
+        (delta / TOTAL_POWER as f64).abs()
+    }
+
+    /// If the power difference to the latest valset is large enough for the module to create a new valset. The module
+    /// also creates one whenever a validator begins unbonding, which can not be seen from the valsets alone
+    pub fn significant_power_diff(&self, latest: &Valset) -> bool {
+        self.power_diff(latest) > SIGNIFICANT_POWER_DIFF
+    }
+
+    /// The summed power of the members with the given Ethereum addresses, each member is counted at most once and
+    /// addresses which are not members are ignored
+    pub fn signed_power<S: AsRef<str>>(&self, signers: &[S]) -> u64 {
+        let signers: std::collections::HashSet<String> =
+            signers.iter().map(|s| member_key(s.as_ref())).collect();
+        self.members
+            .iter()
+            .filter(|m| signers.contains(&member_key(&m.ethereum_address)))
+            .fold(0, |acc, m| acc.saturating_add(m.power))
+    }
+
+    /// If the members with the given Ethereum addresses have enough power for Gravity.sol to accept their signatures,
+    /// the powers must be normalized as they are in every valset created by the module
+    pub fn power_threshold_met<S: AsRef<str>>(&self, signers: &[S]) -> bool {
+        self.signed_power(signers) > CONSTANT_POWER_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod valset_tests {
+    use super::*;
+
+    const A: &str = "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const B: &str = "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+    const C: &str = "0xcccccccccccccccccccccccccccccccccccccccc";
+
+    fn valset(members: &[(&str, u64)]) -> Valset {
+        Valset {
+            nonce: 1,
+            members: members
+                .iter()
+                .map(|(address, power)| BridgeValidator {
+                    power: *power,
+                    ethereum_address: address.to_string(),
+                })
+                .collect(),
+            height: 1,
+            reward_amount: "0".to_string(),
+            reward_token: String::new(),
+        }
+    }
+
+    fn powers(valset: &Valset) -> Vec<u64> {
+        valset.members.iter().map(|m| m.power).collect()
+    }
+
+    #[test]
+    fn normalize_powers_rounds_down() {
+        let mut set = valset(&[(A, 3), (B, 7)]);
+        set.normalize_powers();
+        // 3 * 4294967295 / 10 = 1288490188.5 and 7 * 4294967295 / 10 = 3006477106.5
+        assert_eq!(powers(&set), vec![1_288_490_188, 3_006_477_106]);
+        assert_eq!(set.total_power(), TOTAL_POWER - 1);
+
+        let mut empty = valset(&[(A, 0), (B, 0)]);
+        empty.normalize_powers();
+        assert_eq!(powers(&empty), vec![0, 0]);
+    }
+
+    #[test]
+    fn sort_members_breaks_ties_on_checksummed_address() {
+        // The checksummed forms are 0xaAaAaAaa.., 0xbBbBBBBb.. and 0xCcCCcccc.., upper case sorts before lower case
+        // so C comes before A and B although it is last in lower case
+        let mut set = valset(&[(A, 5), (B, 10), (C, 5)]);
+        set.sort_members();
+        let order: Vec<&str> = set
+            .members
+            .iter()
+            .map(|m| m.ethereum_address.as_str())
+            .collect();
+        assert_eq!(order, vec![B, C, A]);
+    }
+
+    #[test]
+    fn power_diff_counts_added_and_removed_members() {
+        let current = valset(&[(A, 3_000_000_000), (B, 1_294_967_295)]);
+        assert_eq!(current.power_diff(&current), 0.0);
+
+        // B is removed and C added with the same power
+        let latest = valset(&[(A, 3_000_000_000), (C, 1_294_967_295)]);
+        let expected = 2.0 * 1_294_967_295.0 / TOTAL_POWER as f64;
+        assert_eq!(current.power_diff(&latest), expected);
+        assert_eq!(latest.power_diff(&current), expected);
+        assert!(current.significant_power_diff(&latest));
+
+        // Differently cased addresses are the same member
+        let recased = valset(&[
+            ("0xaAaAaAaaAaAaAaaAaAAAAAAAAaaaAaAaAaaAaaAa", 2_957_051_149),
+            (B, 1_337_916_146),
+        ]);
+        let moved = 2.0 * 42_948_851.0 / TOTAL_POWER as f64;
+        assert_eq!(current.power_diff(&recased), moved);
+        assert!(!current.significant_power_diff(&recased));
+    }
+
+    #[test]
+    fn power_threshold_met_requires_more_than_constant_threshold() {
+        let exact = valset(&[
+            (A, CONSTANT_POWER_THRESHOLD),
+            (B, TOTAL_POWER - CONSTANT_POWER_THRESHOLD),
+        ]);
+        assert_eq!(exact.signed_power(&[A]), 2_863_311_530);
+        assert!(!exact.power_threshold_met(&[A]));
+        assert!(exact.power_threshold_met(&[A, B]));
+
+        let above = valset(&[
+            (A, CONSTANT_POWER_THRESHOLD + 1),
+            (B, TOTAL_POWER - CONSTANT_POWER_THRESHOLD - 1),
+        ]);
+        assert_eq!(above.signed_power(&[A]), 2_863_311_531);
+        assert!(above.power_threshold_met(&[A]));
+        // Counting the same signer twice or non members adds nothing
+        assert!(!exact.power_threshold_met(&[A, A, C]));
+    }
+}
@@ -14,7 +14,7 @@ use std::{
 
 use walkdir::WalkDir;
 
-use crate::{compile_protos, copy_generated_files, RegexReplace, GOOGLE_COMMON_ROOT};
+use crate::{compile_protos, copy_generated_files, prost_config, RegexReplace, GOOGLE_COMMON_ROOT};
 
 pub struct RootDirs {
     pub cosmos: String,
@@ -199,9 +199,7 @@ fn compile_tendermint_protos_and_services(
         .unwrap_or_else(|_| panic!("Failed to create {:?}", tmp_path.to_str()));
 
     // Compile all proto files
-    let mut config = prost_build::Config::default();
-    config.out_dir(tmp_path);
-    config
+    prost_config(tmp_path)
         .compile_protos(&protos, &proto_include_paths)
         .unwrap();
 
@@ -25,18 +25,27 @@ use walkdir::WalkDir;
 pub mod althea;
 pub mod cosmos_sdk;
 pub mod gravity;
+pub mod proto_json;
 
 #[macro_use]
 extern crate log;
 
 /// Attribute preceeding a Tonic client definition
-pub const TONIC_CLIENT_ATTRIBUTE: &str = "#[doc = r\" Generated client implementations.\"]";
+pub const TONIC_CLIENT_ATTRIBUTE: &str = "/// Generated client implementations.";
 /// Attributes to add to gRPC clients
 pub const GRPC_CLIENT_ATTRIBUTES: &[&str] = &[
     "#[cfg(feature = \"grpc\")]",
     "#[cfg_attr(docsrs, doc(cfg(feature = \"grpc\")))]",
     TONIC_CLIENT_ATTRIBUTE,
 ];
+/// Attribute preceeding a Tonic server definition
+pub const TONIC_SERVER_ATTRIBUTE: &str = "/// Generated server implementations.";
+/// Attributes to add to gRPC servers
+pub const GRPC_SERVER_ATTRIBUTES: &[&str] = &[
+    "#[cfg(feature = \"grpc-server\")]",
+    "#[cfg_attr(docsrs, doc(cfg(feature = \"grpc-server\")))]",
+    TONIC_SERVER_ATTRIBUTE,
+];
 /// Root directories of the managed projects
 pub const ALTHEA_ROOT: &str = "../althea-L1/";
 
@@ -81,6 +90,60 @@ pub const IBC_PROTO_CRATE_REPLACE: &str = "cosmos_sdk_proto::ibc";
 pub const IBC_PROTO_REGEX_REPLACE: RegexReplace =
     RegexReplace::new(IBC_PROTO_IMPORT_REGEX, IBC_PROTO_CRATE_REPLACE);
 
+/// A oneof whose generated enum has the same name as a message in the same module, which does not compile.
+/// The enum is renamed after generation, along with the field and attribute referencing it
+pub struct OneofRename {
+    /// The generated file containing the oneof
+    pub file: &'static str,
+    /// The module prost generates for the message containing the oneof
+    pub module: &'static str,
+    /// The name prost gives the oneof enum
+    pub oneof: &'static str,
+    /// The name the enum is given instead
+    pub rename: &'static str,
+}
+/// The cosmos.staking.v1beta1.StakeAuthorization oneof `validators` collides with the message `Validators`
+pub const ONEOF_RENAMES: &[OneofRename] = &[OneofRename {
+    file: "cosmos.staking.v1beta1.rs",
+    module: "stake_authorization",
+    oneof: "Validators",
+    rename: "ValidatorsEnum",
+}];
+
+/// A clippy lint raised by a generated message or oneof, which is allowed on that item only
+pub struct GeneratedLintAllow {
+    /// The fully qualified proto path of the message or oneof
+    pub path: &'static str,
+    /// Whether the path refers to a oneof, whose enum takes the attribute, rather than a message
+    pub oneof: bool,
+    /// The lint to allow
+    pub lint: &'static str,
+}
+/// Lints raised by generated code, these are allowed on the offending items rather than on the whole crate
+pub const GENERATED_LINT_ALLOWS: &[GeneratedLintAllow] = &[
+    // the doc comment of ValidatorHistoricalRewards contains an unindented list
+    GeneratedLintAllow {
+        path: ".cosmos.distribution.v1beta1.ValidatorHistoricalRewards",
+        oneof: false,
+        lint: "clippy::doc_lazy_continuation",
+    },
+    GeneratedLintAllow {
+        path: ".tendermint.abci.Request.value",
+        oneof: true,
+        lint: "clippy::large_enum_variant",
+    },
+    GeneratedLintAllow {
+        path: ".tendermint.types.Evidence.sum",
+        oneof: true,
+        lint: "clippy::large_enum_variant",
+    },
+];
+/// Matches the request router of a tonic server for a service with no methods, which is a match with a single
+/// wildcard arm and triggers clippy::match_single_binding
+pub const EMPTY_SERVICE_ROUTER_REGEX: &str = r"(?m)^( *)(fn call\(&mut self, req: http::Request<B>\) -> Self::Future \{\n *match req\.uri\(\)\.path\(\) \{\n *_ =>)";
+pub const EMPTY_SERVICE_ROUTER_REPLACE: &str =
+    "${1}#[allow(clippy::match_single_binding)]\n${1}${2}";
+
 fn main() {
     env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
     // used by protobuf-src in order to compile protoc on the fly rather than depend on system protoc
@@ -150,17 +213,17 @@ fn compile_protos(
         .unwrap_or_else(|_| panic!("Failed to create {:?}", tmp_path.to_str()));
 
     // Compile all proto files
-    let mut config = prost_build::Config::default();
-    config.out_dir(tmp_path);
-    config.compile_protos(&protos, proto_include_paths).unwrap();
+    prost_config(tmp_path)
+        .compile_protos(&protos, proto_include_paths)
+        .unwrap();
 
-    // Compile all proto client for GRPC services
-    info!("[info ] Compiling proto clients for GRPC services!");
+    // Compile all proto clients and servers for GRPC services
+    info!("[info ] Compiling proto clients and servers for GRPC services!");
     tonic_build::configure()
         .build_client(true)
-        .build_server(false)
+        .build_server(true)
         .out_dir(tmp_path)
-        .compile_protos(&protos, proto_include_paths)
+        .compile_protos_with_config(prost_config(tmp_path), &protos, proto_include_paths)
         .unwrap();
 
     copy_generated_files(
@@ -175,6 +238,26 @@ fn compile_protos(
     info!("[info ] => Done!");
 }
 
+/// Creates the prost-build config shared by every compilation step. The tonic step rewrites the same
+/// files as the prost step, so both must be given this config or the output will differ between them.
+fn prost_config(tmp_path: &Path) -> prost_build::Config {
+    let mut config = prost_build::Config::default();
+    config.out_dir(tmp_path);
+    // Implement prost::Name for every message, Cosmos type urls have no domain (e.g. "/cosmos.bank.v1beta1.MsgSend")
+    // which allows prost_types::Any::from_msg() and Any::to_msg() to be used directly
+    config.enable_type_names();
+    config.type_name_domain(["."], "");
+    for allow in GENERATED_LINT_ALLOWS {
+        let attribute = format!("#[allow({})]", allow.lint);
+        if allow.oneof {
+            config.enum_attribute(allow.path, attribute);
+        } else {
+            config.message_attribute(allow.path, attribute);
+        }
+    }
+    config
+}
+
 fn copy_generated_files(
     from_dir: &Path,
     to_dir: &Path,
@@ -250,9 +333,62 @@ fn copy_and_patch(
             .replace_all(&contents, &replace.replace)
             .to_string();
     }
+    // Rename any oneof enums which collide with a message
+    if let Some(filename) = src.file_name().and_then(OsStr::to_str) {
+        for rename in ONEOF_RENAMES.iter().filter(|r| r.file == filename) {
+            contents = rename_oneof(&contents, rename)?;
+        }
+    }
+    // Allow the single arm match in the servers of services without methods
+    let contents = Regex::new(EMPTY_SERVICE_ROUTER_REGEX)
+        .unwrap()
+        .replace_all(&contents, EMPTY_SERVICE_ROUTER_REPLACE);
     // Patch each service definition with a feature attribute
-    let patched_contents =
-        contents.replace(TONIC_CLIENT_ATTRIBUTE, &GRPC_CLIENT_ATTRIBUTES.join("\n"));
+    let patched_contents = contents
+        .replace(TONIC_CLIENT_ATTRIBUTE, &GRPC_CLIENT_ATTRIBUTES.join("\n"))
+        .replace(TONIC_SERVER_ATTRIBUTE, &GRPC_SERVER_ATTRIBUTES.join("\n"));
+    // Add the serde feature's derives and proto3 JSON field attributes
+    let patched_contents = proto_json::add_serde_support(&patched_contents)?;
 
     fs::write(dest, patched_contents)
 }
+
+/// Applies a OneofRename to the contents of its file. Every generated reference to the oneof enum must be found,
+/// otherwise prost's output has changed and the rename would silently stop working, so an error is returned instead
+fn rename_oneof(contents: &str, rename: &OneofRename) -> io::Result<String> {
+    let OneofRename {
+        file,
+        module,
+        oneof,
+        rename,
+    } = rename;
+    let replacements = [
+        // the prost attribute of the field
+        (
+            format!("oneof = \"{module}::{oneof}\""),
+            format!("oneof = \"{module}::{rename}\""),
+        ),
+        // the type of the field
+        (
+            format!("::core::option::Option<{module}::{oneof}>"),
+            format!("::core::option::Option<{module}::{rename}>"),
+        ),
+        // the enum definition
+        (
+            format!("pub enum {oneof} {{"),
+            format!("pub enum {rename} {{"),
+        ),
+    ];
+
+    let mut contents = contents.to_string();
+    for (from, to) in replacements {
+        if !contents.contains(&from) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Could not rename oneof {module}::{oneof} in {file}, `{from}` not found"),
+            ));
+        }
+        contents = contents.replace(&from, &to);
+    }
+    Ok(contents)
+}
@@ -0,0 +1,265 @@
+//! Adds optional serde support to the generated code, following the proto3 JSON mapping. Messages and oneofs derive
+//! Serialize/Deserialize with camelCase field names, and fields which are not represented in JSON the way serde
+//! would by default (64 bit integers, bytes, enums, Any, Timestamp, Duration) are annotated to use the helpers in
+//! cosmos_sdk_proto::proto_json. Any fields use the `crate::proto_json` modules each crate declares with
+//! cosmos_sdk_proto::any_json_modules, so that an Any may embed the messages of that crate. Every file also gets a
+//! `register_json_types` function adding its messages to an AnyRegistry. Everything added is gated behind the
+//! `serde` feature of the crate.
+
+use std::io;
+
+/// Path of the helper module, cosmos_sdk_proto declares itself as an extern crate so this path works in every crate
+pub const PROTO_JSON_PATH: &str = "cosmos_sdk_proto::proto_json";
+/// Path of the Any modules, which every crate declares itself with the message types its Any fields may contain
+pub const ANY_JSON_PATH: &str = "crate::proto_json";
+/// Derive added to every message and oneof
+pub const SERDE_DERIVE_ATTRIBUTE: &str =
+    "#[cfg_attr(feature = \"serde\", derive(::serde::Serialize, ::serde::Deserialize))]";
+/// Proto3 JSON omits fields with default values, so every message field must have a default
+pub const SERDE_MESSAGE_ATTRIBUTE: &str =
+    "#[cfg_attr(feature = \"serde\", serde(rename_all = \"camelCase\", default))]";
+pub const SERDE_ONEOF_ATTRIBUTE: &str =
+    "#[cfg_attr(feature = \"serde\", serde(rename_all = \"camelCase\"))]";
+
+const MESSAGE_DERIVES: &[&str] = &[
+    "#[derive(Clone, PartialEq, ::prost::Message)]",
+    "#[derive(Clone, Copy, PartialEq, ::prost::Message)]",
+];
+const ONEOF_DERIVES: &[&str] = &[
+    "#[derive(Clone, PartialEq, ::prost::Oneof)]",
+    "#[derive(Clone, Copy, PartialEq, ::prost::Oneof)]",
+];
+const ENUMERATION_DERIVE_SUFFIX: &str = "::prost::Enumeration)]";
+
+/// Patches the contents of a generated file with the serde attributes, Enumeration impls and the function
+/// registering its messages
+pub fn add_serde_support(contents: &str) -> io::Result<String> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let mut out: Vec<String> = Vec::with_capacity(lines.len() * 2);
+    // (indent, enum name) of the last seen enumeration, whose impl block is pending
+    let mut enumeration: Option<(String, String)> = None;
+    // the closing line of the enumeration's inherent impl block, once found
+    let mut enumeration_impl_end: Option<String> = None;
+    // (indent, name) of the modules enclosing the current line, and the paths of the messages seen so far
+    let mut modules: Vec<(String, String)> = Vec::new();
+    let mut messages: Vec<String> = Vec::new();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        if MESSAGE_DERIVES.contains(&trimmed) {
+            out.push(format!("{indent}{SERDE_DERIVE_ATTRIBUTE}"));
+            out.push(format!("{indent}{SERDE_MESSAGE_ATTRIBUTE}"));
+            out.push(line.to_string());
+            // the struct definition follows the remaining attributes
+            let name = following_definition(&lines, i, "pub struct ")?;
+            let path = modules
+                .iter()
+                .map(|(_, module)| format!("{module}::"))
+                .collect::<String>();
+            messages.push(format!("{path}{name}"));
+        } else if ONEOF_DERIVES.contains(&trimmed) {
+            out.push(format!("{indent}{SERDE_DERIVE_ATTRIBUTE}"));
+            out.push(format!("{indent}{SERDE_ONEOF_ATTRIBUTE}"));
+            out.push(line.to_string());
+        } else if trimmed.starts_with("#[derive(") && derive_end(&lines[i..]).1 {
+            // derives with many traits may be broken across multiple lines
+            let (end, _) = derive_end(&lines[i..]);
+            for l in &lines[i..=i + end] {
+                out.push(l.to_string());
+            }
+            i += end;
+            // the enum definition follows the remaining attributes
+            let name = following_definition(&lines, i, "pub enum ")?;
+            enumeration = Some((indent.to_string(), name.to_string()));
+        } else if trimmed.starts_with("#[prost(") {
+            // prost attributes may be broken across multiple lines
+            let mut attribute = trimmed.to_string();
+            while !attribute.ends_with(")]") {
+                out.push(lines[i].to_string());
+                i += 1;
+                let next = lines.get(i).ok_or_else(|| {
+                    invalid_data(format!("Unterminated prost attribute `{attribute}`"))
+                })?;
+                attribute.push_str(next.trim());
+            }
+            out.push(lines[i].to_string());
+            // the field or variant definition is everything up to the next line ending in a comma
+            let mut definition = String::new();
+            for l in &lines[i + 1..] {
+                definition.push_str(l.trim());
+                if l.ends_with(',') {
+                    break;
+                }
+            }
+            if let Some(serde) = field_attribute(&attribute, &definition) {
+                out.push(format!(
+                    "{indent}#[cfg_attr(feature = \"serde\", serde({serde}))]"
+                ));
+            }
+        } else {
+            if let Some(module) = trimmed
+                .strip_prefix("pub mod ")
+                .and_then(|l| l.strip_suffix(" {"))
+            {
+                modules.push((indent.to_string(), module.to_string()));
+            } else if modules
+                .last()
+                .is_some_and(|(module_indent, _)| line == format!("{module_indent}}}"))
+            {
+                modules.pop();
+            }
+            out.push(line.to_string());
+        }
+
+        // Enumeration impls go after the inherent impl prost generates for each enum
+        if let Some((enum_indent, name)) = &enumeration {
+            if line == format!("{enum_indent}impl {name} {{") {
+                enumeration_impl_end = Some(format!("{enum_indent}}}"));
+            } else if Some(line) == enumeration_impl_end.as_deref() {
+                out.extend(enumeration_impl(enum_indent, name));
+                enumeration = None;
+                enumeration_impl_end = None;
+            }
+        }
+        i += 1;
+    }
+
+    out.extend(register_json_types(&messages));
+
+    let mut patched = out.join("\n");
+    if contents.ends_with('\n') {
+        patched.push('\n');
+    }
+    Ok(patched)
+}
+
+/// Returns the name of the struct or enum defined after the attributes starting at lines[start], where prefix is
+/// "pub struct " or "pub enum "
+fn following_definition<'a>(lines: &[&'a str], start: usize, prefix: &str) -> io::Result<&'a str> {
+    lines[start + 1..]
+        .iter()
+        .map(|l| l.trim_start())
+        .find(|l| !l.starts_with("#["))
+        .and_then(|l| l.strip_prefix(prefix))
+        .and_then(|l| l.split(|c: char| !c.is_alphanumeric() && c != '_').next())
+        .ok_or_else(|| {
+            invalid_data(format!(
+                "Expected `{prefix}` after the attributes on line {}",
+                start + 1
+            ))
+        })
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Returns the offset of the last line of the derive attribute starting at lines[0], and whether it derives
+/// prost::Enumeration
+fn derive_end(lines: &[&str]) -> (usize, bool) {
+    let mut derive = String::new();
+    for (offset, line) in lines.iter().enumerate() {
+        derive.push_str(line.trim());
+        if derive.ends_with(")]") {
+            return (offset, derive.ends_with(ENUMERATION_DERIVE_SUFFIX));
+        }
+    }
+    (lines.len() - 1, false)
+}
+
+/// Returns the contents of the serde attribute needed by the field or oneof variant with the given prost attribute
+/// and definition, or None if serde's default behavior matches the proto3 JSON mapping
+fn field_attribute(attribute: &str, definition: &str) -> Option<String> {
+    let with =
+        |module: &str, suffix: &str| format!("with = \"{PROTO_JSON_PATH}::{module}{suffix}\"");
+    // map attributes contain a comma separated key and value type, e.g. `map = "string, uint64"`
+    if let Some(map) = attribute.strip_prefix("#[prost(map = \"") {
+        let value = map.split('"').next()?.split(", ").nth(1)?;
+        return match value {
+            "int64" | "uint64" | "sint64" | "fixed64" | "sfixed64" => Some(with("int64", "_map")),
+            _ => None,
+        };
+    }
+    let args = attribute
+        .trim_start_matches("#[prost(")
+        .trim_end_matches(")]")
+        .split(',')
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    let suffix = if args.contains(&"repeated") {
+        "_vec"
+    } else if args.contains(&"optional") && !args.contains(&"message") {
+        // messages are always optional, the wrapping Option is handled by the message helpers themselves
+        "_opt"
+    } else {
+        ""
+    };
+    let message_suffix = if args.contains(&"repeated") {
+        "_vec"
+    } else if args.contains(&"optional") {
+        "_opt"
+    } else {
+        ""
+    };
+
+    let kind = args[0];
+    match kind {
+        "int64" | "uint64" | "sint64" | "fixed64" | "sfixed64" => Some(with("int64", suffix)),
+        "bytes" | "bytes = \"vec\"" => Some(with("bytes", suffix)),
+        "message" if definition.contains("::prost_types::Any") => {
+            Some(format!("with = \"{ANY_JSON_PATH}::any{message_suffix}\""))
+        }
+        "message"
+            if definition.contains("::prost_types::Timestamp")
+                || definition.contains("::prost_types::Duration") =>
+        {
+            Some(with("string", message_suffix))
+        }
+        _ if kind.starts_with("oneof = ") => Some("flatten".to_string()),
+        _ if kind.starts_with("enumeration = ") => {
+            let enum_path = kind.trim_start_matches("enumeration = ").trim_matches('"');
+            Some(format!(
+                "serialize_with = \"{PROTO_JSON_PATH}::enumeration{suffix}::serialize::<{enum_path}, _>\", \
+                 deserialize_with = \"{PROTO_JSON_PATH}::enumeration{suffix}::deserialize::<{enum_path}, _>\""
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Implements cosmos_sdk_proto::proto_json::Enumeration by forwarding to the methods prost generates
+fn enumeration_impl(indent: &str, name: &str) -> Vec<String> {
+    vec![
+        "#[cfg(feature = \"serde\")]".to_string(),
+        format!("impl {PROTO_JSON_PATH}::Enumeration for {name} {{"),
+        "    fn proto_name(&self) -> &'static str {".to_string(),
+        "        self.as_str_name()".to_string(),
+        "    }".to_string(),
+        "    fn from_proto_name(value: &str) -> ::core::option::Option<Self> {".to_string(),
+        "        Self::from_str_name(value)".to_string(),
+        "    }".to_string(),
+        "}".to_string(),
+    ]
+    .into_iter()
+    .map(|l| format!("{indent}{l}"))
+    .collect()
+}
+
+/// The function adding every message of a file to a cosmos_sdk_proto::proto_json::AnyRegistry
+fn register_json_types(messages: &[String]) -> Vec<String> {
+    let mut lines = vec![
+        "/// Registers the messages of this file for use in Any, see cosmos_sdk_proto::proto_json::AnyRegistry"
+            .to_string(),
+        "#[cfg(feature = \"serde\")]".to_string(),
+        format!("pub fn register_json_types(registry: &mut {PROTO_JSON_PATH}::AnyRegistry) {{"),
+    ];
+    for message in messages {
+        lines.push(format!("    registry.register::<{message}>();"));
+    }
+    lines.push("}".to_string());
+    lines
+}